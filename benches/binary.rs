@@ -0,0 +1,69 @@
+//! Compares the binary encoding of programs with json.
+#![feature(test)]
+
+extern crate test;
+
+use std::hint::black_box;
+use swc::{
+    binary::{decode_program, encode_program},
+    common::FileName,
+    config::{InputSourceMap, JscTarget},
+    ecmascript::ast::Program,
+    Compiler,
+};
+use test::Bencher;
+use testing::Tester;
+
+const SOURCE: &str = include_str!("../ecmascript/codegen/benches/large-partial.js");
+
+fn parse() -> Program {
+    Tester::new()
+        .print_errors(|cm, handler| {
+            let c = Compiler::new(cm.clone(), handler);
+            let fm = cm.new_source_file(FileName::Anon, SOURCE.into());
+            let (program, _) = c
+                .parse_js(
+                    fm,
+                    JscTarget::Es2019,
+                    Default::default(),
+                    true,
+                    false,
+                    &InputSourceMap::Bool(false),
+                )
+                .unwrap();
+            Ok(program)
+        })
+        .unwrap()
+}
+
+#[bench]
+fn encode_binary(b: &mut Bencher) {
+    let program = parse();
+    b.bytes = SOURCE.len() as _;
+
+    b.iter(|| black_box(encode_program(&program).unwrap()));
+}
+
+#[bench]
+fn encode_json(b: &mut Bencher) {
+    let program = parse();
+    b.bytes = SOURCE.len() as _;
+
+    b.iter(|| black_box(serde_json::to_vec(&program).unwrap()));
+}
+
+#[bench]
+fn decode_binary(b: &mut Bencher) {
+    let bytes = encode_program(&parse()).unwrap();
+    b.bytes = SOURCE.len() as _;
+
+    b.iter(|| black_box(decode_program(&bytes).unwrap()));
+}
+
+#[bench]
+fn decode_json(b: &mut Bencher) {
+    let bytes = serde_json::to_vec(&parse()).unwrap();
+    b.bytes = SOURCE.len() as _;
+
+    b.iter(|| black_box(serde_json::from_slice::<Program>(&bytes).unwrap()));
+}
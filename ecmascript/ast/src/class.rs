@@ -59,6 +59,9 @@ pub enum ClassMember {
     PrivateProp(PrivateProp),
     #[tag("TsIndexSignature")]
     TsIndexSignature(TsIndexSignature),
+    /// es2022
+    #[tag("StaticBlock")]
+    StaticBlock(StaticBlock),
 }
 
 macro_rules! property {
@@ -102,6 +105,10 @@ macro_rules! property {
 
             #[serde(default)]
             pub definite: bool,
+
+            /// `accessor` keyword of auto-accessors.
+            #[serde(default)]
+            pub is_accessor: bool,
         }
     };
 }
@@ -163,6 +170,15 @@ pub struct Constructor {
     pub is_optional: bool,
 }
 
+/// `static { ... }`
+#[ast_node("StaticBlock")]
+#[derive(Eq, Hash)]
+pub struct StaticBlock {
+    pub span: Span,
+
+    pub body: BlockStmt,
+}
+
 #[ast_node("Decorator")]
 #[derive(Eq, Hash)]
 pub struct Decorator {
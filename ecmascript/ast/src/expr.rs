@@ -148,6 +148,10 @@ pub enum Expr {
     #[tag("OptionalChainingExpression")]
     OptChain(OptChainExpr),
 
+    /// `obj::func` or `::obj.func`
+    #[tag("BindExpression")]
+    Bind(BindExpr),
+
     #[tag("Invalid")]
     Invalid(Invalid),
 }
@@ -578,6 +582,19 @@ pub struct OptChainExpr {
     pub expr: Box<Expr>,
 }
 
+/// Function bind expression, e.g. `obj::func` or `::obj.func`.
+#[ast_node("BindExpression")]
+#[derive(Eq, Hash)]
+pub struct BindExpr {
+    pub span: Span,
+
+    /// `None` for `::obj.func`.
+    #[serde(default, rename = "object")]
+    pub obj: Option<Box<Expr>>,
+
+    pub callee: Box<Expr>,
+}
+
 test_de!(
     jsx_element,
     JSXElement,
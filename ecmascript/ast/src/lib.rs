@@ -9,14 +9,15 @@
 pub use self::{
     class::{
         Class, ClassMember, ClassMethod, ClassProp, Constructor, Decorator, MethodKind,
-        PrivateMethod, PrivateProp,
+        PrivateMethod, PrivateProp, StaticBlock,
     },
     decl::{ClassDecl, Decl, FnDecl, VarDecl, VarDeclKind, VarDeclarator},
     expr::{
-        ArrayLit, ArrowExpr, AssignExpr, AwaitExpr, BinExpr, BlockStmtOrExpr, CallExpr, ClassExpr,
-        CondExpr, Expr, ExprOrSpread, ExprOrSuper, FnExpr, MemberExpr, MetaPropExpr, NewExpr,
-        ObjectLit, OptChainExpr, ParenExpr, PatOrExpr, PropOrSpread, SeqExpr, SpreadElement, Super,
-        TaggedTpl, ThisExpr, Tpl, TplElement, UnaryExpr, UpdateExpr, YieldExpr,
+        ArrayLit, ArrowExpr, AssignExpr, AwaitExpr, BinExpr, BindExpr, BlockStmtOrExpr, CallExpr,
+        ClassExpr, CondExpr, Expr, ExprOrSpread, ExprOrSuper, FnExpr, MemberExpr, MetaPropExpr,
+        NewExpr, ObjectLit, OptChainExpr, ParenExpr, PatOrExpr, PropOrSpread, SeqExpr,
+        SpreadElement, Super, TaggedTpl, ThisExpr, Tpl, TplElement, UnaryExpr, UpdateExpr,
+        YieldExpr,
     },
     function::{Function, Param, ParamOrTsParamProp},
     ident::{Ident, IdentExt, PrivateName},
@@ -31,8 +32,8 @@ pub use self::{
     module_decl::{
         DefaultDecl, ExportAll, ExportDecl, ExportDefaultDecl, ExportDefaultExpr,
         ExportDefaultSpecifier, ExportNamedSpecifier, ExportNamespaceSpecifier, ExportSpecifier,
-        ImportDecl, ImportDefaultSpecifier, ImportNamedSpecifier, ImportSpecifier,
-        ImportStarAsSpecifier, ModuleDecl, NamedExport,
+        ImportAttributes, ImportAttributesKind, ImportDecl, ImportDefaultSpecifier,
+        ImportNamedSpecifier, ImportSpecifier, ImportStarAsSpecifier, ModuleDecl, NamedExport,
     },
     operators::{AssignOp, BinaryOp, UnaryOp, UpdateOp},
     pat::{
@@ -58,10 +59,10 @@ pub use self::{
         TsModuleName, TsModuleRef, TsNamespaceBody, TsNamespaceDecl, TsNamespaceExportDecl,
         TsNonNullExpr, TsOptionalType, TsParamProp, TsParamPropParam, TsParenthesizedType,
         TsPropertySignature, TsQualifiedName, TsRestType, TsSignatureDecl, TsThisType,
-        TsThisTypeOrIdent, TsTupleType, TsType, TsTypeAliasDecl, TsTypeAnn, TsTypeAssertion,
-        TsTypeCastExpr, TsTypeElement, TsTypeLit, TsTypeOperator, TsTypeOperatorOp, TsTypeParam,
-        TsTypeParamDecl, TsTypeParamInstantiation, TsTypePredicate, TsTypeQuery, TsTypeQueryExpr,
-        TsTypeRef, TsUnionOrIntersectionType, TsUnionType,
+        TsThisTypeOrIdent, TsTplLitType, TsTupleElement, TsTupleType, TsType, TsTypeAliasDecl,
+        TsTypeAnn, TsTypeAssertion, TsTypeCastExpr, TsTypeElement, TsTypeLit, TsTypeOperator,
+        TsTypeOperatorOp, TsTypeParam, TsTypeParamDecl, TsTypeParamInstantiation, TsTypePredicate,
+        TsTypeQuery, TsTypeQueryExpr, TsTypeRef, TsUnionOrIntersectionType, TsUnionType,
     },
 };
 use swc_common::{ast_node, Span};
@@ -1,10 +1,13 @@
 use crate::{
     decl::Decl,
-    expr::{ClassExpr, Expr, FnExpr},
+    expr::{ClassExpr, Expr, FnExpr, ObjectLit},
     ident::Ident,
     lit::Str,
     typescript::{TsExportAssignment, TsImportEqualsDecl, TsInterfaceDecl, TsNamespaceExportDecl},
 };
+use string_enum::StringEnum;
+#[cfg(feature = "fold")]
+use swc_common::Fold;
 use swc_common::{ast_node, Span};
 
 #[ast_node]
@@ -69,6 +72,9 @@ pub struct ImportDecl {
 
     #[serde(rename = "typeOnly")]
     pub type_only: bool,
+
+    #[serde(default, rename = "attributes")]
+    pub attrs: Option<ImportAttributes>,
 }
 
 /// `export * from 'mod'`
@@ -79,6 +85,9 @@ pub struct ExportAll {
 
     #[serde(rename = "source")]
     pub src: Str,
+
+    #[serde(default, rename = "attributes")]
+    pub attrs: Option<ImportAttributes>,
 }
 
 /// `export { foo } from 'mod'`
@@ -95,6 +104,32 @@ pub struct NamedExport {
 
     #[serde(rename = "typeOnly")]
     pub type_only: bool,
+
+    #[serde(default, rename = "attributes")]
+    pub attrs: Option<ImportAttributes>,
+}
+
+/// `with { type: "json" }` or `assert { type: "json" }` after a module
+/// specifier.
+#[ast_node("ImportAttributes")]
+#[derive(Eq, Hash)]
+pub struct ImportAttributes {
+    pub span: Span,
+
+    pub kind: ImportAttributesKind,
+
+    /// Keys are identifiers or strings, and values are string literals.
+    #[serde(rename = "attributes")]
+    pub obj: ObjectLit,
+}
+
+#[derive(StringEnum, Clone, Copy, Eq, PartialEq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "fold", derive(Fold))]
+pub enum ImportAttributesKind {
+    /// `with`
+    With,
+    /// `assert`
+    Assert,
 }
 
 #[ast_node("ExportDefaultDeclaration")]
@@ -157,6 +192,10 @@ pub struct ImportNamedSpecifier {
 
     #[serde(default)]
     pub imported: Option<Ident>,
+
+    /// Flow: `import { type Foo } from 'mod.js'`
+    #[serde(default)]
+    pub is_type_only: bool,
 }
 
 #[ast_node]
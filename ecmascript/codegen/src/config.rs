@@ -1,4 +1,89 @@
+use serde::{Deserialize, Serialize};
+use swc_ecma_parser::JscTarget;
+
 #[derive(Debug, Default, Clone, Copy)]
 pub struct Config {
     pub minify: bool,
+
+    /// Print non-ASCII characters in strings, templates, regular expressions
+    /// and identifiers as escape sequences.
+    pub ascii_only: bool,
+
+    /// Quote used for string literals, unless the other one requires fewer
+    /// escapes.
+    pub quote_style: QuoteStyle,
+
+    /// Print numbers and strings as written in the input, if possible.
+    pub keep_raw: bool,
+
+    /// The emitter does not print syntax newer than this, like `\u{...}`
+    /// escapes.
+    pub target: JscTarget,
+
+    /// Enables the formatting mode. This should be used with
+    /// [FormatWriter](crate::text_writer::FormatWriter).
+    pub format: Option<FormatConfig>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum QuoteStyle {
+    Single,
+    Double,
+}
+
+impl Default for QuoteStyle {
+    fn default() -> Self {
+        QuoteStyle::Single
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+pub struct FormatConfig {
+    /// Groups longer than this are broken into multiple lines.
+    #[serde(default = "default_print_width")]
+    pub print_width: usize,
+
+    /// Number of spaces per indentation level.
+    #[serde(default = "default_indent_width")]
+    pub indent_width: usize,
+
+    #[serde(default)]
+    pub trailing_comma: TrailingComma,
+}
+
+impl Default for FormatConfig {
+    fn default() -> Self {
+        FormatConfig {
+            print_width: default_print_width(),
+            indent_width: default_indent_width(),
+            trailing_comma: Default::default(),
+        }
+    }
+}
+
+fn default_print_width() -> usize {
+    80
+}
+
+fn default_indent_width() -> usize {
+    4
+}
+
+/// Trailing commas added to lists broken into multiple lines.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum TrailingComma {
+    None,
+    /// Array and object literals, and named imports and exports.
+    Es5,
+    /// Also arguments of calls, if the target is es2017 or newer.
+    All,
+}
+
+impl Default for TrailingComma {
+    fn default() -> Self {
+        TrailingComma::Es5
+    }
 }
@@ -49,6 +49,9 @@ mod tests {
         assert_min("a <<= 10", "a<<=10;");
         assert_min("a >>= 10", "a>>=10;");
         assert_min("a >>>= 10", "a>>>=10;");
+        assert_min("a &&= 10", "a&&=10;");
+        assert_min("a ||= 10", "a||=10;");
+        assert_min("a ??= 10", "a??=10;");
         assert_min("2 + 2", "2+2;");
         assert_min("2 - 2", "2-2;");
         assert_min("2 * 2", "2*2;");
@@ -1,7 +1,7 @@
 #![recursion_limit = "1024"]
 #![allow(unused_variables)]
 
-pub use self::config::Config;
+pub use self::config::{Config, FormatConfig, QuoteStyle, TrailingComma};
 use self::{
     list::ListFormat,
     text_writer::WriteJs,
@@ -12,6 +12,7 @@ use swc_atoms::JsWord;
 use swc_common::{comments::Comments, BytePos, SourceMap, Span, Spanned, SyntaxContext, DUMMY_SP};
 use swc_ecma_ast::*;
 use swc_ecma_codegen_macros::emitter;
+use swc_ecma_parser::JscTarget;
 
 #[macro_use]
 pub mod macros;
@@ -37,18 +38,31 @@ pub trait Handlers {
 
 pub trait Node: Spanned {
     fn emit_with(&self, e: &mut Emitter<'_>) -> Result;
+
+    /// Returns true for elisions of array literals and patterns.
+    fn is_hole(&self) -> bool {
+        false
+    }
 }
 impl<N: Node> Node for Box<N> {
     #[inline(always)]
     fn emit_with(&self, e: &mut Emitter<'_>) -> Result {
         (**self).emit_with(e)
     }
+
+    fn is_hole(&self) -> bool {
+        (**self).is_hole()
+    }
 }
 impl<'a, N: Node> Node for &'a N {
     #[inline(always)]
     fn emit_with(&self, e: &mut Emitter<'_>) -> Result {
         (**self).emit_with(e)
     }
+
+    fn is_hole(&self) -> bool {
+        (**self).is_hole()
+    }
 }
 
 pub struct Emitter<'a> {
@@ -75,8 +89,13 @@ impl<'a> Emitter<'a> {
             self.wr.write_str_lit(DUMMY_SP, &*shebang)?;
             self.wr.write_line()?;
         }
+        let mut prev: Option<Span> = None;
         for stmt in &node.body {
+            if let Some(prev) = prev {
+                self.emit_blank_line_between(prev, stmt.span())?;
+            }
             emit!(stmt);
+            prev = Some(stmt.span());
         }
     }
 
@@ -87,8 +106,13 @@ impl<'a> Emitter<'a> {
             self.wr.write_str_lit(DUMMY_SP, &*shebang)?;
             self.wr.write_line()?;
         }
+        let mut prev: Option<Span> = None;
         for stmt in &node.body {
+            if let Some(prev) = prev {
+                self.emit_blank_line_between(prev, stmt.span())?;
+            }
             emit!(stmt);
+            prev = Some(stmt.span());
         }
     }
 
@@ -213,11 +237,28 @@ impl<'a> Emitter<'a> {
 
         formatting_space!();
         emit!(node.src);
+        emit!(node.attrs);
         semi!();
     }
 
+    #[emitter]
+    fn emit_import_attributes(&mut self, node: &ImportAttributes) -> Result {
+        formatting_space!();
+        match node.kind {
+            ImportAttributesKind::With => keyword!("with"),
+            ImportAttributesKind::Assert => keyword!("assert"),
+        }
+        formatting_space!();
+        emit!(node.obj);
+    }
+
     #[emitter]
     fn emit_import_specific(&mut self, node: &ImportNamedSpecifier) -> Result {
+        if node.is_type_only {
+            keyword!("type");
+            space!();
+        }
+
         if let Some(ref imported) = node.imported {
             emit!(imported);
             space!();
@@ -274,6 +315,7 @@ impl<'a> Emitter<'a> {
             space!();
             keyword!("from");
             emit!(src);
+            emit!(node.attrs);
             semi!();
         }
     }
@@ -289,6 +331,7 @@ impl<'a> Emitter<'a> {
         keyword!("from");
         space!();
         emit!(node.src);
+        emit!(node.attrs);
         semi!();
     }
 
@@ -310,7 +353,13 @@ impl<'a> Emitter<'a> {
             Lit::Num(ref n) => emit!(n),
             Lit::Regex(ref n) => {
                 punct!("/");
-                self.wr.write_str(&n.exp)?;
+                if self.cfg.ascii_only {
+                    // `\u{...}` is only valid in unicode mode.
+                    let exp = escape_non_ascii(&n.exp, n.flags.contains('u'));
+                    self.wr.write_str(&exp)?;
+                } else {
+                    self.wr.write_str(&n.exp)?;
+                }
                 punct!("/");
                 self.wr.write_str(&n.flags)?;
             }
@@ -328,26 +377,35 @@ impl<'a> Emitter<'a> {
     fn emit_str_lit(&mut self, node: &Str) -> Result {
         self.emit_leading_comments_of_pos(node.span().lo())?;
 
-        // if let Some(s) = get_text_of_node(&self.cm, node, false) {
-        //     self.wr.write_str_lit(node.span, &s)?;
-        //     return Ok(());
-        // }
-        let value = escape(&node.value);
-        // let value = node.value.replace("\n", "\\n");
+        if self.cfg.keep_raw {
+            if let Some(raw) = self.raw_str(node) {
+                self.wr.write_str_lit(node.span, &raw)?;
+                return Ok(());
+            }
+        }
 
-        if !node.value.contains('\'') {
-            punct!("'");
-            self.wr.write_str_lit(node.span, &value)?;
-            punct!("'");
-        } else if !node.value.contains('\"') {
-            punct!("\"");
-            self.wr.write_str_lit(node.span, &value)?;
-            punct!("\"");
-        } else {
+        let mut value = escape(&node.value).into_owned();
+        if self.cfg.ascii_only {
+            value = escape_non_ascii(&value, self.cfg.target >= JscTarget::Es2015).into_owned();
+        }
+
+        let single = node.value.matches('\'').count();
+        let double = node.value.matches('"').count();
+        let use_single = match self.cfg.quote_style {
+            QuoteStyle::Single => single <= double,
+            QuoteStyle::Double => single < double,
+        };
+
+        if use_single {
             punct!("'");
             self.wr
                 .write_str_lit(node.span, &value.replace("'", "\\'"))?;
             punct!("'");
+        } else {
+            punct!("\"");
+            self.wr
+                .write_str_lit(node.span, &value.replace("\"", "\\\""))?;
+            punct!("\"");
         }
     }
 
@@ -355,6 +413,13 @@ impl<'a> Emitter<'a> {
     fn emit_num_lit(&mut self, num: &Number) -> Result {
         self.emit_leading_comments_of_pos(num.span().lo())?;
 
+        if self.cfg.keep_raw {
+            if let Some(raw) = self.raw_num(num) {
+                self.wr.write_str_lit(num.span, &raw)?;
+                return Ok(());
+            }
+        }
+
         // Handle infinity
         if num.value.is_infinite() {
             if num.value.is_sign_negative() {
@@ -454,6 +519,7 @@ impl<'a> Emitter<'a> {
             Expr::TsConstAssertion(ref n) => emit!(n),
             Expr::TsTypeCast(ref n) => emit!(n),
             Expr::OptChain(ref n) => emit!(n),
+            Expr::Bind(ref n) => emit!(n),
             Expr::Invalid(ref n) => emit!(n),
         }
     }
@@ -487,6 +553,15 @@ impl<'a> Emitter<'a> {
         }
     }
 
+    #[emitter]
+    fn emit_bind_expr(&mut self, n: &BindExpr) -> Result {
+        self.emit_leading_comments_of_pos(n.span().lo())?;
+
+        emit!(n.obj);
+        self.wr.write_operator("::")?;
+        emit!(n.callee);
+    }
+
     #[emitter]
     fn emit_invalid(&mut self, n: &Invalid) -> Result {
         self.emit_leading_comments_of_pos(n.span.lo())?;
@@ -722,9 +797,19 @@ impl<'a> Emitter<'a> {
             ClassMember::PrivateMethod(ref n) => emit!(n),
             ClassMember::PrivateProp(ref n) => emit!(n),
             ClassMember::TsIndexSignature(ref n) => emit!(n),
+            ClassMember::StaticBlock(ref n) => emit!(n),
         }
     }
 
+    #[emitter]
+    fn emit_static_block(&mut self, n: &StaticBlock) -> Result {
+        self.emit_leading_comments_of_pos(n.span().lo())?;
+
+        keyword!("static");
+        formatting_space!();
+        emit!(n.body);
+    }
+
     #[emitter]
     fn emit_private_method(&mut self, n: &PrivateMethod) -> Result {
         self.emit_leading_comments_of_pos(n.span().lo())?;
@@ -835,7 +920,42 @@ impl<'a> Emitter<'a> {
     fn emit_private_prop(&mut self, n: &PrivateProp) -> Result {
         self.emit_leading_comments_of_pos(n.span().lo())?;
 
-        unimplemented!("emit_private_prop")
+        if n.accessibility != Some(Accessibility::Public) {
+            self.emit_accesibility(n.accessibility)?;
+        }
+
+        if n.readonly {
+            keyword!("readonly");
+            space!()
+        }
+
+        if n.is_static {
+            keyword!("static");
+            space!();
+        }
+
+        if n.is_accessor {
+            keyword!("accessor");
+            space!();
+        }
+
+        emit!(n.key);
+
+        if let Some(ty) = &n.type_ann {
+            punct!(":");
+            space!();
+            emit!(ty);
+        }
+
+        if let Some(v) = &n.value {
+            formatting_space!();
+            punct!("=");
+            formatting_space!();
+
+            emit!(v);
+        }
+
+        semi!();
     }
 
     #[emitter]
@@ -856,6 +976,11 @@ impl<'a> Emitter<'a> {
             space!();
         }
 
+        if n.is_accessor {
+            keyword!("accessor");
+            space!();
+        }
+
         if n.computed {
             punct!("[");
             emit!(n.key);
@@ -1058,8 +1183,13 @@ impl<'a> Emitter<'a> {
 
     #[emitter]
     fn emit_quasi(&mut self, node: &TplElement) -> Result {
-        self.wr
-            .write_str_lit(node.span, &unescape(&node.raw.value))?;
+        let raw = unescape(&node.raw.value);
+        if self.cfg.ascii_only {
+            self.wr
+                .write_str_lit(node.span, &escape_non_ascii(&raw, true))?;
+        } else {
+            self.wr.write_str_lit(node.span, &raw)?;
+        }
         return Ok(());
     }
 
@@ -1169,7 +1299,9 @@ impl<'a> Emitter<'a> {
         self.emit_leading_comments_of_pos(node.span().lo())?;
 
         punct!("{");
-        if !self.cfg.minify {
+        // Line breaks of the formatting mode are written by `emit_list`.
+        let write_lines = !self.cfg.minify && self.cfg.format.is_none();
+        if write_lines {
             self.wr.write_line()?;
         }
         self.emit_list(
@@ -1177,7 +1309,7 @@ impl<'a> Emitter<'a> {
             Some(&node.props),
             ListFormat::ObjectLiteralExpressionProperties,
         )?;
-        if !self.cfg.minify {
+        if write_lines {
             self.wr.write_line()?;
         }
         punct!("}");
@@ -1294,7 +1426,12 @@ impl<'a> Emitter<'a> {
             unimplemented!()
         } else {
             // TODO: span
-            self.wr.write_symbol(ident.span, &ident.sym)?;
+            if self.cfg.ascii_only {
+                self.wr
+                    .write_symbol(ident.span, &escape_non_ascii(&ident.sym, true))?;
+            } else {
+                self.wr.write_symbol(ident.span, &ident.sym)?;
+            }
 
             if let Some(ty) = &ident.type_ann {
                 punct!(":");
@@ -1341,6 +1478,13 @@ impl<'a> Emitter<'a> {
             return Ok(());
         }
 
+        if let Some(format_cfg) = self.format_cfg() {
+            if format.contains(ListFormat::Breakable) {
+                let children = children.map(|c| &c[start..start + count]).unwrap_or(&[]);
+                return self.emit_breakable_list(parent_node, children, format, format_cfg);
+            }
+        }
+
         let is_empty = children.is_none() || start > children.unwrap().len() || count == 0;
         if is_empty && format.contains(ListFormat::OptionalIfEmpty) {
             // self.handlers.onBeforeEmitNodeArray(children)
@@ -1447,6 +1591,7 @@ impl<'a> Emitter<'a> {
                         if !self.cfg.minify {
                             self.wr.write_line()?;
                         }
+                        self.emit_blank_line_between(previous_sibling, child.span())?;
                         should_emit_intervening_comments = false;
                     } else if format.contains(ListFormat::SpaceBetweenSiblings) {
                         formatting_space!(self);
@@ -1551,6 +1696,133 @@ impl<'a> Emitter<'a> {
     }
 }
 
+/// Formatting mode
+impl<'a> Emitter<'a> {
+    fn format_cfg(&self) -> Option<config::FormatConfig> {
+        if self.cfg.minify {
+            return None;
+        }
+        self.cfg.format
+    }
+
+    /// Emits a comma separated list as a group, which is broken into one line
+    /// per item if it does not fit in a line.
+    fn emit_breakable_list<N: Node>(
+        &mut self,
+        parent_node: Span,
+        children: &[N],
+        format: ListFormat,
+        format_cfg: config::FormatConfig,
+    ) -> Result {
+        if children.is_empty() {
+            return Ok(());
+        }
+
+        let flat = if format.contains(ListFormat::SpaceBetweenBraces) {
+            " "
+        } else {
+            ""
+        };
+        let may_emit_intervening_comments = !format.intersects(ListFormat::NoInterveningComments);
+
+        self.wr.begin_group()?;
+        self.wr.increase_indent()?;
+
+        // Object literals written on multiple lines stay expanded, like
+        // prettier.
+        let first = children[0].span();
+        if format.contains(ListFormat::Braces)
+            && !parent_node.is_dummy()
+            && !first.is_dummy()
+            && !self.cm.is_on_same_line(parent_node.lo(), first.lo())
+        {
+            self.wr.write_line()?;
+        } else {
+            self.wr.write_soft_line(flat)?;
+        }
+
+        let mut previous_sibling: Option<Span> = None;
+        for child in children {
+            if let Some(previous_sibling) = previous_sibling {
+                if previous_sibling.hi() != parent_node.hi() {
+                    self.emit_leading_comments_of_pos(previous_sibling.hi())?;
+                }
+                self.wr.write_punct(",")?;
+                self.wr.write_soft_line(" ")?;
+            }
+
+            child.emit_with(self)?;
+
+            if may_emit_intervening_comments {
+                self.emit_trailing_comments_of_pos(child.comment_range().hi(), false)?;
+            }
+            previous_sibling = Some(child.span());
+        }
+
+        if let Some(previous_sibling) = previous_sibling {
+            if previous_sibling.hi() != parent_node.hi() {
+                self.emit_leading_comments_of_pos(previous_sibling.hi())?;
+            }
+        }
+
+        if children[children.len() - 1].is_hole() {
+            // `[a, ,]` has two elements.
+            self.wr.write_punct(",")?;
+        } else if self.allows_trailing_comma(format, format_cfg) {
+            self.wr.write_if_break(",")?;
+        }
+
+        self.wr.decrease_indent()?;
+        self.wr.write_soft_line(flat)?;
+        self.wr.end_group()?;
+
+        Ok(())
+    }
+
+    fn allows_trailing_comma(&self, format: ListFormat, format_cfg: config::FormatConfig) -> bool {
+        let es5 =
+            format.contains(ListFormat::TrailingCommaEs5) && self.cfg.target >= JscTarget::Es5;
+        let es2017 = format.contains(ListFormat::TrailingCommaEs2017)
+            && self.cfg.target >= JscTarget::Es2017;
+
+        match format_cfg.trailing_comma {
+            config::TrailingComma::None => false,
+            config::TrailingComma::Es5 => es5,
+            config::TrailingComma::All => es5 || es2017,
+        }
+    }
+
+    /// Preserves a blank line between two statements or members in the
+    /// formatting mode.
+    fn emit_blank_line_between(&mut self, prev: Span, next: Span) -> Result {
+        if self.format_cfg().is_none()
+            || prev.is_dummy()
+            || next.is_dummy()
+            || prev.hi() > next.lo()
+        {
+            return Ok(());
+        }
+
+        let gap = match self
+            .cm
+            .span_to_snippet(Span::new(prev.hi(), next.lo(), Default::default()))
+        {
+            Ok(gap) => gap,
+            Err(_) => return Ok(()),
+        };
+        let lines: Vec<_> = gap.split('\n').collect();
+        if lines.len() > 2
+            && lines[1..lines.len() - 1]
+                .iter()
+                .any(|l| l.trim().is_empty())
+        {
+            self.wr.write_blank_line()?;
+        }
+
+        Ok(())
+    }
+}
+
 /// Patterns
 impl<'a> Emitter<'a> {
     #[emitter]
@@ -2092,9 +2364,64 @@ impl<'a> Emitter<'a> {
             VarDeclOrExpr::VarDecl(ref node) => emit!(node),
         }
     }
+
+    /// Returns the original text of a string literal if it can be printed
+    /// as-is.
+    fn raw_str(&self, node: &Str) -> Option<String> {
+        let raw = get_text_of_node(&self.cm, node, false)?;
+        let quote = raw.chars().next()?;
+        if raw.len() < 2 || (quote != '\'' && quote != '"') || !raw.ends_with(quote) {
+            return None;
+        }
+        if self.cfg.ascii_only && !raw.is_ascii() {
+            return None;
+        }
+        if self.cfg.target < JscTarget::Es2015 && raw.contains("\\u{") {
+            return None;
+        }
+        if self.cfg.target < JscTarget::Es2019 && raw.contains(&['\u{2028}', '\u{2029}'][..]) {
+            return None;
+        }
+
+        // Transforms may change the value without touching the span.
+        if unescape_str(&raw[1..raw.len() - 1])? != *node.value {
+            return None;
+        }
+
+        Some(raw)
+    }
+
+    /// Returns the original text of a numeric literal if it can be printed
+    /// as-is.
+    fn raw_num(&self, num: &Number) -> Option<String> {
+        let raw = get_text_of_node(&self.cm, num, false)?;
+        // Numeric separators
+        if raw.contains('_') {
+            return None;
+        }
+
+        let lower = raw.to_ascii_lowercase();
+        let value = if lower.starts_with("0x") {
+            u64::from_str_radix(&lower[2..], 16).ok()? as f64
+        } else if lower.starts_with("0b") || lower.starts_with("0o") {
+            if self.cfg.target < JscTarget::Es2015 {
+                return None;
+            }
+            let radix = if lower.starts_with("0b") { 2 } else { 8 };
+            u64::from_str_radix(&lower[2..], radix).ok()? as f64
+        } else {
+            // Legacy octal literals are rejected as `017` parses as `17`.
+            lower.parse::<f64>().ok()?
+        };
+
+        if value != num.value {
+            return None;
+        }
+
+        Some(raw)
+    }
 }
 
-#[allow(dead_code)]
 fn get_text_of_node<T: Spanned>(
     cm: &Arc<SourceMap>,
     node: &T,
@@ -2106,7 +2433,7 @@ fn get_text_of_node<T: Spanned>(
         return None;
     }
 
-    let s = cm.span_to_snippet(span).unwrap();
+    let s = cm.span_to_snippet(span).ok()?;
     if s == "" {
         return None;
     }
@@ -2174,6 +2501,10 @@ where
             None => Ok(()),
         }
     }
+
+    fn is_hole(&self) -> bool {
+        self.is_none()
+    }
 }
 
 fn unescape(s: &str) -> String {
@@ -2310,6 +2641,93 @@ fn escape(s: &str) -> Cow<str> {
             .replace("\07", "\\x007")
             .replace("\08", "\\x008")
             .replace("\09", "\\x009")
-            .replace("\0", "\\0"),
+            .replace("\0", "\\0")
+            .replace('\u{2028}', "\\u2028")
+            .replace('\u{2029}', "\\u2029"),
     )
 }
+
+/// Escapes non-ASCII characters of `s` as `\uXXXX`.
+///
+/// Characters outside of the BMP are printed as `\u{XXXXX}` if
+/// `code_point_escape` is true and as surrogate pairs otherwise.
+fn escape_non_ascii(s: &str, code_point_escape: bool) -> Cow<str> {
+    if s.is_ascii() {
+        return Cow::Borrowed(s);
+    }
+
+    let mut buf = String::with_capacity(s.len() + 8);
+    for c in s.chars() {
+        if c.is_ascii() {
+            buf.push(c);
+        } else if (c as u32) <= 0xffff {
+            write!(buf, "\\u{:04x}", c as u32).unwrap();
+        } else if code_point_escape {
+            write!(buf, "\\u{{{:x}}}", c as u32).unwrap();
+        } else {
+            let mut units = [0; 2];
+            for unit in c.encode_utf16(&mut units) {
+                write!(buf, "\\u{:04x}", unit).unwrap();
+            }
+        }
+    }
+    Cow::Owned(buf)
+}
+
+/// Decodes the escape sequences of a string literal.
+///
+/// Returns `None` for legacy octal escapes and lone surrogates.
+fn unescape_str(s: &str) -> Option<String> {
+    let mut buf = String::with_capacity(s.len());
+    let mut chars = s.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            buf.push(c);
+            continue;
+        }
+
+        match chars.next()? {
+            'b' => buf.push('\u{0008}'),
+            'f' => buf.push('\u{000C}'),
+            'n' => buf.push('\n'),
+            'r' => buf.push('\r'),
+            't' => buf.push('\t'),
+            'v' => buf.push('\u{000B}'),
+            '0' if !chars.peek().map_or(false, |c| c.is_ascii_digit()) => buf.push('\0'),
+            '0'..='9' => return None,
+            'x' => {
+                let hex: String = chars.by_ref().take(2).collect();
+                buf.push(char::from_u32(parse_hex(&hex)?)?);
+            }
+            'u' => {
+                let code = if chars.peek() == Some(&'{') {
+                    chars.next();
+                    let hex: String = chars.by_ref().take_while(|&c| c != '}').collect();
+                    parse_hex(&hex)?
+                } else {
+                    let hex: String = chars.by_ref().take(4).collect();
+                    parse_hex(&hex)?
+                };
+                buf.push(char::from_u32(code)?);
+            }
+            // Line continuations
+            '\r' => {
+                if chars.peek() == Some(&'\n') {
+                    chars.next();
+                }
+            }
+            '\n' | '\u{2028}' | '\u{2029}' => {}
+            c => buf.push(c),
+        }
+    }
+
+    Some(buf)
+}
+
+fn parse_hex(s: &str) -> Option<u32> {
+    if s.is_empty() || !s.chars().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+    u32::from_str_radix(s, 16).ok()
+}
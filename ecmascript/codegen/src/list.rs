@@ -79,6 +79,15 @@ add_bitflags!(
         NoSpaceIfEmpty: 1 << 18,
         SingleElement: 1 << 19,
     },
+    /// Formatting mode
+    Values {
+        /// The list may be broken into multiple lines.
+        Breakable: 1 << 20,
+        /// A trailing comma is allowed since es5.
+        TrailingCommaEs5: 1 << 21,
+        /// A trailing comma is allowed since es2017.
+        TrailingCommaEs2017: 1 << 22,
+    },
     /// Precomputed Formats
     Values {
         Modifiers: SingleLine | SpaceBetweenSiblings | NoInterveningComments,
@@ -88,7 +97,11 @@ add_bitflags!(
             | SpaceBetweenSiblings
             | Indented,
         MultiLineTypeLiteralMembers: MultiLine | Indented,
-        TupleTypeElements: CommaDelimited | SpaceBetweenSiblings | SingleLine | Indented,
+        TupleTypeElements: CommaDelimited
+            | SpaceBetweenSiblings
+            | SingleLine
+            | Indented
+            | Breakable,
         UnionTypeConstituents: BarDelimited | SpaceBetweenSiblings | SingleLine,
         IntersectionTypeConstituents: AmpersandDelimited | SpaceBetweenSiblings | SingleLine,
         ObjectBindingPatternElements: SingleLine
@@ -96,12 +109,14 @@ add_bitflags!(
             | SpaceBetweenBraces
             | CommaDelimited
             | SpaceBetweenSiblings
-            | NoSpaceIfEmpty,
+            | NoSpaceIfEmpty
+            | Breakable,
         ArrayBindingPatternElements: SingleLine
             | AllowTrailingComma
             | CommaDelimited
             | SpaceBetweenSiblings
-            | NoSpaceIfEmpty,
+            | NoSpaceIfEmpty
+            | Breakable,
         // ObjectLiteralExpressionProperties: PreserveLines
         //     | CommaDelimited
         //     | SpaceBetweenSiblings
@@ -115,20 +130,31 @@ add_bitflags!(
             | SpaceBetweenBraces
             | Indented
             | Braces
-            | NoSpaceIfEmpty,
+            | NoSpaceIfEmpty
+            | Breakable
+            | TrailingCommaEs5,
         ArrayLiteralExpressionElements: PreserveLines
             | CommaDelimited
             | SpaceBetweenSiblings
             | AllowTrailingComma
             | Indented
-            | SquareBrackets,
-        CommaListElements: CommaDelimited | SpaceBetweenSiblings | SingleLine,
-        CallExpressionArguments: CommaDelimited | SpaceBetweenSiblings | SingleLine | Parenthesis,
+            | SquareBrackets
+            | Breakable
+            | TrailingCommaEs5,
+        CommaListElements: CommaDelimited | SpaceBetweenSiblings | SingleLine | Breakable,
+        CallExpressionArguments: CommaDelimited
+            | SpaceBetweenSiblings
+            | SingleLine
+            | Parenthesis
+            | Breakable
+            | TrailingCommaEs2017,
         NewExpressionArguments: CommaDelimited
             | SpaceBetweenSiblings
             | SingleLine
             | Parenthesis
-            | OptionalIfUndefined,
+            | OptionalIfUndefined
+            | Breakable
+            | TrailingCommaEs2017,
         TemplateExpressionSpans: SingleLine | NoInterveningComments,
         SingleLineBlockStatements: SpaceBetweenBraces | SpaceBetweenSiblings | SingleLine,
         MultiLineBlockStatements: Indented | MultiLine,
@@ -144,7 +170,9 @@ add_bitflags!(
             | SpaceBetweenSiblings
             | AllowTrailingComma
             | SingleLine
-            | SpaceBetweenBraces,
+            | SpaceBetweenBraces
+            | Breakable
+            | TrailingCommaEs5,
         JsxElementOrFragmentChildren: SingleLine | NoInterveningComments,
         JsxElementAttributes: SingleLine | SpaceBetweenSiblings | NoInterveningComments,
         CaseOrDefaultClauseStatements: Indented | MultiLine | NoTrailingNewLine | OptionalIfEmpty,
@@ -155,13 +183,15 @@ add_bitflags!(
             | SpaceBetweenSiblings
             | SingleLine
             | AngleBrackets
-            | Optional,
+            | Optional
+            | Breakable,
         TypeParameters: CommaDelimited
             | SpaceBetweenSiblings
             | SingleLine
             | AngleBrackets
-            | Optional,
-        Parameters: CommaDelimited | SpaceBetweenSiblings | SingleLine | Parenthesis,
+            | Optional
+            | Breakable,
+        Parameters: CommaDelimited | SpaceBetweenSiblings | SingleLine | Parenthesis | Breakable,
         IndexSignatureParameters: CommaDelimited
             | SpaceBetweenSiblings
             | SingleLine
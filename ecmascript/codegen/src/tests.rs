@@ -1,6 +1,6 @@
 use self::swc_ecma_parser::{Parser, Session, SourceFileInput, Syntax};
 use super::*;
-use crate::config::Config;
+use crate::config::{Config, FormatConfig, TrailingComma};
 use std::{
     fmt::{self, Debug, Display, Formatter},
     io::Write,
@@ -26,12 +26,16 @@ impl Builder {
         let mut e = Emitter {
             cfg: self.cfg,
             cm: self.cm.clone(),
-            wr: Box::new(text_writer::JsWriter::new(self.cm.clone(), "\n", s, None)),
+            wr: match self.cfg.format {
+                Some(format) => Box::new(text_writer::FormatWriter::new(format, "\n", s, None)),
+                None => Box::new(text_writer::JsWriter::new(self.cm.clone(), "\n", s, None)),
+            },
             comments: Some(&self.comments),
             handlers: Box::new(Noop),
         };
 
         let ret = op(&mut e);
+        e.wr.finish().expect("failed to write buffered output");
 
         ret
     }
@@ -48,7 +52,7 @@ impl Builder {
     }
 }
 
-fn parse_then_emit(from: &str, cfg: Config) -> String {
+fn parse_then_emit(from: &str, cfg: Config, syntax: Syntax) -> String {
     ::testing::run_test(false, |cm, handler| {
         let src = cm.new_source_file(FileName::Real("custom.js".into()), from.to_string());
         println!(
@@ -60,7 +64,7 @@ fn parse_then_emit(from: &str, cfg: Config) -> String {
         let res = {
             let mut parser = Parser::new(
                 Session { handler: &handler },
-                Syntax::default(),
+                syntax,
                 SourceFileInput::from(&*src),
                 Some(&comments),
             );
@@ -76,19 +80,43 @@ fn parse_then_emit(from: &str, cfg: Config) -> String {
 }
 
 pub(crate) fn assert_min(from: &str, to: &str) {
-    let out = parse_then_emit(from, Config { minify: true });
+    let out = parse_then_emit(
+        from,
+        Config {
+            minify: true,
+            ..Default::default()
+        },
+        Syntax::default(),
+    );
 
     assert_eq!(DebugUsingDisplay(out.trim()), DebugUsingDisplay(to),);
 }
 
 pub(crate) fn assert_pretty(from: &str, to: &str) {
-    let out = parse_then_emit(from, Config { minify: false });
+    let out = parse_then_emit(
+        from,
+        Config {
+            minify: false,
+            ..Default::default()
+        },
+        Syntax::default(),
+    );
 
     assert_eq!(DebugUsingDisplay(&out.trim()), DebugUsingDisplay(to),);
 }
 
 fn test_from_to(from: &str, to: &str) {
-    let out = parse_then_emit(from, Default::default());
+    let out = parse_then_emit(from, Default::default(), Syntax::default());
+
+    assert_eq!(DebugUsingDisplay(out.trim()), DebugUsingDisplay(to.trim()),);
+}
+
+pub(crate) fn assert_typescript(from: &str, to: &str) {
+    let out = parse_then_emit(
+        from,
+        Default::default(),
+        Syntax::Typescript(Default::default()),
+    );
 
     assert_eq!(DebugUsingDisplay(out.trim()), DebugUsingDisplay(to.trim()),);
 }
@@ -203,6 +231,203 @@ fn issue_639() {
     test_from_to(r"`\x1b[33m Yellow \x1b[0m`;", r"`\x1b[33m Yellow \x1b[0m`;");
 }
 
+#[test]
+fn import_attributes() {
+    let syntax = Syntax::Es(swc_ecma_parser::EsConfig {
+        import_attributes: true,
+        ..Default::default()
+    });
+    let min = |from: &str| {
+        parse_then_emit(
+            from,
+            Config {
+                minify: true,
+                ..Default::default()
+            },
+            syntax,
+        )
+    };
+
+    assert_eq!(
+        DebugUsingDisplay(min("import foo from './foo.json' with { type: 'json' };").trim()),
+        DebugUsingDisplay("import foo from'./foo.json'with{type:'json'};")
+    );
+    assert_eq!(
+        DebugUsingDisplay(min("export * from './foo.json' assert { type: 'json' };").trim()),
+        DebugUsingDisplay("export *from './foo.json'assert{type:'json'};")
+    );
+}
+
+fn assert_with(cfg: Config, from: &str, to: &str) {
+    let out = parse_then_emit(from, cfg, Syntax::default());
+
+    assert_eq!(DebugUsingDisplay(out.trim()), DebugUsingDisplay(to));
+}
+
+#[test]
+fn ascii_only() {
+    let cfg = |target| Config {
+        minify: true,
+        ascii_only: true,
+        target,
+        ..Default::default()
+    };
+
+    assert_with(
+        cfg(JscTarget::Es5),
+        "'café 😀';",
+        r"'caf\u00e9 \ud83d\ude00';",
+    );
+    assert_with(
+        cfg(JscTarget::Es2015),
+        "'café 😀';",
+        r"'caf\u00e9 \u{1f600}';",
+    );
+    assert_with(cfg(JscTarget::Es5), "var café;", r"var caf\u00e9;");
+    assert_with(cfg(JscTarget::Es5), "/é😀/;", r"/\u00e9\ud83d\ude00/;");
+    assert_with(cfg(JscTarget::Es5), "/😀/u;", r"/\u{1f600}/u;");
+    assert_with(cfg(JscTarget::Es2015), "`é${a}`;", r"`\u00e9${a}`;");
+}
+
+#[test]
+fn line_separators() {
+    assert_with(Default::default(), "'a b';", r"'a\u2028b';");
+}
+
+#[test]
+fn quote_style() {
+    let double = Config {
+        quote_style: QuoteStyle::Double,
+        ..Default::default()
+    };
+
+    assert_with(Default::default(), r#""a";"#, "'a';");
+    assert_with(Default::default(), r#""'";"#, r#""'";"#);
+    assert_with(Default::default(), r#"'"\'"';"#, r#"'"\'"';"#);
+    assert_with(double, "'a';", r#""a";"#);
+    assert_with(double, r#"'"';"#, r#"'"';"#);
+    assert_with(double, r#"'\'"\'';"#, r#""'\"'";"#);
+}
+
+#[test]
+fn keep_raw() {
+    let cfg = |target| Config {
+        keep_raw: true,
+        target,
+        ..Default::default()
+    };
+
+    assert_with(cfg(JscTarget::Es5), r#""\x41\n";"#, r#""\x41\n";"#);
+    assert_with(cfg(JscTarget::Es5), "0xFF;", "0xFF;");
+    assert_with(cfg(JscTarget::Es5), "1e3;", "1e3;");
+    assert_with(cfg(JscTarget::Es5), "0b11;", "3;");
+    assert_with(cfg(JscTarget::Es2015), "0b11;", "0b11;");
+    assert_with(cfg(JscTarget::Es5), r"'\u{41}';", "'A';");
+    assert_with(cfg(JscTarget::Es2015), r"'\u{41}';", r"'\u{41}';");
+    assert_with(
+        Config {
+            ascii_only: true,
+            ..cfg(JscTarget::Es5)
+        },
+        "'é';",
+        r"'\u00e9';",
+    );
+}
+
+fn format_cfg(print_width: usize, trailing_comma: TrailingComma) -> Config {
+    Config {
+        format: Some(FormatConfig {
+            print_width,
+            trailing_comma,
+            ..Default::default()
+        }),
+        target: JscTarget::Es2017,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn format_groups() {
+    let cfg = format_cfg(20, TrailingComma::Es5);
+
+    assert_with(cfg, "foo(a,b);", "foo(a, b);");
+    assert_with(
+        cfg,
+        "foo(aaaaaaaa, bbbbbbbb, cccccccc);",
+        "foo(\n    aaaaaaaa,\n    bbbbbbbb,\n    cccccccc\n);",
+    );
+    assert_with(
+        cfg,
+        "[aaaaaaaa, bbbbbbbb, cccccccc];",
+        "[\n    aaaaaaaa,\n    bbbbbbbb,\n    cccccccc,\n];",
+    );
+    assert_with(
+        cfg,
+        "foo([aaaaaaaa, bbbbbbbb]);",
+        "foo(\n    [\n        aaaaaaaa,\n        bbbbbbbb,\n    ]\n);",
+    );
+    assert_with(cfg, "[a, , ];", "[a, ,];");
+}
+
+#[test]
+fn format_trailing_comma() {
+    let src = "foo(aaaaaaaa, bbbbbbbb, cccccccc);";
+
+    assert_with(
+        format_cfg(20, TrailingComma::All),
+        src,
+        "foo(\n    aaaaaaaa,\n    bbbbbbbb,\n    cccccccc,\n);",
+    );
+    assert_with(
+        format_cfg(20, TrailingComma::None),
+        "[aaaaaaaa, bbbbbbbb, cccccccc];",
+        "[\n    aaaaaaaa,\n    bbbbbbbb,\n    cccccccc\n];",
+    );
+}
+
+#[test]
+fn format_objects() {
+    let cfg = format_cfg(80, TrailingComma::Es5);
+
+    assert_with(cfg, "var a = {b: 1};", "var a = { b: 1 };");
+    assert_with(cfg, "var a = {\n b: 1};", "var a = {\n    b: 1,\n};");
+    assert_with(cfg, "var a = {};", "var a = {};");
+}
+
+#[test]
+fn format_blank_lines() {
+    let cfg = format_cfg(80, TrailingComma::Es5);
+
+    assert_with(cfg, "a;\n\n\nb;\nc;", "a;\n\nb;\nc;");
+    assert_with(
+        cfg,
+        "function foo() {\n    a;\n\n    b;\n}",
+        "function foo() {\n    a;\n\n    b;\n}",
+    );
+}
+
+#[test]
+fn format_writer_reports_write_errors() {
+    struct Failing;
+    impl Write for Failing {
+        fn write(&mut self, _: &[u8]) -> io::Result<usize> {
+            Err(io::Error::new(io::ErrorKind::Other, "failed"))
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    let mut wr = text_writer::FormatWriter::new(FormatConfig::default(), "\n", Failing, None);
+    // Output of groups is buffered.
+    wr.begin_group().unwrap();
+    wr.write_str("a").unwrap();
+    wr.end_group().unwrap();
+
+    assert!(wr.finish().is_err());
+}
+
 #[derive(Debug, Clone)]
 struct Buf(Arc<RwLock<Vec<u8>>>);
 impl Write for Buf {
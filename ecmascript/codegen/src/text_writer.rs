@@ -1,8 +1,9 @@
-pub use self::{basic_impl::JsWriter, semicolon::omit_trailing_semi};
+pub use self::{basic_impl::JsWriter, format::FormatWriter, semicolon::omit_trailing_semi};
 use super::*;
 use swc_common::Span;
 
 mod basic_impl;
+mod format;
 mod semicolon;
 
 /// TODO
@@ -35,6 +36,40 @@ pub trait WriteJs {
     fn write_symbol(&mut self, span: Span, s: &str) -> Result;
 
     fn write_punct(&mut self, s: &'static str) -> Result;
+
+    /// Starts a group. Soft line breaks of a group are written as line breaks
+    /// if the group does not fit in a line.
+    ///
+    /// Only used in the formatting mode.
+    fn begin_group(&mut self) -> Result {
+        Ok(())
+    }
+
+    fn end_group(&mut self) -> Result {
+        Ok(())
+    }
+
+    /// Writes a line break if the current group is broken, and `flat`
+    /// otherwise.
+    fn write_soft_line(&mut self, flat: &'static str) -> Result {
+        self.write_str(flat)
+    }
+
+    /// Writes `s` only if the current group is broken.
+    fn write_if_break(&mut self, _s: &'static str) -> Result {
+        Ok(())
+    }
+
+    /// Writes an empty line, if the last line is not empty.
+    fn write_blank_line(&mut self) -> Result {
+        self.write_line()
+    }
+
+    /// Writes buffered output. This should be called once everything is
+    /// emitted.
+    fn finish(&mut self) -> Result {
+        Ok(())
+    }
 }
 
 impl<W> WriteJs for Box<W>
@@ -93,4 +128,23 @@ where
     fn write_punct(&mut self, s: &'static str) -> Result {
         (**self).write_punct(s)
     }
+
+    fn begin_group(&mut self) -> Result {
+        (**self).begin_group()
+    }
+    fn end_group(&mut self) -> Result {
+        (**self).end_group()
+    }
+    fn write_soft_line(&mut self, flat: &'static str) -> Result {
+        (**self).write_soft_line(flat)
+    }
+    fn write_if_break(&mut self, s: &'static str) -> Result {
+        (**self).write_if_break(s)
+    }
+    fn write_blank_line(&mut self) -> Result {
+        (**self).write_blank_line()
+    }
+    fn finish(&mut self) -> Result {
+        (**self).finish()
+    }
 }
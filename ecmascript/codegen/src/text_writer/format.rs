@@ -0,0 +1,347 @@
+use super::{Result, WriteJs};
+use crate::config::FormatConfig;
+use std::{
+    io::{self, Write},
+    mem,
+};
+use swc_common::{BytePos, LineCol, Span};
+
+/// Writer for the formatting mode.
+///
+/// Output is buffered while a group is open. A group is printed on a single
+/// line if it fits in [FormatConfig::print_width], and otherwise all of its
+/// soft line breaks are written as line breaks.
+///
+/// Buffered output is written by [WriteJs::finish], which reports errors of
+/// the underlying writer. Dropping the writer without calling it writes the
+/// output on a best-effort basis.
+pub struct FormatWriter<'a, W: Write> {
+    cfg: FormatConfig,
+    new_line: &'a str,
+    srcmap: Option<&'a mut Vec<(BytePos, LineCol)>>,
+    wr: W,
+
+    cmds: Vec<Cmd>,
+    /// Number of open groups.
+    depth: usize,
+
+    indent: usize,
+    /// Modes of open groups. `true` means the group is broken.
+    modes: Vec<bool>,
+    line_start: bool,
+    blank_line: bool,
+    line_count: usize,
+    /// Bytes written to the current line.
+    line_pos: usize,
+    /// Characters written to the current line.
+    col: usize,
+}
+
+#[derive(Debug)]
+enum Cmd {
+    Text(Option<Span>, String),
+    Indent,
+    Dedent,
+    Line,
+    BlankLine,
+    SoftLine(&'static str),
+    IfBreak(&'static str),
+    BeginGroup,
+    EndGroup,
+}
+
+impl<'a, W: Write> FormatWriter<'a, W> {
+    pub fn new(
+        cfg: FormatConfig,
+        new_line: &'a str,
+        wr: W,
+        srcmap: Option<&'a mut Vec<(BytePos, LineCol)>>,
+    ) -> Self {
+        FormatWriter {
+            cfg,
+            new_line,
+            srcmap,
+            wr,
+            cmds: vec![],
+            depth: 0,
+            indent: 0,
+            modes: vec![],
+            line_start: true,
+            blank_line: false,
+            line_count: 0,
+            line_pos: 0,
+            col: 0,
+        }
+    }
+
+    fn push(&mut self, cmd: Cmd) -> Result {
+        match cmd {
+            Cmd::BeginGroup => self.depth += 1,
+            Cmd::EndGroup => self.depth -= 1,
+            _ => {}
+        }
+        let is_line = match cmd {
+            Cmd::Line | Cmd::BlankLine => true,
+            _ => false,
+        };
+        self.cmds.push(cmd);
+
+        // Nothing after a line break affects the layout of groups before it.
+        if self.depth == 0 && is_line {
+            self.flush()?;
+        }
+        Ok(())
+    }
+
+    fn text(&mut self, span: Option<Span>, s: &str) -> Result {
+        if !s.is_empty() {
+            self.push(Cmd::Text(span, s.to_string()))?;
+        }
+        Ok(())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        let cmds = mem::replace(&mut self.cmds, vec![]);
+
+        for (i, cmd) in cmds.iter().enumerate() {
+            match *cmd {
+                Cmd::Text(span, ref s) => self.write(span, s)?,
+                Cmd::Indent => self.indent += 1,
+                Cmd::Dedent => self.indent = self.indent.saturating_sub(1),
+                Cmd::Line => self.write_new_line()?,
+                Cmd::BlankLine => {
+                    self.write_new_line()?;
+                    if !self.blank_line && self.line_count != 0 {
+                        self.raw_write(self.new_line.as_bytes())?;
+                        self.line_count += 1;
+                        self.line_pos = 0;
+                        self.blank_line = true;
+                    }
+                }
+                Cmd::SoftLine(flat) => {
+                    if self.is_broken() {
+                        self.write_new_line()?
+                    } else {
+                        self.write(None, flat)?
+                    }
+                }
+                Cmd::IfBreak(s) => {
+                    if self.is_broken() {
+                        self.write(None, s)?
+                    }
+                }
+                Cmd::BeginGroup => {
+                    let broken = self.is_broken() && !self.fits(&cmds[i..]);
+                    self.modes.push(broken);
+                }
+                Cmd::EndGroup => {
+                    self.modes.pop();
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn is_broken(&self) -> bool {
+        self.modes.last().cloned().unwrap_or(true)
+    }
+
+    /// Returns true if the group starting at `cmds[0]` and the text following
+    /// it fit in the rest of the current line.
+    fn fits(&self, cmds: &[Cmd]) -> bool {
+        let col = if self.line_start {
+            self.indent * self.cfg.indent_width
+        } else {
+            self.col
+        };
+        let mut width = self.cfg.print_width as isize - col as isize;
+        let mut depth = 0;
+
+        for cmd in cmds {
+            let in_group = depth > 0;
+
+            match *cmd {
+                Cmd::Text(_, ref s) => match s.find('\n') {
+                    Some(_) if in_group => return false,
+                    Some(idx) => return width >= s[..idx].chars().count() as isize,
+                    None => width -= s.chars().count() as isize,
+                },
+                Cmd::SoftLine(flat) => {
+                    if !in_group {
+                        return width >= 0;
+                    }
+                    width -= flat.len() as isize
+                }
+                Cmd::Line | Cmd::BlankLine => return !in_group && width >= 0,
+                Cmd::BeginGroup => depth += 1,
+                Cmd::EndGroup => depth -= 1,
+                Cmd::IfBreak(_) | Cmd::Indent | Cmd::Dedent => {}
+            }
+
+            if width < 0 {
+                return false;
+            }
+        }
+
+        true
+    }
+
+    fn write_new_line(&mut self) -> io::Result<()> {
+        if !self.line_start {
+            self.raw_write(self.new_line.as_bytes())?;
+            self.line_count += 1;
+            self.line_pos = 0;
+            self.col = 0;
+            self.line_start = true;
+        }
+        Ok(())
+    }
+
+    fn write(&mut self, span: Option<Span>, s: &str) -> io::Result<()> {
+        if s.is_empty() {
+            return Ok(());
+        }
+
+        if let Some(span) = span {
+            if !span.is_dummy() {
+                self.srcmap(span.lo())
+            }
+        }
+
+        if self.line_start {
+            let indent = " ".repeat(self.indent * self.cfg.indent_width);
+            self.raw_write(indent.as_bytes())?;
+            self.col = self.indent * self.cfg.indent_width;
+            self.line_start = false;
+        }
+        self.raw_write(s.as_bytes())?;
+        self.blank_line = false;
+
+        match s.rfind('\n') {
+            Some(idx) => {
+                self.line_count += s.matches('\n').count();
+                self.line_pos = s.len() - idx - 1;
+                self.col = s[idx + 1..].chars().count();
+            }
+            None => self.col += s.chars().count(),
+        }
+
+        if let Some(span) = span {
+            if !span.is_dummy() {
+                self.srcmap(span.hi())
+            }
+        }
+
+        Ok(())
+    }
+
+    fn raw_write(&mut self, data: &[u8]) -> io::Result<()> {
+        self.wr.write_all(data)?;
+        self.line_pos += data.len();
+        Ok(())
+    }
+
+    fn srcmap(&mut self, byte_pos: BytePos) {
+        if let Some(ref mut srcmap) = self.srcmap {
+            srcmap.push((
+                byte_pos,
+                LineCol {
+                    line: self.line_count as _,
+                    col: self.line_pos as _,
+                },
+            ))
+        }
+    }
+}
+
+impl<'a, W: Write> Drop for FormatWriter<'a, W> {
+    fn drop(&mut self) {
+        let _ = self.flush();
+    }
+}
+
+impl<'a, W: Write> WriteJs for FormatWriter<'a, W> {
+    fn increase_indent(&mut self) -> Result {
+        self.push(Cmd::Indent)
+    }
+    fn decrease_indent(&mut self) -> Result {
+        self.push(Cmd::Dedent)
+    }
+
+    fn write_semi(&mut self) -> Result {
+        self.text(None, ";")
+    }
+    fn write_space(&mut self) -> Result {
+        self.text(None, " ")
+    }
+
+    fn write_keyword(&mut self, span: Option<Span>, s: &'static str) -> Result {
+        self.text(span, s)
+    }
+
+    fn write_operator(&mut self, s: &str) -> Result {
+        self.text(None, s)
+    }
+
+    fn write_param(&mut self, s: &str) -> Result {
+        self.text(None, s)
+    }
+
+    fn write_property(&mut self, s: &str) -> Result {
+        self.text(None, s)
+    }
+
+    fn write_line(&mut self) -> Result {
+        self.push(Cmd::Line)
+    }
+
+    fn write_lit(&mut self, span: Span, s: &str) -> Result {
+        self.text(Some(span), s)
+    }
+
+    fn write_comment(&mut self, span: Span, s: &str) -> Result {
+        self.text(Some(span), s)
+    }
+
+    fn write_str_lit(&mut self, span: Span, s: &str) -> Result {
+        self.text(Some(span), s)
+    }
+
+    fn write_str(&mut self, s: &str) -> Result {
+        self.text(None, s)
+    }
+
+    fn write_symbol(&mut self, span: Span, s: &str) -> Result {
+        self.text(Some(span), s)
+    }
+
+    fn write_punct(&mut self, s: &'static str) -> Result {
+        self.text(None, s)
+    }
+
+    fn begin_group(&mut self) -> Result {
+        self.push(Cmd::BeginGroup)
+    }
+
+    fn end_group(&mut self) -> Result {
+        self.push(Cmd::EndGroup)
+    }
+
+    fn write_soft_line(&mut self, flat: &'static str) -> Result {
+        self.push(Cmd::SoftLine(flat))
+    }
+
+    fn write_if_break(&mut self, s: &'static str) -> Result {
+        self.push(Cmd::IfBreak(s))
+    }
+
+    fn write_blank_line(&mut self) -> Result {
+        self.push(Cmd::BlankLine)
+    }
+
+    fn finish(&mut self) -> Result {
+        self.flush()?;
+        self.wr.flush()
+    }
+}
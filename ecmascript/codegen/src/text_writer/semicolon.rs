@@ -54,6 +54,16 @@ impl<W: WriteJs> WriteJs for OmitTrailingSemi<W> {
     with_semi!(write_str(s: &str));
     with_semi!(write_symbol(span: Span, s: &str));
     with_semi!(write_punct(s: &'static str));
+    with_semi!(begin_group());
+    with_semi!(end_group());
+    with_semi!(write_soft_line(flat: &'static str));
+    with_semi!(write_if_break(s: &'static str));
+    with_semi!(write_blank_line());
+
+    /// The pending semicolon is the trailing one, so it's dropped.
+    fn finish(&mut self) -> Result {
+        self.inner.finish()
+    }
 }
 
 impl<W: WriteJs> OmitTrailingSemi<W> {
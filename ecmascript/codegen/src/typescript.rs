@@ -4,6 +4,47 @@ use swc_common::Spanned;
 use swc_ecma_ast::*;
 use swc_ecma_codegen_macros::emitter;
 
+#[cfg(test)]
+mod tests {
+    use crate::tests::assert_typescript;
+
+    #[test]
+    fn labeled_tuple() {
+        assert_typescript(
+            "let x: [start: number, end?: number, ...rest: string[]];",
+            "let x: [start: number, end?: number, ...rest: string[]];",
+        );
+        assert_typescript(
+            "let x: [...string[], number];",
+            "let x: [...string[], number];",
+        );
+    }
+
+    #[test]
+    fn template_literal_type() {
+        assert_typescript(
+            "type A = `prefix-${T}-${number}`;",
+            "type A = `prefix-${T}-${number}`;",
+        );
+    }
+
+    #[test]
+    fn mapped_type_as() {
+        assert_typescript(
+            "type A = { [K in string as `get-${K}`]: number };",
+            "type A = {\n    [K in string as `get-${K}`]: number;\n};",
+        );
+    }
+
+    #[test]
+    fn abstract_constructor_type() {
+        assert_typescript(
+            "let x: abstract new () => T;",
+            "let x: abstract new () => T;",
+        );
+    }
+}
+
 impl<'a> Emitter<'a> {
     #[emitter]
     fn emit_pat_or_ts_param_prop(&mut self, n: &ParamOrTsParamProp) -> Result {
@@ -83,6 +124,10 @@ impl<'a> Emitter<'a> {
     fn emit_ts_constructor_type(&mut self, n: &TsConstructorType) -> Result {
         self.emit_leading_comments_of_pos(n.span().lo())?;
 
+        if n.is_abstract {
+            keyword!("abstract");
+            space!();
+        }
         keyword!("new");
         space!();
         if let Some(type_params) = &n.type_params {
@@ -344,6 +389,27 @@ impl<'a> Emitter<'a> {
         }
     }
 
+    #[emitter]
+    fn emit_ts_tpl_lit_type(&mut self, n: &TsTplLitType) -> Result {
+        debug_assert!(n.quasis.len() == n.types.len() + 1);
+
+        self.emit_leading_comments_of_pos(n.span().lo())?;
+
+        punct!("`");
+
+        for i in 0..(n.quasis.len() + n.types.len()) {
+            if i % 2 == 0 {
+                emit!(n.quasis[i / 2]);
+            } else {
+                punct!("${");
+                emit!(n.types[i / 2]);
+                punct!("}");
+            }
+        }
+
+        punct!("`");
+    }
+
     #[emitter]
     fn emit_ts_lit_type(&mut self, n: &TsLitType) -> Result {
         self.emit_leading_comments_of_pos(n.span().lo())?;
@@ -397,6 +463,13 @@ impl<'a> Emitter<'a> {
 
         emit!(n.type_param.constraint);
 
+        if let Some(name_type) = &n.name_type {
+            space!();
+            keyword!("as");
+            space!();
+            emit!(name_type);
+        }
+
         punct!("]");
 
         match n.optional {
@@ -407,7 +480,7 @@ impl<'a> Emitter<'a> {
                 }
                 TruePlusMinus::Plus => {
                     punct!("+");
-                    punct!("/");
+                    punct!("?");
                 }
                 TruePlusMinus::Minus => {
                     punct!("-");
@@ -668,6 +741,21 @@ impl<'a> Emitter<'a> {
         punct!("]");
     }
 
+    #[emitter]
+    fn emit_ts_tuple_element(&mut self, n: &TsTupleElement) -> Result {
+        self.emit_leading_comments_of_pos(n.span().lo())?;
+
+        if let Some(label) = &n.label {
+            emit!(label);
+            if let Pat::Ident(Ident { optional: true, .. }) = label {
+                punct!("?");
+            }
+            punct!(":");
+            formatting_space!();
+        }
+        emit!(n.ty);
+    }
+
     #[emitter]
     fn emit_ts_type(&mut self, n: &TsType) -> Result {
         match n {
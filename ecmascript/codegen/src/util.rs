@@ -212,6 +212,10 @@ impl StartsWithAlphaNum for Expr {
             // TODO
             Expr::TsTypeCast(..) => true,
             Expr::OptChain(ref e) => e.expr.starts_with_alpha_num(),
+            Expr::Bind(BindExpr {
+                obj: Some(ref obj), ..
+            }) => obj.starts_with_alpha_num(),
+            Expr::Bind(BindExpr { obj: None, .. }) => false,
 
             Expr::Invalid(..) => true,
         }
@@ -0,0 +1,63 @@
+use serde::{Deserialize, Serialize};
+
+/// `lint` in `.swcrc`.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+pub struct LintConfig {
+    #[serde(default)]
+    pub rules: RulesConfig,
+
+    /// Global variables defined by the environment, in addition to the
+    /// builtins of ecmascript.
+    ///
+    /// Used by `no-undef`.
+    #[serde(default)]
+    pub globals: Vec<String>,
+}
+
+/// `lint.rules` in `.swcrc`.
+///
+/// Rules are disabled by default.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct RulesConfig {
+    #[serde(rename = "no-undef", default)]
+    pub no_undef: RuleSeverity,
+
+    #[serde(rename = "no-unused-vars", default)]
+    pub no_unused_vars: RuleSeverity,
+
+    #[serde(rename = "no-dupe-keys", default)]
+    pub no_dupe_keys: RuleSeverity,
+
+    #[serde(rename = "no-unreachable", default)]
+    pub no_unreachable: RuleSeverity,
+
+    #[serde(rename = "no-self-assign", default)]
+    pub no_self_assign: RuleSeverity,
+
+    #[serde(rename = "no-const-assign", default)]
+    pub no_const_assign: RuleSeverity,
+
+    #[serde(rename = "valid-typeof", default)]
+    pub valid_typeof: RuleSeverity,
+
+    #[serde(rename = "no-debugger", default)]
+    pub no_debugger: RuleSeverity,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RuleSeverity {
+    #[serde(rename = "off")]
+    Off,
+    #[serde(rename = "warn")]
+    Warn,
+    #[serde(rename = "error")]
+    Error,
+}
+
+impl Default for RuleSeverity {
+    fn default() -> Self {
+        RuleSeverity::Off
+    }
+}
@@ -0,0 +1,109 @@
+//! Linter for ecmascript.
+//!
+//! Rules are visitors which report problems through the [Handler], using
+//! the name of the rule as the code of the diagnostic. Fixes are attached to
+//! diagnostics as [CodeSuggestion](swc_common::errors::CodeSuggestion)s.
+#![feature(specialization)]
+
+pub use self::config::{LintConfig, RuleSeverity, RulesConfig};
+use swc_common::{
+    errors::{DiagnosticBuilder, DiagnosticId, Handler},
+    Span,
+};
+use swc_ecma_ast::Program;
+use swc_ecma_utils::semantic::{analyze, SemanticModel};
+
+mod config;
+mod rules;
+
+/// Runs rules enabled by `config` on `program`.
+///
+/// This should be called before applying transforms, because spans of
+/// diagnostics and suggestions point to the original source.
+pub fn lint(program: &Program, config: &LintConfig, handler: &Handler) {
+    let rules: &[(&'static str, RuleSeverity, fn(&Context<'_>, &Program))] = &[
+        ("no-undef", config.rules.no_undef, rules::no_undef::check),
+        (
+            "no-unused-vars",
+            config.rules.no_unused_vars,
+            rules::no_unused_vars::check,
+        ),
+        (
+            "no-dupe-keys",
+            config.rules.no_dupe_keys,
+            rules::no_dupe_keys::check,
+        ),
+        (
+            "no-unreachable",
+            config.rules.no_unreachable,
+            rules::no_unreachable::check,
+        ),
+        (
+            "no-self-assign",
+            config.rules.no_self_assign,
+            rules::no_self_assign::check,
+        ),
+        (
+            "no-const-assign",
+            config.rules.no_const_assign,
+            rules::no_const_assign::check,
+        ),
+        (
+            "valid-typeof",
+            config.rules.valid_typeof,
+            rules::valid_typeof::check,
+        ),
+        (
+            "no-debugger",
+            config.rules.no_debugger,
+            rules::no_debugger::check,
+        ),
+    ];
+
+    if rules
+        .iter()
+        .all(|&(_, severity, _)| severity == RuleSeverity::Off)
+    {
+        return;
+    }
+
+    let model = analyze(program);
+
+    for &(rule, severity, check) in rules {
+        if severity == RuleSeverity::Off {
+            continue;
+        }
+
+        check(
+            &Context {
+                handler,
+                model: &model,
+                config,
+                rule,
+                severity,
+            },
+            program,
+        );
+    }
+}
+
+/// Passed to rules.
+pub(crate) struct Context<'a> {
+    handler: &'a Handler,
+    pub model: &'a SemanticModel,
+    pub config: &'a LintConfig,
+    rule: &'static str,
+    severity: RuleSeverity,
+}
+
+impl<'a> Context<'a> {
+    /// Creates a diagnostic for the rule. Caller should emit it.
+    pub fn report(&self, span: Span, msg: &str) -> DiagnosticBuilder<'a> {
+        let code = DiagnosticId::Lint(self.rule.into());
+
+        match self.severity {
+            RuleSeverity::Error => self.handler.struct_span_err_with_code(span, msg, code),
+            _ => self.handler.struct_span_warn_with_code(span, msg, code),
+        }
+    }
+}
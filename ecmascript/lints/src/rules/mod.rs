@@ -0,0 +1,43 @@
+pub(crate) mod no_const_assign;
+pub(crate) mod no_debugger;
+pub(crate) mod no_dupe_keys;
+pub(crate) mod no_self_assign;
+pub(crate) mod no_undef;
+pub(crate) mod no_unreachable;
+pub(crate) mod no_unused_vars;
+pub(crate) mod valid_typeof;
+
+/// Returns the candidate most similar to `name`, if it's similar enough to be
+/// a typo.
+fn find_similar<'a, I>(name: &str, candidates: I) -> Option<&'a str>
+where
+    I: IntoIterator<Item = &'a str>,
+{
+    let max = (name.chars().count() / 3).max(1);
+
+    candidates
+        .into_iter()
+        .filter(|&c| c != name)
+        .map(|c| (edit_distance(name, c), c))
+        .filter(|&(d, _)| d <= max)
+        .min_by_key(|&(d, _)| d)
+        .map(|(_, c)| c)
+}
+
+/// Levenshtein distance.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b = b.chars().collect::<Vec<_>>();
+    let mut prev = (0..=b.len()).collect::<Vec<_>>();
+    let mut cur = vec![0; b.len() + 1];
+
+    for (i, ca) in a.chars().enumerate() {
+        cur[0] = i + 1;
+        for (j, &cb) in b.iter().enumerate() {
+            let cost = if ca == cb { 0 } else { 1 };
+            cur[j + 1] = (prev[j] + cost).min(prev[j + 1] + 1).min(cur[j] + 1);
+        }
+        std::mem::swap(&mut prev, &mut cur);
+    }
+
+    prev[b.len()]
+}
@@ -0,0 +1,80 @@
+use crate::Context;
+use std::collections::HashMap;
+use swc_common::{errors::Applicability, BytePos, Span, Visit, VisitWith};
+use swc_ecma_ast::*;
+use swc_ecma_utils::semantic::BindingKind;
+
+/// Disallows reassigning `const` variables.
+pub(crate) fn check(cx: &Context<'_>, program: &Program) {
+    let mut decls = ConstDecls::default();
+    program.visit_with(&mut decls);
+
+    for (_, b) in cx.model.bindings() {
+        if b.kind != BindingKind::Const {
+            continue;
+        }
+
+        for &r in &b.references {
+            let r = cx.model.reference(r);
+            if !r.kind.is_write() {
+                continue;
+            }
+
+            let mut diag = cx.report(r.span, &format!("`{}` is constant", b.name));
+            diag.span_label(b.span, "declared as a constant here");
+            if let Some(&keyword) = decls.0.get(&b.span) {
+                diag.span_suggestion_with_applicability(
+                    keyword,
+                    "declare it with `let` instead",
+                    "let".into(),
+                    Applicability::MaybeIncorrect,
+                );
+            }
+            diag.emit();
+        }
+    }
+}
+
+/// Span of declared identifiers to the span of the `const` keyword.
+#[derive(Default)]
+struct ConstDecls(HashMap<Span, Span>);
+
+impl Visit<VarDecl> for ConstDecls {
+    fn visit(&mut self, v: &VarDecl) {
+        if v.kind == VarDeclKind::Const && !v.span.is_dummy() {
+            let keyword = v.span.with_hi(v.span.lo() + BytePos(5));
+            let mut ids = vec![];
+            for decl in &v.decls {
+                collect_idents(&decl.name, &mut ids);
+            }
+            for i in ids {
+                self.0.insert(i.span, keyword);
+            }
+        }
+
+        v.visit_children(self);
+    }
+}
+
+fn collect_idents<'a>(pat: &'a Pat, ids: &mut Vec<&'a Ident>) {
+    match pat {
+        Pat::Ident(i) => ids.push(i),
+        Pat::Array(a) => {
+            for elem in a.elems.iter().flatten() {
+                collect_idents(elem, ids);
+            }
+        }
+        Pat::Rest(r) => collect_idents(&r.arg, ids),
+        Pat::Object(o) => {
+            for prop in &o.props {
+                match prop {
+                    ObjectPatProp::KeyValue(p) => collect_idents(&p.value, ids),
+                    ObjectPatProp::Assign(p) => ids.push(&p.key),
+                    ObjectPatProp::Rest(r) => collect_idents(&r.arg, ids),
+                }
+            }
+        }
+        Pat::Assign(a) => collect_idents(&a.left, ids),
+        Pat::Invalid(..) | Pat::Expr(..) => {}
+    }
+}
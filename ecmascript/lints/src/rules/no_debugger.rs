@@ -0,0 +1,56 @@
+use crate::Context;
+use swc_common::{errors::Applicability, Span, Visit, VisitWith};
+use swc_ecma_ast::*;
+
+/// Disallows `debugger` statements.
+pub(crate) fn check(cx: &Context<'_>, program: &Program) {
+    program.visit_with(&mut NoDebugger { cx });
+}
+
+struct NoDebugger<'a, 'b> {
+    cx: &'a Context<'b>,
+}
+
+impl NoDebugger<'_, '_> {
+    /// `replacement` is empty if the statement is in a statement list, and
+    /// `;` otherwise to keep the parent statement valid.
+    fn report(&self, span: Span, replacement: &str) {
+        self.cx
+            .report(span, "Unexpected `debugger` statement")
+            .span_suggestion_with_applicability(
+                span,
+                "remove the `debugger` statement",
+                replacement.into(),
+                Applicability::MachineApplicable,
+            )
+            .emit();
+    }
+}
+
+impl Visit<Vec<Stmt>> for NoDebugger<'_, '_> {
+    fn visit(&mut self, stmts: &Vec<Stmt>) {
+        for stmt in stmts {
+            match stmt {
+                Stmt::Debugger(s) => self.report(s.span, ""),
+                _ => stmt.visit_with(self),
+            }
+        }
+    }
+}
+
+impl Visit<Vec<ModuleItem>> for NoDebugger<'_, '_> {
+    fn visit(&mut self, items: &Vec<ModuleItem>) {
+        for item in items {
+            match item {
+                ModuleItem::Stmt(Stmt::Debugger(s)) => self.report(s.span, ""),
+                _ => item.visit_with(self),
+            }
+        }
+    }
+}
+
+impl Visit<DebuggerStmt> for NoDebugger<'_, '_> {
+    fn visit(&mut self, s: &DebuggerStmt) {
+        self.report(s.span, ";");
+    }
+}
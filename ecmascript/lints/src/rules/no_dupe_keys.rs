@@ -0,0 +1,97 @@
+use crate::Context;
+use std::collections::HashMap;
+use swc_atoms::JsWord;
+use swc_common::{errors::Applicability, Span, Spanned, Visit, VisitWith};
+use swc_ecma_ast::*;
+
+/// Disallows duplicate keys in object literals.
+///
+/// A getter and a setter for the same key are allowed.
+pub(crate) fn check(cx: &Context<'_>, program: &Program) {
+    program.visit_with(&mut NoDupeKeys { cx });
+}
+
+struct NoDupeKeys<'a, 'b> {
+    cx: &'a Context<'b>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Kind {
+    Init,
+    Getter,
+    Setter,
+}
+
+impl Visit<ObjectLit> for NoDupeKeys<'_, '_> {
+    fn visit(&mut self, obj: &ObjectLit) {
+        // Key to the index of the previous property.
+        let mut seen: HashMap<JsWord, (usize, Kind)> = HashMap::new();
+
+        for (idx, prop) in obj.props.iter().enumerate() {
+            let (key, kind) = match prop {
+                PropOrSpread::Prop(prop) => match &**prop {
+                    Prop::Shorthand(i) => (i.sym.clone(), Kind::Init),
+                    Prop::KeyValue(p) => match prop_name(&p.key) {
+                        Some(key) => (key, Kind::Init),
+                        None => continue,
+                    },
+                    Prop::Method(p) => match prop_name(&p.key) {
+                        Some(key) => (key, Kind::Init),
+                        None => continue,
+                    },
+                    Prop::Getter(p) => match prop_name(&p.key) {
+                        Some(key) => (key, Kind::Getter),
+                        None => continue,
+                    },
+                    Prop::Setter(p) => match prop_name(&p.key) {
+                        Some(key) => (key, Kind::Setter),
+                        None => continue,
+                    },
+                    Prop::Assign(..) => continue,
+                },
+                PropOrSpread::Spread(..) => continue,
+            };
+
+            // `__proto__: value` sets the prototype instead of defining a
+            // property.
+            if &*key == "__proto__" {
+                continue;
+            }
+
+            match seen.get(&key) {
+                Some(&(prev_idx, prev_kind))
+                    if prev_kind == Kind::Init || kind == Kind::Init || prev_kind == kind =>
+                {
+                    let prev = obj.props[prev_idx].span();
+                    // Remove the previous property with the following comma.
+                    let removal = prev.with_hi(obj.props[prev_idx + 1].span().lo());
+
+                    self.cx
+                        .report(prop.span(), &format!("Duplicate key `{}`", key))
+                        .span_label(prev, "previously defined here")
+                        .span_suggestion_with_applicability(
+                            removal,
+                            "remove the overwritten property",
+                            String::new(),
+                            Applicability::MaybeIncorrect,
+                        )
+                        .emit();
+                }
+                _ => {}
+            }
+
+            seen.insert(key, (idx, kind));
+        }
+
+        obj.visit_children(self);
+    }
+}
+
+fn prop_name(key: &PropName) -> Option<JsWord> {
+    match key {
+        PropName::Ident(i) => Some(i.sym.clone()),
+        PropName::Str(s) => Some(s.value.clone()),
+        PropName::Num(n) => Some(n.value.to_string().into()),
+        PropName::Computed(..) => None,
+    }
+}
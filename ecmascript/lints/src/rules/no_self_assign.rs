@@ -0,0 +1,139 @@
+use crate::Context;
+use swc_common::{errors::Applicability, Span, Spanned, Visit, VisitWith};
+use swc_ecma_ast::*;
+
+/// Disallows assignments like `a = a`, `a.b = a.b` and `[a, b] = [a, b]`.
+pub(crate) fn check(cx: &Context<'_>, program: &Program) {
+    program.visit_with(&mut NoSelfAssign { cx });
+}
+
+struct NoSelfAssign<'a, 'b> {
+    cx: &'a Context<'b>,
+}
+
+impl NoSelfAssign<'_, '_> {
+    fn report(&self, span: Span, stmt: Option<Span>) {
+        let mut diag = self.cx.report(span, "Assignment to itself");
+        if let Some(stmt) = stmt {
+            diag.span_suggestion_with_applicability(
+                stmt,
+                "remove the assignment",
+                String::new(),
+                Applicability::MaybeIncorrect,
+            );
+        }
+        diag.emit();
+    }
+
+    /// `stmt` is the span of the statement to remove if the whole assignment
+    /// is a no-op.
+    fn check_assign(&self, e: &AssignExpr, stmt: Option<Span>) {
+        match e.op {
+            AssignOp::Assign
+            | AssignOp::AndAssign
+            | AssignOp::OrAssign
+            | AssignOp::NullishAssign => {}
+            _ => return,
+        }
+
+        let pat = match &e.left {
+            PatOrExpr::Expr(left) => {
+                self.check_expr(left, e, stmt);
+                return;
+            }
+            PatOrExpr::Pat(pat) => &**pat,
+        };
+
+        match pat {
+            Pat::Expr(left) => self.check_expr(left, e, stmt),
+            Pat::Ident(i) => {
+                if let Expr::Ident(r) = &*e.right {
+                    if i.sym == r.sym {
+                        self.report(r.span, stmt);
+                    }
+                }
+            }
+            Pat::Array(left) => {
+                let right = match &*e.right {
+                    Expr::Array(right) => right,
+                    _ => return,
+                };
+                // `[a, ...b] = [a, ...b]` is not a no-op.
+                let has_rest = left.elems.iter().any(|elem| match elem {
+                    Some(Pat::Rest(..)) => true,
+                    _ => false,
+                });
+                if has_rest {
+                    return;
+                }
+
+                for (l, r) in left.elems.iter().zip(right.elems.iter()) {
+                    if let (Some(Pat::Ident(l)), Some(ExprOrSpread { spread: None, expr })) = (l, r)
+                    {
+                        if let Expr::Ident(r) = &**expr {
+                            if l.sym == r.sym {
+                                self.report(r.span, None);
+                            }
+                        }
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn check_expr(&self, left: &Expr, e: &AssignExpr, stmt: Option<Span>) {
+        if is_same_ref(left, &e.right) {
+            self.report(e.right.span(), stmt);
+        }
+    }
+}
+
+impl Visit<ExprStmt> for NoSelfAssign<'_, '_> {
+    fn visit(&mut self, s: &ExprStmt) {
+        match &*s.expr {
+            Expr::Assign(e) => {
+                self.check_assign(e, Some(s.span));
+                e.visit_children(self);
+            }
+            _ => s.visit_children(self),
+        }
+    }
+}
+
+impl Visit<AssignExpr> for NoSelfAssign<'_, '_> {
+    fn visit(&mut self, e: &AssignExpr) {
+        self.check_assign(e, None);
+
+        e.visit_children(self);
+    }
+}
+
+/// Returns true if `a` and `b` are the same identifier or the same member
+/// expression.
+fn is_same_ref(a: &Expr, b: &Expr) -> bool {
+    match (a, b) {
+        (Expr::Ident(a), Expr::Ident(b)) => a.sym == b.sym,
+        (Expr::This(..), Expr::This(..)) => true,
+        (Expr::Paren(a), _) => is_same_ref(&a.expr, b),
+        (_, Expr::Paren(b)) => is_same_ref(a, &b.expr),
+        (Expr::Member(a), Expr::Member(b)) => {
+            let same_obj = match (&a.obj, &b.obj) {
+                (ExprOrSuper::Expr(a), ExprOrSuper::Expr(b)) => is_same_ref(a, b),
+                (ExprOrSuper::Super(..), ExprOrSuper::Super(..)) => true,
+                _ => false,
+            };
+            if !same_obj || a.computed != b.computed {
+                return false;
+            }
+
+            match (&*a.prop, &*b.prop) {
+                (Expr::Ident(l), Expr::Ident(r)) if !a.computed => l.sym == r.sym,
+                (Expr::Lit(Lit::Str(l)), Expr::Lit(Lit::Str(r))) => l.value == r.value,
+                (Expr::Lit(Lit::Num(l)), Expr::Lit(Lit::Num(r))) => l.value == r.value,
+                _ => false,
+            }
+        }
+        _ => false,
+    }
+}
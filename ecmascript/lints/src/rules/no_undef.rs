@@ -0,0 +1,117 @@
+use super::find_similar;
+use crate::Context;
+use std::collections::HashSet;
+use swc_common::{errors::Applicability, Span, Visit, VisitWith};
+use swc_ecma_ast::*;
+
+/// Global variables defined by ecmascript.
+static BUILTINS: &[&str] = &[
+    "Array",
+    "ArrayBuffer",
+    "Atomics",
+    "BigInt",
+    "BigInt64Array",
+    "BigUint64Array",
+    "Boolean",
+    "DataView",
+    "Date",
+    "Error",
+    "EvalError",
+    "Float32Array",
+    "Float64Array",
+    "Function",
+    "Infinity",
+    "Int16Array",
+    "Int32Array",
+    "Int8Array",
+    "JSON",
+    "Map",
+    "Math",
+    "NaN",
+    "Number",
+    "Object",
+    "Promise",
+    "Proxy",
+    "RangeError",
+    "ReferenceError",
+    "Reflect",
+    "RegExp",
+    "Set",
+    "SharedArrayBuffer",
+    "String",
+    "Symbol",
+    "SyntaxError",
+    "TypeError",
+    "URIError",
+    "Uint16Array",
+    "Uint32Array",
+    "Uint8Array",
+    "Uint8ClampedArray",
+    "WeakMap",
+    "WeakSet",
+    "arguments",
+    "decodeURI",
+    "decodeURIComponent",
+    "encodeURI",
+    "encodeURIComponent",
+    "escape",
+    "eval",
+    "globalThis",
+    "isFinite",
+    "isNaN",
+    "parseFloat",
+    "parseInt",
+    "undefined",
+    "unescape",
+];
+
+/// Disallows references to undeclared variables, except for operands of
+/// `typeof`.
+pub(crate) fn check(cx: &Context<'_>, program: &Program) {
+    let mut typeof_operands = TypeofOperands::default();
+    program.visit_with(&mut typeof_operands);
+
+    for (_, r) in cx.model.unresolved() {
+        if BUILTINS.contains(&&*r.name)
+            || cx.config.globals.iter().any(|g| *g == *r.name)
+            || typeof_operands.0.contains(&r.span)
+        {
+            continue;
+        }
+
+        let mut diag = cx.report(r.span, &format!("`{}` is not defined", r.name));
+
+        // Suggest a visible binding with a similar name.
+        let mut visible = vec![];
+        let mut scope = Some(r.scope);
+        while let Some(id) = scope {
+            let s = cx.model.scope(id);
+            visible.extend(s.bindings.iter().map(|&b| &*cx.model.binding(b).name));
+            scope = s.parent;
+        }
+        if let Some(similar) = find_similar(&r.name, visible) {
+            diag.span_suggestion_with_applicability(
+                r.span,
+                "a variable with a similar name exists",
+                similar.into(),
+                Applicability::MaybeIncorrect,
+            );
+        }
+
+        diag.emit();
+    }
+}
+
+#[derive(Default)]
+struct TypeofOperands(HashSet<Span>);
+
+impl Visit<UnaryExpr> for TypeofOperands {
+    fn visit(&mut self, e: &UnaryExpr) {
+        match (e.op, &*e.arg) {
+            (UnaryOp::TypeOf, Expr::Ident(i)) => {
+                self.0.insert(i.span);
+            }
+            _ => e.visit_children(self),
+        }
+    }
+}
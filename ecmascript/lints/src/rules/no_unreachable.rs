@@ -0,0 +1,102 @@
+use crate::Context;
+use swc_common::{errors::Applicability, Span, Spanned, Visit, VisitWith};
+use swc_ecma_ast::*;
+
+/// Disallows statements after `return`, `throw`, `break` and `continue`.
+///
+/// Function declarations, type declarations and `var` declarations without
+/// initializers are not reported.
+pub(crate) fn check(cx: &Context<'_>, program: &Program) {
+    program.visit_with(&mut NoUnreachable { cx });
+}
+
+struct NoUnreachable<'a, 'b> {
+    cx: &'a Context<'b>,
+}
+
+impl NoUnreachable<'_, '_> {
+    fn check_stmts<'s, I>(&self, stmts: I)
+    where
+        I: IntoIterator<Item = Option<&'s Stmt>>,
+    {
+        let mut terminated = false;
+        // Span of consecutive unreachable statements.
+        let mut unreachable: Option<Span> = None;
+
+        for stmt in stmts {
+            if !terminated {
+                match stmt {
+                    Some(Stmt::Return(..))
+                    | Some(Stmt::Throw(..))
+                    | Some(Stmt::Break(..))
+                    | Some(Stmt::Continue(..)) => terminated = true,
+                    _ => {}
+                }
+                continue;
+            }
+
+            match stmt {
+                Some(stmt) if !is_hoisted(stmt) => {
+                    let span = stmt.span();
+                    unreachable = Some(match unreachable {
+                        Some(prev) => prev.to(span),
+                        None => span,
+                    });
+                }
+                _ => {
+                    if let Some(span) = unreachable.take() {
+                        self.report(span);
+                    }
+                }
+            }
+        }
+
+        if let Some(span) = unreachable {
+            self.report(span);
+        }
+    }
+
+    fn report(&self, span: Span) {
+        self.cx
+            .report(span, "Unreachable code")
+            .span_suggestion_with_applicability(
+                span,
+                "remove the unreachable code",
+                String::new(),
+                Applicability::MaybeIncorrect,
+            )
+            .emit();
+    }
+}
+
+impl Visit<Vec<Stmt>> for NoUnreachable<'_, '_> {
+    fn visit(&mut self, stmts: &Vec<Stmt>) {
+        self.check_stmts(stmts.iter().map(Some));
+
+        stmts.visit_children(self);
+    }
+}
+
+impl Visit<Vec<ModuleItem>> for NoUnreachable<'_, '_> {
+    fn visit(&mut self, items: &Vec<ModuleItem>) {
+        self.check_stmts(items.iter().map(|item| match item {
+            ModuleItem::Stmt(stmt) => Some(stmt),
+            ModuleItem::ModuleDecl(..) => None,
+        }));
+
+        items.visit_children(self);
+    }
+}
+
+fn is_hoisted(stmt: &Stmt) -> bool {
+    match stmt {
+        Stmt::Empty(..) => true,
+        Stmt::Decl(Decl::Fn(..))
+        | Stmt::Decl(Decl::TsInterface(..))
+        | Stmt::Decl(Decl::TsTypeAlias(..)) => true,
+        Stmt::Decl(Decl::Var(v)) => {
+            v.kind == VarDeclKind::Var && v.decls.iter().all(|decl| decl.init.is_none())
+        }
+        _ => false,
+    }
+}
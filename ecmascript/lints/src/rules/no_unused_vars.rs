@@ -0,0 +1,201 @@
+use crate::Context;
+use std::collections::HashSet;
+use swc_atoms::JsWord;
+use swc_common::{errors::Applicability, Span, Visit, VisitWith};
+use swc_ecma_ast::*;
+use swc_ecma_utils::semantic::{Binding, BindingKind, ScopeKind};
+
+/// Disallows variables which are never read.
+///
+/// Names starting with `_` and catch parameters are ignored, and parameters
+/// are reported only if they are declared after the last used parameter.
+pub(crate) fn check(cx: &Context<'_>, program: &Program) {
+    let mut info = Info::default();
+    program.visit_with(&mut info);
+
+    let is_used = |b: &Binding| {
+        b.references
+            .iter()
+            .any(|&r| cx.model.reference(r).kind.is_read())
+    };
+
+    for (_, scope) in cx.model.scopes() {
+        // `function f(a, b, c) { return b }` reports only `c`.
+        let mut after_used = scope.kind == ScopeKind::Fn;
+
+        for &id in scope.bindings.iter().rev() {
+            let b = cx.model.binding(id);
+            if b.kind == BindingKind::Param {
+                if !after_used {
+                    continue;
+                }
+                if is_used(b) {
+                    after_used = false;
+                    continue;
+                }
+            }
+
+            if b.kind == BindingKind::CatchParam
+                || b.name.starts_with('_')
+                || is_used(b)
+                || info.exported.contains(&b.span)
+                || info.expr_names.contains(&b.span)
+                || info.type_refs.contains(&b.name)
+            {
+                continue;
+            }
+
+            let msg = if b.references.is_empty() {
+                format!("`{}` is declared but never used", b.name)
+            } else {
+                format!("`{}` is assigned a value but never used", b.name)
+            };
+
+            // Renaming shorthand properties and imports would change the key.
+            let replacement = if info.shorthand_imports.contains(&b.span) {
+                format!("{} as _{}", b.name, b.name)
+            } else if info.shorthand_props.contains(&b.span) {
+                format!("{}: _{}", b.name, b.name)
+            } else {
+                format!("_{}", b.name)
+            };
+
+            cx.report(b.span, &msg)
+                .span_suggestion_with_applicability(
+                    b.span,
+                    "if this is intentional, prefix it with an underscore",
+                    replacement,
+                    Applicability::MaybeIncorrect,
+                )
+                .emit();
+        }
+    }
+}
+
+#[derive(Default)]
+struct Info {
+    /// Identifiers of exported declarations.
+    exported: HashSet<Span>,
+    /// Names of function expressions and class expressions.
+    expr_names: HashSet<Span>,
+    /// Names referenced from typescript types.
+    type_refs: HashSet<JsWord>,
+    /// `a` in `import { a } from 'a'`.
+    shorthand_imports: HashSet<Span>,
+    /// `a` in `const { a } = obj`.
+    shorthand_props: HashSet<Span>,
+}
+
+impl Info {
+    fn add_exported(&mut self, pat: &Pat) {
+        match pat {
+            Pat::Ident(i) => {
+                self.exported.insert(i.span);
+            }
+            Pat::Array(a) => {
+                for elem in a.elems.iter().flatten() {
+                    self.add_exported(elem);
+                }
+            }
+            Pat::Rest(r) => self.add_exported(&r.arg),
+            Pat::Object(o) => {
+                for prop in &o.props {
+                    match prop {
+                        ObjectPatProp::KeyValue(p) => self.add_exported(&p.value),
+                        ObjectPatProp::Assign(p) => {
+                            self.exported.insert(p.key.span);
+                        }
+                        ObjectPatProp::Rest(r) => self.add_exported(&r.arg),
+                    }
+                }
+            }
+            Pat::Assign(a) => self.add_exported(&a.left),
+            Pat::Invalid(..) | Pat::Expr(..) => {}
+        }
+    }
+}
+
+impl Visit<ExportDecl> for Info {
+    fn visit(&mut self, export: &ExportDecl) {
+        match &export.decl {
+            Decl::Class(c) => {
+                self.exported.insert(c.ident.span);
+            }
+            Decl::Fn(f) => {
+                self.exported.insert(f.ident.span);
+            }
+            Decl::Var(v) => {
+                for decl in &v.decls {
+                    self.add_exported(&decl.name);
+                }
+            }
+            Decl::TsEnum(e) => {
+                self.exported.insert(e.id.span);
+            }
+            _ => {}
+        }
+
+        export.visit_children(self);
+    }
+}
+
+impl Visit<ExportDefaultDecl> for Info {
+    fn visit(&mut self, export: &ExportDefaultDecl) {
+        match &export.decl {
+            DefaultDecl::Class(ClassExpr { ident: Some(i), .. })
+            | DefaultDecl::Fn(FnExpr { ident: Some(i), .. }) => {
+                self.exported.insert(i.span);
+            }
+            _ => {}
+        }
+
+        export.decl.visit_children(self);
+    }
+}
+
+impl Visit<FnExpr> for Info {
+    fn visit(&mut self, f: &FnExpr) {
+        if let Some(i) = &f.ident {
+            self.expr_names.insert(i.span);
+        }
+
+        f.visit_children(self);
+    }
+}
+
+impl Visit<ClassExpr> for Info {
+    fn visit(&mut self, c: &ClassExpr) {
+        if let Some(i) = &c.ident {
+            self.expr_names.insert(i.span);
+        }
+
+        c.visit_children(self);
+    }
+}
+
+impl Visit<TsEntityName> for Info {
+    fn visit(&mut self, n: &TsEntityName) {
+        match n {
+            TsEntityName::Ident(i) => {
+                self.type_refs.insert(i.sym.clone());
+            }
+            TsEntityName::TsQualifiedName(q) => q.left.visit_with(self),
+        }
+    }
+}
+
+impl Visit<ImportNamedSpecifier> for Info {
+    fn visit(&mut self, s: &ImportNamedSpecifier) {
+        if s.imported.is_none() {
+            self.shorthand_imports.insert(s.local.span);
+        }
+    }
+}
+
+impl Visit<AssignPatProp> for Info {
+    fn visit(&mut self, p: &AssignPatProp) {
+        self.shorthand_props.insert(p.key.span);
+
+        p.visit_children(self);
+    }
+}
@@ -0,0 +1,71 @@
+use super::find_similar;
+use crate::Context;
+use swc_common::{errors::Applicability, Visit, VisitWith};
+use swc_ecma_ast::*;
+
+static VALID_TYPES: &[&str] = &[
+    "bigint",
+    "boolean",
+    "function",
+    "number",
+    "object",
+    "string",
+    "symbol",
+    "undefined",
+];
+
+/// Disallows comparing the result of `typeof` with an invalid string.
+pub(crate) fn check(cx: &Context<'_>, program: &Program) {
+    program.visit_with(&mut ValidTypeof { cx });
+}
+
+struct ValidTypeof<'a, 'b> {
+    cx: &'a Context<'b>,
+}
+
+impl Visit<BinExpr> for ValidTypeof<'_, '_> {
+    fn visit(&mut self, e: &BinExpr) {
+        match e.op {
+            BinaryOp::EqEq | BinaryOp::NotEq | BinaryOp::EqEqEq | BinaryOp::NotEqEq => {
+                let s = match (&*e.left, &*e.right) {
+                    (
+                        Expr::Unary(UnaryExpr {
+                            op: UnaryOp::TypeOf,
+                            ..
+                        }),
+                        Expr::Lit(Lit::Str(s)),
+                    )
+                    | (
+                        Expr::Lit(Lit::Str(s)),
+                        Expr::Unary(UnaryExpr {
+                            op: UnaryOp::TypeOf,
+                            ..
+                        }),
+                    ) => Some(s),
+                    _ => None,
+                };
+
+                if let Some(s) = s {
+                    if !VALID_TYPES.contains(&&*s.value) {
+                        let mut diag = self.cx.report(
+                            s.span,
+                            &format!("Invalid typeof comparison value `{}`", s.value),
+                        );
+                        if let Some(similar) = find_similar(&s.value, VALID_TYPES.iter().copied()) {
+                            diag.span_suggestion_with_applicability(
+                                s.span,
+                                "did you mean",
+                                format!("\"{}\"", similar),
+                                Applicability::MaybeIncorrect,
+                            );
+                        }
+                        diag.emit();
+                    }
+                }
+            }
+            _ => {}
+        }
+
+        e.visit_children(self);
+    }
+}
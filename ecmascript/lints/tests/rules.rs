@@ -0,0 +1,217 @@
+use swc_common::{
+    errors::{Diagnostic, DiagnosticId, Level},
+    FileName,
+};
+use swc_ecma_ast::Program;
+use swc_ecma_lints::{lint, LintConfig, RuleSeverity};
+use swc_ecma_parser::{Parser, Session, SourceFileInput, Syntax};
+
+/// Lints `src` with `config` and returns diagnostics and the source with
+/// all suggestions applied.
+fn run(src: &str, config: LintConfig) -> (Vec<Diagnostic>, String) {
+    let mut start_pos = 0;
+
+    let diagnostics = testing::Tester::new()
+        .errors(|cm, handler| {
+            let fm = cm.new_source_file(FileName::Anon, src.into());
+            start_pos = fm.start_pos.0 as usize;
+            let mut p = Parser::new(
+                Session { handler: &handler },
+                Syntax::default(),
+                SourceFileInput::from(&*fm),
+                None,
+            );
+            let module = p.parse_module().map_err(|mut e| e.emit())?;
+
+            lint(&Program::Module(module), &config, &handler);
+
+            Err(())
+        })
+        .unwrap_err();
+
+    let mut parts = diagnostics
+        .iter()
+        .flat_map(|d| d.suggestions.iter())
+        .map(|s| &s.substitutions[0].parts[0])
+        .map(|p| {
+            (
+                p.span.lo().0 as usize - start_pos,
+                p.span.hi().0 as usize - start_pos,
+                &*p.snippet,
+            )
+        })
+        .collect::<Vec<_>>();
+    parts.sort();
+    parts.dedup();
+
+    // Apply suggestions from the end, so offsets of earlier ones stay valid.
+    let mut fixed = src.to_string();
+    for &(lo, hi, snippet) in parts.iter().rev() {
+        fixed.replace_range(lo..hi, snippet);
+    }
+
+    (diagnostics, fixed)
+}
+
+fn config(f: impl FnOnce(&mut LintConfig)) -> LintConfig {
+    let mut config = LintConfig::default();
+    f(&mut config);
+    config
+}
+
+fn messages(diagnostics: &[Diagnostic]) -> Vec<String> {
+    diagnostics.iter().map(|d| d.message()).collect()
+}
+
+#[test]
+fn severity_and_code() {
+    let (d, _) = run(
+        "debugger;",
+        config(|c| c.rules.no_debugger = RuleSeverity::Warn),
+    );
+    assert_eq!(d.len(), 1);
+    assert_eq!(d[0].level, Level::Warning);
+    assert_eq!(d[0].code, Some(DiagnosticId::Lint("no-debugger".into())));
+
+    let (d, _) = run(
+        "debugger;",
+        config(|c| c.rules.no_debugger = RuleSeverity::Error),
+    );
+    assert_eq!(d[0].level, Level::Error);
+
+    let (d, _) = run("debugger;", LintConfig::default());
+    assert!(d.is_empty());
+}
+
+#[test]
+fn config_from_json() {
+    let config: LintConfig = serde_json::from_str(
+        r#"{ "rules": { "no-debugger": "error", "no-undef": "off" }, "globals": ["window"] }"#,
+    )
+    .unwrap();
+    assert_eq!(config.rules.no_debugger, RuleSeverity::Error);
+    assert_eq!(config.rules.no_undef, RuleSeverity::Off);
+    assert_eq!(config.globals, vec!["window".to_string()]);
+
+    assert!(
+        serde_json::from_str::<LintConfig>(r#"{ "rules": { "no-such-rule": "error" } }"#).is_err()
+    );
+}
+
+#[test]
+fn no_debugger() {
+    let (d, fixed) = run(
+        "debugger; if (a) debugger;",
+        config(|c| c.rules.no_debugger = RuleSeverity::Error),
+    );
+    assert_eq!(d.len(), 2);
+    assert_eq!(fixed, " if (a) ;");
+}
+
+#[test]
+fn no_undef() {
+    let (d, fixed) = run(
+        "let value; valu; typeof missing; Object; window;",
+        config(|c| {
+            c.rules.no_undef = RuleSeverity::Error;
+            c.globals.push("window".into());
+        }),
+    );
+    assert_eq!(messages(&d), vec!["`valu` is not defined"]);
+    assert_eq!(fixed, "let value; value; typeof missing; Object; window;");
+}
+
+#[test]
+fn no_unused_vars() {
+    let (d, fixed) = run(
+        "import { a } from 'a';
+let b = 1;
+b = 2;
+const { c } = {};
+function f(x, y, z) { return y; }
+export const e = 1;
+export { f };
+let _ignored;",
+        config(|c| c.rules.no_unused_vars = RuleSeverity::Error),
+    );
+    assert_eq!(
+        messages(&d),
+        vec![
+            "`a` is declared but never used",
+            "`b` is assigned a value but never used",
+            "`c` is declared but never used",
+            "`z` is declared but never used",
+        ]
+    );
+    assert_eq!(
+        fixed,
+        "import { a as _a } from 'a';
+let _b = 1;
+b = 2;
+const { c: _c } = {};
+function f(x, y, _z) { return y; }
+export const e = 1;
+export { f };
+let _ignored;"
+    );
+}
+
+#[test]
+fn no_dupe_keys() {
+    let (d, fixed) = run(
+        "({ a: 1, b: 2, 'a': 3, get c() {}, set c(v) {} });",
+        config(|c| c.rules.no_dupe_keys = RuleSeverity::Error),
+    );
+    assert_eq!(messages(&d), vec!["Duplicate key `a`"]);
+    assert_eq!(fixed, "({ b: 2, 'a': 3, get c() {}, set c(v) {} });");
+}
+
+#[test]
+fn no_unreachable() {
+    let (d, fixed) = run(
+        "function f() { return; a(); b(); function g() {} var c; }",
+        config(|c| c.rules.no_unreachable = RuleSeverity::Error),
+    );
+    assert_eq!(messages(&d), vec!["Unreachable code"]);
+    assert_eq!(fixed, "function f() { return;  function g() {} var c; }");
+}
+
+#[test]
+fn no_self_assign() {
+    let (d, fixed) = run(
+        "a = a; a.b = a.b; a.b = a.c; [a, b] = [a, c]; a += a;",
+        config(|c| c.rules.no_self_assign = RuleSeverity::Error),
+    );
+    assert_eq!(d.len(), 3);
+    assert_eq!(fixed, "  a.b = a.c; [a, b] = [a, c]; a += a;");
+}
+
+#[test]
+fn no_const_assign() {
+    let (d, fixed) = run(
+        "const a = 1; a = 2; a++; const b = 1; b;",
+        config(|c| c.rules.no_const_assign = RuleSeverity::Error),
+    );
+    assert_eq!(messages(&d), vec!["`a` is constant", "`a` is constant"]);
+    // Both suggestions replace the same keyword.
+    assert!(fixed.starts_with("let a = 1;"));
+}
+
+#[test]
+fn valid_typeof() {
+    let (d, fixed) = run(
+        "typeof a === 'strnig'; typeof a == 'number'; 'foo' !== typeof a;",
+        config(|c| c.rules.valid_typeof = RuleSeverity::Error),
+    );
+    assert_eq!(
+        messages(&d),
+        vec![
+            "Invalid typeof comparison value `strnig`",
+            "Invalid typeof comparison value `foo`",
+        ]
+    );
+    assert_eq!(
+        fixed,
+        "typeof a === \"string\"; typeof a == 'number'; 'foo' !== typeof a;"
+    );
+}
@@ -146,11 +146,14 @@ pub enum SyntaxError {
     InvalidLeadingDecorator,
     DecoratorOnExport,
 
-    TsNonLastRest,
+    TsRestAfterRest,
+    TsOptionalAfterRest,
     TsRequiredAfterOptional,
+    TsMixedTupleLabels,
     TsInvalidParamPropPat,
 
     SpaceBetweenHashAndIdent,
+    PrivateNameNotInOrMember,
 
     AsyncConstructor,
     PropertyNamedConstructor,
@@ -163,11 +166,17 @@ pub enum SyntaxError {
 
     ExportDefaultWithOutFrom,
     ExportNamespaceFrom,
+    ImportAttributeValue,
 
     DotsWithoutIdentifier,
 
     NumericSeparatorIsAllowedOnlyBetweenTwoDigits,
 
+    FlowDeclareModuleExports,
+    FlowDeclareExportDefault,
+
+    InvalidBindCallee,
+
     TS1003,
     TS1005,
     TS1009,
@@ -189,7 +198,6 @@ pub enum SyntaxError {
     TS1105,
     TS1107,
     TS1109,
-    TS1110,
     TS1114,
     TS1115,
     TS1116,
@@ -334,14 +342,19 @@ impl<'a> From<ErrorToDiag<'a>> for DiagnosticBuilder<'a> {
             DecoratorOnExport => "Using the export keyword between a decorator and a class is not \
                                   allowed. Please use `export @dec class` instead."
                 .into(),
-            TsNonLastRest => "A rest element must be last in a tuple type.".into(),
+            TsRestAfterRest => "A rest element cannot follow another rest element.".into(),
+            TsOptionalAfterRest => "An optional element cannot follow a rest element.".into(),
             TsRequiredAfterOptional => {
                 "A required element cannot follow an optional element.".into()
             }
+            TsMixedTupleLabels => "Tuple members must all have names or all not have names.".into(),
             TsInvalidParamPropPat => {
                 "Typescript parameter property must be identifer or assignment pattern".into()
             }
             SpaceBetweenHashAndIdent => "Unexpected space between # and identifier".into(),
+            PrivateNameNotInOrMember => "Private names are only allowed in property accesses \
+                                         (`obj.#x`) or in `in` expressions (`#x in obj`)"
+                .into(),
             AsyncConstructor => "Constructor can't be an async function".into(),
             PropertyNamedConstructor => {
                 "Classes may not have a non-static field named 'constructor'".into()
@@ -362,11 +375,19 @@ impl<'a> From<ErrorToDiag<'a>> for DiagnosticBuilder<'a> {
             ExportNamespaceFrom => "export * as Foo from 'foo'; requires \
                                     `jsc.parser.exportNamespaceFrom` to be true"
                 .into(),
+            ImportAttributeValue => {
+                "Only string literals are allowed as import attribute values".into()
+            }
 
             DotsWithoutIdentifier => {
                 "`...` must be followed by an identifier in declaration contexts".into()
             }
 
+            FlowDeclareModuleExports => "`declare module.exports` is not supported".into(),
+            FlowDeclareExportDefault => "`declare export default` is not supported".into(),
+
+            InvalidBindCallee => "Binding should be performed on object property".into(),
+
             NumericSeparatorIsAllowedOnlyBetweenTwoDigits => {
                 "A numeric separator is only allowed between two digits".into()
             }
@@ -379,7 +400,6 @@ impl<'a> From<ErrorToDiag<'a>> for DiagnosticBuilder<'a> {
             }
 
             TS1056 => "jsc.taraget should be es5 or upper to use getter / setter".into(),
-            TS1110 => "type expected".into(),
             TS1141 => "literal in an import type should be string literal".into(),
 
             // TODO:
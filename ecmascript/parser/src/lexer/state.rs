@@ -179,7 +179,7 @@ impl<'a, I: Input> Iterator for Lexer<'a, I> {
 
             self.state.start = start;
 
-            if self.syntax.typescript() && self.ctx.in_type {
+            if self.syntax.typed() && self.ctx.in_type {
                 if c == '<' {
                     self.input.bump();
                     return Ok(Some(tok!('<')));
@@ -282,6 +282,15 @@ impl State {
 }
 
 impl State {
+    /// Returns true if no token is lexed yet.
+    pub(super) fn is_before_first_token(&self) -> bool {
+        self.token_type.is_none()
+    }
+
+    pub(super) fn set_syntax(&mut self, syntax: Syntax) {
+        self.syntax = syntax;
+    }
+
     pub fn can_skip_space(&self) -> bool {
         !self
             .context
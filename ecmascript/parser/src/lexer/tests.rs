@@ -470,6 +470,24 @@ fn complex_divide() {
     )
 }
 
+#[test]
+fn logical_assign() {
+    assert_eq!(
+        lex_tokens(Syntax::default(), "a &&= b ||= c ??= d ?? e"),
+        vec![
+            Word(Word::Ident("a".into())),
+            AssignOp(AndAssign),
+            Word(Word::Ident("b".into())),
+            AssignOp(OrAssign),
+            Word(Word::Ident("c".into())),
+            AssignOp(NullishAssign),
+            Word(Word::Ident("d".into())),
+            BinOp(NullishCoalescing),
+            Word(Word::Ident("e".into())),
+        ]
+    );
+}
+
 // ---------- Tests from tc39 spec
 
 #[test]
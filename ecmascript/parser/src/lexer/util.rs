@@ -6,7 +6,10 @@
 //!
 //! [babylon/util/identifier.js]:https://github.com/babel/babel/blob/master/packages/babylon/src/util/identifier.js
 use super::{input::Input, Char, LexResult, Lexer};
-use crate::error::{ErrorToDiag, SyntaxError};
+use crate::{
+    error::{ErrorToDiag, SyntaxError},
+    Syntax,
+};
 use std::char;
 use swc_common::{
     comments::{Comment, CommentKind},
@@ -190,6 +193,8 @@ impl<'a, I: Input> Lexer<'a, I> {
             }
         }
 
+        self.check_flow_pragma(slice_start, end);
+
         if let Some(ref comments) = self.comments {
             let s = self.input.slice(slice_start, end);
             let cmt = Comment {
@@ -232,6 +237,8 @@ impl<'a, I: Input> Lexer<'a, I> {
                 self.bump(); // '/'
 
                 let pos = self.cur_pos();
+                self.check_flow_pragma(slice_start, pos);
+
                 if let Some(ref comments) = self.comments {
                     let src = self.input.slice(slice_start, pos);
                     let s = &src[..src.len() - 2];
@@ -260,6 +267,22 @@ impl<'a, I: Input> Lexer<'a, I> {
 
         self.error(start, SyntaxError::UnterminatedBlockComment)?
     }
+
+    /// Enables all flow syntax if a comment before the first token contains
+    /// `@flow`.
+    fn check_flow_pragma(&mut self, start: BytePos, end: BytePos) {
+        let mut config = match self.syntax {
+            Syntax::Flow(config) if !config.all => config,
+            _ => return,
+        };
+        if !self.state.is_before_first_token() || !self.input.slice(start, end).contains("@flow") {
+            return;
+        }
+
+        config.all = true;
+        self.syntax = Syntax::Flow(config);
+        self.state.set_syntax(self.syntax);
+    }
 }
 
 /// Implemented for `char`.
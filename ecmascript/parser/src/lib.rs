@@ -108,6 +108,7 @@ use swc_common::{errors::Handler, Span, SpanData};
 mod macros;
 mod error;
 pub mod lexer;
+pub mod lossless;
 mod parser;
 pub mod token;
 
@@ -119,6 +120,8 @@ pub enum Syntax {
     Es(EsConfig),
     #[serde(rename = "typescript")]
     Typescript(TsConfig),
+    #[serde(rename = "flow")]
+    Flow(FlowConfig),
 }
 
 impl Default for Syntax {
@@ -132,7 +135,8 @@ impl Syntax {
     pub fn jsx(self) -> bool {
         match self {
             Syntax::Es(EsConfig { jsx: true, .. })
-            | Syntax::Typescript(TsConfig { tsx: true, .. }) => true,
+            | Syntax::Typescript(TsConfig { tsx: true, .. })
+            | Syntax::Flow(FlowConfig { jsx: true, .. }) => true,
             _ => false,
         }
     }
@@ -143,7 +147,8 @@ impl Syntax {
                 optional_chaining: true,
                 ..
             })
-            | Syntax::Typescript(TsConfig { .. }) => true,
+            | Syntax::Typescript(TsConfig { .. })
+            | Syntax::Flow(..) => true,
             _ => false,
         }
     }
@@ -157,6 +162,10 @@ impl Syntax {
             | Syntax::Typescript(TsConfig {
                 dynamic_import: true,
                 ..
+            })
+            | Syntax::Flow(FlowConfig {
+                dynamic_import: true,
+                ..
             }) => true,
             _ => false,
         }
@@ -183,6 +192,9 @@ impl Syntax {
             })
             | Syntax::Typescript(TsConfig {
                 decorators: true, ..
+            })
+            | Syntax::Flow(FlowConfig {
+                decorators: true, ..
             }) => true,
             _ => false,
         }
@@ -210,7 +222,7 @@ impl Syntax {
     }
 
     pub fn class_props(self) -> bool {
-        if self.typescript() {
+        if self.typed() {
             return true;
         }
         match self {
@@ -240,6 +252,22 @@ impl Syntax {
         }
     }
 
+    /// Should we parse flow?
+    pub fn flow(self) -> bool {
+        match self {
+            Syntax::Flow(..) => true,
+            _ => false,
+        }
+    }
+
+    /// Should we parse type annotations?
+    ///
+    /// Flow types are parsed into the typescript ast, so most of the parser
+    /// only has to know whether types are enabled.
+    pub fn typed(self) -> bool {
+        self.typescript() || self.flow()
+    }
+
     pub fn export_default_from(self) -> bool {
         match self {
             Syntax::Es(EsConfig {
@@ -267,7 +295,8 @@ impl Syntax {
                 nullish_coalescing: true,
                 ..
             })
-            | Syntax::Typescript(..) => true,
+            | Syntax::Typescript(..)
+            | Syntax::Flow(..) => true,
 
             _ => false,
         }
@@ -296,6 +325,51 @@ impl Syntax {
         }
     }
 
+    pub fn static_blocks(self) -> bool {
+        match self {
+            Syntax::Es(EsConfig {
+                static_blocks: true,
+                ..
+            })
+            | Syntax::Typescript(TsConfig {
+                static_blocks: true,
+                ..
+            }) => true,
+
+            _ => false,
+        }
+    }
+
+    pub fn import_attributes(self) -> bool {
+        match self {
+            Syntax::Es(EsConfig {
+                import_attributes: true,
+                ..
+            })
+            | Syntax::Typescript(TsConfig {
+                import_attributes: true,
+                ..
+            }) => true,
+
+            _ => false,
+        }
+    }
+
+    pub fn private_in_object(self) -> bool {
+        match self {
+            Syntax::Es(EsConfig {
+                private_in_object: true,
+                ..
+            })
+            | Syntax::Typescript(TsConfig {
+                private_in_object: true,
+                ..
+            }) => true,
+
+            _ => false,
+        }
+    }
+
     pub fn dts(self) -> bool {
         match self {
             Syntax::Typescript(t) => t.dts,
@@ -316,11 +390,42 @@ pub struct TsConfig {
     #[serde(default)]
     pub dynamic_import: bool,
 
+    /// `static { ... }` in classes.
+    #[serde(default)]
+    pub static_blocks: bool,
+
+    /// `#x in obj`
+    #[serde(default)]
+    pub private_in_object: bool,
+
+    /// `import foo from './foo.json' with { type: "json" }` and the older
+    /// `assert { type: "json" }` form.
+    #[serde(default)]
+    pub import_attributes: bool,
+
     /// `.d.ts`
     #[serde(skip, default)]
     pub dts: bool,
 }
 
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+pub struct FlowConfig {
+    #[serde(default)]
+    pub jsx: bool,
+
+    /// If false, ambiguous syntax like `f<T>(x)` is parsed as flow only if
+    /// the file starts with a `@flow` pragma.
+    #[serde(default)]
+    pub all: bool,
+
+    #[serde(default)]
+    pub decorators: bool,
+
+    #[serde(default)]
+    pub dynamic_import: bool,
+}
+
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialOrd, Ord, PartialEq, Eq)]
 pub enum JscTarget {
     #[serde(rename = "es3")]
@@ -407,6 +512,19 @@ pub struct EsConfig {
     /// Stage 3.
     #[serde(default)]
     pub top_level_await: bool,
+
+    /// `static { ... }` in classes.
+    #[serde(default)]
+    pub static_blocks: bool,
+
+    /// `#x in obj`
+    #[serde(default)]
+    pub private_in_object: bool,
+
+    /// `import foo from './foo.json' with { type: "json" }` and the older
+    /// `assert { type: "json" }` form.
+    #[serde(default)]
+    pub import_attributes: bool,
 }
 
 /// Syntactic context.
@@ -429,6 +547,11 @@ pub struct Context {
     in_type: bool,
     /// Typescript extension.
     in_declare: bool,
+    /// Flow extension.
+    ///
+    /// If true, `T => U` is not parsed as a function type. Used while parsing
+    /// the return type of an arrow function.
+    no_anon_fn_type: bool,
     span_of_fn_name: Option<Span>,
 
     /// If true, `:` should not be treated as a type annotation.
@@ -0,0 +1,316 @@
+//! Lossless token stream for tooling like syntax highlighters, formatters and
+//! codemods.
+//!
+//! [tokenize] returns tokens *and* trivia (whitespace, line breaks and
+//! comments), so concatenating the source text of all items reproduces the
+//! file exactly.
+use crate::{
+    lexer::Lexer,
+    parser::{Capturing, Parser},
+    token::TokenAndSpan,
+    JscTarget, Session, SourceFileInput, Syntax,
+};
+use swc_common::{BytePos, SourceFile, Span, SyntaxContext};
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum LosslessToken {
+    Token(TokenAndSpan),
+    Trivia(Trivia),
+}
+
+impl LosslessToken {
+    pub fn span(&self) -> Span {
+        match *self {
+            LosslessToken::Token(ref t) => Span::new(t.span.lo, t.span.hi, t.span.ctxt),
+            LosslessToken::Trivia(ref t) => t.span,
+        }
+    }
+
+    pub fn is_trivia(&self) -> bool {
+        match *self {
+            LosslessToken::Token(..) => false,
+            LosslessToken::Trivia(..) => true,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Trivia {
+    pub kind: TriviaKind,
+    pub span: Span,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TriviaKind {
+    /// Spaces and tabs, excluding line breaks.
+    Whitespace,
+    /// A line break. `\r\n` is a single line break.
+    NewLine,
+    /// `// ...`, excluding the line break.
+    LineComment,
+    /// `/* ... */`
+    BlockComment,
+    /// Text the lexer could not tokenize.
+    Skipped,
+}
+
+/// Tokenizes `fm` into tokens and trivia which cover the whole file.
+///
+/// The file is parsed as a module to lex each token in the context the parser
+/// uses, e.g. regular expressions vs divisions, type arguments of typescript
+/// and jsx texts. If it cannot be parsed, it's only lexed. Syntax errors may
+/// be reported to the handler of `session`.
+pub fn tokenize(
+    session: Session<'_>,
+    syntax: Syntax,
+    target: JscTarget,
+    fm: &SourceFile,
+) -> Vec<LosslessToken> {
+    let lexer = Lexer::new(session, syntax, target, SourceFileInput::from(fm), None);
+    let mut parser = Parser::new_from(session, Capturing::new(lexer));
+
+    let tokens = match parser.parse_module() {
+        Ok(..) => parser.input().take(),
+        Err(mut err) => {
+            err.cancel();
+            Lexer::new(session, syntax, target, SourceFileInput::from(fm), None).collect()
+        }
+    };
+
+    with_trivia(fm, tokens)
+}
+
+fn with_trivia(fm: &SourceFile, tokens: Vec<TokenAndSpan>) -> Vec<LosslessToken> {
+    let mut buf = Vec::with_capacity(tokens.len() * 2);
+    let mut pos = fm.start_pos;
+
+    for token in tokens {
+        // Tokens may overlap if the lexer recovered from an error.
+        if token.span.lo < pos || token.span.hi > fm.end_pos {
+            continue;
+        }
+
+        push_trivia(&mut buf, fm, pos, token.span.lo);
+        pos = token.span.hi;
+        buf.push(LosslessToken::Token(token));
+    }
+    push_trivia(&mut buf, fm, pos, fm.end_pos);
+
+    buf
+}
+
+/// Splits `lo..hi`, which contains no token, into trivia.
+fn push_trivia(buf: &mut Vec<LosslessToken>, fm: &SourceFile, lo: BytePos, hi: BytePos) {
+    let offset = fm.start_pos.0;
+    let src = &fm.src[(lo.0 - offset) as usize..(hi.0 - offset) as usize];
+
+    let mut start = 0;
+    while start < src.len() {
+        let rest = &src[start..];
+        let (kind, len) = if rest.starts_with("//") {
+            let len = rest.find(is_line_break).unwrap_or_else(|| rest.len());
+            (TriviaKind::LineComment, len)
+        } else if rest.starts_with("/*") {
+            let len = rest[2..]
+                .find("*/")
+                .map(|i| i + 4)
+                .unwrap_or_else(|| rest.len());
+            (TriviaKind::BlockComment, len)
+        } else if rest.starts_with("\r\n") {
+            (TriviaKind::NewLine, 2)
+        } else {
+            let c = rest.chars().next().unwrap();
+            if is_line_break(c) {
+                (TriviaKind::NewLine, c.len_utf8())
+            } else if c.is_whitespace() {
+                let len = rest
+                    .find(|c: char| !c.is_whitespace() || is_line_break(c))
+                    .unwrap_or_else(|| rest.len());
+                (TriviaKind::Whitespace, len)
+            } else {
+                let first = c.len_utf8();
+                let len = rest[first..]
+                    .find(|c: char| c.is_whitespace() || c == '/')
+                    .map(|len| len + first)
+                    .unwrap_or_else(|| rest.len());
+                (TriviaKind::Skipped, len)
+            }
+        };
+
+        let span_lo = lo + BytePos(start as u32);
+        buf.push(LosslessToken::Trivia(Trivia {
+            kind,
+            span: Span::new(
+                span_lo,
+                span_lo + BytePos(len as u32),
+                SyntaxContext::empty(),
+            ),
+        }));
+        start += len;
+    }
+}
+
+fn is_line_break(c: char) -> bool {
+    match c {
+        '\n' | '\r' | '\u{2028}' | '\u{2029}' => true,
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{token::Token, EsConfig};
+    use swc_common::FileName;
+
+    fn tokens(syntax: Syntax, src: &str) -> Vec<(String, bool)> {
+        ::testing::run_test(false, |cm, handler| {
+            let fm = cm.new_source_file(FileName::Anon, src.into());
+            let tokens = tokenize(
+                Session { handler: &handler },
+                syntax,
+                JscTarget::Es2019,
+                &fm,
+            );
+
+            let texts: Vec<_> = tokens
+                .iter()
+                .map(|t| (cm.span_to_snippet(t.span()).unwrap(), t.is_trivia()))
+                .collect();
+            assert_eq!(texts.iter().map(|t| &*t.0).collect::<String>(), src);
+
+            Ok(texts)
+        })
+        .unwrap()
+    }
+
+    fn kinds(syntax: Syntax, src: &str) -> Vec<TriviaKind> {
+        ::testing::run_test(false, |cm, handler| {
+            let fm = cm.new_source_file(FileName::Anon, src.into());
+
+            Ok(tokenize(
+                Session { handler: &handler },
+                syntax,
+                JscTarget::Es2019,
+                &fm,
+            )
+            .into_iter()
+            .filter_map(|t| match t {
+                LosslessToken::Trivia(t) => Some(t.kind),
+                _ => None,
+            })
+            .collect())
+        })
+        .unwrap()
+    }
+
+    /// Texts of tokens, excluding empty template elements.
+    fn texts(tokens: &[(String, bool)]) -> Vec<&str> {
+        tokens
+            .iter()
+            .filter(|t| !t.1 && !t.0.is_empty())
+            .map(|t| &*t.0)
+            .collect()
+    }
+
+    #[test]
+    fn trivia() {
+        assert_eq!(
+            kinds(
+                Default::default(),
+                "#!/usr/bin/env node\r\n// a\na; /* b */\tb\u{2028}"
+            ),
+            vec![
+                TriviaKind::NewLine,
+                TriviaKind::LineComment,
+                TriviaKind::NewLine,
+                TriviaKind::Whitespace,
+                TriviaKind::BlockComment,
+                TriviaKind::Whitespace,
+                TriviaKind::NewLine,
+            ]
+        );
+    }
+
+    #[test]
+    fn regex() {
+        let t = tokens(Default::default(), "a / b / c;\nx = /a b/g.test(y) / 2;");
+
+        assert_eq!(
+            texts(&t),
+            vec![
+                "a", "/", "b", "/", "c", ";", "x", "=", "/a b/g", ".", "test", "(", "y", ")", "/",
+                "2", ";"
+            ]
+        );
+    }
+
+    #[test]
+    fn template() {
+        let t = tokens(Default::default(), "`a ${ `b${c}` } d`");
+
+        assert_eq!(
+            texts(&t),
+            vec!["`", "a ", "${", "`", "b", "${", "c", "}", "`", "}", " d", "`"]
+        );
+    }
+
+    #[test]
+    fn jsx() {
+        let syntax = Syntax::Es(EsConfig {
+            jsx: true,
+            ..Default::default()
+        });
+        let t = tokens(syntax, "<a b='c'>{d} e // f\n</a>");
+
+        assert_eq!(
+            texts(&t),
+            vec![
+                "<",
+                "a",
+                "b",
+                "=",
+                "'c'",
+                ">",
+                "{",
+                "d",
+                "}",
+                " e // f\n",
+                "<",
+                "/",
+                "a",
+                ">"
+            ]
+        );
+    }
+
+    #[test]
+    fn unparsable() {
+        let t = tokens(Default::default(), "a = (b; /* c */");
+
+        assert_eq!(texts(&t), vec!["a", "=", "(", "b", ";"]);
+    }
+
+    #[test]
+    fn shebang() {
+        ::testing::run_test(false, |cm, handler| {
+            let fm = cm.new_source_file(FileName::Anon, "#!/bin/node\na".into());
+            let tokens = tokenize(
+                Session { handler: &handler },
+                Default::default(),
+                JscTarget::Es2019,
+                &fm,
+            );
+
+            match tokens[0] {
+                LosslessToken::Token(TokenAndSpan {
+                    token: Token::Shebang(..),
+                    ..
+                }) => {}
+                _ => panic!("expected a shebang: {:?}", tokens),
+            }
+            Ok(())
+        })
+        .unwrap();
+    }
+}
@@ -89,7 +89,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
                 p.emit_err(span, SyntaxError::TS2414);
             }
 
-            let type_params = if p.input.syntax().typescript() {
+            let type_params = if p.input.syntax().typed() {
                 p.try_parse_ts_type_params()?
             } else {
                 None
@@ -97,7 +97,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
 
             let (mut super_class, mut super_type_params) = if eat!("extends") {
                 let super_class = p.parse_lhs_expr().map(Some)?;
-                let super_type_params = if p.input.syntax().typescript() && is!('<') {
+                let super_type_params = if p.input.syntax().typed() && is!('<') {
                     Some(p.parse_ts_type_args()?)
                 } else {
                     None
@@ -126,7 +126,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
                 }
             };
 
-            let implements = if p.input.syntax().typescript() && eat!("implements") {
+            let implements = if p.input.syntax().typed() && eat!("implements") {
                 p.parse_ts_heritage_clause()?
             } else {
                 vec![]
@@ -294,7 +294,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
             }))
     }
 
-    fn parse_class_member(&mut self) -> PResult<'a, ClassMember> {
+    pub(super) fn parse_class_member(&mut self) -> PResult<'a, ClassMember> {
         let start = cur_pos!();
         let decorators = self.parse_decorators(false)?;
 
@@ -318,6 +318,10 @@ impl<'a, I: Tokens> Parser<'a, I> {
         };
 
         if let Some(static_token) = static_token {
+            if is!('{') && self.input.syntax().static_blocks() {
+                return self.parse_static_block(start).map(ClassMember::StaticBlock);
+            }
+
             // Handle static(){}
             if self.is_class_method()? {
                 let key = Either::Right(PropName::Ident(Ident::new(
@@ -357,6 +361,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
                     is_optional,
                     false,
                     false,
+                    false,
                 );
             } else {
                 // TODO: error if static contains escape
@@ -366,6 +371,30 @@ impl<'a, I: Tokens> Parser<'a, I> {
         self.parse_class_member_with_is_static(start, accessibility, static_token, decorators)
     }
 
+    /// Parses the block of `static { ... }`.
+    fn parse_static_block(&mut self, start: BytePos) -> PResult<'a, StaticBlock> {
+        // A static block is parsed like the body of a function without
+        // parameters, except that `return` is not allowed.
+        let ctx = Context {
+            in_async: false,
+            in_generator: false,
+            in_function: false,
+            is_break_allowed: false,
+            is_continue_allowed: false,
+            ..self.ctx()
+        };
+        let state = State {
+            labels: vec![],
+            ..Default::default()
+        };
+        let body = self.with_ctx(ctx).with_state(state).parse_block(false)?;
+
+        Ok(StaticBlock {
+            span: span!(start),
+            body,
+        })
+    }
+
     #[allow(clippy::cognitive_complexity)]
     fn parse_class_member_with_is_static(
         &mut self,
@@ -467,7 +496,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
                 let params = self.parse_constructor_params()?;
                 expect!(')');
 
-                if self.syntax().typescript() && is!(':') {
+                if self.syntax().typed() && is!(':') {
                     let start = cur_pos!();
                     let type_ann = self.parse_ts_type_ann(true, start)?;
 
@@ -551,6 +580,36 @@ impl<'a, I: Tokens> Parser<'a, I> {
                 is_optional,
                 readonly,
                 is_abstract,
+                false,
+            );
+        }
+
+        if self.input.syntax().decorators()
+            && is_simple
+            && match key {
+                Either::Right(PropName::Ident(ref i)) => &*i.sym == "accessor",
+                _ => false,
+            }
+            && !self.input.had_line_break_before_cur()
+        {
+            // handle accessor foo = 1;
+
+            let key = self.parse_class_prop_name()?;
+            let is_optional = self.input.syntax().typescript() && eat!('?');
+            if !self.is_class_property()? {
+                unexpected!()
+            }
+
+            return self.make_property(
+                start,
+                decorators,
+                accessibility,
+                key,
+                is_static,
+                is_optional,
+                readonly,
+                is_abstract,
+                true,
             );
         }
 
@@ -676,6 +735,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
         is_optional: bool,
         readonly: bool,
         is_abstract: bool,
+        is_accessor: bool,
     ) -> PResult<'a, ClassMember> {
         if !self.input.syntax().class_props() {
             syntax_error!(span!(start), SyntaxError::ClassProperty)
@@ -722,6 +782,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
                     definite,
                     type_ann,
                     computed: false,
+                    is_accessor,
                 }
                 .into(),
                 Either::Right(key) => ClassProp {
@@ -745,6 +806,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
                     readonly,
                     definite,
                     type_ann,
+                    is_accessor,
                 }
                 .into(),
             })
@@ -752,11 +814,11 @@ impl<'a, I: Tokens> Parser<'a, I> {
     }
 
     fn is_class_method(&mut self) -> PResult<'a, bool> {
-        Ok(is!('(') || (self.input.syntax().typescript() && is!('<')))
+        Ok(is!('(') || (self.input.syntax().typed() && is!('<')))
     }
 
     fn is_class_property(&mut self) -> PResult<'a, bool> {
-        Ok((self.input.syntax().typescript() && is_one_of!('!', ':')) || is_one_of!('=', ';', '}'))
+        Ok((self.input.syntax().typed() && is_one_of!('!', ':')) || is_one_of!('=', ';', '}'))
     }
 
     fn parse_fn<T>(
@@ -845,7 +907,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
         };
 
         self.with_ctx(ctx).parse_with(|p| {
-            let type_params = if p.syntax().typescript() && is!('<') {
+            let type_params = if p.syntax().typed() && is!('<') {
                 //
                 Some(p.parse_ts_type_params()?)
             } else {
@@ -863,7 +925,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
             expect!(')');
 
             // typescript extension
-            let return_type = if p.syntax().typescript() && is!(':') {
+            let return_type = if p.syntax().typed() && is!(':') {
                 p.parse_ts_type_or_type_predicate_ann(&tok!(':'))
                     .map(Some)?
             } else {
@@ -1139,7 +1201,11 @@ impl<'a, I: Tokens> FnBodyParser<'a, BlockStmtOrExpr> for Parser<'a, I> {
 impl<'a, I: Tokens> FnBodyParser<'a, Option<BlockStmt>> for Parser<'a, I> {
     fn parse_fn_body_inner(&mut self) -> PResult<'a, Option<BlockStmt>> {
         // allow omitting body and allow placing `{` on next line
-        if self.input.syntax().typescript() && !is!('{') && eat!(';') {
+        if (self.input.syntax().typescript()
+            || (self.input.syntax().flow() && self.ctx().in_declare))
+            && !is!('{')
+            && eat!(';')
+        {
             return Ok(None);
         }
         self.include_in_expr(true).parse_block(true).map(Some)
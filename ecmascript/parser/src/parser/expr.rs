@@ -67,10 +67,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
             }
         }
 
-        if self.input.syntax().typescript()
-            && (is_one_of!('<', JSXTagStart))
-            && peeked_is!(IdentName)
-        {
+        if self.input.syntax().typed() && (is_one_of!('<', JSXTagStart)) && peeked_is!(IdentName) {
             let res = self.try_parse_ts(|p| {
                 let type_parameters = p.parse_ts_type_params()?;
                 let mut arrow = p.parse_assignment_expr_base()?;
@@ -244,7 +241,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
                 return self.parse_async_fn_expr();
             }
 
-            if can_be_arrow && self.input.syntax().typescript() && peeked_is!('<') {
+            if can_be_arrow && self.input.syntax().typed() && peeked_is!('<') {
                 // try parsing `async<T>() => {}`
                 if let Some(res) = self.try_parse_ts(|p| {
                     let start = cur_pos!();
@@ -269,6 +266,21 @@ impl<'a, I: Tokens> Parser<'a, I> {
             return self.parse_object();
         }
 
+        // `::obj.func`
+        if eat!("::") {
+            let callee = self.parse_no_call_expr()?;
+            match *callee {
+                Expr::Member(..) => {}
+                _ => syntax_error!(callee.span(), SyntaxError::InvalidBindCallee),
+            }
+
+            return Ok(Box::new(Expr::Bind(BindExpr {
+                span: span!(start),
+                obj: None,
+                callee,
+            })));
+        }
+
         let decorators = self.parse_decorators(false)?;
 
         // Handle FunctionExpression and GeneratorExpression
@@ -458,7 +470,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
             let callee = self.parse_member_expr_or_new_expr(is_new_expr)?;
             return_if_arrow!(callee);
 
-            let type_args = if self.input.syntax().typescript() && is!('<') {
+            let type_args = if self.is_type_args_allowed() && is!('<') {
                 self.try_parse_ts(|p| {
                     let args = p.parse_ts_type_args()?;
                     if !is!('(') {
@@ -531,7 +543,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
                 expect!(',');
                 // Handle trailing comma.
                 if is!(')') {
-                    if is_dynamic_import {
+                    if is_dynamic_import && !self.input.syntax().import_attributes() {
                         syntax_error!(span!(start), SyntaxError::TrailingCommaInsideImport)
                     }
 
@@ -586,11 +598,13 @@ impl<'a, I: Tokens> Parser<'a, I> {
         });
 
         // This is slow path. We handle arrow in conditional expression.
-        if self.syntax().typescript() && self.ctx().in_cond_expr && is!(':') {
+        if self.syntax().typed() && self.ctx().in_cond_expr && is!(':') {
             // TODO: Remove clone
             let items_ref = &paren_items;
             if let Some(expr) = self.try_parse_ts(|p| {
-                let return_type = p.parse_ts_type_or_type_predicate_ann(&tok!(':'))?;
+                let return_type = p
+                    .without_anon_fn_type()
+                    .parse_ts_type_or_type_predicate_ann(&tok!(':'))?;
 
                 expect!("=>");
 
@@ -615,12 +629,14 @@ impl<'a, I: Tokens> Parser<'a, I> {
             }
         }
 
-        let return_type =
-            if !self.ctx().in_cond_expr && self.input.syntax().typescript() && is!(':') {
-                Some(self.parse_ts_type_or_type_predicate_ann(&tok!(':'))?)
-            } else {
-                None
-            };
+        let return_type = if !self.ctx().in_cond_expr && self.input.syntax().typed() && is!(':') {
+            Some(
+                self.without_anon_fn_type()
+                    .parse_ts_type_or_type_predicate_ann(&tok!(':'))?,
+            )
+        } else {
+            None
+        };
 
         // we parse arrow function at here, to handle it efficiently.
         if has_pattern || return_type.is_some() || is!("=>") {
@@ -669,11 +685,29 @@ impl<'a, I: Tokens> Parser<'a, I> {
             return Ok(Box::new(Expr::Arrow(arrow_expr)));
         }
 
+        let is_flow = self.input.syntax().flow();
         let expr_or_spreads = paren_items
             .into_iter()
             .map(|item| -> PResult<'a, _> {
                 match item {
                     PatOrExprOrSpread::ExprOrSpread(e) => Ok(e),
+                    // Flow: `(a: T)` is a type cast.
+                    PatOrExprOrSpread::Pat(Pat::Ident(Ident {
+                        span,
+                        sym,
+                        type_ann: Some(type_ann),
+                        optional: false,
+                    })) if is_flow => Ok(ExprOrSpread {
+                        spread: None,
+                        expr: Box::new(Expr::TsTypeCast(TsTypeCastExpr {
+                            span,
+                            expr: Box::new(Expr::Ident(Ident::new(
+                                sym,
+                                Span::new(span.lo(), type_ann.span.lo(), Default::default()),
+                            ))),
+                            type_ann,
+                        })),
+                    }),
                     _ => syntax_error!(item.span(), SyntaxError::InvalidExpr),
                 }
             })
@@ -813,7 +847,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
         })
     }
 
-    fn parse_tpl_element(&mut self, is_tagged: bool) -> PResult<'a, TplElement> {
+    pub(super) fn parse_tpl_element(&mut self, is_tagged: bool) -> PResult<'a, TplElement> {
         let start = cur_pos!();
 
         let (raw, cooked) = match *cur!(true)? {
@@ -867,8 +901,11 @@ impl<'a, I: Tokens> Parser<'a, I> {
         let _ = cur!(false);
         let start = obj.span().lo();
 
-        if self.input.syntax().typescript() {
-            if !self.input.had_line_break_before_cur() && is!('!') {
+        if self.input.syntax().typed() {
+            if self.input.syntax().typescript()
+                && !self.input.had_line_break_before_cur()
+                && is!('!')
+            {
                 self.input.set_expr_allowed(false);
                 assert_and_bump!('!');
 
@@ -891,7 +928,8 @@ impl<'a, I: Tokens> Parser<'a, I> {
                     // super() cannot be generic
                     _ => false,
                 }
-            } && is!('<')
+            } && self.is_type_args_allowed()
+                && is!('<')
             {
                 let obj_ref = &obj;
                 // tsTryParseAndCatch is expensive, so avoid if not necessary.
@@ -1026,6 +1064,19 @@ impl<'a, I: Tokens> Parser<'a, I> {
 
         match obj {
             ExprOrSuper::Expr(expr) => {
+                // $obj::func
+                if !no_call && eat!("::") {
+                    let callee = self.parse_no_call_expr()?;
+                    return Ok((
+                        Box::new(Expr::Bind(BindExpr {
+                            span: span!(start),
+                            obj: Some(expr),
+                            callee,
+                        })),
+                        true,
+                    ));
+                }
+
                 // MemberExpression[?Yield, ?Await] TemplateLiteral[?Yield, ?Await, +Tagged]
                 if is!('`') {
                     let tpl = self.parse_tagged_tpl(expr, None)?;
@@ -1042,6 +1093,21 @@ impl<'a, I: Tokens> Parser<'a, I> {
             }
         }
     }
+
+    /// Parses the callee of a function bind expression.
+    ///
+    /// babel: `parseNoCallExpr`
+    fn parse_no_call_expr(&mut self) -> PResult<'a, Box<Expr>> {
+        let start = cur_pos!();
+        let obj = if eat!("super") {
+            ExprOrSuper::Super(Super { span: span!(start) })
+        } else {
+            ExprOrSuper::Expr(self.parse_primary_expr()?)
+        };
+
+        self.parse_subscripts(obj, true)
+    }
+
     /// Parse call, dot, and `[]`-subscript expressions.
     pub(super) fn parse_lhs_expr(&mut self) -> PResult<'a, Box<Expr>> {
         let start = cur_pos!();
@@ -1088,7 +1154,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
         let callee = self.parse_new_expr()?;
         return_if_arrow!(callee);
 
-        let type_args = if self.input.syntax().typescript() && is!('<') {
+        let type_args = if self.is_type_args_allowed() && is!('<') {
             self.try_parse_ts(|p| {
                 let type_args = p.parse_ts_type_args()?;
                 if is!('(') {
@@ -1187,7 +1253,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
             let pat_start = cur_pos!();
 
             let mut arg = {
-                if self.input.syntax().typescript()
+                if self.input.syntax().typed()
                     && (is!(IdentRef) || (is!("...") && peeked_is!(IdentRef)))
                 {
                     let spread = if eat!("...") {
@@ -1216,7 +1282,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
                 }
             };
 
-            let optional = if self.input.syntax().typescript() {
+            let optional = if self.input.syntax().typed() {
                 if is!('?') {
                     if peeked_is!(',') || peeked_is!(':') || peeked_is!(')') || peeked_is!('=') {
                         assert_and_bump!('?');
@@ -1272,7 +1338,35 @@ impl<'a, I: Tokens> Parser<'a, I> {
                 false
             };
 
-            if optional || (self.input.syntax().typescript() && is!(':')) {
+            // Flow: `(a.b: T)` is a type cast, as `a.b` cannot be a parameter.
+            if !optional
+                && self.input.syntax().flow()
+                && is!(':')
+                && match arg {
+                    ExprOrSpread {
+                        spread: None,
+                        ref expr,
+                    } => match **expr {
+                        Expr::Ident(..) | Expr::Array(..) | Expr::Object(..) | Expr::Assign(..) => {
+                            false
+                        }
+                        _ => true,
+                    },
+                    _ => false,
+                }
+            {
+                let type_ann = self.parse_ts_type_ann(true, cur_pos!())?;
+                arg = ExprOrSpread {
+                    spread: None,
+                    expr: Box::new(Expr::TsTypeCast(TsTypeCastExpr {
+                        span: span!(start),
+                        expr: arg.expr,
+                        type_ann,
+                    })),
+                };
+            }
+
+            if optional || (self.input.syntax().typed() && is!(':')) {
                 let start = cur_pos!();
 
                 // TODO: `async(...args?: any[]) : any => {}`
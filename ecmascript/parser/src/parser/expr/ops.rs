@@ -10,7 +10,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
     pub(super) fn parse_bin_expr(&mut self) -> PResult<'a, Box<Expr>> {
         let ctx = self.ctx();
 
-        let left = match self.parse_unary_expr() {
+        let left = match self.parse_unary_expr_or_private_name() {
             Ok(v) => v,
             Err(mut err) => {
                 match {
@@ -150,7 +150,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
         }
 
         let right = {
-            let left_of_right = self.parse_unary_expr()?;
+            let left_of_right = self.parse_unary_expr_or_private_name()?;
             self.parse_bin_op_recursively(
                 left_of_right,
                 if op == op!("**") {
@@ -161,6 +161,11 @@ impl<'a, I: Tokens> Parser<'a, I> {
                 },
             )?
         };
+        // `#x` is only allowed on the left of `in`, as in `a + #x in b`.
+        if let Expr::PrivateName(ref n) = *right {
+            syntax_error!(n.span, SyntaxError::PrivateNameNotInOrMember)
+        }
+
         /* this check is for all ?? operators
          * a ?? b && c for this example
          * b && c => This is considered as a logical expression in the ast tree
@@ -210,6 +215,19 @@ impl<'a, I: Tokens> Parser<'a, I> {
         Ok(expr)
     }
 
+    /// Parses `#x` of `#x in obj`, or an unary expression.
+    fn parse_unary_expr_or_private_name(&mut self) -> PResult<'a, Box<Expr>> {
+        if is!('#') && self.input.syntax().private_in_object() {
+            let name = self.parse_private_name()?;
+            if !(is!("in") && self.ctx().include_in_expr) {
+                syntax_error!(name.span, SyntaxError::PrivateNameNotInOrMember)
+            }
+            return Ok(Box::new(Expr::PrivateName(name)));
+        }
+
+        self.parse_unary_expr()
+    }
+
     /// Parse unary expression and update expression.
     ///
     /// spec: 'UnaryExpression'
@@ -424,6 +424,109 @@ fn issue_675() {
     expr("Object.setPrototypeOf(this, new.target.prototype)");
 }
 
+fn private_in_object(s: &'static str) -> Box<Expr> {
+    test_parser(
+        s,
+        Syntax::Es(EsConfig {
+            private_in_object: true,
+            ..Default::default()
+        }),
+        |p| {
+            p.parse_expr().map_err(|mut e| {
+                e.emit();
+            })
+        },
+    )
+}
+
+#[test]
+fn private_in() {
+    let brand_check = |name: &str| {
+        box Expr::Bin(BinExpr {
+            span,
+            op: op!("in"),
+            left: box Expr::PrivateName(PrivateName {
+                span,
+                id: Ident::new(name.into(), span),
+            }),
+            right: box Expr::Ident(Ident::new("obj".into(), span)),
+        })
+    };
+
+    assert_eq_ignore_span!(private_in_object("#x in obj"), brand_check("x"));
+    assert_eq_ignore_span!(
+        private_in_object("#x in obj && #y in obj"),
+        box Expr::Bin(BinExpr {
+            span,
+            op: op!("&&"),
+            left: brand_check("x"),
+            right: brand_check("y"),
+        })
+    );
+}
+
+#[test]
+#[should_panic(expected = "Private names are only allowed")]
+fn private_in_without_in() {
+    private_in_object("#x + obj");
+}
+
+#[test]
+#[should_panic(expected = "Private names are only allowed")]
+fn private_in_right_of_higher_precedence() {
+    private_in_object("a + #x in obj");
+}
+
+#[test]
+fn static_block() {
+    let body = test_parser(
+        "class Foo { static { this.bar = 1; } static = 1 }",
+        Syntax::Es(EsConfig {
+            static_blocks: true,
+            class_props: true,
+            ..Default::default()
+        }),
+        |p| {
+            p.parse_stmt(true).map_err(|mut e| {
+                e.emit();
+            })
+        },
+    );
+    let body = match body {
+        Stmt::Decl(Decl::Class(ClassDecl { class, .. })) => class.body,
+        _ => unreachable!(),
+    };
+
+    assert_eq!(body.len(), 2);
+    match body[0] {
+        ClassMember::StaticBlock(StaticBlock { ref body, .. }) => assert_eq!(body.stmts.len(), 1),
+        _ => panic!("expected a static block, got {:?}", body[0]),
+    }
+}
+
+#[test]
+#[should_panic(expected = "Return statement is not allowed here")]
+fn static_block_return() {
+    test_parser(
+        "function f() { class Foo { static { return; } } }",
+        Syntax::Es(EsConfig {
+            static_blocks: true,
+            ..Default::default()
+        }),
+        |p| {
+            p.parse_module().map_err(|mut e| {
+                e.emit();
+            })?;
+            // `return` is reported without aborting the parser.
+            if p.session.handler.has_errors() {
+                Err(())
+            } else {
+                Ok(())
+            }
+        },
+    );
+}
+
 #[bench]
 fn bench_new_expr_ts(b: &mut Bencher) {
     bench_parser(
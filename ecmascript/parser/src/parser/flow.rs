@@ -0,0 +1,991 @@
+//! Flow extension.
+//!
+//! Flow types are parsed into the typescript ast. Constructs without a
+//! typescript counterpart are lowered to the closest node, e.g. `?T` becomes
+//! `T | null | undefined` and exact object types `{| a: T |}` become type
+//! literals. Variance annotations and supertypes of opaque types are dropped.
+use super::{typescript::make_decl_declare, *};
+use crate::{token::BinOpToken, FlowConfig};
+use swc_common::Spanned;
+
+#[parser]
+impl<'a, I: Tokens> Parser<'a, I> {
+    /// `flowParseType`
+    pub(super) fn parse_flow_type(&mut self) -> PResult<'a, Box<TsType>> {
+        debug_assert!(self.input.syntax().flow());
+        debug_assert!(self.ctx().in_type);
+
+        let start = cur_pos!();
+        eat!('|');
+
+        let ty = self.parse_flow_intersection_type()?;
+        if !self.is_flow_type_op(&tok!('|')) {
+            return Ok(ty);
+        }
+
+        let mut types = vec![ty];
+        while self.is_flow_type_op(&tok!('|')) {
+            bump!();
+            types.push(self.parse_flow_intersection_type()?);
+        }
+
+        Ok(Box::new(TsType::from(TsUnionType {
+            span: span!(start),
+            types,
+        })))
+    }
+
+    /// `flowParseIntersectionType`
+    fn parse_flow_intersection_type(&mut self) -> PResult<'a, Box<TsType>> {
+        let start = cur_pos!();
+        eat!('&');
+
+        let ty = self.parse_flow_anon_fn_without_parens()?;
+        if !self.is_flow_type_op(&tok!('&')) {
+            return Ok(ty);
+        }
+
+        let mut types = vec![ty];
+        while self.is_flow_type_op(&tok!('&')) {
+            bump!();
+            types.push(self.parse_flow_anon_fn_without_parens()?);
+        }
+
+        Ok(Box::new(TsType::from(TsIntersectionType {
+            span: span!(start),
+            types,
+        })))
+    }
+
+    /// `flowParseAnonFunctionWithoutParens`
+    ///
+    /// Handles `T => U`.
+    fn parse_flow_anon_fn_without_parens(&mut self) -> PResult<'a, Box<TsType>> {
+        let start = cur_pos!();
+
+        let param = self.parse_flow_prefix_type()?;
+        if self.ctx().no_anon_fn_type || !is!("=>") {
+            return Ok(param);
+        }
+
+        let params = vec![TsFnParam::Ident(unnamed_fn_param(0, param))];
+        self.finish_flow_fn_type(start, None, params)
+    }
+
+    /// `flowParsePrefixType`
+    fn parse_flow_prefix_type(&mut self) -> PResult<'a, Box<TsType>> {
+        let start = cur_pos!();
+
+        if eat!('?') {
+            let ty = self.parse_flow_prefix_type()?;
+            let span = span!(start);
+            return Ok(Box::new(TsType::from(TsUnionType {
+                span,
+                types: vec![
+                    ty,
+                    keyword(span, TsKeywordTypeKind::TsNullKeyword),
+                    keyword(span, TsKeywordTypeKind::TsUndefinedKeyword),
+                ],
+            })));
+        }
+
+        self.parse_flow_postfix_type()
+    }
+
+    /// `flowParsePostfixType`
+    fn parse_flow_postfix_type(&mut self) -> PResult<'a, Box<TsType>> {
+        let start = cur_pos!();
+
+        let mut ty = self.parse_flow_primary_type()?;
+        while !self.input.had_line_break_before_cur() && eat!('[') {
+            if eat!(']') {
+                ty = Box::new(TsType::TsArrayType(TsArrayType {
+                    span: span!(start),
+                    elem_type: ty,
+                }));
+            } else {
+                let index_type = self.parse_flow_type_in_brackets()?;
+                expect!(']');
+                ty = Box::new(TsType::TsIndexedAccessType(TsIndexedAccessType {
+                    span: span!(start),
+                    readonly: false,
+                    obj_type: ty,
+                    index_type,
+                }));
+            }
+        }
+
+        Ok(ty)
+    }
+
+    /// `flowParsePrimaryType`
+    fn parse_flow_primary_type(&mut self) -> PResult<'a, Box<TsType>> {
+        let start = cur_pos!();
+
+        let kind = match *cur!(true)? {
+            Token::Word(Word::Ident(ref sym)) => match &**sym {
+                "any" => Some(TsKeywordTypeKind::TsAnyKeyword),
+                "mixed" => Some(TsKeywordTypeKind::TsUnknownKeyword),
+                "empty" => Some(TsKeywordTypeKind::TsNeverKeyword),
+                "bool" | "boolean" => Some(TsKeywordTypeKind::TsBooleanKeyword),
+                "number" => Some(TsKeywordTypeKind::TsNumberKeyword),
+                "bigint" => Some(TsKeywordTypeKind::TsBigIntKeyword),
+                "string" => Some(TsKeywordTypeKind::TsStringKeyword),
+                "symbol" => Some(TsKeywordTypeKind::TsSymbolKeyword),
+                _ => None,
+            },
+            tok!("void") => Some(TsKeywordTypeKind::TsVoidKeyword),
+            tok!("null") => Some(TsKeywordTypeKind::TsNullKeyword),
+            // Existential type
+            tok!('*') => Some(TsKeywordTypeKind::TsAnyKeyword),
+            _ => None,
+        };
+        if let Some(kind) = kind {
+            if !peeked_is!('.') {
+                bump!();
+                return Ok(keyword(span!(start), kind));
+            }
+        }
+
+        match *cur!(true)? {
+            tok!('{') => return self.parse_flow_obj_type(),
+            tok!('[') => return self.parse_flow_tuple_type(),
+            tok!('(') => return self.parse_flow_paren_or_fn_type(),
+            tok!('<') => {
+                let type_params = self.parse_ts_type_params()?;
+                expect!('(');
+                let params = self.parse_flow_fn_type_params(vec![])?;
+                return self.finish_flow_fn_type(start, Some(type_params), params);
+            }
+            tok!("typeof") => {
+                return self.parse_ts_type_query().map(TsType::from).map(Box::new);
+            }
+            tok!("this") => {
+                return self
+                    .parse_ts_this_type_node()
+                    .map(TsType::from)
+                    .map(Box::new);
+            }
+            Token::Str { .. } | Token::Num { .. } | tok!("true") | tok!("false") => {
+                return self
+                    .parse_ts_lit_type_node()
+                    .map(TsType::from)
+                    .map(Box::new);
+            }
+            tok!('-') => {
+                bump!();
+                let lit = match self.parse_lit()? {
+                    Lit::Num(num) => TsLit::Number(Number {
+                        span: num.span,
+                        value: -num.value,
+                    }),
+                    _ => unexpected!(),
+                };
+
+                return Ok(Box::new(TsType::TsLitType(TsLitType {
+                    span: span!(start),
+                    lit,
+                })));
+            }
+            Token::Word(Word::Ident(..)) => return self.parse_flow_generic_type(),
+            _ => {}
+        }
+
+        unexpected!()
+    }
+
+    /// `flowParseGenericType`
+    fn parse_flow_generic_type(&mut self) -> PResult<'a, Box<TsType>> {
+        let start = cur_pos!();
+
+        let type_name = self.parse_ts_entity_name(/* allow_reserved_words */ true)?;
+        let type_params = if is!('<') {
+            let ctx = Context {
+                no_anon_fn_type: false,
+                ..self.ctx()
+            };
+            Some(self.with_ctx(ctx).parse_ts_type_args()?)
+        } else {
+            None
+        };
+
+        Ok(Box::new(TsType::TsTypeRef(TsTypeRef {
+            span: span!(start),
+            type_name,
+            type_params,
+        })))
+    }
+
+    /// `flowParseTupleType`
+    fn parse_flow_tuple_type(&mut self) -> PResult<'a, Box<TsType>> {
+        let start = cur_pos!();
+        expect!('[');
+
+        let mut elem_types = vec![];
+        while !eof!() && !is!(']') {
+            let ty = self.parse_flow_type_in_brackets()?;
+            elem_types.push(TsTupleElement {
+                span: ty.span(),
+                label: None,
+                ty,
+            });
+            if !is!(']') {
+                expect!(',');
+            }
+        }
+        expect!(']');
+
+        Ok(Box::new(TsType::TsTupleType(TsTupleType {
+            span: span!(start),
+            elem_types,
+        })))
+    }
+
+    /// Parses a parenthesized type or a function type.
+    fn parse_flow_paren_or_fn_type(&mut self) -> PResult<'a, Box<TsType>> {
+        let start = cur_pos!();
+        expect!('(');
+
+        // `(A)`, `(A | B)` and `(A, B) => C` start with a type, while `(a: A) => B`
+        // and `(...A) => B` start with a parameter.
+        let is_grouped = if is!(')') || is!("...") {
+            false
+        } else if is!(IdentName) {
+            !peeked_is!('?') && !peeked_is!(':')
+        } else {
+            true
+        };
+
+        let mut params = vec![];
+        if is_grouped {
+            let ty = self.parse_flow_type_in_brackets()?;
+
+            if self.ctx().no_anon_fn_type || !(is!(',') || (is!(')') && peeked_is!("=>"))) {
+                expect!(')');
+                return Ok(Box::new(TsType::TsParenthesizedType(TsParenthesizedType {
+                    span: span!(start),
+                    type_ann: ty,
+                })));
+            }
+
+            eat!(',');
+            params.push(TsFnParam::Ident(unnamed_fn_param(0, ty)));
+        }
+
+        let params = self.parse_flow_fn_type_params(params)?;
+        self.finish_flow_fn_type(start, None, params)
+    }
+
+    /// `flowParseFunctionTypeParams`
+    ///
+    /// This should be called after eating `(`. This eats `)`.
+    fn parse_flow_fn_type_params(
+        &mut self,
+        mut params: Vec<TsFnParam>,
+    ) -> PResult<'a, Vec<TsFnParam>> {
+        while !eof!() && !is!(')') {
+            let start = cur_pos!();
+
+            if eat!("...") {
+                let dot3_token = span!(start);
+                let mut arg = self.parse_flow_fn_type_param(params.len())?;
+                let type_ann = arg.type_ann.take();
+                params.push(TsFnParam::Rest(RestPat {
+                    span: span!(start),
+                    dot3_token,
+                    arg: Box::new(Pat::Ident(arg)),
+                    type_ann,
+                }));
+            } else {
+                let param = self.parse_flow_fn_type_param(params.len())?;
+                params.push(TsFnParam::Ident(param));
+            }
+
+            if !is!(')') {
+                expect!(',');
+            }
+        }
+        expect!(')');
+
+        Ok(params)
+    }
+
+    /// `flowParseFunctionTypeParam`
+    ///
+    /// Unnamed parameters like `string` in `(string) => void` are named `_0`,
+    /// `_1`, ... by their position.
+    fn parse_flow_fn_type_param(&mut self, index: usize) -> PResult<'a, Ident> {
+        let start = cur_pos!();
+
+        if is!(IdentName) && (peeked_is!(':') || peeked_is!('?')) {
+            let mut name = self.parse_ident_name()?;
+            name.optional = eat!('?');
+
+            let type_ann_start = cur_pos!();
+            expect!(':');
+            let type_ann = self.parse_flow_type_in_brackets()?;
+
+            name.type_ann = Some(TsTypeAnn {
+                span: span!(type_ann_start),
+                type_ann,
+            });
+            name.span = span!(start);
+            return Ok(name);
+        }
+
+        let ty = self.parse_flow_type_in_brackets()?;
+        Ok(unnamed_fn_param(index, ty))
+    }
+
+    /// Parses `=> ReturnType` of a function type.
+    fn finish_flow_fn_type(
+        &mut self,
+        start: BytePos,
+        type_params: Option<TsTypeParamDecl>,
+        params: Vec<TsFnParam>,
+    ) -> PResult<'a, Box<TsType>> {
+        let return_type_start = cur_pos!();
+        expect!("=>");
+        let type_ann = self.parse_flow_type()?;
+
+        Ok(Box::new(TsType::from(TsFnType {
+            span: span!(start),
+            params,
+            type_params,
+            type_ann: TsTypeAnn {
+                span: span!(return_type_start),
+                type_ann,
+            },
+        })))
+    }
+
+    /// `flowParseObjectType`
+    ///
+    /// Spreads are converted to an intersection type.
+    fn parse_flow_obj_type(&mut self) -> PResult<'a, Box<TsType>> {
+        let start = cur_pos!();
+
+        let (members, mut types) = self.parse_flow_obj_type_members()?;
+        let lit = Box::new(TsType::TsTypeLit(TsTypeLit {
+            span: span!(start),
+            members,
+        }));
+        if types.is_empty() {
+            return Ok(lit);
+        }
+
+        types.push(lit);
+        Ok(Box::new(TsType::from(TsIntersectionType {
+            span: span!(start),
+            types,
+        })))
+    }
+
+    /// Parses the body of an interface.
+    pub(super) fn parse_flow_interface_body(&mut self) -> PResult<'a, Vec<TsTypeElement>> {
+        let (members, spreads) = self.parse_flow_obj_type_members()?;
+        if !spreads.is_empty() {
+            unexpected!()
+        }
+
+        Ok(members)
+    }
+
+    /// Parses `{ ... }` or `{| ... |}` and returns members and types of spread
+    /// elements.
+    fn parse_flow_obj_type_members(
+        &mut self,
+    ) -> PResult<'a, (Vec<TsTypeElement>, Vec<Box<TsType>>)> {
+        let ctx = Context {
+            no_anon_fn_type: false,
+            ..self.ctx()
+        };
+
+        self.with_ctx(ctx).parse_with(|p| {
+            expect!('{');
+
+            // `{||}` is lexed as `{`, `||`, `}`.
+            if p.input.is(&Token::BinOp(BinOpToken::LogicalOr)) {
+                bump!();
+                expect!('}');
+                return Ok((vec![], vec![]));
+            }
+            let exact = eat!('|');
+
+            let mut members = vec![];
+            let mut spreads = vec![];
+            loop {
+                if exact && eat!('|') {
+                    expect!('}');
+                    break;
+                }
+                if !exact && eat!('}') {
+                    break;
+                }
+
+                let start = cur_pos!();
+                if eat!("...") {
+                    // `...` without a type marks an inexact object type.
+                    if !is_one_of!('}', '|', ',') && !is_exact!(';') {
+                        spreads.push(p.parse_flow_type()?);
+                    }
+                } else {
+                    members.push(p.parse_flow_obj_type_member(start)?);
+                }
+
+                if !eat!(',') && !eat!(';') && !is!('|') {
+                    unexpected!()
+                }
+            }
+
+            Ok((members, spreads))
+        })
+    }
+
+    /// `flowParseObjectTypeProperty`, `flowParseObjectTypeIndexer` and
+    /// `flowParseObjectTypeCallProperty`
+    fn parse_flow_obj_type_member(&mut self, start: BytePos) -> PResult<'a, TsTypeElement> {
+        if is!('(') || is!('<') {
+            let (type_params, params, type_ann) = self.parse_flow_method_signature()?;
+            return Ok(TsTypeElement::TsCallSignatureDecl(TsCallSignatureDecl {
+                span: span!(start),
+                params,
+                type_ann: Some(type_ann),
+                type_params,
+            }));
+        }
+
+        let readonly = if eat!('+') {
+            true
+        } else {
+            eat!('-');
+            false
+        };
+
+        if eat!('[') {
+            let key = if is!(IdentName) && peeked_is!(':') {
+                let key = self.parse_ident_name()?;
+                expect!(':');
+                Some(key)
+            } else {
+                None
+            };
+            let key_type = self.parse_flow_type()?;
+            expect!(']');
+
+            let type_ann_start = cur_pos!();
+            expect!(':');
+            let value_type = self.parse_flow_type()?;
+
+            let key_span = key_type.span();
+            let mut key = key.unwrap_or_else(|| Ident::new("key".into(), key_span));
+            key.type_ann = Some(TsTypeAnn {
+                span: key_span,
+                type_ann: key_type,
+            });
+
+            return Ok(TsTypeElement::TsIndexSignature(TsIndexSignature {
+                params: vec![TsFnParam::Ident(key)],
+                type_ann: Some(TsTypeAnn {
+                    span: span!(type_ann_start),
+                    type_ann: value_type,
+                }),
+                readonly,
+                span: span!(start),
+            }));
+        }
+
+        let key = match *cur!(true)? {
+            Token::Str { .. } | Token::Num { .. } => Box::new(Expr::Lit(self.parse_lit()?)),
+            _ => Box::new(Expr::Ident(self.parse_ident_name()?)),
+        };
+
+        if is!('(') || is!('<') {
+            let (type_params, params, type_ann) = self.parse_flow_method_signature()?;
+            return Ok(TsTypeElement::TsMethodSignature(TsMethodSignature {
+                span: span!(start),
+                readonly,
+                key,
+                computed: false,
+                optional: false,
+                params,
+                type_ann: Some(type_ann),
+                type_params,
+            }));
+        }
+
+        let optional = eat!('?');
+        let type_ann_start = cur_pos!();
+        expect!(':');
+        let type_ann = self.parse_flow_type()?;
+
+        Ok(TsTypeElement::TsPropertySignature(TsPropertySignature {
+            span: span!(start),
+            readonly,
+            key,
+            computed: false,
+            optional,
+            init: None,
+            params: vec![],
+            type_ann: Some(TsTypeAnn {
+                span: span!(type_ann_start),
+                type_ann,
+            }),
+            type_params: None,
+        }))
+    }
+
+    /// Parses `<T>(a: A): R` of methods and call properties.
+    fn parse_flow_method_signature(
+        &mut self,
+    ) -> PResult<'a, (Option<TsTypeParamDecl>, Vec<TsFnParam>, TsTypeAnn)> {
+        let type_params = self.try_parse_ts_type_params()?;
+        expect!('(');
+        let params = self.parse_flow_fn_type_params(vec![])?;
+
+        let start = cur_pos!();
+        expect!(':');
+        let type_ann = self.parse_flow_type()?;
+
+        Ok((
+            type_params,
+            params,
+            TsTypeAnn {
+                span: span!(start),
+                type_ann,
+            },
+        ))
+    }
+
+    /// `flowParseTypeParameter`
+    ///
+    /// The bound (`T: Bound`) is stored as the constraint.
+    pub(super) fn parse_flow_type_param(&mut self) -> PResult<'a, TsTypeParam> {
+        let start = cur_pos!();
+
+        if !eat!('+') {
+            eat!('-');
+        }
+        let name = self.parse_ident_name()?;
+
+        let constraint = if eat!(':') {
+            Some(self.in_type().parse_flow_type()?)
+        } else {
+            None
+        };
+        let default = if eat!('=') {
+            Some(self.in_type().parse_flow_type()?)
+        } else {
+            None
+        };
+
+        Ok(TsTypeParam {
+            span: span!(start),
+            name,
+            constraint,
+            default,
+        })
+    }
+
+    /// Parses a return type, which may be followed by `%checks`.
+    ///
+    /// `%checks` without a type is treated as `boolean`.
+    pub(super) fn parse_flow_return_type_ann(
+        &mut self,
+        return_token: &'static Token,
+    ) -> PResult<'a, TsTypeAnn> {
+        self.in_type().parse_with(|p| {
+            let start = cur_pos!();
+            if !p.input.eat(return_token) {
+                let cur = format!("{:?}", cur!(false).ok());
+                let span = p.input.cur_span();
+                syntax_error!(span, SyntaxError::Expected(return_token, cur))
+            }
+
+            if p.eat_flow_checks() {
+                return Ok(TsTypeAnn {
+                    span: span!(start),
+                    type_ann: keyword(span!(start), TsKeywordTypeKind::TsBooleanKeyword),
+                });
+            }
+
+            let type_ann = p.parse_flow_type()?;
+            p.eat_flow_checks();
+
+            Ok(TsTypeAnn {
+                span: span!(start),
+                type_ann,
+            })
+        })
+    }
+
+    /// Eats `%checks`.
+    fn eat_flow_checks(&mut self) -> bool {
+        if self.input.is(&Token::BinOp(BinOpToken::Mod)) && self.peeked_is_flow_word("checks") {
+            bump!();
+            bump!();
+            return true;
+        }
+
+        false
+    }
+
+    /// Parses declarations starting with an identifier, like `type`, `opaque
+    /// type`, `interface` and `declare`.
+    pub(super) fn parse_flow_expr_stmt(&mut self, expr: Ident) -> PResult<'a, Option<Decl>> {
+        let start = expr.span.lo();
+
+        if self.input.had_line_break_before_cur() {
+            return Ok(None);
+        }
+
+        match &*expr.sym {
+            "type" if is!(IdentRef) => self
+                .parse_ts_type_alias_decl(start)
+                .map(Decl::from)
+                .map(Some),
+            "opaque" if is!("type") => self
+                .parse_flow_opaque_type(start, false)
+                .map(Decl::from)
+                .map(Some),
+            "interface" if is!(IdentRef) => self
+                .parse_ts_interface_decl(start)
+                .map(Decl::from)
+                .map(Some),
+            "declare" => self.parse_flow_declare(start),
+            _ => Ok(None),
+        }
+    }
+
+    /// `flowParseDeclare`
+    ///
+    /// This should be called after eating `declare`.
+    fn parse_flow_declare(&mut self, start: BytePos) -> PResult<'a, Option<Decl>> {
+        let ctx = Context {
+            in_declare: true,
+            ..self.ctx()
+        };
+
+        let decl = self.with_ctx(ctx).parse_with(|p| {
+            if is!("class") {
+                return p.parse_class_decl(start, start, vec![]).map(Some);
+            }
+            if is!("function") {
+                return p.parse_fn_decl(vec![]).map(Some);
+            }
+            if is_one_of!("var", "let", "const") {
+                return p.parse_var_stmt(false).map(Decl::Var).map(Some);
+            }
+
+            if p.is_flow_word("module") {
+                if peeked_is!('.') {
+                    syntax_error!(SyntaxError::FlowDeclareModuleExports)
+                }
+                bump!();
+
+                let is_str = match *cur!(true)? {
+                    Token::Str { .. } => true,
+                    _ => false,
+                };
+                let decl = if is_str {
+                    p.parse_ts_ambient_external_module_decl(start)?
+                } else {
+                    p.parse_ts_module_or_ns_decl(start)?
+                };
+                return Ok(Some(Decl::from(decl)));
+            }
+
+            if is!("type") && peeked_is!(IdentRef) {
+                bump!();
+                return p.parse_ts_type_alias_decl(start).map(Decl::from).map(Some);
+            }
+            if p.is_flow_word("opaque") && peeked_is!("type") {
+                bump!();
+                return p
+                    .parse_flow_opaque_type(start, true)
+                    .map(Decl::from)
+                    .map(Some);
+            }
+            if is!("interface") && peeked_is!(IdentRef) {
+                bump!();
+                return p.parse_ts_interface_decl(start).map(Decl::from).map(Some);
+            }
+
+            Ok(None)
+        })?;
+
+        Ok(decl.map(make_decl_declare))
+    }
+
+    /// `opaque type A: Super = T`
+    ///
+    /// This should be called after eating `opaque`. If the type is declared,
+    /// the right hand side is optional and defaults to the supertype.
+    fn parse_flow_opaque_type(
+        &mut self,
+        start: BytePos,
+        declare: bool,
+    ) -> PResult<'a, TsTypeAliasDecl> {
+        assert_and_bump!("type");
+
+        let id = self.parse_ident_name()?;
+        let type_params = self.try_parse_ts_type_params()?;
+
+        let supertype = if eat!(':') {
+            Some(self.in_type().parse_flow_type()?)
+        } else {
+            None
+        };
+
+        let type_ann = if !declare || is!('=') {
+            expect!('=');
+            self.in_type().parse_flow_type()?
+        } else {
+            match supertype {
+                Some(ty) => ty,
+                None => keyword(span!(start), TsKeywordTypeKind::TsUnknownKeyword),
+            }
+        };
+        expect!(';');
+
+        Ok(TsTypeAliasDecl {
+            span: span!(start),
+            declare,
+            id,
+            type_params,
+            type_ann,
+        })
+    }
+
+    /// Parses `type`, `opaque type` and `interface` after `export`.
+    pub(super) fn try_parse_flow_export_decl(&mut self) -> PResult<'a, Option<Decl>> {
+        let start = cur_pos!();
+
+        if is!("type") && peeked_is!(IdentRef) {
+            bump!();
+            return self
+                .parse_ts_type_alias_decl(start)
+                .map(Decl::from)
+                .map(Some);
+        }
+        if self.is_flow_word("opaque") && peeked_is!("type") {
+            bump!();
+            let declare = self.ctx().in_declare;
+            return self
+                .parse_flow_opaque_type(start, declare)
+                .map(Decl::from)
+                .map(Some);
+        }
+        if is!("interface") && peeked_is!(IdentRef) {
+            bump!();
+            return self
+                .parse_ts_interface_decl(start)
+                .map(Decl::from)
+                .map(Some);
+        }
+
+        Ok(None)
+    }
+}
+
+impl<'a, I: Tokens> Parser<'a, I> {
+    /// Returns true if type arguments of calls like `f<T>(x)` should be parsed.
+    pub(super) fn is_type_args_allowed(&self) -> bool {
+        match self.input.syntax() {
+            Syntax::Typescript(..) => true,
+            Syntax::Flow(FlowConfig { all, .. }) => all,
+            _ => false,
+        }
+    }
+
+    /// Original context is restored when returned guard is dropped.
+    ///
+    /// Used for the return type of arrow functions, where `T => U` is not a
+    /// function type.
+    pub(super) fn without_anon_fn_type<'w>(&'w mut self) -> WithCtx<'w, 'a, I> {
+        let ctx = Context {
+            no_anon_fn_type: self.input.syntax().flow(),
+            ..self.ctx()
+        };
+        self.with_ctx(ctx)
+    }
+
+    /// `(A)` in a type position resets `no_anon_fn_type`.
+    fn parse_flow_type_in_brackets(&mut self) -> PResult<'a, Box<TsType>> {
+        let ctx = Context {
+            no_anon_fn_type: false,
+            ..self.ctx()
+        };
+        self.with_ctx(ctx).parse_flow_type()
+    }
+
+    /// Returns true if `op` is a union or intersection operator.
+    ///
+    /// `|` followed by `}` ends an exact object type.
+    fn is_flow_type_op(&mut self, op: &Token) -> bool {
+        self.input.is(op) && !self.input.peeked_is(&tok!('}'))
+    }
+
+    /// Returns true if the current token is the contextual keyword `word`.
+    fn is_flow_word(&mut self, word: &str) -> bool {
+        match self.input.cur() {
+            Some(&Token::Word(Word::Ident(ref w))) => &**w == word,
+            _ => false,
+        }
+    }
+
+    fn peeked_is_flow_word(&mut self, word: &str) -> bool {
+        match self.input.peek() {
+            Some(&Token::Word(Word::Ident(ref w))) => &**w == word,
+            _ => false,
+        }
+    }
+}
+
+fn keyword(span: Span, kind: TsKeywordTypeKind) -> Box<TsType> {
+    Box::new(TsType::TsKeywordType(TsKeywordType { span, kind }))
+}
+
+fn unnamed_fn_param(index: usize, ty: Box<TsType>) -> Ident {
+    let span = ty.span();
+    Ident {
+        span,
+        sym: format!("_{}", index).into(),
+        type_ann: Some(TsTypeAnn { span, type_ann: ty }),
+        optional: false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{test_parser, FlowConfig, Syntax};
+    use swc_common::DUMMY_SP;
+    use swc_ecma_ast::*;
+    use testing::assert_eq_ignore_span;
+
+    fn syntax() -> Syntax {
+        Syntax::Flow(FlowConfig {
+            all: true,
+            ..Default::default()
+        })
+    }
+
+    fn type_alias(src: &'static str) -> Box<TsType> {
+        let module = test_parser(src, syntax(), |p| {
+            p.parse_module().map_err(|mut e| e.emit())
+        });
+        match module.body.into_iter().next() {
+            Some(ModuleItem::Stmt(Stmt::Decl(Decl::TsTypeAlias(alias)))) => alias.type_ann,
+            item => panic!("expected a type alias, got {:?}", item),
+        }
+    }
+
+    fn keyword(kind: TsKeywordTypeKind) -> Box<TsType> {
+        box TsType::TsKeywordType(TsKeywordType {
+            span: DUMMY_SP,
+            kind,
+        })
+    }
+
+    #[test]
+    fn maybe_type() {
+        assert_eq_ignore_span!(
+            type_alias("type A = ?mixed;"),
+            box TsType::from(TsUnionType {
+                span: DUMMY_SP,
+                types: vec![
+                    keyword(TsKeywordTypeKind::TsUnknownKeyword),
+                    keyword(TsKeywordTypeKind::TsNullKeyword),
+                    keyword(TsKeywordTypeKind::TsUndefinedKeyword),
+                ],
+            })
+        );
+    }
+
+    #[test]
+    fn exact_object() {
+        assert_eq_ignore_span!(
+            type_alias("type A = {| +a: string |};"),
+            box TsType::TsTypeLit(TsTypeLit {
+                span: DUMMY_SP,
+                members: vec![TsTypeElement::TsPropertySignature(TsPropertySignature {
+                    span: DUMMY_SP,
+                    readonly: true,
+                    key: box Expr::Ident(Ident::new("a".into(), DUMMY_SP)),
+                    computed: false,
+                    optional: false,
+                    init: None,
+                    params: vec![],
+                    type_ann: Some(TsTypeAnn {
+                        span: DUMMY_SP,
+                        type_ann: keyword(TsKeywordTypeKind::TsStringKeyword),
+                    }),
+                    type_params: None,
+                })],
+            })
+        );
+    }
+
+    #[test]
+    fn anon_fn_type() {
+        assert_eq_ignore_span!(
+            type_alias("type A = number => void;"),
+            box TsType::from(TsFnType {
+                span: DUMMY_SP,
+                params: vec![TsFnParam::Ident(Ident {
+                    span: DUMMY_SP,
+                    sym: "_0".into(),
+                    type_ann: Some(TsTypeAnn {
+                        span: DUMMY_SP,
+                        type_ann: keyword(TsKeywordTypeKind::TsNumberKeyword),
+                    }),
+                    optional: false,
+                })],
+                type_params: None,
+                type_ann: TsTypeAnn {
+                    span: DUMMY_SP,
+                    type_ann: keyword(TsKeywordTypeKind::TsVoidKeyword),
+                },
+            })
+        );
+    }
+
+    #[test]
+    fn arrow_return_type() {
+        test_parser(
+            "const f = (x: number): string => String(x);",
+            syntax(),
+            |p| p.parse_module().map_err(|mut e| e.emit()),
+        );
+    }
+
+    #[test]
+    fn type_cast() {
+        let module = test_parser("(a.b: any);", syntax(), |p| {
+            p.parse_module().map_err(|mut e| e.emit())
+        });
+        match module.body[0] {
+            ModuleItem::Stmt(Stmt::Expr(ExprStmt { ref expr, .. })) => match **expr {
+                Expr::Paren(ParenExpr { ref expr, .. }) => match **expr {
+                    Expr::TsTypeCast(..) => {}
+                    _ => panic!("expected a type cast, got {:?}", expr),
+                },
+                _ => panic!("expected a parenthesized expression, got {:?}", expr),
+            },
+            _ => panic!("expected an expression statement"),
+        }
+    }
+
+    #[test]
+    fn declare_module() {
+        test_parser(
+            "declare module 'foo' {
+                declare export function foo(a: string): void;
+                declare export var bar: { [key: string]: number, ... };
+                declare type T = Array<?T>;
+            }",
+            syntax(),
+            |p| p.parse_module().map_err(|mut e| e.emit()),
+        );
+    }
+}
@@ -0,0 +1,675 @@
+//! Incremental reparsing for editors.
+//!
+//! [reparse_module] applies a [TextEdit] to a file and reparses only the top
+//! level items (or class members) affected by it. Other items are reused, with
+//! their spans shifted to the new file.
+use super::{PResult, Parser, Tokens};
+use crate::{
+    lexer::{Lexer, TokenContext, TokenContexts},
+    token::Token,
+    Context, Input, JscTarget, Session, SourceFileInput, Syntax,
+};
+use std::{collections::HashSet, mem, ops::Range, sync::Arc};
+use swc_common::{
+    comments::{Comment, Comments},
+    BytePos, Fold, SourceFile, SourceMap, Span, Spanned,
+};
+use swc_ecma_ast::*;
+use swc_ecma_parser_macros::parser;
+
+/// Replacement of `lo..hi` of the old file with `text`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TextEdit {
+    pub lo: BytePos,
+    pub hi: BytePos,
+    pub text: String,
+}
+
+#[derive(Debug)]
+pub struct Reparsed {
+    pub module: Module,
+    /// The edited file.
+    pub fm: Arc<SourceFile>,
+    /// Indices of the items of `module.body` which changed.
+    pub changed: Range<usize>,
+    /// Indices of the items of the old module which are replaced by `changed`.
+    pub replaced: Range<usize>,
+}
+
+/// Applies `edit` to `old_fm` and parses the result as a module.
+///
+/// `old` should be the result of [Parser::parse_module] for `old_fm`. The
+/// returned module is identical to the one of a full reparse, but only the
+/// items touching the edit are parsed again. If the edit is in the body of a
+/// top level class, only the class members touching the edit are parsed
+/// again.
+///
+/// Syntax errors are reported only for the reparsed items.
+///
+/// If `comments` is given, it should contain the comments of `old_fm`. They
+/// are moved to the edited file, and comments of the reparsed items are
+/// collected again. `comments` is left in an unspecified state if an error is
+/// returned.
+///
+/// # Panics
+///
+/// Panics if `edit` is not in `old_fm`.
+pub fn reparse_module<'a>(
+    session: Session<'a>,
+    syntax: Syntax,
+    target: JscTarget,
+    cm: &SourceMap,
+    old_fm: &SourceFile,
+    old: &Module,
+    edit: &TextEdit,
+    comments: Option<&'a Comments>,
+) -> PResult<'a, Reparsed> {
+    assert!(
+        old_fm.start_pos <= edit.lo && edit.lo <= edit.hi && edit.hi <= old_fm.end_pos,
+        "reparse_module: edit {:?}..{:?} is not in the file",
+        edit.lo,
+        edit.hi,
+    );
+
+    let src = {
+        let lo = (edit.lo - old_fm.start_pos).0 as usize;
+        let hi = (edit.hi - old_fm.start_pos).0 as usize;
+        format!("{}{}{}", &old_fm.src[..lo], edit.text, &old_fm.src[hi..])
+    };
+    let fm = cm.new_source_file(old_fm.name.clone(), src);
+
+    let mut shift = ShiftSpans {
+        old_start: old_fm.start_pos,
+        new_start: fm.start_pos,
+        edit_lo: edit.lo,
+        edit_hi: edit.hi,
+        new_edit_hi: fm.start_pos + (edit.lo - old_fm.start_pos) + BytePos(edit.text.len() as _),
+    };
+
+    let env = Env {
+        session,
+        syntax,
+        target,
+        fm: &fm,
+        comments,
+    };
+    let (module, changed, replaced, relexed) =
+        match reparse_class_members(env, old, edit, &mut shift) {
+            Some(module) => module,
+            None => {
+                // Drop comments collected by the failed attempt.
+                if let Some(comments) = comments {
+                    let in_new = |pos: &BytePos, _: &mut Vec<Comment>| {
+                        *pos < fm.start_pos || fm.end_pos < *pos
+                    };
+                    comments.retain_leading(in_new);
+                    comments.retain_trailing(in_new);
+                }
+                reparse_items(env, old, edit, &mut shift)?
+            }
+        };
+
+    if let Some(comments) = comments {
+        shift_comments(comments, old_fm, &mut shift, relexed);
+    }
+
+    Ok(Reparsed {
+        module,
+        fm,
+        changed,
+        replaced,
+    })
+}
+
+/// Returns the module, the changed items, the replaced items and the range of
+/// the new file which is lexed again.
+fn reparse_items<'a>(
+    env: Env<'a, '_>,
+    old: &Module,
+    edit: &TextEdit,
+    shift: &mut ShiftSpans,
+) -> PResult<'a, (Module, Range<usize>, Range<usize>, Range<BytePos>)> {
+    let fm = env.fm;
+    let body = &old.body;
+
+    // The item before the edit is parsed again because automatic semicolon
+    // insertion depends on the next token.
+    let first = body
+        .iter()
+        .position(|item| item.span().hi() >= edit.lo)
+        .unwrap_or_else(|| body.len());
+    let keep = first.saturating_sub(1);
+    let reusable = body
+        .iter()
+        .position(|item| item.span().lo() > edit.hi)
+        .unwrap_or_else(|| body.len())
+        .max(first);
+    let ends: Vec<_> = body[reusable..]
+        .iter()
+        .map(|item| shift.pos(item.span().lo()))
+        .collect();
+
+    let start = if keep == 0 {
+        fm.start_pos
+    } else {
+        shift.pos(body[keep - 1].span().hi())
+    };
+    let mut p = env.parser_at(start, 1);
+
+    let (lo, shebang) = if keep == 0 {
+        let lo = p.input.cur_pos();
+        (lo, p.parse_shebang()?)
+    } else {
+        (shift.pos(old.span.lo()), old.shebang.clone())
+    };
+    let (items, end) = p.parse_module_items_until(&ends)?;
+    let (hi, replaced_end) = match end {
+        Some(idx) => (shift.pos(old.span.hi()), reusable + idx),
+        None => (p.input.prev_span().hi, body.len()),
+    };
+    let relexed = start..end.map(|idx| ends[idx]).unwrap_or(fm.end_pos);
+
+    let mut changed = keep..keep + items.len();
+    let mut replaced = keep..replaced_end;
+
+    let mut new_body = Vec::with_capacity(keep + items.len() + body.len() - replaced_end);
+    new_body.extend(body[..keep].iter().cloned().map(|item| shift.fold(item)));
+    new_body.extend(items);
+    new_body.extend(
+        body[replaced_end..]
+            .iter()
+            .cloned()
+            .map(|item| shift.fold(item)),
+    );
+    let body = new_body;
+
+    // Items parsed again are not changed if the edit was only in whitespaces.
+    while !changed.is_empty()
+        && !replaced.is_empty()
+        && body[changed.start] == shift.fold(old.body[replaced.start].clone())
+    {
+        changed.start += 1;
+        replaced.start += 1;
+    }
+    while !changed.is_empty()
+        && !replaced.is_empty()
+        && body[changed.end - 1] == shift.fold(old.body[replaced.end - 1].clone())
+    {
+        changed.end -= 1;
+        replaced.end -= 1;
+    }
+
+    Ok((
+        Module {
+            span: Span::new(lo, hi, Default::default()),
+            body,
+            shebang,
+        },
+        changed,
+        replaced,
+        relexed,
+    ))
+}
+
+/// Returns `None` if the edit is not in the body of a top level class, or if
+/// the body does not end at the same `}` after the edit.
+fn reparse_class_members(
+    env: Env,
+    old: &Module,
+    edit: &TextEdit,
+    shift: &mut ShiftSpans,
+) -> Option<(Module, Range<usize>, Range<usize>, Range<BytePos>)> {
+    let idx = old
+        .body
+        .iter()
+        .position(|item| item.span().hi() >= edit.lo)?;
+    let (class_hi, members) = match class_of(&old.body[idx]) {
+        Some(class) => (class.span.hi(), &class.body),
+        None => return None,
+    };
+    let first_member = members.first()?;
+    if edit.lo < first_member.span().lo() || edit.hi >= class_hi {
+        return None;
+    }
+
+    let first = members
+        .iter()
+        .position(|m| m.span().hi() >= edit.lo)
+        .unwrap_or_else(|| members.len());
+    let keep = first.saturating_sub(1);
+    let reusable = members
+        .iter()
+        .position(|m| m.span().lo() > edit.hi)
+        .unwrap_or_else(|| members.len())
+        .max(first);
+    let ends: Vec<_> = members[reusable..]
+        .iter()
+        .map(|m| shift.pos(m.span().lo()))
+        .collect();
+
+    let start = if keep == 0 {
+        shift.pos(first_member.span().lo())
+    } else {
+        shift.pos(members[keep - 1].span().hi())
+    };
+    let mut p = env.parser_at(start, 2);
+
+    let (new_members, end) = match p.parse_class_members_until(&ends) {
+        Ok(v) => v,
+        Err(mut err) => {
+            err.cancel();
+            return None;
+        }
+    };
+    let (replaced_end, stop) = match end {
+        Some(i) => (reusable + i, ends[i]),
+        None => {
+            let close = shift.pos(class_hi - BytePos(1));
+            if !is!(p, '}') || p.input.cur_pos() != close {
+                return None;
+            }
+            (members.len(), close)
+        }
+    };
+
+    let mut module = old.clone();
+    let members = mem::replace(&mut class_of_mut(&mut module.body[idx])?.body, vec![]);
+    let mut module = shift.fold(module);
+
+    let mut body = Vec::with_capacity(keep + new_members.len() + members.len() - replaced_end);
+    body.extend(members[..keep].iter().cloned().map(|m| shift.fold(m)));
+    body.extend(new_members);
+    body.extend(
+        members[replaced_end..]
+            .iter()
+            .cloned()
+            .map(|m| shift.fold(m)),
+    );
+    class_of_mut(&mut module.body[idx])?.body = body;
+
+    Some((module, idx..idx + 1, idx..idx + 1, start..stop))
+}
+
+fn class_of(item: &ModuleItem) -> Option<&Class> {
+    match *item {
+        ModuleItem::Stmt(Stmt::Decl(Decl::Class(ref c)))
+        | ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl {
+            decl: Decl::Class(ref c),
+            ..
+        })) if !c.declare => Some(&c.class),
+        ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultDecl(ExportDefaultDecl {
+            decl: DefaultDecl::Class(ref c),
+            ..
+        })) => Some(&c.class),
+        _ => None,
+    }
+}
+
+fn class_of_mut(item: &mut ModuleItem) -> Option<&mut Class> {
+    match *item {
+        ModuleItem::Stmt(Stmt::Decl(Decl::Class(ref mut c)))
+        | ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl {
+            decl: Decl::Class(ref mut c),
+            ..
+        })) => Some(&mut c.class),
+        ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultDecl(ExportDefaultDecl {
+            decl: DefaultDecl::Class(ref mut c),
+            ..
+        })) => Some(&mut c.class),
+        _ => None,
+    }
+}
+
+/// Used to create parsers for the edited file.
+#[derive(Clone, Copy)]
+struct Env<'a, 'b> {
+    session: Session<'a>,
+    syntax: Syntax,
+    target: JscTarget,
+    fm: &'b SourceFile,
+    comments: Option<&'a Comments>,
+}
+
+impl<'a, 'b> Env<'a, 'b> {
+    /// Creates a parser for module code starting at `pos`, in `depth` nested
+    /// braces.
+    fn parser_at(self, pos: BytePos, depth: usize) -> Parser<'a, Lexer<'a, SourceFileInput<'b>>> {
+        let mut input = SourceFileInput::from(self.fm);
+        input.reset_to(pos);
+
+        let mut lexer = Lexer::new(self.session, self.syntax, self.target, input, self.comments);
+        lexer.set_token_context(TokenContexts(vec![TokenContext::BraceStmt; depth]));
+
+        let mut p = Parser::new_from(self.session, lexer);
+        let ctx = Context {
+            module: true,
+            strict: true,
+            ..p.ctx()
+        };
+        p.set_ctx(ctx);
+        p
+    }
+}
+
+#[parser]
+impl<'a, I: Tokens> Parser<'a, I> {
+    /// Parses module items until the current token starts at one of `ends`,
+    /// and returns the index of it.
+    fn parse_module_items_until(
+        &mut self,
+        ends: &[BytePos],
+    ) -> PResult<'a, (Vec<ModuleItem>, Option<usize>)> {
+        let mut items = vec![];
+        while !eof!() {
+            if let Ok(idx) = ends.binary_search(&cur_pos!()) {
+                return Ok((items, Some(idx)));
+            }
+            items.push(self.parse_stmt_like(true, true)?);
+        }
+        Ok((items, None))
+    }
+
+    /// Parses class members until the current token starts at one of `ends`,
+    /// and returns the index of it.
+    fn parse_class_members_until(
+        &mut self,
+        ends: &[BytePos],
+    ) -> PResult<'a, (Vec<ClassMember>, Option<usize>)> {
+        let mut members = vec![];
+        while !eof!() && !is!('}') {
+            if eat_exact!(';') {
+                continue;
+            }
+            if let Ok(idx) = ends.binary_search(&cur_pos!()) {
+                return Ok((members, Some(idx)));
+            }
+            members.push(self.parse_class_member()?);
+        }
+        Ok((members, None))
+    }
+}
+
+/// Moves comments of the old file to the new file.
+///
+/// Comments attached to positions in `relexed`, excluding the start, are
+/// dropped, as the lexer has added them again. Trailing comments of the token
+/// ending at the start are kept, and the lexer, which did not lex the token,
+/// adds them as leading comments of the next token instead. These are
+/// removed.
+fn shift_comments(
+    comments: &Comments,
+    old_fm: &SourceFile,
+    shift: &mut ShiftSpans,
+    relexed: Range<BytePos>,
+) {
+    let in_old = |pos: &BytePos| old_fm.start_pos <= *pos && *pos <= old_fm.end_pos;
+    let is_relexed = |pos: &BytePos| relexed.start < *pos && *pos <= relexed.end;
+
+    let mut leading = vec![];
+    comments.retain_leading(|pos, v| {
+        if in_old(pos) {
+            leading.push((*pos, mem::replace(v, vec![])));
+            return false;
+        }
+        true
+    });
+    let mut trailing = vec![];
+    comments.retain_trailing(|pos, v| {
+        if in_old(pos) {
+            trailing.push((*pos, mem::replace(v, vec![])));
+            return false;
+        }
+        true
+    });
+
+    let leading = leading
+        .into_iter()
+        .filter(|&(pos, _)| !is_relexed(&shift.pos(pos)))
+        .collect::<Vec<_>>();
+    let trailing = trailing
+        .into_iter()
+        .filter(|&(pos, _)| !is_relexed(&shift.pos(pos)))
+        .collect::<Vec<_>>();
+
+    let mut kept = HashSet::new();
+    let mut shift_all = |pos: BytePos, v: Vec<Comment>| {
+        let pos = shift.pos(pos);
+        let v = v
+            .into_iter()
+            .map(|c| Comment {
+                span: shift.fold(c.span),
+                ..c
+            })
+            .collect::<Vec<_>>();
+        kept.extend(v.iter().map(|c| c.span));
+        (pos, v)
+    };
+    for (pos, v) in leading {
+        let (pos, v) = shift_all(pos, v);
+        comments.add_leading(pos, v);
+    }
+    for (pos, v) in trailing {
+        let (pos, v) = shift_all(pos, v);
+        for c in v {
+            comments.add_trailing(pos, c);
+        }
+    }
+
+    comments.retain_leading(|pos, v| {
+        if is_relexed(pos) {
+            v.retain(|c| !kept.contains(&c.span));
+        }
+        !v.is_empty()
+    });
+}
+
+/// Maps spans of the old file to the new file.
+struct ShiftSpans {
+    old_start: BytePos,
+    new_start: BytePos,
+    edit_lo: BytePos,
+    edit_hi: BytePos,
+    /// Position of `edit_hi` in the new file.
+    new_edit_hi: BytePos,
+}
+
+impl ShiftSpans {
+    fn pos(&self, pos: BytePos) -> BytePos {
+        if pos <= self.edit_lo || pos < self.edit_hi {
+            self.new_start + (pos - self.old_start)
+        } else {
+            self.new_edit_hi + (pos - self.edit_hi)
+        }
+    }
+}
+
+impl Fold<Span> for ShiftSpans {
+    fn fold(&mut self, span: Span) -> Span {
+        if span.is_dummy() {
+            return span;
+        }
+
+        Span::new(self.pos(span.lo()), self.pos(span.hi()), span.ctxt())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::EsConfig;
+    use swc_common::{comments::CommentMap, FileName};
+
+    type Sorted = Vec<(BytePos, Vec<Comment>)>;
+
+    /// Leading and trailing comments sorted by position, to compare them.
+    fn sorted(comments: Comments) -> (Sorted, Sorted) {
+        let sort = |map: CommentMap| {
+            let mut v = map.into_iter().collect::<Sorted>();
+            v.sort_by_key(|&(pos, _)| pos);
+            v
+        };
+
+        let (leading, trailing) = comments.take_all();
+        (sort(leading), sort(trailing))
+    }
+
+    /// Replaces `from` in `src` with `to`, and checks that the reparsed module
+    /// and its comments are identical to a full reparse.
+    fn reparse(src: &str, from: &str, to: &str) -> (Range<usize>, Range<usize>) {
+        ::testing::run_test(false, |cm, handler| {
+            let comments = Comments::default();
+            let expected_comments = Comments::default();
+            let session = Session { handler: &handler };
+            let syntax = Syntax::Es(EsConfig {
+                class_props: true,
+                ..Default::default()
+            });
+            let parse = |fm: &SourceFile, comments: &Comments| {
+                let lexer = Lexer::new(
+                    session,
+                    syntax,
+                    JscTarget::Es2019,
+                    SourceFileInput::from(fm),
+                    Some(comments),
+                );
+                Parser::new_from(session, lexer)
+                    .parse_module()
+                    .map_err(|mut err| err.emit())
+            };
+
+            let fm = cm.new_source_file(FileName::Anon, src.into());
+            let old = parse(&fm, &comments)?;
+
+            let lo = fm.start_pos + BytePos(src.find(from).unwrap() as _);
+            let edit = TextEdit {
+                lo,
+                hi: lo + BytePos(from.len() as _),
+                text: to.into(),
+            };
+            let reparsed = reparse_module(
+                session,
+                syntax,
+                JscTarget::Es2019,
+                &cm,
+                &fm,
+                &old,
+                &edit,
+                Some(&comments),
+            )
+            .map_err(|mut err| err.emit())?;
+
+            assert_eq!(*reparsed.fm.src, src.replacen(from, to, 1));
+            assert_eq!(reparsed.module, parse(&reparsed.fm, &expected_comments)?);
+            assert_eq!(sorted(comments), sorted(expected_comments));
+
+            Ok((reparsed.changed, reparsed.replaced))
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn stmt() {
+        assert_eq!(reparse("a = 1;\nb = 2;\nc = 3;\n", "2", "42"), (1..2, 1..2));
+    }
+
+    #[test]
+    fn asi() {
+        assert_eq!(reparse("a\nb;\nc;", "b", "(b)"), (0..1, 0..2));
+    }
+
+    #[test]
+    fn whitespace() {
+        assert_eq!(reparse("a;\nb;\nc;", "\n", "\n\n\t"), (2..2, 2..2));
+    }
+
+    #[test]
+    fn comment_out() {
+        assert_eq!(reparse("a;\nb;\nc;", "b", "//b"), (1..1, 1..2));
+    }
+
+    #[test]
+    fn shebang() {
+        assert_eq!(
+            reparse("#!/usr/bin/env node\na;\nb;", "a", "x"),
+            (0..1, 0..1)
+        );
+    }
+
+    #[test]
+    fn append() {
+        assert_eq!(reparse("a;\nb;", "b;", "b;\nc;\n"), (1..3, 1..2));
+    }
+
+    #[test]
+    fn remove_all() {
+        assert_eq!(reparse("a;\nb;", "a;\nb;", ""), (0..0, 0..2));
+    }
+
+    #[test]
+    fn class_member() {
+        assert_eq!(
+            reparse(
+                "foo();\nclass A {\n  a() {}\n  b() { return 1 }\n  c = 1\n}\nbar();",
+                "1 }",
+                "[2] }"
+            ),
+            (1..2, 1..2)
+        );
+        assert_eq!(
+            reparse(
+                "export default class {\n  a = 1\n  b = 2\n}\nfoo();",
+                "b = 2",
+                "b() {}"
+            ),
+            (0..1, 0..1)
+        );
+    }
+
+    #[test]
+    fn class_member_asi() {
+        assert_eq!(
+            reparse("export class A {\n  a = 1\n  b\n}", "b", "[b]"),
+            (0..1, 0..1)
+        );
+    }
+
+    #[test]
+    fn comments() {
+        assert_eq!(
+            reparse(
+                "// a\na(); // after a\n/* b */ b();\n// c\nc();",
+                "b()",
+                "b(1)"
+            ),
+            (1..2, 1..2)
+        );
+        assert_eq!(
+            reparse("a();\nb(); // b\nc();\nd();", "d()", "d(1)"),
+            (3..4, 3..4)
+        );
+        assert_eq!(
+            reparse("a(); // a\nb();\n// c\nc();", "b();", "/* b */"),
+            (1..1, 1..2)
+        );
+        assert_eq!(
+            reparse(
+                "class A {\n  // a\n  a() {}\n  /** b */\n  b() {}\n}",
+                "b() {}",
+                "b() { return 1 }"
+            ),
+            (0..1, 0..1)
+        );
+    }
+
+    #[test]
+    fn class_split() {
+        assert_eq!(
+            reparse(
+                "class A {\n  a() {}\n}\nclass B {\n  b() {}\n}",
+                "a() {}",
+                "a() {}\n}\nclass C {"
+            ),
+            (0..2, 0..1)
+        );
+    }
+}
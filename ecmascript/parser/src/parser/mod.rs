@@ -1,5 +1,7 @@
 #![allow(dead_code, unused_variables)]
 #![deny(non_snake_case)]
+#[cfg(any(test, feature = "fold"))]
+pub use self::incremental::{reparse_module, Reparsed, TextEdit};
 pub use self::input::{Capturing, Tokens, TokensInput};
 use self::{input::Buffer, util::ParseObject};
 use crate::{
@@ -22,7 +24,10 @@ use test::Bencher;
 mod macros;
 mod class_and_fn;
 mod expr;
+mod flow;
 mod ident;
+#[cfg(any(test, feature = "fold"))]
+mod incremental;
 pub mod input;
 mod jsx;
 mod object;
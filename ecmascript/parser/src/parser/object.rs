@@ -182,7 +182,7 @@ impl<'a, I: Tokens> ParseObject<'a, Box<Expr>> for Parser<'a, I> {
         }
 
         // Handle `a(){}` (and async(){} / get(){} / set(){})
-        if (self.input.syntax().typescript() && is!('<')) || is!('(') {
+        if (self.input.syntax().typed() && is!('<')) || is!('(') {
             return self
                 .parse_fn_args_body(
                     // no decorator in an object literal
@@ -150,7 +150,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
         let mut pat = self.parse_binding_element()?;
         let mut opt = false;
 
-        if self.input.syntax().typescript() {
+        if self.input.syntax().typed() {
             if eat!('?') {
                 match pat {
                     Pat::Ident(Ident {
@@ -257,7 +257,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
                 let dot3_token = span!(pat_start);
 
                 let pat = self.parse_binding_pat_or_ident()?;
-                let type_ann = if self.input.syntax().typescript() && is!(':') {
+                let type_ann = if self.input.syntax().typed() && is!(':') {
                     let cur_pos = cur_pos!();
                     Some(self.parse_ts_type_ann(/* eat_colon */ true, cur_pos)?)
                 } else {
@@ -370,7 +370,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
                     .into();
                 }
 
-                let type_ann = if self.input.syntax().typescript() && is!(':') {
+                let type_ann = if self.input.syntax().typed() && is!(':') {
                     let cur_pos = cur_pos!();
                     let ty = self.parse_ts_type_ann(/* eat_colon */ true, cur_pos)?;
                     Some(ty)
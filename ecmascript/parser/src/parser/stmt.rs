@@ -67,7 +67,11 @@ impl<'a, I: Tokens> Parser<'a, I> {
     }
 
     /// Parse a statement, declaration or module item.
-    fn parse_stmt_like<Type>(&mut self, include_decl: bool, top_level: bool) -> PResult<'a, Type>
+    pub(super) fn parse_stmt_like<Type>(
+        &mut self,
+        include_decl: bool,
+        top_level: bool,
+    ) -> PResult<'a, Type>
     where
         Self: StmtLikeParser<'a, Type>,
         Type: IsDirective + From<Stmt>,
@@ -79,6 +83,11 @@ impl<'a, I: Tokens> Parser<'a, I> {
             return self.handle_import_export(top_level, decorators);
         }
 
+        // `declare export` of flow
+        if self.input.syntax().flow() && is!("declare") && peeked_is!("export") {
+            return self.handle_import_export(top_level, decorators);
+        }
+
         self.parse_stmt_internal(start, include_decl, top_level, decorators)
             .map(From::from)
     }
@@ -308,6 +317,12 @@ impl<'a, I: Tokens> Parser<'a, I> {
                     return Ok(Stmt::Decl(decl));
                 }
             }
+
+            if self.input.syntax().flow() {
+                if let Some(decl) = self.parse_flow_expr_stmt(ident.clone())? {
+                    return Ok(Stmt::Decl(decl));
+                }
+            }
         }
 
         if self.ctx().strict {
@@ -566,7 +581,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
         if eat!('(') {
             let pat = self.parse_binding_pat_or_ident()?;
 
-            if self.syntax().typescript() && eat!(':') {
+            if self.syntax().typed() && eat!(':') {
                 let ctx = Context {
                     in_type: true,
                     ..self.ctx()
@@ -702,7 +717,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
         };
 
         // Typescript extension
-        if self.input.syntax().typescript() && is!(':') {
+        if self.input.syntax().typed() && is!(':') {
             let type_annotation = self.try_parse_ts_type_ann()?;
             match name {
                 Pat::Array(ArrayPat {
@@ -1136,7 +1151,7 @@ impl<'a, I: Tokens> StmtLikeParser<'a, Stmt> for Parser<'a, I> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::EsConfig;
+    use crate::{EsConfig, TsConfig};
     use swc_common::DUMMY_SP as span;
 
     fn stmt(s: &'static str) -> Stmt {
@@ -1679,4 +1694,116 @@ export default function waitUntil(callback, options = {}) {
             },
         );
     }
+
+    fn import_attributes_module(src: &'static str) -> Module {
+        test_parser(
+            src,
+            Syntax::Es(EsConfig {
+                import_attributes: true,
+                dynamic_import: true,
+                export_namespace_from: true,
+                ..Default::default()
+            }),
+            |p| {
+                p.parse_module().map_err(|mut e| {
+                    e.emit();
+                })
+            },
+        )
+    }
+
+    #[test]
+    fn import_attributes_with() {
+        let m = import_attributes_module("import foo from './foo.json' with { type: 'json' };");
+        match m.body[0] {
+            ModuleItem::ModuleDecl(ModuleDecl::Import(ImportDecl {
+                attrs:
+                    Some(ImportAttributes {
+                        kind: ImportAttributesKind::With,
+                        ref obj,
+                        ..
+                    }),
+                ..
+            })) => assert_eq!(obj.props.len(), 1),
+            ref item => panic!("expected import with attributes, got {:?}", item),
+        }
+    }
+
+    #[test]
+    fn import_attributes_assert() {
+        let m = import_attributes_module(
+            "import './foo.css' assert { type: 'css' };
+            export * from './foo.json' assert { \"type\": 'json', };
+            export { a } from './foo.json' assert { type: 'json' };",
+        );
+        for item in &m.body {
+            match *item {
+                ModuleItem::ModuleDecl(ModuleDecl::Import(ImportDecl {
+                    attrs: Some(..), ..
+                }))
+                | ModuleItem::ModuleDecl(ModuleDecl::ExportAll(ExportAll {
+                    attrs: Some(..),
+                    ..
+                }))
+                | ModuleItem::ModuleDecl(ModuleDecl::ExportNamed(NamedExport {
+                    attrs: Some(..),
+                    ..
+                })) => {}
+                _ => panic!("expected attributes on {:?}", item),
+            }
+        }
+    }
+
+    #[test]
+    fn import_attributes_assert_after_line_break() {
+        // `assert` is not a keyword, so a line break ends the declaration.
+        let m = import_attributes_module("import './foo.json'\nassert({ type: 'json' });");
+        assert_eq!(m.body.len(), 2);
+    }
+
+    #[test]
+    fn import_attributes_dynamic() {
+        import_attributes_module("import('./foo.json', { with: { type: 'json' } },);");
+    }
+
+    fn import_attributes_ts_module(src: &'static str, import_attributes: bool) -> Module {
+        test_parser(
+            src,
+            Syntax::Typescript(TsConfig {
+                import_attributes,
+                ..Default::default()
+            }),
+            |p| {
+                p.parse_module().map_err(|mut e| {
+                    e.emit();
+                })
+            },
+        )
+    }
+
+    #[test]
+    fn import_attributes_typescript() {
+        let m = import_attributes_ts_module(
+            "import foo from './foo.json' with { type: 'json' };",
+            true,
+        );
+        match m.body[0] {
+            ModuleItem::ModuleDecl(ModuleDecl::Import(ImportDecl {
+                attrs: Some(..), ..
+            })) => {}
+            ref item => panic!("expected import with attributes, got {:?}", item),
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn import_attributes_typescript_disabled() {
+        import_attributes_ts_module("import foo from './foo.json' with { type: 'json' };", false);
+    }
+
+    #[test]
+    #[should_panic(expected = "Only string literals are allowed as import attribute values")]
+    fn import_attributes_non_string_value() {
+        import_attributes_module("import foo from './foo.json' with { type: json };");
+    }
 }
@@ -1,4 +1,5 @@
 use super::*;
+use crate::parser::typescript::make_decl_declare;
 
 #[parser]
 impl<'a, I: Tokens> Parser<'a, I> {
@@ -50,17 +51,20 @@ impl<'a, I: Tokens> Parser<'a, I> {
                 },
                 _ => unreachable!(),
             };
+            let attrs = self.parse_import_attributes()?;
             expect!(';');
             return Ok(ModuleDecl::Import(ImportDecl {
                 span: span!(start),
                 src,
                 specifiers: vec![],
                 type_only: false,
+                attrs,
             }))
             .map(ModuleItem::from);
         }
 
-        let type_only = self.syntax().typescript() && eat!("type");
+        let type_only =
+            (self.syntax().typed() && eat!("type")) || (self.syntax().flow() && eat!("typeof"));
 
         let mut specifiers = vec![];
 
@@ -100,13 +104,14 @@ impl<'a, I: Tokens> Parser<'a, I> {
             }
         }
 
-        let src = self.parse_from_clause_and_semi()?;
+        let (src, attrs) = self.parse_from_clause_and_semi()?;
 
         Ok(ModuleDecl::Import(ImportDecl {
             span: span!(start),
             specifiers,
             src,
             type_only,
+            attrs,
         }))
         .map(ModuleItem::from)
     }
@@ -116,6 +121,15 @@ impl<'a, I: Tokens> Parser<'a, I> {
         let start = cur_pos!();
         match cur!(false) {
             Ok(&Word(..)) => {
+                // Flow: `import { type Foo, typeof Bar } from 'mod.js'`
+                let is_type_only = self.input.syntax().flow()
+                    && is_one_of!("type", "typeof")
+                    && peeked_is!(IdentName)
+                    && !peeked_is!("as");
+                if is_type_only {
+                    bump!();
+                }
+
                 let orig_name = self.parse_ident_name()?;
 
                 if eat!("as") {
@@ -124,6 +138,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
                         span: Span::new(start, local.span.hi(), Default::default()),
                         local,
                         imported: Some(orig_name),
+                        is_type_only,
                     }));
                 }
 
@@ -140,6 +155,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
                     span: span!(start),
                     local,
                     imported: None,
+                    is_type_only,
                 }))
             }
             _ => unexpected!(),
@@ -193,6 +209,15 @@ impl<'a, I: Tokens> Parser<'a, I> {
             }
         }
 
+        if self.input.syntax().flow() {
+            if let Some(decl) = self.try_parse_flow_export_decl()? {
+                return Ok(ModuleDecl::ExportDecl(ExportDecl {
+                    span: span!(start),
+                    decl,
+                }));
+            }
+        }
+
         if self.input.syntax().typescript() {
             if eat!("import") {
                 // export import A = B
@@ -233,10 +258,11 @@ impl<'a, I: Tokens> Parser<'a, I> {
         if eat!('*') {
             has_star = true;
             if is!("from") {
-                let src = self.parse_from_clause_and_semi()?;
+                let (src, attrs) = self.parse_from_clause_and_semi()?;
                 return Ok(ModuleDecl::ExportAll(ExportAll {
                     span: span!(start),
                     src,
+                    attrs,
                 }));
             }
             if eat!("as") {
@@ -253,7 +279,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
             }
         }
 
-        let type_only = self.input.syntax().typescript() && eat!("type");
+        let type_only = self.input.syntax().typed() && eat!("type");
 
         // Some("default") if default is exported from 'src'
         let mut export_default = None;
@@ -371,12 +397,13 @@ impl<'a, I: Tokens> Parser<'a, I> {
 
             if is!("from") {
                 if let Some(s) = export_ns {
-                    let src = self.parse_from_clause_and_semi()?;
+                    let (src, attrs) = self.parse_from_clause_and_semi()?;
                     return Ok(ModuleDecl::ExportNamed(NamedExport {
                         span: Span::new(start, src.span.hi(), Default::default()),
                         specifiers: vec![s],
                         src: Some(src),
                         type_only,
+                        attrs,
                     }));
                 }
             }
@@ -394,7 +421,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
 
             if is!("from") {
                 if let Some(default) = default {
-                    let src = self.parse_from_clause_and_semi()?;
+                    let (src, attrs) = self.parse_from_clause_and_semi()?;
                     return Ok(ModuleDecl::ExportNamed(NamedExport {
                         span: Span::new(start, src.span.hi(), Default::default()),
                         specifiers: vec![ExportSpecifier::Default(ExportDefaultSpecifier {
@@ -402,16 +429,18 @@ impl<'a, I: Tokens> Parser<'a, I> {
                         })],
                         src: Some(src),
                         type_only,
+                        attrs,
                     }));
                 }
             }
 
             if has_star && export_ns.is_none() {
                 // improve error message for `export * from foo`
-                let src = self.parse_from_clause_and_semi()?;
+                let (src, attrs) = self.parse_from_clause_and_semi()?;
                 return Ok(ModuleDecl::ExportAll(ExportAll {
                     span: Span::new(start, src.span.hi(), Default::default()),
                     src,
+                    attrs,
                 }));
             }
 
@@ -446,20 +475,22 @@ impl<'a, I: Tokens> Parser<'a, I> {
             }
             expect!('}');
 
-            let src = if is!("from") {
-                Some(self.parse_from_clause_and_semi()?)
+            let (src, attrs) = if is!("from") {
+                let (src, attrs) = self.parse_from_clause_and_semi()?;
+                (Some(src), attrs)
             } else {
                 eat!(';');
                 if has_default || has_ns {
                     syntax_error!(span!(start), SyntaxError::ExportDefaultWithOutFrom);
                 }
-                None
+                (None, None)
             };
             return Ok(ModuleDecl::ExportNamed(NamedExport {
                 span: span!(start),
                 specifiers,
                 src,
                 type_only,
+                attrs,
             }));
         };
 
@@ -469,6 +500,32 @@ impl<'a, I: Tokens> Parser<'a, I> {
         }))
     }
 
+    /// Flow: `declare export function foo(): void;`
+    fn parse_flow_declare_export(&mut self, decorators: Vec<Decorator>) -> PResult<'a, ModuleDecl> {
+        debug_assert!(self.input.syntax().flow());
+
+        assert_and_bump!("declare");
+        if peeked_is!("default") {
+            syntax_error!(SyntaxError::FlowDeclareExportDefault)
+        }
+
+        let ctx = Context {
+            in_declare: true,
+            ..self.ctx()
+        };
+        let decl = self.with_ctx(ctx).parse_export(decorators)?;
+
+        Ok(match decl {
+            ModuleDecl::ExportDecl(ExportDecl { span, decl }) => {
+                ModuleDecl::ExportDecl(ExportDecl {
+                    span,
+                    decl: make_decl_declare(decl),
+                })
+            }
+            _ => decl,
+        })
+    }
+
     fn parse_named_export_specifier(&mut self) -> PResult<'a, ExportNamedSpecifier> {
         let start = cur_pos!();
 
@@ -487,7 +544,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
         })
     }
 
-    fn parse_from_clause_and_semi(&mut self) -> PResult<'a, Str> {
+    fn parse_from_clause_and_semi(&mut self) -> PResult<'a, (Str, Option<ImportAttributes>)> {
         expect!("from");
 
         let str_start = cur_pos!();
@@ -502,8 +559,69 @@ impl<'a, I: Tokens> Parser<'a, I> {
             },
             _ => unexpected!(),
         };
+        let attrs = self.parse_import_attributes()?;
         expect!(';');
-        Ok(src)
+        Ok((src, attrs))
+    }
+
+    /// Parses `with { type: "json" }` (or `assert { type: "json" }`) after a
+    /// module specifier.
+    fn parse_import_attributes(&mut self) -> PResult<'a, Option<ImportAttributes>> {
+        if !self.input.syntax().import_attributes() {
+            return Ok(None);
+        }
+
+        let start = cur_pos!();
+        let kind = if is!("with") {
+            ImportAttributesKind::With
+        } else if is!("assert") && !self.input.had_line_break_before_cur() {
+            ImportAttributesKind::Assert
+        } else {
+            return Ok(None);
+        };
+        bump!();
+
+        let obj_start = cur_pos!();
+        expect!('{');
+
+        let mut props = vec![];
+        let mut first = true;
+        while !eof!() && !is!('}') {
+            if first {
+                first = false;
+            } else {
+                expect!(',');
+                if is!('}') {
+                    break;
+                }
+            }
+
+            let key = match *cur!(true)? {
+                Token::Str { .. } | Word(..) => self.parse_prop_name()?,
+                _ => unexpected!(),
+            };
+            expect!(':');
+
+            let value = match *cur!(true)? {
+                Token::Str { .. } => box Expr::Lit(self.parse_lit()?),
+                _ => syntax_error!(self.input.cur_span(), SyntaxError::ImportAttributeValue),
+            };
+
+            props.push(PropOrSpread::Prop(box Prop::KeyValue(KeyValueProp {
+                key,
+                value,
+            })));
+        }
+        expect!('}');
+
+        Ok(Some(ImportAttributes {
+            span: span!(start),
+            kind,
+            obj: ObjectLit {
+                span: span!(obj_start),
+                props,
+            },
+        }))
     }
 }
 
@@ -532,6 +650,9 @@ impl<'a, I: Tokens> StmtLikeParser<'a, ModuleItem> for Parser<'a, I> {
             self.parse_import()?
         } else if is!("export") {
             self.parse_export(decorators).map(ModuleItem::from)?
+        } else if is!("declare") {
+            self.parse_flow_declare_export(decorators)
+                .map(ModuleItem::from)?
         } else {
             unreachable!(
                 "handle_import_export should not be called if current token isn't import nor \
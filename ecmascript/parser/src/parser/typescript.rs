@@ -8,7 +8,7 @@ use swc_common::{Spanned, SyntaxContext};
 impl<'a, I: Tokens> Parser<'a, I> {
     /// `tsNextTokenCanFollowModifier`
     fn ts_next_token_can_follow_modifier(&mut self) -> PResult<'a, bool> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().typed());
 
         // Note: TypeScript's implementation is much more complicated because
         // more things are considered modifiers there.
@@ -56,7 +56,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
     /// `tsIsListTerminator`
 
     fn is_ts_list_terminator(&mut self, kind: ParsingContext) -> PResult<'a, bool> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().typed());
 
         Ok(match kind {
             ParsingContext::EnumMembers | ParsingContext::TypeMembers => is!('}'),
@@ -77,7 +77,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
     where
         F: FnMut(&mut Self) -> PResult<'a, T>,
     {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().typed());
 
         let mut buf = vec![];
         while !self.is_ts_list_terminator(kind)? {
@@ -113,7 +113,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
     where
         F: FnMut(&mut Self) -> PResult<'a, (BytePos, T)>,
     {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().typed());
 
         let mut buf = vec![];
 
@@ -164,7 +164,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
     where
         F: FnMut(&mut Self) -> PResult<'a, T>,
     {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().typed());
 
         if !skip_first_token {
             if bracket {
@@ -186,8 +186,11 @@ impl<'a, I: Tokens> Parser<'a, I> {
     }
 
     /// `tsParseEntityName`
-    fn parse_ts_entity_name(&mut self, allow_reserved_words: bool) -> PResult<'a, TsEntityName> {
-        debug_assert!(self.input.syntax().typescript());
+    pub(super) fn parse_ts_entity_name(
+        &mut self,
+        allow_reserved_words: bool,
+    ) -> PResult<'a, TsEntityName> {
+        debug_assert!(self.input.syntax().typed());
 
         let init = self.parse_ident_name()?;
         match init {
@@ -230,7 +233,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
 
     /// `tsParseTypeReference`
     fn parse_ts_type_ref(&mut self) -> PResult<'a, TsTypeRef> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().typed());
 
         let start = cur_pos!();
 
@@ -261,7 +264,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
         has_asserts_keyword: bool,
         lhs: TsThisType,
     ) -> PResult<'a, TsTypePredicate> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().typed());
 
         let param_name = TsThisTypeOrIdent::TsThisType(lhs);
         let type_ann = if eat!("is") {
@@ -283,8 +286,8 @@ impl<'a, I: Tokens> Parser<'a, I> {
     }
 
     /// `tsParseThisTypeNode`
-    fn parse_ts_this_type_node(&mut self) -> PResult<'a, TsThisType> {
-        debug_assert!(self.input.syntax().typescript());
+    pub(super) fn parse_ts_this_type_node(&mut self) -> PResult<'a, TsThisType> {
+        debug_assert!(self.input.syntax().typed());
 
         expect!("this");
 
@@ -336,8 +339,8 @@ impl<'a, I: Tokens> Parser<'a, I> {
     }
 
     /// `tsParseTypeQuery`
-    fn parse_ts_type_query(&mut self) -> PResult<'a, TsTypeQuery> {
-        debug_assert!(self.input.syntax().typescript());
+    pub(super) fn parse_ts_type_query(&mut self) -> PResult<'a, TsTypeQuery> {
+        debug_assert!(self.input.syntax().typed());
 
         let start = cur_pos!();
         expect!("typeof");
@@ -359,7 +362,11 @@ impl<'a, I: Tokens> Parser<'a, I> {
 
     /// `tsParseTypeParameter`
     fn parse_ts_type_param(&mut self) -> PResult<'a, TsTypeParam> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().typed());
+
+        if self.input.syntax().flow() {
+            return self.parse_flow_type_param();
+        }
 
         let start = cur_pos!();
 
@@ -403,7 +410,11 @@ impl<'a, I: Tokens> Parser<'a, I> {
         &mut self,
         return_token: &'static Token,
     ) -> PResult<'a, TsTypeAnn> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().typed());
+
+        if self.input.syntax().flow() {
+            return self.parse_flow_return_type_ann(return_token);
+        }
 
         self.in_type().parse_with(|p| {
             let return_token_start = cur_pos!();
@@ -463,7 +474,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
     where
         F: FnOnce(&mut Self) -> PResult<'a, Option<bool>>,
     {
-        if !self.input.syntax().typescript() {
+        if !self.input.syntax().typed() {
             return Ok(false);
         }
         let mut cloned = self.clone();
@@ -488,7 +499,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
     where
         F: FnOnce(&mut Self) -> PResult<'a, Option<T>>,
     {
-        if !self.input.syntax().typescript() {
+        if !self.input.syntax().typed() {
             return None;
         }
         let mut cloned = self.clone();
@@ -513,7 +524,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
         eat_colon: bool,
         start: BytePos,
     ) -> PResult<'a, TsTypeAnn> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().typed());
 
         self.in_type().parse_with(|p| {
             if eat_colon {
@@ -545,7 +556,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
 
     /// `tsExpectThenParseType`
     fn expect_then_parse_ts_type(&mut self, token: &'static Token) -> PResult<'a, Box<TsType>> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().typed());
 
         self.in_type().parse_with(|p| {
             if !p.input.eat(token) {
@@ -558,7 +569,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
 
     /// `tsNextThenParseType`
     pub(super) fn next_then_parse_ts_type(&mut self) -> PResult<'a, Box<TsType>> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().typed());
 
         self.in_type().parse_with(|p| {
             bump!();
@@ -569,7 +580,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
 
     /// `tsParseEnumMember`
     fn parse_ts_enum_member(&mut self) -> PResult<'a, TsEnumMember> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().typed());
 
         let start = cur_pos!();
         // Computed property names are grammar errors in an enum, so accept just string
@@ -632,7 +643,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
         start: BytePos,
         is_const: bool,
     ) -> PResult<'a, TsEnumDecl> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().typed());
 
         let id = self.parse_ident_name()?;
         expect!('{');
@@ -651,7 +662,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
 
     /// `tsParseModuleBlock`
     fn parse_ts_module_block(&mut self) -> PResult<'a, TsModuleBlock> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().typed());
 
         let start = cur_pos!();
         expect!('{');
@@ -670,8 +681,11 @@ impl<'a, I: Tokens> Parser<'a, I> {
     }
 
     /// `tsParseModuleOrNamespaceDeclaration`
-    fn parse_ts_module_or_ns_decl(&mut self, start: BytePos) -> PResult<'a, TsModuleDecl> {
-        debug_assert!(self.input.syntax().typescript());
+    pub(super) fn parse_ts_module_or_ns_decl(
+        &mut self,
+        start: BytePos,
+    ) -> PResult<'a, TsModuleDecl> {
+        debug_assert!(self.input.syntax().typed());
 
         let id = self.parse_ident_name()?;
         let body: TsNamespaceBody = if eat!('.') {
@@ -702,11 +716,11 @@ impl<'a, I: Tokens> Parser<'a, I> {
     }
 
     /// `tsParseAmbientExternalModuleDeclaration`
-    fn parse_ts_ambient_external_module_decl(
+    pub(super) fn parse_ts_ambient_external_module_decl(
         &mut self,
         start: BytePos,
     ) -> PResult<'a, TsModuleDecl> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().typed());
 
         let (global, id) = if is!("global") {
             let id = self.parse_ident_name()?;
@@ -741,7 +755,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
     }
 
     pub fn parse_type(&mut self) -> PResult<'a, Box<TsType>> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().typed());
 
         self.in_type().parse_ts_type()
     }
@@ -750,11 +764,15 @@ impl<'a, I: Tokens> Parser<'a, I> {
     ///
     /// `tsParseType`
     pub(super) fn parse_ts_type(&mut self) -> PResult<'a, Box<TsType>> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().typed());
 
         // Need to set `state.inType` so that we don't parse JSX in a type context.
         debug_assert!(self.ctx().in_type);
 
+        if self.input.syntax().flow() {
+            return self.parse_flow_type();
+        }
+
         let start = cur_pos!();
 
         let ty = self.parse_ts_non_conditional_type()?;
@@ -784,7 +802,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
 
     /// `tsParseNonConditionalType`
     fn parse_ts_non_conditional_type(&mut self) -> PResult<'a, Box<TsType>> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().typed());
 
         if self.is_ts_start_of_fn_type()? {
             return self
@@ -792,8 +810,8 @@ impl<'a, I: Tokens> Parser<'a, I> {
                 .map(TsType::from)
                 .map(Box::new);
         }
-        if is!("new") {
-            // As in `new () => Date`
+        if is!("new") || (is!("abstract") && peeked_is!("new")) {
+            // As in `new () => Date` or `abstract new () => Date`
             return self
                 .parse_ts_fn_or_constructor_type(false)
                 .map(TsType::from)
@@ -804,7 +822,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
     }
 
     fn is_ts_start_of_fn_type(&mut self) -> PResult<'a, bool> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().typed());
 
         if is!('<') {
             return Ok(true);
@@ -818,7 +836,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
         &mut self,
         start: BytePos,
     ) -> PResult<'a, TsTypeAssertion> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().typed());
 
         // Not actually necessary to set state.inType because we never reach here if JSX
         // plugin is enabled, but need `tsInType` to satisfy the assertion in
@@ -835,7 +853,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
 
     /// `tsParseHeritageClause`
     pub(super) fn parse_ts_heritage_clause(&mut self) -> PResult<'a, Vec<TsExprWithTypeArgs>> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().typed());
 
         self.parse_ts_delimited_list(ParsingContext::HeritageClauseElement, |p| {
             p.parse_expr_with_type_args()
@@ -844,7 +862,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
 
     /// `tsParseExpressionWithTypeArguments`
     fn parse_expr_with_type_args(&mut self) -> PResult<'a, TsExprWithTypeArgs> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().typed());
 
         let start = cur_pos!();
         // Note: TS uses parseLeftHandSideExpressionOrHigher,
@@ -868,7 +886,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
         &mut self,
         start: BytePos,
     ) -> PResult<'a, TsInterfaceDecl> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().typed());
 
         let id = self.parse_ident_name()?;
         match id.sym {
@@ -907,9 +925,13 @@ impl<'a, I: Tokens> Parser<'a, I> {
         }
 
         let body_start = cur_pos!();
-        let body = self
-            .in_type()
-            .parse_with(|p| p.parse_ts_object_type_members())?;
+        let body = self.in_type().parse_with(|p| {
+            if p.input.syntax().flow() {
+                p.parse_flow_interface_body()
+            } else {
+                p.parse_ts_object_type_members()
+            }
+        })?;
         let body = TsInterfaceBody {
             span: span!(body_start),
             body,
@@ -925,8 +947,11 @@ impl<'a, I: Tokens> Parser<'a, I> {
     }
 
     /// `tsParseTypeAliasDeclaration`
-    fn parse_ts_type_alias_decl(&mut self, start: BytePos) -> PResult<'a, TsTypeAliasDecl> {
-        debug_assert!(self.input.syntax().typescript());
+    pub(super) fn parse_ts_type_alias_decl(
+        &mut self,
+        start: BytePos,
+    ) -> PResult<'a, TsTypeAliasDecl> {
+        debug_assert!(self.input.syntax().typed());
 
         let id = self.parse_ident_name()?;
         let type_params = self.try_parse_ts_type_params()?;
@@ -947,7 +972,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
         start: BytePos,
         is_export: bool,
     ) -> PResult<'a, TsImportEqualsDecl> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().typed());
 
         let id = self.parse_ident_name()?;
         expect!('=');
@@ -965,14 +990,14 @@ impl<'a, I: Tokens> Parser<'a, I> {
 
     /// `tsIsExternalModuleReference`
     fn is_ts_external_module_ref(&mut self) -> PResult<'a, bool> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().typed());
 
         Ok(is!("require") && peeked_is!('('))
     }
 
     /// `tsParseModuleReference`
     fn parse_ts_module_ref(&mut self) -> PResult<'a, TsModuleRef> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().typed());
 
         if self.is_ts_external_module_ref()? {
             self.parse_ts_external_module_ref().map(From::from)
@@ -985,7 +1010,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
     /// `tsParseExternalModuleReference`
     #[allow(clippy::cognitive_complexity)]
     fn parse_ts_external_module_ref(&mut self) -> PResult<'a, TsExternalModuleRef> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().typed());
 
         let start = cur_pos!();
         expect!("require");
@@ -1009,7 +1034,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
     where
         F: FnOnce(&mut Self) -> PResult<'a, T>,
     {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().typed());
 
         let mut cloned = self.clone();
         cloned.emit_err = false;
@@ -1019,7 +1044,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
 
     /// `tsIsUnambiguouslyStartOfFunctionType`
     fn is_ts_unambiguously_start_of_fn_type(&mut self) -> PResult<'a, bool> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().typed());
 
         assert_and_bump!('(');
         if is_one_of!(')', "...") {
@@ -1045,7 +1070,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
 
     /// `tsSkipParameterStart`
     fn skip_ts_parameter_start(&mut self) -> PResult<'a, bool> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().typed());
 
         let _ = self.eat_any_ts_modifier()?;
 
@@ -1074,7 +1099,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
 
     /// `tsParseTypeMemberSemicolon`
     fn parse_ts_type_member_semicolon(&mut self) -> PResult<'a, ()> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().typed());
 
         if !eat!(',') {
             expect!(';');
@@ -1088,7 +1113,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
         &mut self,
         kind: SignatureParsingMode,
     ) -> PResult<'a, Either<TsCallSignatureDecl, TsConstructSignatureDecl>> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().typed());
 
         let start = cur_pos!();
 
@@ -1131,7 +1156,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
 
     /// `tsIsUnambiguouslyIndexSignature`
     fn is_ts_unambiguously_index_signature(&mut self) -> PResult<'a, bool> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().typed());
 
         // Note: babel's comment is wrong
         assert_and_bump!('['); // Skip '['
@@ -1187,7 +1212,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
         start: BytePos,
         readonly: bool,
     ) -> PResult<'a, Either<TsPropertySignature, TsMethodSignature>> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().typed());
 
         // ----- inlined self.parsePropertyName(node);
         let (computed, key) = if eat!('[') {
@@ -1261,7 +1286,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
 
     /// `tsParseTypeMember`
     fn parse_ts_type_member(&mut self) -> PResult<'a, TsTypeElement> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().typed());
 
         fn into_type_elem(
             e: Either<TsCallSignatureDecl, TsConstructSignatureDecl>,
@@ -1299,7 +1324,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
 
     /// `tsIsStartOfConstructSignature`
     fn is_ts_start_of_construct_signature(&mut self) -> PResult<'a, bool> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().typed());
 
         bump!();
 
@@ -1308,7 +1333,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
 
     /// `tsParseTypeLiteral`
     fn parse_ts_type_lit(&mut self) -> PResult<'a, TsTypeLit> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().typed());
 
         let start = cur_pos!();
         let members = self.parse_ts_object_type_members()?;
@@ -1320,7 +1345,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
 
     /// `tsParseObjectTypeMembers`
     fn parse_ts_object_type_members(&mut self) -> PResult<'a, Vec<TsTypeElement>> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().typed());
 
         expect!('{');
         let members =
@@ -1331,7 +1356,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
 
     /// `tsIsStartOfMappedType`
     fn is_ts_start_of_mapped_type(&mut self) -> PResult<'a, bool> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().typed());
 
         bump!();
         if eat!('+') || eat!('-') {
@@ -1354,7 +1379,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
 
     /// `tsParseMappedTypeParameter`
     fn parse_ts_mapped_type_param(&mut self) -> PResult<'a, TsTypeParam> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().typed());
 
         let start = cur_pos!();
         let name = self.parse_ident_name()?;
@@ -1371,7 +1396,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
     /// `tsParseMappedType`
     #[allow(clippy::cognitive_complexity)]
     fn parse_ts_mapped_type(&mut self) -> PResult<'a, TsMappedType> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().typed());
 
         let start = cur_pos!();
         expect!('{');
@@ -1390,6 +1415,11 @@ impl<'a, I: Tokens> Parser<'a, I> {
 
         expect!('[');
         let type_param = self.parse_ts_mapped_type_param()?;
+        let name_type = if eat!("as") {
+            Some(self.parse_ts_type()?)
+        } else {
+            None
+        };
         expect!(']');
 
         let mut optional = None;
@@ -1414,54 +1444,134 @@ impl<'a, I: Tokens> Parser<'a, I> {
             readonly,
             optional,
             type_param,
+            name_type,
             type_ann,
         })
     }
 
     /// `tsParseTupleType`
     fn parse_ts_tuple_type(&mut self) -> PResult<'a, TsTupleType> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().typed());
 
         let start = cur_pos!();
         let elem_types = self.parse_ts_bracketed_list(
             ParsingContext::TupleElementTypes,
-            |p| p.parse_ts_tuple_element_type(),
+            |p| p.parse_ts_tuple_element(),
             /* bracket */ true,
             /* skipFirstToken */ false,
         )?;
 
         // Validate the elementTypes to ensure:
         //   No mandatory elements may follow optional elements
-        //   If there's a rest element, it must be at the end of the tuple
+        //   No optional or rest elements may follow a rest element
+        //   Either all of the elements are labeled or none of them are
 
         let mut seen_optional_element = false;
-        let len = elem_types.len();
-        for (i, elem_type) in elem_types.iter().enumerate() {
-            match **elem_type {
-                TsType::TsRestType(..) => {
-                    if i != len - 1 {
-                        syntax_error!(span!(start), SyntaxError::TsNonLastRest)
-                    }
-                }
-                TsType::TsOptionalType(..) => {
-                    seen_optional_element = true;
+        let mut seen_rest_element = false;
+        for elem in &elem_types {
+            let is_rest = match elem.label {
+                Some(Pat::Rest(..)) => true,
+                Some(..) => false,
+                None => match *elem.ty {
+                    TsType::TsRestType(..) => true,
+                    _ => false,
+                },
+            };
+            let is_optional = match elem.label {
+                Some(Pat::Ident(ref i)) => i.optional,
+                Some(..) => false,
+                None => match *elem.ty {
+                    TsType::TsOptionalType(..) => true,
+                    _ => false,
+                },
+            };
+
+            if is_rest {
+                if seen_rest_element {
+                    syntax_error!(elem.span, SyntaxError::TsRestAfterRest)
                 }
-                _ if seen_optional_element => {
-                    syntax_error!(span!(start), SyntaxError::TsRequiredAfterOptional)
+                seen_rest_element = true;
+            } else if is_optional {
+                if seen_rest_element {
+                    syntax_error!(elem.span, SyntaxError::TsOptionalAfterRest)
                 }
-                _ => {}
+                seen_optional_element = true;
+            } else if seen_optional_element {
+                syntax_error!(elem.span, SyntaxError::TsRequiredAfterOptional)
             }
         }
 
+        let labeled = elem_types.iter().filter(|e| e.label.is_some()).count();
+        if labeled != 0 && labeled != elem_types.len() {
+            syntax_error!(span!(start), SyntaxError::TsMixedTupleLabels)
+        }
+
         Ok(TsTupleType {
             span: span!(start),
             elem_types,
         })
     }
 
+    fn is_ts_start_of_labeled_tuple_element(&mut self) -> PResult<'a, bool> {
+        debug_assert!(self.input.syntax().typed());
+
+        let is_rest = eat!("...");
+        if !is!(IdentName) {
+            return Ok(false);
+        }
+        bump!();
+        if !is_rest {
+            eat!('?');
+        }
+
+        Ok(is!(':'))
+    }
+
+    /// Parses an element of a tuple type, which may be labeled as in
+    /// `[start: number, end?: number, ...rest: any[]]`.
+    fn parse_ts_tuple_element(&mut self) -> PResult<'a, TsTupleElement> {
+        debug_assert!(self.input.syntax().typed());
+
+        let start = cur_pos!();
+
+        if !self.ts_look_ahead(|p| p.is_ts_start_of_labeled_tuple_element())? {
+            let ty = self.parse_ts_tuple_element_type()?;
+            return Ok(TsTupleElement {
+                span: span!(start),
+                label: None,
+                ty,
+            });
+        }
+
+        let label = if eat!("...") {
+            let dot3_token = span!(start);
+            let arg = self.parse_ident_name()?;
+            Pat::Rest(RestPat {
+                span: span!(start),
+                dot3_token,
+                arg: Box::new(Pat::Ident(arg)),
+                type_ann: None,
+            })
+        } else {
+            let mut ident = self.parse_ident_name()?;
+            if eat!('?') {
+                ident.optional = true;
+            }
+            Pat::Ident(ident)
+        };
+        expect!(':');
+        let ty = self.parse_ts_type()?;
+
+        Ok(TsTupleElement {
+            span: span!(start),
+            label: Some(label),
+            ty,
+        })
+    }
+
     /// `tsParseTupleElementType`
     fn parse_ts_tuple_element_type(&mut self) -> PResult<'a, Box<TsType>> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().typed());
 
         // parses `...TsType[]`
         let start = cur_pos!();
@@ -1489,7 +1599,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
 
     /// `tsParseParenthesizedType`
     fn parse_ts_parenthesized_type(&mut self) -> PResult<'a, TsParenthesizedType> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().typed());
 
         let start = cur_pos!();
         expect!('(');
@@ -1506,9 +1616,10 @@ impl<'a, I: Tokens> Parser<'a, I> {
         &mut self,
         is_fn_type: bool,
     ) -> PResult<'a, TsFnOrConstructorType> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().typed());
 
         let start = cur_pos!();
+        let is_abstract = !is_fn_type && eat!("abstract");
         if !is_fn_type {
             expect!("new");
         }
@@ -1533,24 +1644,19 @@ impl<'a, I: Tokens> Parser<'a, I> {
                 type_params,
                 params,
                 type_ann,
+                is_abstract,
             })
         })
     }
 
     /// `tsParseLiteralTypeNode`
-    fn parse_ts_lit_type_node(&mut self) -> PResult<'a, TsLitType> {
-        debug_assert!(self.input.syntax().typescript());
+    pub(super) fn parse_ts_lit_type_node(&mut self) -> PResult<'a, TsLitType> {
+        debug_assert!(self.input.syntax().typed());
 
         let start = cur_pos!();
 
         let lit = if is!('`') {
-            let tpl = self.parse_tpl()?;
-            if !tpl.exprs.is_empty() {
-                // template literals with expressions are not
-                // allowed in a type
-                self.emit_err(span!(start), SyntaxError::TS1110);
-            }
-            TsLit::Tpl(tpl)
+            TsLit::Tpl(self.parse_ts_tpl_lit_type()?)
         } else {
             match self.parse_lit()? {
                 Lit::Bool(n) => TsLit::Bool(n),
@@ -1566,9 +1672,40 @@ impl<'a, I: Tokens> Parser<'a, I> {
         })
     }
 
+    /// Parses a template literal type, as in `` `prefix-${T}` ``.
+    fn parse_ts_tpl_lit_type(&mut self) -> PResult<'a, TsTplLitType> {
+        debug_assert!(self.input.syntax().typed());
+
+        let start = cur_pos!();
+        assert_and_bump!('`');
+
+        let mut types = vec![];
+
+        let cur_elem = self.parse_tpl_element(false)?;
+        let mut is_tail = cur_elem.tail;
+        let mut quasis = vec![cur_elem];
+
+        while !is_tail {
+            expect!("${");
+            types.push(self.parse_ts_type()?);
+            expect!('}');
+            let elem = self.parse_tpl_element(false)?;
+            is_tail = elem.tail;
+            quasis.push(elem);
+        }
+
+        expect!('`');
+
+        Ok(TsTplLitType {
+            span: span!(start),
+            types,
+            quasis,
+        })
+    }
+
     /// `tsParseBindingListForSignature`
     fn parse_ts_binding_list_for_signature(&mut self) -> PResult<'a, Vec<TsFnParam>> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().typed());
 
         let params = self.parse_formal_params()?;
         let mut list = vec![];
@@ -1624,7 +1761,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
     /// `tsParseNonArrayType`
     #[allow(clippy::cognitive_complexity)]
     fn parse_ts_non_array_type(&mut self) -> PResult<'a, Box<TsType>> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().typed());
 
         let start = cur_pos!();
 
@@ -1758,7 +1895,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
 
     /// `tsParseArrayTypeOrHigher`
     fn parse_ts_array_type_or_higher(&mut self, readonly: bool) -> PResult<'a, Box<TsType>> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().typed());
 
         let mut ty = self.parse_ts_non_array_type()?;
 
@@ -1785,7 +1922,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
 
     /// `tsParseTypeOperator`
     fn parse_ts_type_operator(&mut self, op: TsTypeOperatorOp) -> PResult<'a, TsTypeOperator> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().typed());
 
         let start = cur_pos!();
         match op {
@@ -1804,7 +1941,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
 
     /// `tsParseInferType`
     fn parse_ts_infer_type(&mut self) -> PResult<'a, TsInferType> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().typed());
 
         let start = cur_pos!();
         expect!("infer");
@@ -1823,7 +1960,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
 
     /// `tsParseTypeOperatorOrHigher`
     fn parse_ts_type_operator_or_higher(&mut self) -> PResult<'a, Box<TsType>> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().typed());
 
         let operator = if is!("keyof") {
             Some(TsTypeOperatorOp::KeyOf)
@@ -2191,7 +2328,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
 
     /// `tsParseTypeArguments`
     pub fn parse_ts_type_args(&mut self) -> PResult<'a, TsTypeParamInstantiation> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().typed());
 
         let start = cur_pos!();
         let params = self.in_type().parse_with(|p| {
@@ -2217,7 +2354,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
 
     /// `tsParseIntersectionTypeOrHigher`
     fn parse_ts_intersection_type_or_higher(&mut self) -> PResult<'a, Box<TsType>> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().typed());
 
         self.parse_ts_union_or_intersection_type(
             UnionOrIntersection::Intersection,
@@ -2228,7 +2365,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
 
     /// `tsParseUnionTypeOrHigher`
     fn parse_ts_union_type_or_higher(&mut self) -> PResult<'a, Box<TsType>> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().typed());
 
         self.parse_ts_union_or_intersection_type(
             UnionOrIntersection::Union,
@@ -2247,7 +2384,7 @@ impl<'a, I: Tokens> Parser<'a, I> {
     where
         F: FnMut(&mut Self) -> PResult<'a, Box<TsType>>,
     {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().typed());
 
         let start = cur_pos!(); // include the leading operator in the start
         self.input.eat(operator);
@@ -2280,11 +2417,11 @@ impl<'a, I: Tokens> Parser<'a, I> {
 
 impl<'a, I: Tokens> Parser<'a, I> {
     /// In no lexer context
-    fn ts_in_no_context<T, F>(&mut self, op: F) -> PResult<'a, T>
+    pub(super) fn ts_in_no_context<T, F>(&mut self, op: F) -> PResult<'a, T>
     where
         F: FnOnce(&mut Self) -> PResult<'a, T>,
     {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().typed());
 
         let cloned = self.input.token_context().clone();
         self.input
@@ -2318,7 +2455,7 @@ enum SignatureParsingMode {
 }
 
 /// Mark as declare
-fn make_decl_declare(mut decl: Decl) -> Decl {
+pub(super) fn make_decl_declare(mut decl: Decl) -> Decl {
     match decl {
         Decl::Class(ref mut c) => c.declare = true,
         Decl::Fn(ref mut f) => f.declare = true,
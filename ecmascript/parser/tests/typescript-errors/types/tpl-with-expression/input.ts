@@ -1 +0,0 @@
-type MyType = `text${test}`;
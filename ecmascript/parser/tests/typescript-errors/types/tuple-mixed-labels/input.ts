@@ -0,0 +1 @@
+let x: [a: string, number]
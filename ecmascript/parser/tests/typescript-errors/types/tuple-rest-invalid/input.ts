@@ -1 +0,0 @@
-let x: [...number[], string]
@@ -0,0 +1,4 @@
+class C {
+    accessor a = 1;
+    static accessor #b: string;
+}
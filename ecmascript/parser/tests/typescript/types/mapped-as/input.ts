@@ -0,0 +1 @@
+let x: { [K in string as `get-${K}`]: number }
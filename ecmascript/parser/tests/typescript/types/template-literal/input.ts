@@ -0,0 +1 @@
+let x: `prefix-${T}-${number}`
@@ -0,0 +1 @@
+let x: [start: number, end?: number, ...rest: string[]]
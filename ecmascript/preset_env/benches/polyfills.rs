@@ -27,7 +27,7 @@ fn run(b: &mut Bencher, src: &str, config: Config) {
                 e.emit();
             })
             .unwrap();
-        let mut folder = preset_env(Mark::fresh(Mark::root()), config);
+        let mut folder = preset_env(Mark::fresh(Mark::root()), config, Default::default());
 
         b.iter(|| test::black_box(module.clone().fold_with(&mut folder)));
         Ok(())
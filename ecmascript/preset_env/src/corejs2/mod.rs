@@ -13,8 +13,19 @@ mod builtin;
 mod data;
 mod entry;
 
+/// Returns true if `feature` should be polyfilled for `target`.
+pub(crate) fn is_required(target: Versions, feature: &str) -> bool {
+    if target.is_any_target() {
+        return true;
+    }
+
+    match BUILTINS.get(feature) {
+        Some(v) => should_enable(target, *v, true),
+        None => true,
+    }
+}
+
 pub(crate) struct UsageVisitor {
-    is_any_target: bool,
     target: Versions,
     pub required: FxHashSet<&'static str>,
 }
@@ -41,7 +52,6 @@ impl UsageVisitor {
         //        v
 
         let v = Self {
-            is_any_target: target.is_any_target(),
             target,
             required: Default::default(),
         };
@@ -54,24 +64,10 @@ impl UsageVisitor {
 
     /// Add imports
     fn add(&mut self, features: &'static [&'static str]) {
-        let UsageVisitor {
-            is_any_target,
-            target,
-            ..
-        } = self;
-
-        self.required.extend(features.iter().filter_map(|f| {
-            if !*is_any_target {
-                if let Some(v) = BUILTINS.get(&**f) {
-                    // Skip
-                    if !should_enable(*target, *v, true) {
-                        return None;
-                    }
-                }
-            }
+        let target = self.target;
 
-            Some(f)
-        }));
+        self.required
+            .extend(features.iter().filter(|f| is_required(target, f)));
     }
 
     fn add_property_deps_inner(&mut self, obj: Option<&JsWord>, prop: &JsWord) {
@@ -110,6 +106,19 @@ impl UsageVisitor {
     }
 }
 
+/// Features required by the global `name`.
+pub(crate) fn builtin_features(name: &str) -> Option<&'static [&'static str]> {
+    BUILTIN_TYPES.get_data(name).copied()
+}
+
+/// Features required by `obj.prop`.
+pub(crate) fn static_features(obj: &str, prop: &str) -> Option<&'static [&'static str]> {
+    STATIC_PROPERTIES
+        .get_data(obj)
+        .and_then(|map| map.get_data(prop))
+        .copied()
+}
+
 // TODO:
 //     Program(path: NodePath) {
 //      path.get("body").forEach(bodyPath => {
@@ -1,6 +1,29 @@
-pub(crate) use self::{entry::Entry, usage::UsageVisitor};
+use self::data::{BUILTINS, INSTANCE_PROPERTIES, STATIC_PROPERTIES};
+pub(crate) use self::{
+    entry::Entry,
+    usage::{is_required, UsageVisitor},
+};
+use crate::util::DataMapExt;
 
 mod compat;
 mod data;
 mod entry;
 mod usage;
+
+/// Features required by the global `name`.
+pub(crate) fn builtin_features(name: &str) -> Option<&'static [&'static str]> {
+    BUILTINS.get_data(name).copied()
+}
+
+/// Features required by `obj.prop`.
+pub(crate) fn static_features(obj: &str, prop: &str) -> Option<&'static [&'static str]> {
+    STATIC_PROPERTIES
+        .get_data(obj)
+        .and_then(|map| map.get_data(prop))
+        .copied()
+}
+
+/// Features required by `x.prop`.
+pub(crate) fn instance_features(prop: &str) -> Option<&'static [&'static str]> {
+    INSTANCE_PROPERTIES.get_data(prop).copied()
+}
@@ -16,9 +16,24 @@ use swc_atoms::{js_word, JsWord};
 use swc_common::{Visit, VisitWith, DUMMY_SP};
 use swc_ecma_ast::*;
 
+/// Returns true if `feature` should be polyfilled for `target`.
+pub(crate) fn is_required(target: Versions, shipped_proposals: bool, feature: &str) -> bool {
+    if !shipped_proposals && feature.starts_with("esnext.") {
+        return false;
+    }
+
+    if target.is_any_target() {
+        return true;
+    }
+
+    match CORE_JS_COMPAT_DATA.get(feature) {
+        Some(v) => should_enable(target, *v, true),
+        None => true,
+    }
+}
+
 pub(crate) struct UsageVisitor {
     shipped_proposals: bool,
-    is_any_target: bool,
     target: Versions,
     pub required: FxHashSet<&'static str>,
 }
@@ -46,7 +61,6 @@ impl UsageVisitor {
 
         Self {
             shipped_proposals,
-            is_any_target: target.is_any_target(),
             target,
             required: Default::default(),
         }
@@ -56,28 +70,15 @@ impl UsageVisitor {
     fn add(&mut self, features: &[&'static str]) {
         let UsageVisitor {
             shipped_proposals,
-            is_any_target,
             target,
             ..
         } = self;
 
-        self.required.extend(features.iter().filter_map(|f| {
-            if !*shipped_proposals && f.starts_with("esnext.") {
-                return None;
-            }
-
-            let feature = CORE_JS_COMPAT_DATA.get(&**f);
-
-            if !*is_any_target {
-                if let Some(feature) = feature {
-                    if !should_enable(*target, *feature, true) {
-                        return None;
-                    }
-                }
-            }
-
-            Some(f)
-        }));
+        self.required.extend(
+            features
+                .iter()
+                .filter(|f| is_required(*target, *shipped_proposals, f)),
+        );
     }
 
     fn add_builtin(&mut self, built_in: &str) {
@@ -18,21 +18,23 @@ use swc_atoms::{js_word, JsWord};
 use swc_common::{chain, Fold, FoldWith, FromVariant, Mark, VisitWith, DUMMY_SP};
 use swc_ecma_ast::*;
 use swc_ecma_transforms::{
-    compat::{es2015, es2016, es2017, es2018, es3},
+    compat::{es2015, es2016, es2017, es2018, es2021, es3},
     pass::{noop, Optional, Pass},
     util::prepend_stmts,
+    Assumptions,
 };
 
 #[macro_use]
 mod util;
 mod corejs2;
 mod corejs3;
+mod pure;
 mod regenerator;
 mod transform_data;
 mod version;
 
-pub fn preset_env(global_mark: Mark, c: Config) -> impl Pass {
-    let loose = c.loose;
+pub fn preset_env(global_mark: Mark, c: Config, assumptions: Assumptions) -> impl Pass {
+    let iterable_is_array = c.loose || assumptions.iterable_is_array;
     let targets: Versions = c.targets.try_into().expect("failed to parse targets");
     let is_any_target = targets.is_any_target();
 
@@ -67,8 +69,22 @@ pub fn preset_env(global_mark: Mark, c: Config) -> impl Pass {
         }};
     }
 
+    // ES2021
+    let pass = add!(
+        pass,
+        LogicalAssignmentOperators,
+        es2021::logical_assignments()
+    );
+
     // ES2018
-    let pass = add!(pass, ObjectRestSpread, es2018::object_rest_spread());
+    let pass = add!(
+        pass,
+        ObjectRestSpread,
+        es2018::object_rest_spread(es2018::object_rest_spread::Config {
+            set_spread_properties: assumptions.set_spread_properties,
+            ignore_to_primitive_hint: assumptions.ignore_to_primitive_hint,
+        })
+    );
     let pass = add!(pass, OptionalCatchBinding, es2018::optional_catch_binding());
 
     // ES2017
@@ -85,11 +101,20 @@ pub fn preset_env(global_mark: Mark, c: Config) -> impl Pass {
         es2015::TemplateLiteral::default(),
         true
     );
-    let pass = add!(pass, Classes, es2015::Classes::default());
+    let pass = add!(
+        pass,
+        Classes,
+        es2015::Classes::new(es2015::classes::Config {
+            constant_super: assumptions.constant_super,
+            no_class_calls: assumptions.no_class_calls,
+        })
+    );
     let pass = add!(
         pass,
         Spread,
-        es2015::spread(es2015::spread::Config { loose }),
+        es2015::spread(es2015::spread::Config {
+            loose: iterable_is_array
+        }),
         true
     );
     let pass = add!(pass, FunctionName, es2015::function_name());
@@ -104,7 +129,7 @@ pub fn preset_env(global_mark: Mark, c: Config) -> impl Pass {
         pass,
         ForOf,
         es2015::for_of(es2015::for_of::Config {
-            assume_array: loose
+            assume_array: iterable_is_array
         }),
         true
     );
@@ -117,7 +142,9 @@ pub fn preset_env(global_mark: Mark, c: Config) -> impl Pass {
     let pass = add!(
         pass,
         Destructuring,
-        es2015::destructuring(es2015::destructuring::Config { loose }),
+        es2015::destructuring(es2015::destructuring::Config {
+            loose: iterable_is_array
+        }),
         true
     );
     let pass = add!(pass, Regenerator, es2015::regenerator(global_mark), true);
@@ -168,7 +195,7 @@ pub fn preset_env(global_mark: Mark, c: Config) -> impl Pass {
 }
 
 /// A map without allocation.
-#[derive(Debug, Default, Deserialize, Clone, Copy, StaticMap)]
+#[derive(Debug, Default, Serialize, Deserialize, Clone, Copy, StaticMap)]
 #[serde(deny_unknown_fields)]
 pub struct BrowserData<T: Default> {
     #[serde(default)]
@@ -255,6 +282,32 @@ impl Fold<Module> for Polyfills {
 
                 _ => unimplemented!("corejs version other than 2 / 3"),
             },
+            Some(Mode::Pure) => {
+                let mut v = pure::Pure::new(&m, self.corejs, self.targets, self.shipped_proposals);
+                m = m.fold_with(&mut v);
+
+                prepend_stmts(
+                    &mut m.body,
+                    v.imports.into_iter().map(|(src, local)| {
+                        ModuleItem::ModuleDecl(ModuleDecl::Import(ImportDecl {
+                            span,
+                            specifiers: vec![ImportSpecifier::Default(ImportDefaultSpecifier {
+                                span: DUMMY_SP,
+                                local,
+                            })],
+                            src: Str {
+                                span: DUMMY_SP,
+                                value: src,
+                                has_escape: false,
+                            },
+                            type_only: false,
+                            attrs: None,
+                        }))
+                    }),
+                );
+
+                Default::default()
+            }
         };
         let required = required
             .into_iter()
@@ -290,6 +343,7 @@ impl Fold<Module> for Polyfills {
                             has_escape: false,
                         },
                         type_only: false,
+                        attrs: None,
                     }))
                 }),
             );
@@ -306,6 +360,7 @@ impl Fold<Module> for Polyfills {
                             has_escape: false,
                         },
                         type_only: false,
+                        attrs: None,
                     }))
                 }),
             );
@@ -329,17 +384,62 @@ impl Fold<Module> for Polyfills {
 }
 
 impl Fold<Script> for Polyfills {
-    fn fold(&mut self, _: Script) -> Script {
-        unimplemented!("automatic polyfill for scripts")
+    fn fold(&mut self, s: Script) -> Script {
+        match self.mode {
+            Some(Mode::Pure) => {}
+            _ => unimplemented!("automatic polyfill for scripts"),
+        }
+
+        let span = s.span;
+        let mut v = pure::Pure::new(&s, self.corejs, self.targets, self.shipped_proposals);
+        let mut s = s.fold_with(&mut v);
+
+        // var _Promise = require("core-js-pure/stable/promise");
+        prepend_stmts(
+            &mut s.body,
+            v.imports.into_iter().map(|(src, local)| {
+                Stmt::Decl(Decl::Var(VarDecl {
+                    span,
+                    kind: VarDeclKind::Var,
+                    decls: vec![VarDeclarator {
+                        span: DUMMY_SP,
+                        name: Pat::Ident(local),
+                        init: Some(box Expr::Call(CallExpr {
+                            span: DUMMY_SP,
+                            callee: ExprOrSuper::Expr(box Expr::Ident(Ident::new(
+                                js_word!("require"),
+                                DUMMY_SP,
+                            ))),
+                            args: vec![ExprOrSpread {
+                                spread: None,
+                                expr: box Expr::Lit(Lit::Str(Str {
+                                    span: DUMMY_SP,
+                                    value: src,
+                                    has_escape: false,
+                                })),
+                            }],
+                            type_args: Default::default(),
+                        })),
+                        definite: false,
+                    }],
+                    declare: false,
+                }))
+            }),
+        );
+
+        s
     }
 }
 
-#[derive(Debug, Clone, Copy, Deserialize, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
 pub enum Mode {
     #[serde(rename = "usage")]
     Usage,
     #[serde(rename = "entry")]
     Entry,
+    /// Import polyfills from `core-js-pure` instead of patching globals.
+    #[serde(rename = "pure")]
+    Pure,
 }
 
 pub type Versions = BrowserData<Option<Version>>;
@@ -394,7 +494,7 @@ impl BrowserData<Option<Version>> {
     }
 }
 
-#[derive(Debug, Clone, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
 #[serde(rename_all = "camelCase")]
 pub struct Config {
     #[serde(default)]
@@ -440,7 +540,7 @@ fn default_targets() -> Option<Targets> {
     Some(Targets::Query(Query::Single("".into())))
 }
 
-#[derive(Debug, Clone, Deserialize, FromVariant)]
+#[derive(Debug, Clone, Serialize, Deserialize, FromVariant)]
 #[serde(untagged)]
 pub enum FeatureOrModule {
     Feature(Feature),
@@ -465,7 +565,7 @@ impl FeatureOrModule {
     }
 }
 
-#[derive(Debug, Clone, Deserialize, FromVariant)]
+#[derive(Debug, Clone, Serialize, Deserialize, FromVariant)]
 #[serde(untagged)]
 pub enum Targets {
     Query(Query),
@@ -474,19 +574,21 @@ pub enum Targets {
     HashMap(FxHashMap<String, QueryOrVersion>),
 }
 
-#[derive(Debug, Clone, Copy, Deserialize)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
 pub struct EsModules {
     esmodules: bool,
 }
 
-#[derive(Debug, Clone, Deserialize, FromVariant)]
+#[derive(Debug, Clone, Serialize, Deserialize, FromVariant)]
 #[serde(untagged)]
 pub enum QueryOrVersion {
     Query(Query),
     Version(Version),
 }
 
-#[derive(Debug, Clone, Deserialize, FromVariant, Eq, PartialEq, PartialOrd, Ord, Hash)]
+#[derive(
+    Debug, Clone, Serialize, Deserialize, FromVariant, Eq, PartialEq, PartialOrd, Ord, Hash,
+)]
 #[serde(untagged)]
 pub enum Query {
     Single(String),
@@ -0,0 +1,562 @@
+//! `pure` mode: replaces references to built-ins with imports from
+//! `core-js-pure` (or `core-js/library` for core-js 2) instead of patching
+//! globals.
+//!
+//! This is what `@babel/plugin-transform-runtime` does with the `corejs`
+//! option.
+use crate::{corejs2, corejs3, version::Version, Versions};
+use fxhash::{FxHashMap, FxHashSet};
+use std::mem::replace;
+use swc_atoms::{js_word, JsWord};
+use swc_common::{Fold, FoldWith, Mark, Span, Visit, VisitWith, DUMMY_SP};
+use swc_ecma_ast::*;
+use swc_ecma_transforms::util::{alias_if_required, id, ExprFactory, Id, StmtLike};
+
+/// Globals which can be imported from `core-js-pure`.
+static GLOBALS: &[(&str, &str)] = &[
+    ("AggregateError", "aggregate-error"),
+    ("Map", "map"),
+    ("Observable", "observable"),
+    ("Promise", "promise"),
+    ("Set", "set"),
+    ("Symbol", "symbol"),
+    ("URL", "url"),
+    ("URLSearchParams", "url-search-params"),
+    ("WeakMap", "weak-map"),
+    ("WeakSet", "weak-set"),
+    ("clearImmediate", "clear-immediate"),
+    ("compositeKey", "composite-key"),
+    ("compositeSymbol", "composite-symbol"),
+    ("globalThis", "global-this"),
+    ("parseFloat", "parse-float"),
+    ("parseInt", "parse-int"),
+    ("queueMicrotask", "queue-microtask"),
+    ("setImmediate", "set-immediate"),
+    ("setInterval", "set-interval"),
+    ("setTimeout", "set-timeout"),
+];
+
+/// Objects whose static properties can be imported from `core-js-pure`.
+static STATIC_OBJECTS: &[(&str, &str)] = &[
+    ("Array", "array"),
+    ("Date", "date"),
+    ("JSON", "json"),
+    ("Map", "map"),
+    ("Math", "math"),
+    ("Number", "number"),
+    ("Object", "object"),
+    ("Promise", "promise"),
+    ("Reflect", "reflect"),
+    ("Set", "set"),
+    ("String", "string"),
+    ("Symbol", "symbol"),
+    ("WeakMap", "weak-map"),
+    ("WeakSet", "weak-set"),
+];
+
+/// Instance methods which can be imported from `core-js-pure/*/instance`.
+static INSTANCE_METHODS: &[&str] = &[
+    "at",
+    "bind",
+    "codePointAt",
+    "codePoints",
+    "concat",
+    "copyWithin",
+    "endsWith",
+    "entries",
+    "every",
+    "fill",
+    "filter",
+    "find",
+    "findIndex",
+    "flags",
+    "flat",
+    "flatMap",
+    "forEach",
+    "includes",
+    "indexOf",
+    "keys",
+    "lastIndexOf",
+    "map",
+    "matchAll",
+    "padEnd",
+    "padStart",
+    "reduce",
+    "reduceRight",
+    "repeat",
+    "replaceAll",
+    "reverse",
+    "slice",
+    "some",
+    "sort",
+    "splice",
+    "startsWith",
+    "trim",
+    "trimEnd",
+    "trimLeft",
+    "trimRight",
+    "trimStart",
+    "values",
+];
+
+pub(crate) struct Pure {
+    corejs: Version,
+    target: Versions,
+    shipped_proposals: bool,
+    /// Identifiers declared in the file. References to them are not
+    /// rewritten.
+    ///
+    /// The resolver gives a binding and references to it the same syntax
+    /// context, so anything else is a global.
+    declared: FxHashSet<Id>,
+    /// Source of imports, in the order they are first used.
+    pub imports: Vec<(JsWord, Ident)>,
+    imported: FxHashMap<JsWord, Ident>,
+    vars: Vec<VarDeclarator>,
+}
+
+impl Pure {
+    pub fn new<T>(node: &T, corejs: Version, target: Versions, shipped_proposals: bool) -> Self
+    where
+        T: VisitWith<DeclaredIds>,
+    {
+        let mut v = DeclaredIds::default();
+        node.visit_with(&mut v);
+
+        Pure {
+            corejs,
+            target,
+            shipped_proposals,
+            declared: v.ids,
+            imports: Default::default(),
+            imported: Default::default(),
+            vars: Default::default(),
+        }
+    }
+
+    fn prefix(&self) -> &'static str {
+        match self.corejs {
+            Version { major: 2, .. } => "core-js/library/fn",
+            Version { major: 3, .. } if self.shipped_proposals => "core-js-pure/features",
+            Version { major: 3, .. } => "core-js-pure/stable",
+            _ => unimplemented!("corejs version other than 2 / 3"),
+        }
+    }
+
+    /// Returns true if any of `features` is required for the targets.
+    fn is_required(&self, features: Option<&'static [&'static str]>) -> bool {
+        let features = match features {
+            Some(v) => v,
+            None => return false,
+        };
+
+        match self.corejs {
+            Version { major: 2, .. } => features
+                .iter()
+                .any(|f| corejs2::is_required(self.target, f)),
+            _ => features
+                .iter()
+                .any(|f| corejs3::is_required(self.target, self.shipped_proposals, f)),
+        }
+    }
+
+    fn import(&mut self, path: String, name: String, span: Span) -> Ident {
+        let src: JsWord = format!("{}/{}", self.prefix(), path).into();
+
+        let ident = match self.imported.get(&src) {
+            Some(i) => i.clone(),
+            None => {
+                let i = Ident::new(
+                    format!("_{}", name).into(),
+                    DUMMY_SP.apply_mark(Mark::fresh(Mark::root())),
+                );
+                self.imported.insert(src.clone(), i.clone());
+                self.imports.push((src, i.clone()));
+                i
+            }
+        };
+
+        Ident {
+            span: span.with_ctxt(ident.span.ctxt()),
+            ..ident
+        }
+    }
+
+    fn global(&mut self, i: &Ident) -> Option<Ident> {
+        if self.declared.contains(&id(i)) {
+            return None;
+        }
+
+        let path = GLOBALS
+            .iter()
+            .find(|(name, _)| *name == &*i.sym)
+            .map(|(_, path)| *path)?;
+
+        let features = match self.corejs {
+            Version { major: 2, .. } => corejs2::builtin_features(&i.sym),
+            _ => corejs3::builtin_features(&i.sym),
+        };
+        if !self.is_required(features) {
+            return None;
+        }
+
+        Some(self.import(path.into(), i.sym.to_string(), i.span))
+    }
+
+    /// `Array.from` => `_Array$from`
+    fn static_prop(&mut self, span: Span, obj: &ExprOrSuper, prop: &Expr) -> Option<Ident> {
+        let (obj, prop) = match (obj, prop) {
+            (ExprOrSuper::Expr(box Expr::Ident(obj)), Expr::Ident(prop)) => (obj, prop),
+            _ => return None,
+        };
+        if self.declared.contains(&id(obj)) {
+            return None;
+        }
+
+        let obj_path = STATIC_OBJECTS
+            .iter()
+            .find(|(name, _)| *name == &*obj.sym)
+            .map(|(_, path)| *path)?;
+
+        let features = match self.corejs {
+            Version { major: 2, .. } => corejs2::static_features(&obj.sym, &prop.sym),
+            _ => corejs3::static_features(&obj.sym, &prop.sym),
+        };
+        if !self.is_required(features) {
+            return None;
+        }
+
+        Some(self.import(
+            format!("{}/{}", obj_path, kebab_case(&prop.sym)),
+            format!("{}${}", obj.sym, prop.sym),
+            span,
+        ))
+    }
+
+    /// `includes` => `_includesInstanceProperty`
+    ///
+    /// core-js 2 does not provide instance methods without patching
+    /// prototypes, so this always returns `None` for it.
+    fn instance_prop(&mut self, prop: &Expr) -> Option<Ident> {
+        let prop = match prop {
+            Expr::Ident(prop) => prop,
+            _ => return None,
+        };
+
+        match self.corejs {
+            Version { major: 2, .. } => return None,
+            _ => {}
+        }
+
+        if !INSTANCE_METHODS.contains(&&*prop.sym) {
+            return None;
+        }
+        if !self.is_required(corejs3::instance_features(&prop.sym)) {
+            return None;
+        }
+
+        Some(self.import(
+            format!("instance/{}", kebab_case(&prop.sym)),
+            format!("{}InstanceProperty", prop.sym),
+            DUMMY_SP,
+        ))
+    }
+}
+
+impl<T> Fold<Vec<T>> for Pure
+where
+    T: FoldWith<Self> + StmtLike,
+{
+    fn fold(&mut self, stmts: Vec<T>) -> Vec<T> {
+        let mut buf = Vec::with_capacity(stmts.len());
+
+        for stmt in stmts {
+            let stmt = stmt.fold_with(self);
+
+            if !self.vars.is_empty() {
+                buf.push(T::from_stmt(Stmt::Decl(Decl::Var(VarDecl {
+                    span: DUMMY_SP,
+                    kind: VarDeclKind::Var,
+                    decls: replace(&mut self.vars, Default::default()),
+                    declare: false,
+                }))));
+            }
+
+            buf.push(stmt);
+        }
+
+        buf
+    }
+}
+
+impl Fold<Expr> for Pure {
+    fn fold(&mut self, e: Expr) -> Expr {
+        match e {
+            Expr::Ident(i) => Expr::Ident(self.global(&i).unwrap_or(i)),
+
+            Expr::Member(MemberExpr {
+                span,
+                obj,
+                prop,
+                computed: false,
+            }) => {
+                if let Some(i) = self.static_prop(span, &obj, &prop) {
+                    return Expr::Ident(i);
+                }
+
+                let obj = obj.fold_with(self);
+                let helper = match obj {
+                    ExprOrSuper::Expr(..) => self.instance_prop(&prop),
+                    ExprOrSuper::Super(..) => None,
+                };
+
+                match (obj, helper) {
+                    // `a.includes` => `_includesInstanceProperty(a)`
+                    (ExprOrSuper::Expr(obj), Some(helper)) => Expr::Call(CallExpr {
+                        span,
+                        callee: helper.as_callee(),
+                        args: vec![ExprOrSpread {
+                            spread: None,
+                            expr: obj,
+                        }],
+                        type_args: Default::default(),
+                    }),
+                    (obj, _) => Expr::Member(MemberExpr {
+                        span,
+                        obj,
+                        prop,
+                        computed: false,
+                    }),
+                }
+            }
+
+            Expr::Call(CallExpr {
+                span,
+                callee:
+                    ExprOrSuper::Expr(box Expr::Member(MemberExpr {
+                        span: callee_span,
+                        obj: ExprOrSuper::Expr(obj),
+                        prop,
+                        computed: false,
+                    })),
+                args,
+                type_args,
+            }) => {
+                let obj = ExprOrSuper::Expr(obj);
+
+                if let Some(i) = self.static_prop(callee_span, &obj, &prop) {
+                    return Expr::Call(CallExpr {
+                        span,
+                        callee: i.as_callee(),
+                        args: args.fold_with(self),
+                        type_args,
+                    });
+                }
+
+                let obj = match obj.fold_with(self) {
+                    ExprOrSuper::Expr(obj) => obj,
+                    ExprOrSuper::Super(..) => unreachable!(),
+                };
+                let args = args.fold_with(self);
+
+                let helper = match self.instance_prop(&prop) {
+                    Some(helper) => helper,
+                    None => {
+                        return Expr::Call(CallExpr {
+                            span,
+                            callee: MemberExpr {
+                                span: callee_span,
+                                obj: ExprOrSuper::Expr(obj),
+                                prop,
+                                computed: false,
+                            }
+                            .as_callee(),
+                            args,
+                            type_args,
+                        })
+                    }
+                };
+
+                // `a.includes(x)` => `_includesInstanceProperty(a).call(a, x)`
+                let (this, aliased) = alias_if_required(&obj, "context");
+                let obj = if aliased {
+                    self.vars.push(VarDeclarator {
+                        span: DUMMY_SP,
+                        name: Pat::Ident(this.clone()),
+                        init: None,
+                        definite: false,
+                    });
+
+                    box Expr::Assign(AssignExpr {
+                        span: DUMMY_SP,
+                        op: AssignOp::Assign,
+                        left: PatOrExpr::Pat(box Pat::Ident(this.clone())),
+                        right: obj,
+                    })
+                } else {
+                    obj
+                };
+
+                Expr::Call(CallExpr {
+                    span,
+                    callee: CallExpr {
+                        span: callee_span,
+                        callee: helper.as_callee(),
+                        args: vec![ExprOrSpread {
+                            spread: None,
+                            expr: obj,
+                        }],
+                        type_args: Default::default(),
+                    }
+                    .member(Ident::new(js_word!("call"), DUMMY_SP))
+                    .as_callee(),
+                    args: Some(this.as_arg()).into_iter().chain(args).collect(),
+                    type_args,
+                })
+            }
+
+            _ => e.fold_children(self),
+        }
+    }
+}
+
+impl Fold<MemberExpr> for Pure {
+    fn fold(&mut self, e: MemberExpr) -> MemberExpr {
+        MemberExpr {
+            obj: e.obj.fold_with(self),
+            prop: if e.computed {
+                e.prop.fold_with(self)
+            } else {
+                e.prop
+            },
+            ..e
+        }
+    }
+}
+
+/// Assignment targets are not rewritten.
+impl Fold<AssignExpr> for Pure {
+    fn fold(&mut self, e: AssignExpr) -> AssignExpr {
+        let left = match e.left {
+            PatOrExpr::Expr(box Expr::Member(m)) => {
+                PatOrExpr::Expr(box Expr::Member(m.fold_with(self)))
+            }
+            PatOrExpr::Pat(box Pat::Expr(box Expr::Member(m))) => {
+                PatOrExpr::Pat(box Pat::Expr(box Expr::Member(m.fold_with(self))))
+            }
+            left => left.fold_with(self),
+        };
+
+        AssignExpr {
+            left,
+            right: e.right.fold_with(self),
+            ..e
+        }
+    }
+}
+
+/// `{ Promise }` => `{ Promise: _Promise }`
+impl Fold<Prop> for Pure {
+    fn fold(&mut self, p: Prop) -> Prop {
+        match p {
+            Prop::Shorthand(i) => match self.global(&i) {
+                Some(value) => Prop::KeyValue(KeyValueProp {
+                    key: PropName::Ident(i),
+                    value: box Expr::Ident(value),
+                }),
+                None => Prop::Shorthand(i),
+            },
+            _ => p.fold_children(self),
+        }
+    }
+}
+
+/// Collects all identifiers declared in a file.
+#[derive(Default)]
+pub(crate) struct DeclaredIds {
+    ids: FxHashSet<Id>,
+}
+
+impl Visit<Pat> for DeclaredIds {
+    fn visit(&mut self, p: &Pat) {
+        p.visit_children(self);
+
+        match p {
+            Pat::Ident(i) => {
+                self.ids.insert(id(i));
+            }
+            _ => {}
+        }
+    }
+}
+
+impl Visit<FnDecl> for DeclaredIds {
+    fn visit(&mut self, f: &FnDecl) {
+        f.visit_children(self);
+
+        self.ids.insert(id(&f.ident));
+    }
+}
+
+impl Visit<ClassDecl> for DeclaredIds {
+    fn visit(&mut self, c: &ClassDecl) {
+        c.visit_children(self);
+
+        self.ids.insert(id(&c.ident));
+    }
+}
+
+impl Visit<FnExpr> for DeclaredIds {
+    fn visit(&mut self, f: &FnExpr) {
+        f.visit_children(self);
+
+        if let Some(ref i) = f.ident {
+            self.ids.insert(id(i));
+        }
+    }
+}
+
+impl Visit<ClassExpr> for DeclaredIds {
+    fn visit(&mut self, c: &ClassExpr) {
+        c.visit_children(self);
+
+        if let Some(ref i) = c.ident {
+            self.ids.insert(id(i));
+        }
+    }
+}
+
+impl Visit<ImportSpecifier> for DeclaredIds {
+    fn visit(&mut self, s: &ImportSpecifier) {
+        let local = match s {
+            ImportSpecifier::Named(s) => &s.local,
+            ImportSpecifier::Default(s) => &s.local,
+            ImportSpecifier::Namespace(s) => &s.local,
+        };
+
+        self.ids.insert(id(local));
+    }
+}
+
+/// `codePointAt` => `code-point-at`, `MAX_SAFE_INTEGER` => `max-safe-integer`
+fn kebab_case(s: &str) -> String {
+    let s = s.replace("NaN", "Nan");
+    let mut buf = String::with_capacity(s.len() + 4);
+    let mut prev_lower = false;
+
+    for c in s.chars() {
+        if c == '_' {
+            buf.push('-');
+            prev_lower = false;
+            continue;
+        }
+
+        if c.is_ascii_uppercase() && prev_lower {
+            buf.push('-');
+        }
+        prev_lower = c.is_ascii_lowercase() || c.is_ascii_digit();
+        buf.push(c.to_ascii_lowercase());
+    }
+
+    buf
+}
@@ -97,6 +97,9 @@ pub enum Feature {
     /// `proposal-optional-catch-binding`
     OptionalCatchBinding,
 
+    /// `proposal-logical-assignment-operators`
+    LogicalAssignmentOperators,
+
     /// `transform-named-capturing-groups-regex`
     NamedCapturingGroupsRegex,
 
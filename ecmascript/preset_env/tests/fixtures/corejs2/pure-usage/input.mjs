@@ -0,0 +1,4 @@
+var p = Promise.resolve(0);
+Promise.all([p]).then(function (outcome) {
+  alert(Array.from(outcome).includes(1));
+});
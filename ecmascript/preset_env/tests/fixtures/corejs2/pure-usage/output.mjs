@@ -0,0 +1,7 @@
+import _Promise from "core-js/library/fn/promise";
+import _Promise$all from "core-js/library/fn/promise/all";
+import _Array$from from "core-js/library/fn/array/from";
+var p = _Promise.resolve(0);
+_Promise$all([p]).then(function (outcome) {
+  alert(_Array$from(outcome).includes(1));
+});
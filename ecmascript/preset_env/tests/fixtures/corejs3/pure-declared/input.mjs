@@ -0,0 +1,6 @@
+import Map from "immutable";
+
+function Promise() {}
+
+Promise.all([new Map(), new Set()]);
+Symbol.iterator;
@@ -0,0 +1,8 @@
+import _Set from "core-js-pure/stable/set";
+import _Symbol$iterator from "core-js-pure/stable/symbol/iterator";
+import Map from "immutable";
+
+function Promise() {}
+
+Promise.all([new Map(), new _Set()]);
+_Symbol$iterator;
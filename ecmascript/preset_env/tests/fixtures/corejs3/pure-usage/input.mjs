@@ -0,0 +1,5 @@
+var p = Promise.resolve(0);
+Promise.all([p]).then(outcome => {
+  alert(Array.from(outcome).includes(1));
+});
+foo.includes(1);
@@ -0,0 +1,10 @@
+import _Promise from "core-js-pure/stable/promise";
+import _Promise$all from "core-js-pure/stable/promise/all";
+import _Array$from from "core-js-pure/stable/array/from";
+import _includesInstanceProperty from "core-js-pure/stable/instance/includes";
+var p = _Promise.resolve(0);
+_Promise$all([p]).then(function (outcome) {
+  var _context;
+  alert(_includesInstanceProperty(_context = _Array$from(outcome)).call(_context, 1));
+});
+_includesInstanceProperty(foo).call(foo, 1);
@@ -181,6 +181,7 @@ fn exec(c: PresetConfig, dir: PathBuf) -> Result<(), Error> {
                         UseBuiltIns::Bool(false) => None,
                         UseBuiltIns::Str(ref s) if s == "usage" => Some(Mode::Usage),
                         UseBuiltIns::Str(ref s) if s == "entry" => Some(Mode::Entry),
+                        UseBuiltIns::Str(ref s) if s == "pure" => Some(Mode::Pure),
                         v => unreachable!("invalid: {:?}", v),
                     },
                     skip: vec![],
@@ -198,6 +199,7 @@ fn exec(c: PresetConfig, dir: PathBuf) -> Result<(), Error> {
                     shipped_proposals: c.shipped_proposals,
                     targets: c.targets,
                 },
+                Default::default(),
             );
 
             let print = |m: &Module| {
@@ -205,7 +207,10 @@ fn exec(c: PresetConfig, dir: PathBuf) -> Result<(), Error> {
                 {
                     let handlers = box MyHandlers;
                     let mut emitter = Emitter {
-                        cfg: swc_ecma_codegen::Config { minify: false },
+                        cfg: swc_ecma_codegen::Config {
+                            minify: false,
+                            ..Default::default()
+                        },
                         comments: None,
                         cm: cm.clone(),
                         wr: box swc_ecma_codegen::text_writer::JsWriter::new(
@@ -1,5 +1,7 @@
 pub use swc_ecma_ast as ast;
 pub use swc_ecma_codegen as codegen;
+pub use swc_ecma_lints as lints;
 pub use swc_ecma_parser as parser;
 pub use swc_ecma_preset_env as preset_env;
 pub use swc_ecma_transforms as transforms;
+pub use swc_ecma_utils as utils;
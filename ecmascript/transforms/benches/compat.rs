@@ -130,12 +130,12 @@ fn all(b: &mut Bencher) {
 
 #[bench]
 fn es2018(b: &mut Bencher) {
-    tr!(b, || compat::es2018());
+    tr!(b, || compat::es2018(Default::default()));
 }
 
 #[bench]
 fn es2018_object_rest_spread(b: &mut Bencher) {
-    tr!(b, || compat::es2018::object_rest_spread());
+    tr!(b, || compat::es2018::object_rest_spread(Default::default()));
 }
 
 #[bench]
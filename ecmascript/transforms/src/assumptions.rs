@@ -0,0 +1,48 @@
+use serde::{Deserialize, Serialize};
+
+/// Assumptions about the input code, like `assumptions` of babel.
+///
+/// Each assumption allows transforms to generate smaller or faster code, which
+/// is not spec-compliant if the assumption is wrong.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+pub struct Assumptions {
+    /// Iterables are arrays. Used by `spread`, `for_of` and `destructuring`.
+    #[serde(default)]
+    pub iterable_is_array: bool,
+
+    /// Class fields are assigned instead of being defined with
+    /// `Object.defineProperty`. Used by `class_properties`.
+    #[serde(default)]
+    pub set_public_class_fields: bool,
+
+    /// `document.all` is not used, so `== null` can be used to check for
+    /// `null` and `undefined`. Used by `nullish_coalescing` and
+    /// `optional_chaining`.
+    #[serde(default)]
+    pub no_document_all: bool,
+
+    /// The super class of a class is not changed after the class is
+    /// defined. Used by `classes`.
+    #[serde(default)]
+    pub constant_super: bool,
+
+    /// Properties of spread objects are assigned instead of being defined.
+    /// Used by `object_rest_spread`.
+    #[serde(default)]
+    pub set_spread_properties: bool,
+
+    /// Private fields are stored as non-enumerable properties with unique
+    /// keys instead of in `WeakMap`s. Used by `class_properties`.
+    #[serde(default)]
+    pub private_fields_as_properties: bool,
+
+    /// Objects are converted to property keys without the `"string"` hint of
+    /// `Symbol.toPrimitive`. Used by `object_rest_spread`.
+    #[serde(default)]
+    pub ignore_to_primitive_hint: bool,
+
+    /// Classes are never called without `new`. Used by `classes`.
+    #[serde(default)]
+    pub no_class_calls: bool,
+}
@@ -46,7 +46,7 @@ pub fn es2015(global_mark: Mark, c: Config) -> impl Pass {
     chain!(
         BlockScopedFns,
         TemplateLiteral::default(),
-        Classes::default(),
+        Classes::new(c.classes),
         spread(c.spread),
         function_name(),
         exprs(),
@@ -61,6 +61,9 @@ pub fn es2015(global_mark: Mark, c: Config) -> impl Pass {
 
 #[derive(Debug, Default, Deserialize)]
 pub struct Config {
+    #[serde(flatten)]
+    pub classes: classes::Config,
+
     #[serde(flatten)]
     pub for_of: for_of::Config,
 
@@ -8,12 +8,13 @@ use self::{
     prop_name::HashKey,
 };
 use crate::util::{
-    alias_if_required, default_constructor, prepend, prop_name_to_expr, ExprFactory, IsDirective,
-    ModuleItemLike, StmtLike,
+    alias_if_required, default_constructor, move_leading_comments, prepend, prop_name_to_expr,
+    ExprFactory, IsDirective, ModuleItemLike, StmtLike,
 };
 use fxhash::FxBuildHasher;
+use serde::Deserialize;
 use std::iter;
-use swc_common::{Fold, FoldWith, Mark, Spanned, Visit, VisitWith, DUMMY_SP};
+use swc_common::{Fold, FoldWith, Mark, Span, Spanned, Visit, VisitWith, DUMMY_SP};
 use swc_ecma_ast::*;
 
 #[macro_use]
@@ -58,12 +59,38 @@ type IndexMap<K, V> = indexmap::IndexMap<K, V, FxBuildHasher>;
 /// ```
 #[derive(Default, Clone, Copy)]
 pub struct Classes {
+    c: Config,
     in_strict: bool,
 }
 
+#[derive(Debug, Default, Clone, Copy, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Config {
+    /// Access methods of the super class through the prototype instead of
+    /// using `_get` and `_set`.
+    #[serde(default)]
+    pub constant_super: bool,
+
+    /// Don't inject `_classCallCheck`.
+    #[serde(default)]
+    pub no_class_calls: bool,
+}
+
+impl Classes {
+    pub fn new(c: Config) -> Self {
+        Classes {
+            c,
+            ..Default::default()
+        }
+    }
+}
+
 noop_fold_type!(Classes);
 
 struct Data {
+    /// Span of the first method with the key. Leading comments of the methods
+    /// are moved to the descriptor object.
+    span: Span,
     key_prop: Box<Prop>,
     method: Option<Box<Expr>>,
     set: Option<Box<Expr>>,
@@ -106,6 +133,7 @@ where
                                             .into()],
                                             src: None,
                                             type_only: false,
+                                            attrs: None,
                                         },
                                     )) {
                                         Ok(t) => t,
@@ -382,6 +410,9 @@ impl Classes {
                 ClassMember::PrivateProp(..) => unreachable!(
                     "classes pass: private property\nclass_properties pass should remove this"
                 ),
+                ClassMember::StaticBlock(..) => unreachable!(
+                    "classes pass: static block\nclass_properties pass should remove this"
+                ),
                 ClassMember::TsIndexSignature(..) => {
                     // We just strip this.
                 }
@@ -535,7 +566,9 @@ impl Classes {
             );
 
             // inject _classCallCheck(this, Bar);
-            inject_class_call_check(&mut body, class_name.clone());
+            if !self.c.no_class_calls {
+                inject_class_call_check(&mut body, class_name.clone());
+            }
 
             stmts.push(Stmt::Decl(Decl::Fn(FnDecl {
                 ident: class_name.clone(),
@@ -615,6 +648,7 @@ impl Classes {
             in_nested_scope: false,
             in_injected_define_property_call: false,
             this_alias_mark: None,
+            constant_super: self.c.constant_super,
         };
 
         let mut body = body.fold_with(&mut folder);
@@ -700,7 +734,7 @@ impl Classes {
                         add!(data.method, MethodKind::Method, "value");
 
                         ObjectLit {
-                            span: DUMMY_SP,
+                            span: data.span,
                             props,
                         }
                         .as_arg()
@@ -732,6 +766,7 @@ impl Classes {
         let (mut props, mut static_props) = (IndexMap::default(), IndexMap::default());
 
         for m in methods {
+            let span = m.span;
             let key = HashKey::from(&m.key);
             let key_prop = box mk_key_prop(&m.key);
             let computed = match m.key {
@@ -756,6 +791,7 @@ impl Classes {
                 in_nested_scope: false,
                 in_injected_define_property_call: false,
                 this_alias_mark: None,
+                constant_super: self.c.constant_super,
             };
             let mut function = m.function.fold_with(&mut folder);
 
@@ -804,11 +840,14 @@ impl Classes {
             });
 
             let data = append_to.entry(key).or_insert_with(|| Data {
+                span,
                 key_prop,
                 get: None,
                 set: None,
                 method: None,
             });
+            // A getter and a setter share a descriptor.
+            move_leading_comments(span, data.span);
             match m.kind {
                 MethodKind::Getter => data.get = Some(value),
                 MethodKind::Setter => data.set = Some(value),
@@ -116,7 +116,8 @@ impl Visit<Function> for SuperCallFinder {
 
 pub(super) fn constructor_fn(c: Constructor) -> Function {
     Function {
-        span: DUMMY_SP,
+        // Keeps leading comments of the constructor.
+        span: c.span,
         decorators: Default::default(),
         params: c
             .params
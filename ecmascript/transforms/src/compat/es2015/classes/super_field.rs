@@ -37,6 +37,9 @@ pub(crate) struct SuperFieldAccessFolder<'a> {
 
     /// `Some(mark)` if `var this2 = this`is required.
     pub this_alias_mark: Option<Mark>,
+
+    /// Access the prototype directly instead of using `_get` and `_set`.
+    pub constant_super: bool,
 }
 
 noop_fold_type!(SuperFieldAccessFolder<'_>);
@@ -56,6 +59,8 @@ struct SuperCalleeFolder<'a> {
 
     /// `Some(mark)` if `var this2 = this`is required.
     this_alias_mark: Option<Mark>,
+
+    constant_super: bool,
 }
 
 noop_fold_type!(SuperCalleeFolder<'_>);
@@ -193,14 +198,24 @@ impl<'a> SuperCalleeFolder<'a> {
     fn super_to_get_call(&mut self, super_token: Span, prop: Box<Expr>, computed: bool) -> Expr {
         self.inject_get = true;
 
-        let proto_arg = get_prototype_of(if self.is_static {
+        let proto = get_prototype_of(if self.is_static {
             // Foo
             Expr::Ident(self.class_name.clone())
         } else {
             // Foo.prototype
             self.class_name.clone().member(quote_ident!("prototype"))
-        })
-        .as_arg();
+        });
+
+        if self.constant_super {
+            // _getPrototypeOf(Foo.prototype).foo
+            return Expr::Member(MemberExpr {
+                span: super_token,
+                obj: proto.as_obj(),
+                prop,
+                computed,
+            });
+        }
+        let proto_arg = proto.as_arg();
 
         let prop_arg = match *prop {
             Expr::Ident(Ident {
@@ -274,9 +289,6 @@ impl<'a> SuperCalleeFolder<'a> {
             }
         }
 
-        let proto_arg =
-            get_prototype_of(self.class_name.clone().member(quote_ident!("prototype"))).as_arg();
-
         let prop_arg = match *prop {
             Expr::Ident(Ident {
                 sym: ref value,
@@ -290,18 +302,33 @@ impl<'a> SuperCalleeFolder<'a> {
             ref e => e.clone(),
         };
         let prop_arg = match op {
-            op!("=") => prop_arg.as_arg(),
-            _ => AssignExpr {
+            op!("=") => prop_arg,
+            _ => Expr::Assign(AssignExpr {
                 span: DUMMY_SP,
                 left: PatOrExpr::Pat(box Pat::Ident(ref_ident.clone())),
                 op: op!("="),
-                right: prop,
-            }
-            .as_arg(),
+                right: box prop_arg,
+            }),
+        };
+
+        // `super.foo ||= bar` => `_get(..., _ref = "foo", this) || _set(..., _ref,
+        // bar, this, true)`, so the setter runs only if the value changes.
+        let logical_op = match op {
+            op!("&&=") => Some(op!("&&")),
+            op!("||=") => Some(op!("||")),
+            op!("??=") => Some(op!("??")),
+            _ => None,
+        };
+        let (get, prop_arg) = match logical_op {
+            Some(..) => (
+                Some(self.super_to_get_call(super_token, box prop_arg, true)),
+                Expr::Ident(ref_ident.clone()),
+            ),
+            None => (None, prop_arg),
         };
 
         let rhs_arg = match op {
-            op!("=") => rhs.as_arg(),
+            op!("=") | op!("&&=") | op!("||=") | op!("??=") => rhs,
             _ => {
                 let left =
                     box self.super_to_get_call(super_token, box Expr::Ident(ref_ident), true);
@@ -321,11 +348,11 @@ impl<'a> SuperCalleeFolder<'a> {
                     left
                 };
 
-                BinExpr {
+                box Expr::Bin(BinExpr {
                     span: DUMMY_SP,
                     left,
                     op: match op {
-                        op!("=") => unreachable!(),
+                        op!("=") | op!("&&=") | op!("||=") | op!("??=") => unreachable!(),
 
                         op!("+=") => op!(bin, "+"),
                         op!("-=") => op!(bin, "-"),
@@ -341,30 +368,55 @@ impl<'a> SuperCalleeFolder<'a> {
                         op!("**=") => op!("**"),
                     },
                     right: rhs,
-                }
-                .as_arg()
+                })
             }
         };
 
-        let this_arg = ThisExpr { span: super_token }.as_arg();
-
-        let expr = Expr::Call(CallExpr {
-            span: super_token,
-            callee: helper!(set, "set"),
-            args: vec![
-                proto_arg,
-                prop_arg,
-                rhs_arg,
-                this_arg,
-                // strict
-                Lit::Bool(Bool {
+        let expr = if self.constant_super {
+            // this[key] = value
+            Expr::Assign(AssignExpr {
+                span: super_token,
+                left: PatOrExpr::Expr(box Expr::Member(MemberExpr {
                     span: DUMMY_SP,
-                    value: true,
-                })
-                .as_arg(),
-            ],
-            type_args: Default::default(),
-        });
+                    obj: ThisExpr { span: super_token }.as_obj(),
+                    prop: box prop_arg,
+                    computed: true,
+                })),
+                op: op!("="),
+                right: rhs_arg,
+            })
+        } else {
+            let proto_arg =
+                get_prototype_of(self.class_name.clone().member(quote_ident!("prototype")))
+                    .as_arg();
+
+            Expr::Call(CallExpr {
+                span: super_token,
+                callee: helper!(set, "set"),
+                args: vec![
+                    proto_arg,
+                    prop_arg.as_arg(),
+                    rhs_arg.as_arg(),
+                    ThisExpr { span: super_token }.as_arg(),
+                    // strict
+                    Lit::Bool(Bool {
+                        span: DUMMY_SP,
+                        value: true,
+                    })
+                    .as_arg(),
+                ],
+                type_args: Default::default(),
+            })
+        };
+
+        if let (Some(op), Some(get)) = (logical_op, get) {
+            return Expr::Bin(BinExpr {
+                span: super_token,
+                left: box get,
+                op,
+                right: box expr,
+            });
+        }
 
         if is_update {
             Expr::Seq(SeqExpr {
@@ -410,6 +462,7 @@ impl<'a> Fold<Expr> for SuperFieldAccessFolder<'a> {
             is_static: self.is_static,
             in_nested_scope: self.in_nested_scope,
             this_alias_mark: self.this_alias_mark,
+            constant_super: self.constant_super,
         };
 
         let should_invoke_call = match n {
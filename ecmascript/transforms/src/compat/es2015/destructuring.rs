@@ -1057,6 +1057,7 @@ fn can_be_null(e: &Expr) -> bool {
         | Expr::TsTypeCast(TsTypeCastExpr { ref expr, .. })
         | Expr::TsConstAssertion(TsConstAssertion { ref expr, .. }) => can_be_null(expr),
         Expr::OptChain(ref e) => can_be_null(&e.expr),
+        Expr::Bind(..) => false,
 
         Expr::Invalid(..) => unreachable!(),
     }
@@ -296,6 +296,7 @@ impl CaseHandler<'_> {
             | Expr::TsTypeCast(..)
             | Expr::TsAs(..)
             | Expr::PrivateName(..)
+            | Expr::Bind(..)
             | Expr::Invalid(..) => return e,
 
             Expr::OptChain(e) => {
@@ -2,11 +2,21 @@ pub use self::{
     object_rest_spread::object_rest_spread, optional_catch_binding::optional_catch_binding,
 };
 use crate::pass::Pass;
+use serde::Deserialize;
 use swc_common::chain;
 
-mod object_rest_spread;
+pub mod object_rest_spread;
 mod optional_catch_binding;
 
-pub fn es2018() -> impl Pass {
-    chain!(object_rest_spread(), optional_catch_binding())
+pub fn es2018(c: Config) -> impl Pass {
+    chain!(
+        object_rest_spread(c.object_rest_spread),
+        optional_catch_binding()
+    )
+}
+
+#[derive(Debug, Default, Clone, Copy, Deserialize)]
+pub struct Config {
+    #[serde(flatten)]
+    pub object_rest_spread: object_rest_spread::Config,
 }
@@ -4,6 +4,7 @@ use crate::{
         alias_ident_for, alias_if_required, is_literal, var::VarCollector, ExprFactory, StmtLike,
     },
 };
+use serde::Deserialize;
 use std::{iter, mem};
 use swc_common::{
     chain, util::move_map::MoveMap, Fold, FoldWith, Mark, Spanned, Visit, VisitWith, DUMMY_SP,
@@ -11,16 +12,33 @@ use swc_common::{
 use swc_ecma_ast::*;
 
 /// `@babel/plugin-proposal-object-rest-spread`
-pub fn object_rest_spread() -> impl Pass {
-    chain!(ObjectRest, ObjectSpread)
+pub fn object_rest_spread(c: Config) -> impl Pass {
+    chain!(ObjectRest { c }, ObjectSpread { c })
 }
 
-struct ObjectRest;
+#[derive(Debug, Default, Clone, Copy, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Config {
+    /// Use `_extends` instead of `_objectSpread`, which assigns properties
+    /// instead of defining them.
+    #[serde(default)]
+    pub set_spread_properties: bool,
+
+    /// Convert computed keys excluded by a rest pattern to property keys
+    /// without calling `Symbol.toPrimitive` with the `"string"` hint.
+    #[serde(default)]
+    pub ignore_to_primitive_hint: bool,
+}
+
+struct ObjectRest {
+    c: Config,
+}
 
 noop_fold_type!(ObjectRest);
 
 #[allow(clippy::vec_box)]
 struct RestFolder {
+    c: Config,
     /// Injected before the original statement.
     vars: Vec<VarDeclarator>,
     /// Variables which should ceclaraed using `var`
@@ -366,6 +384,7 @@ impl Fold<ModuleDecl> for RestFolder {
                     specifiers,
                     src: None,
                     type_only: false,
+                    attrs: None,
                 };
 
                 let mut var_decl = var_decl.fold_with(self);
@@ -414,6 +433,7 @@ where
 
         for stmt in stmts {
             let mut folder = RestFolder {
+                c: self.c,
                 vars: vec![],
                 mutable_vars: vec![],
                 exprs: vec![],
@@ -883,14 +903,14 @@ impl RestFolder {
                 span: DUMMY_SP,
                 left: PatOrExpr::Pat(last.arg),
                 op: op!("="),
-                right: box object_without_properties(obj, excluded_props),
+                right: box object_without_properties(self.c, obj, excluded_props),
             }));
         } else {
             // println!("Var: rest = objectWithoutProperties()",);
             self.push_var_if_not_empty(VarDeclarator {
                 span: DUMMY_SP,
                 name: *last.arg,
-                init: Some(box object_without_properties(obj, excluded_props)),
+                init: Some(box object_without_properties(self.c, obj, excluded_props)),
                 definite: false,
             });
         }
@@ -904,7 +924,11 @@ impl RestFolder {
     }
 }
 
-fn object_without_properties(obj: Box<Expr>, excluded_props: Vec<Option<ExprOrSpread>>) -> Expr {
+fn object_without_properties(
+    c: Config,
+    obj: Box<Expr>,
+    excluded_props: Vec<Option<ExprOrSpread>>,
+) -> Expr {
     if excluded_props.is_empty() {
         return Expr::Call(CallExpr {
             span: DUMMY_SP,
@@ -958,7 +982,11 @@ fn object_without_properties(obj: Box<Expr>, excluded_props: Vec<Option<ExprOrSp
                     }
                     .member(Ident::new("map".into(), DUMMY_SP))
                     .as_callee(),
-                    args: vec![helper_expr!(to_property_key, "toPropertyKey").as_arg()],
+                    args: vec![if c.ignore_to_primitive_hint {
+                        to_property_key_fn().as_arg()
+                    } else {
+                        helper_expr!(to_property_key, "toPropertyKey").as_arg()
+                    }],
                     type_args: Default::default(),
                 }
                 .as_arg()
@@ -968,6 +996,62 @@ fn object_without_properties(obj: Box<Expr>, excluded_props: Vec<Option<ExprOrSp
     })
 }
 
+/// `function (key) { return typeof key === "symbol" ? key : key + ""; }`
+fn to_property_key_fn() -> Expr {
+    let key = private_ident!("key");
+
+    Expr::Fn(FnExpr {
+        ident: None,
+        function: Function {
+            span: DUMMY_SP,
+            params: vec![Param {
+                span: DUMMY_SP,
+                decorators: Default::default(),
+                pat: Pat::Ident(key.clone()),
+            }],
+            decorators: Default::default(),
+            body: Some(BlockStmt {
+                span: DUMMY_SP,
+                stmts: vec![Stmt::Return(ReturnStmt {
+                    span: DUMMY_SP,
+                    arg: Some(box Expr::Cond(CondExpr {
+                        span: DUMMY_SP,
+                        test: box Expr::Bin(BinExpr {
+                            span: DUMMY_SP,
+                            left: box Expr::Unary(UnaryExpr {
+                                span: DUMMY_SP,
+                                op: op!("typeof"),
+                                arg: box Expr::Ident(key.clone()),
+                            }),
+                            op: op!("==="),
+                            right: box Expr::Lit(Lit::Str(Str {
+                                span: DUMMY_SP,
+                                value: js_word!("symbol"),
+                                has_escape: false,
+                            })),
+                        }),
+                        cons: box Expr::Ident(key.clone()),
+                        alt: box Expr::Bin(BinExpr {
+                            span: DUMMY_SP,
+                            left: box Expr::Ident(key),
+                            op: op!(bin, "+"),
+                            right: box Expr::Lit(Lit::Str(Str {
+                                span: DUMMY_SP,
+                                value: js_word!(""),
+                                has_escape: false,
+                            })),
+                        }),
+                    })),
+                })],
+            }),
+            is_generator: false,
+            is_async: false,
+            type_params: Default::default(),
+            return_type: Default::default(),
+        },
+    })
+}
+
 fn excluded_props(props: &[ObjectPatProp]) -> Vec<Option<ExprOrSpread>> {
     props
         .iter()
@@ -1030,7 +1114,9 @@ fn simplify_pat(pat: Pat) -> Pat {
     pat.fold_with(&mut PatSimplifier)
 }
 
-struct ObjectSpread;
+struct ObjectSpread {
+    c: Config,
+}
 
 noop_fold_type!(ObjectSpread);
 
@@ -1093,7 +1179,11 @@ impl Fold<Expr> for ObjectSpread {
 
                 Expr::Call(CallExpr {
                     span,
-                    callee: helper!(object_spread, "objectSpread"),
+                    callee: if self.c.set_spread_properties {
+                        helper!(extends, "extends")
+                    } else {
+                        helper!(object_spread, "objectSpread")
+                    },
                     args,
                     type_args: Default::default(),
                 })
@@ -0,0 +1,8 @@
+pub use self::logical_assignments::logical_assignments;
+use crate::pass::Pass;
+
+mod logical_assignments;
+
+pub fn es2021() -> impl Pass {
+    logical_assignments()
+}
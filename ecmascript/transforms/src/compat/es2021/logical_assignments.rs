@@ -0,0 +1,269 @@
+use crate::{
+    pass::Pass,
+    util::{alias_ident_for, undefined, StmtLike},
+};
+use std::mem::replace;
+use swc_common::{Fold, FoldWith, Span, Spanned, DUMMY_SP};
+use swc_ecma_ast::*;
+
+#[cfg(test)]
+mod tests;
+
+/// `@babel/plugin-proposal-logical-assignment-operators`
+///
+/// # Example
+///
+/// ## In
+///
+/// ```js
+/// a ||= b;
+/// obj.foo.bar &&= c;
+/// obj[key()] ??= d;
+/// ```
+///
+/// ## Out
+///
+/// ```js
+/// a || (a = b);
+/// var _foo;
+/// (_foo = obj.foo).bar && (_foo.bar = c);
+/// var _key, _ref;
+/// (_ref = obj[_key = key()]) !== null && _ref !== void 0 ? _ref : obj[_key] = d;
+/// ```
+pub fn logical_assignments() -> impl Pass {
+    LogicalAssignments::default()
+}
+
+#[derive(Debug, Default)]
+struct LogicalAssignments {
+    vars: Vec<VarDeclarator>,
+}
+
+noop_fold_type!(LogicalAssignments);
+
+impl<T> Fold<Vec<T>> for LogicalAssignments
+where
+    T: FoldWith<Self> + StmtLike,
+{
+    fn fold(&mut self, stmts: Vec<T>) -> Vec<T> {
+        let old = replace(&mut self.vars, Default::default());
+        let mut buf = Vec::with_capacity(stmts.len() + 2);
+
+        for stmt in stmts {
+            let stmt = stmt.fold_with(self);
+
+            if !self.vars.is_empty() {
+                buf.push(T::from_stmt(Stmt::Decl(Decl::Var(VarDecl {
+                    span: DUMMY_SP,
+                    kind: VarDeclKind::Var,
+                    decls: replace(&mut self.vars, Default::default()),
+                    declare: false,
+                }))));
+            }
+
+            buf.push(stmt);
+        }
+
+        self.vars = old;
+        buf
+    }
+}
+
+/// Temporaries used in the expression body of an arrow function are declared
+/// in the body, which is converted to a block statement.
+impl Fold<BlockStmtOrExpr> for LogicalAssignments {
+    fn fold(&mut self, body: BlockStmtOrExpr) -> BlockStmtOrExpr {
+        match body {
+            BlockStmtOrExpr::Expr(expr) => {
+                let old = replace(&mut self.vars, Default::default());
+                let expr = expr.fold_with(self);
+                let vars = replace(&mut self.vars, old);
+
+                if vars.is_empty() {
+                    return BlockStmtOrExpr::Expr(expr);
+                }
+
+                BlockStmtOrExpr::BlockStmt(BlockStmt {
+                    span: expr.span(),
+                    stmts: vec![
+                        Stmt::Decl(Decl::Var(VarDecl {
+                            span: DUMMY_SP,
+                            kind: VarDeclKind::Var,
+                            decls: vars,
+                            declare: false,
+                        })),
+                        Stmt::Return(ReturnStmt {
+                            span: DUMMY_SP,
+                            arg: Some(expr),
+                        }),
+                    ],
+                })
+            }
+            _ => body.fold_children(self),
+        }
+    }
+}
+
+impl LogicalAssignments {
+    /// Returns `(get, set)`, where `get` reads the assignment target for the
+    /// first time and `set` is the target of the actual assignment.
+    ///
+    /// Objects of member expressions and computed keys are stored in a
+    /// temporary variable by `get`, so they are evaluated only once.
+    fn split_target(&mut self, left: PatOrExpr) -> Result<(Box<Expr>, PatOrExpr), PatOrExpr> {
+        match left {
+            PatOrExpr::Pat(box Pat::Ident(i)) | PatOrExpr::Expr(box Expr::Ident(i)) => Ok((
+                box Expr::Ident(i.clone()),
+                PatOrExpr::Pat(box Pat::Ident(i)),
+            )),
+
+            PatOrExpr::Expr(box Expr::Member(MemberExpr {
+                span,
+                obj,
+                prop,
+                computed,
+            })) => {
+                let (get_obj, set_obj) = match obj {
+                    ExprOrSuper::Expr(obj) => {
+                        let (get, set) = self.memorize(obj, "_ref");
+                        (ExprOrSuper::Expr(get), ExprOrSuper::Expr(set))
+                    }
+                    ExprOrSuper::Super(..) => (obj.clone(), obj),
+                };
+                let (get_prop, set_prop) = if computed {
+                    self.memorize(prop, "_key")
+                } else {
+                    (prop.clone(), prop)
+                };
+
+                Ok((
+                    box Expr::Member(MemberExpr {
+                        span,
+                        obj: get_obj,
+                        prop: get_prop,
+                        computed,
+                    }),
+                    PatOrExpr::Expr(box Expr::Member(MemberExpr {
+                        span,
+                        obj: set_obj,
+                        prop: set_prop,
+                        computed,
+                    })),
+                ))
+            }
+
+            _ => Err(left),
+        }
+    }
+
+    /// Returns `(_ref = e, _ref)` if `e` may have side effects or may change
+    /// while evaluating the right hand side.
+    fn memorize(&mut self, e: Box<Expr>, default: &str) -> (Box<Expr>, Box<Expr>) {
+        match *e {
+            Expr::Ident(..) | Expr::This(..) | Expr::Lit(..) => return (e.clone(), e),
+            _ => {}
+        }
+
+        let alias = alias_ident_for(&e, default);
+        self.vars.push(VarDeclarator {
+            span: DUMMY_SP,
+            name: Pat::Ident(alias.clone()),
+            init: None,
+            definite: false,
+        });
+
+        (
+            box Expr::Assign(AssignExpr {
+                span: DUMMY_SP,
+                op: op!("="),
+                left: PatOrExpr::Pat(box Pat::Ident(alias.clone())),
+                right: e,
+            }),
+            box Expr::Ident(alias),
+        )
+    }
+
+    /// `a ??= b` => `a !== null && a !== void 0 ? a : a = b`
+    fn nullish(&mut self, span: Span, get: Box<Expr>, set: Box<Expr>) -> Expr {
+        let (test, value) = match *get {
+            Expr::Ident(ref i) => (get.clone(), Expr::Ident(i.clone())),
+            _ => {
+                let (test, value) = self.memorize(get, "_ref");
+                (test, *value)
+            }
+        };
+
+        Expr::Cond(CondExpr {
+            span,
+            test: box Expr::Bin(BinExpr {
+                span: DUMMY_SP,
+                left: box Expr::Bin(BinExpr {
+                    span: DUMMY_SP,
+                    left: test,
+                    op: op!("!=="),
+                    right: box Expr::Lit(Lit::Null(Null { span: DUMMY_SP })),
+                }),
+                op: op!("&&"),
+                right: box Expr::Bin(BinExpr {
+                    span: DUMMY_SP,
+                    left: box value.clone(),
+                    op: op!("!=="),
+                    right: undefined(DUMMY_SP),
+                }),
+            }),
+            cons: box value,
+            alt: set,
+        })
+    }
+}
+
+impl Fold<Expr> for LogicalAssignments {
+    fn fold(&mut self, e: Expr) -> Expr {
+        let e = e.fold_children(self);
+
+        match e {
+            Expr::Assign(AssignExpr {
+                span,
+                op,
+                left,
+                right,
+            }) if op == op!("&&=") || op == op!("||=") || op == op!("??=") => {
+                let (get, left) = match self.split_target(left) {
+                    Ok(v) => v,
+                    Err(left) => {
+                        return Expr::Assign(AssignExpr {
+                            span,
+                            op,
+                            left,
+                            right,
+                        })
+                    }
+                };
+
+                let set = box Expr::Assign(AssignExpr {
+                    span: DUMMY_SP,
+                    op: op!("="),
+                    left,
+                    right,
+                });
+
+                if op == op!("??=") {
+                    return self.nullish(span, get, set);
+                }
+
+                Expr::Bin(BinExpr {
+                    span,
+                    left: get,
+                    op: if op == op!("&&=") {
+                        op!("&&")
+                    } else {
+                        op!("||")
+                    },
+                    right: set,
+                })
+            }
+
+            _ => e,
+        }
+    }
+}
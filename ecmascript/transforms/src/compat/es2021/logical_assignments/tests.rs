@@ -0,0 +1,106 @@
+use super::*;
+use swc_ecma_parser::Syntax;
+
+fn tr() -> impl Pass {
+    logical_assignments()
+}
+
+test!(
+    Syntax::default(),
+    |_| tr(),
+    ident,
+    "a ||= b; a &&= b; a ??= b;",
+    "a || (a = b);
+a && (a = b);
+a !== null && a !== void 0 ? a : a = b;"
+);
+
+test!(
+    Syntax::default(),
+    |_| tr(),
+    member,
+    "obj.a ||= 1; this.b &&= 2;",
+    "obj.a || (obj.a = 1);
+this.b && (this.b = 2);"
+);
+
+test!(
+    Syntax::default(),
+    |_| tr(),
+    member_object_once,
+    "foo().bar.baz ||= 1;",
+    "var _bar;
+(_bar = foo().bar).baz || (_bar.baz = 1);"
+);
+
+test!(
+    Syntax::default(),
+    |_| tr(),
+    computed_key_once,
+    "obj[key()] &&= 1;",
+    "var _key;
+obj[_key = key()] && (obj[_key] = 1);"
+);
+
+test!(
+    Syntax::default(),
+    |_| tr(),
+    nullish_member,
+    "obj.a ??= 1;",
+    "var _a;
+(_a = obj.a) !== null && _a !== void 0 ? _a : obj.a = 1;"
+);
+
+test!(
+    Syntax::default(),
+    |_| tr(),
+    arrow_expr_body,
+    "const f = () => obj.a.b ||= 1;",
+    "const f = () => {
+    var _a;
+    return (_a = obj.a).b || (_a.b = 1);
+};"
+);
+
+test!(
+    Syntax::default(),
+    |_| tr(),
+    nested_fn,
+    "[foo().a ||= 1, function () {
+    obj.c.d ||= 1;
+}];",
+    "var _ref;
+[(_ref = foo()).a || (_ref.a = 1), function () {
+    var _c;
+    (_c = obj.c).d || (_c.d = 1);
+}];"
+);
+
+test_exec!(
+    Syntax::default(),
+    |_| tr(),
+    side_effects_exec,
+    r#"
+let calls = 0;
+const obj = { a: 0, b: 1, c: null };
+function get() {
+    calls++;
+    return obj;
+}
+
+get().a ||= 2;
+expect(obj.a).toBe(2);
+get()["b"] &&= 3;
+expect(obj.b).toBe(3);
+get().c ??= 4;
+expect(obj.c).toBe(4);
+get().c ??= 5;
+expect(obj.c).toBe(4);
+expect(calls).toBe(4);
+
+let x = 0;
+expect(x ||= 1).toBe(1);
+expect(x &&= 0).toBe(0);
+expect(x ??= 2).toBe(0);
+"#
+);
@@ -0,0 +1,784 @@
+//! Early errors which require a binding model.
+//!
+//! The parser reports errors which can be detected while parsing a single
+//! node. Errors like `let a; let a;` depend on the scope a name is declared
+//! in, so they are reported by this pass instead.
+use crate::{
+    pass::Pass,
+    util::{IsDirective, HANDLER},
+};
+use fxhash::FxHashMap;
+use std::mem::replace;
+use swc_atoms::{js_word, JsWord};
+use swc_common::{Fold, Span, Spanned, Visit, VisitWith};
+use swc_ecma_ast::*;
+
+#[cfg(test)]
+mod tests;
+
+/// Reports [early errors] which require scope analysis.
+///
+/// Covered errors are
+///
+///  - redeclaration of lexically declared names (`let a; var a;`)
+///  - duplicate parameters in strict mode, arrow functions, methods and
+///    functions with non-simple parameter lists
+///  - `let` as a lexically bound name
+///  - `break` and `continue` with illegal targets
+///  - duplicate `__proto__` properties in object literals
+///  - duplicate export names
+///
+/// This pass does not modify the ast, and it should be run before any other
+/// pass.
+///
+/// [early errors]:https://tc39.es/ecma262/#early-error
+pub fn early_errors() -> impl Pass {
+    EarlyErrors
+}
+
+struct EarlyErrors;
+
+noop_fold_type!(EarlyErrors);
+
+impl Fold<Module> for EarlyErrors {
+    fn fold(&mut self, module: Module) -> Module {
+        let mut v = Validator::new(true, true);
+        for item in &module.body {
+            item.visit_with(&mut v);
+        }
+
+        module
+    }
+}
+
+impl Fold<Script> for EarlyErrors {
+    fn fold(&mut self, script: Script) -> Script {
+        let strict = use_strict(&script.body).is_some();
+        let mut v = Validator::new(false, strict);
+        for stmt in &script.body {
+            stmt.visit_with(&mut v);
+        }
+
+        script
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ScopeKind {
+    Block,
+    Fn,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ParamKind {
+    Param,
+    /// `catch (e)`
+    SimpleCatchParam,
+    /// `catch ({ e })`
+    CatchParam,
+}
+
+#[derive(Debug)]
+struct Scope {
+    kind: ScopeKind,
+    /// Lexically declared names and whether the declaration is a function
+    /// declaration.
+    lexical: FxHashMap<JsWord, (Span, bool)>,
+    /// Names declared by `var` in this scope or in a descendant block scope.
+    vars: FxHashMap<JsWord, Span>,
+    params: FxHashMap<JsWord, (Span, ParamKind)>,
+}
+
+impl Scope {
+    fn new(kind: ScopeKind) -> Self {
+        Scope {
+            kind,
+            lexical: Default::default(),
+            vars: Default::default(),
+            params: Default::default(),
+        }
+    }
+}
+
+struct Validator {
+    is_module: bool,
+    strict: bool,
+    scopes: Vec<Scope>,
+
+    /// Labels in the current function and whether each label is a label of
+    /// an iteration statement.
+    labels: Vec<(JsWord, bool)>,
+    loop_depth: usize,
+    switch_depth: usize,
+    /// Set just before visiting the function of a method.
+    in_method: bool,
+
+    exports: FxHashMap<JsWord, Span>,
+}
+
+noop_visit_type!(Validator);
+
+impl Validator {
+    fn new(is_module: bool, strict: bool) -> Self {
+        Validator {
+            is_module,
+            strict,
+            scopes: vec![Scope::new(ScopeKind::Fn)],
+            labels: vec![],
+            loop_depth: 0,
+            switch_depth: 0,
+            in_method: false,
+            exports: Default::default(),
+        }
+    }
+
+    fn emit(&self, span: Span, msg: &str) {
+        HANDLER.with(|handler| handler.struct_span_err(span, msg).emit());
+    }
+
+    fn emit_redeclaration(&self, i: &Ident, prev: Span) {
+        HANDLER.with(|handler| {
+            let mut diag = handler.struct_span_err(
+                i.span,
+                &format!("Identifier `{}` has already been declared", i.sym),
+            );
+            diag.span_label(prev, "previous declaration");
+            diag.emit();
+        });
+    }
+
+    fn scope(&mut self) -> &mut Scope {
+        self.scopes.last_mut().unwrap()
+    }
+
+    fn with_scope<F>(&mut self, kind: ScopeKind, op: F)
+    where
+        F: FnOnce(&mut Self),
+    {
+        self.scopes.push(Scope::new(kind));
+        op(self);
+        self.scopes.pop();
+    }
+
+    /// Declares a name bound by `let`, `const`, `class`, `import` or by a
+    /// function declaration in a block.
+    fn declare_lexical(&mut self, i: &Ident, is_fn: bool) {
+        let strict = self.strict;
+        let scope = self.scopes.last().unwrap();
+
+        if let Some(&(prev, prev_is_fn)) = scope.lexical.get(&i.sym) {
+            // Annex B.3.3.4
+            if is_fn && prev_is_fn && !strict && scope.kind == ScopeKind::Block {
+                return;
+            }
+            self.emit_redeclaration(i, prev);
+            return;
+        }
+        if let Some(&prev) = scope.vars.get(&i.sym) {
+            self.emit_redeclaration(i, prev);
+            return;
+        }
+        if let Some(&(prev, _)) = scope.params.get(&i.sym) {
+            self.emit_redeclaration(i, prev);
+            return;
+        }
+
+        self.scope().lexical.insert(i.sym.clone(), (i.span, is_fn));
+    }
+
+    /// Declares a name bound by `var` or by a function declaration at the top
+    /// level of a function or a script.
+    fn declare_var(&mut self, i: &Ident) {
+        for scope in self.scopes.iter().rev() {
+            if let Some(&(prev, _)) = scope.lexical.get(&i.sym) {
+                self.emit_redeclaration(i, prev);
+                return;
+            }
+            match scope.params.get(&i.sym) {
+                // B.3.5 VariableStatements in Catch Blocks
+                Some(&(prev, ParamKind::CatchParam)) => {
+                    self.emit_redeclaration(i, prev);
+                    return;
+                }
+                _ => {}
+            }
+
+            if scope.kind == ScopeKind::Fn {
+                break;
+            }
+        }
+
+        for scope in self.scopes.iter_mut().rev() {
+            scope.vars.entry(i.sym.clone()).or_insert(i.span);
+
+            if scope.kind == ScopeKind::Fn {
+                break;
+            }
+        }
+    }
+
+    fn declare_lexical_pat(&mut self, pat: &Pat) {
+        let mut names = vec![];
+        bound_names(pat, &mut names);
+        for i in &names {
+            if i.sym == js_word!("let") {
+                self.emit(i.span, "`let` cannot be used as a lexically bound name");
+            }
+            self.declare_lexical(i, false);
+        }
+    }
+
+    fn declare_params(&mut self, params: &[&Pat], kind: ParamKind, unique: bool) {
+        let mut names = vec![];
+        for pat in params {
+            bound_names(pat, &mut names);
+        }
+
+        for i in &names {
+            if let Some(&(prev, _)) = self.scope().params.get(&i.sym) {
+                if unique {
+                    HANDLER.with(|handler| {
+                        let mut diag = handler.struct_span_err(
+                            i.span,
+                            &format!("Duplicate parameter name `{}`", i.sym),
+                        );
+                        diag.span_label(prev, "previous declaration");
+                        diag.emit();
+                    });
+                }
+                continue;
+            }
+
+            self.scope().params.insert(i.sym.clone(), (i.span, kind));
+        }
+    }
+
+    fn add_export(&mut self, i: &Ident) {
+        if let Some(&prev) = self.exports.get(&i.sym) {
+            HANDLER.with(|handler| {
+                let mut diag =
+                    handler.struct_span_err(i.span, &format!("Duplicate export of `{}`", i.sym));
+                diag.span_label(prev, "previous export");
+                diag.emit();
+            });
+            return;
+        }
+
+        self.exports.insert(i.sym.clone(), i.span);
+    }
+
+    /// `params` are visited in the scope of the function, and `unique` is true
+    /// if parameter names should be unique even in sloppy mode.
+    fn visit_fn(
+        &mut self,
+        params: &[&Pat],
+        body: Option<&BlockStmt>,
+        expr_body: Option<&Expr>,
+        unique: bool,
+    ) {
+        let stmts = body.map(|body| &*body.stmts).unwrap_or(&[]);
+        let is_simple = params.iter().all(|pat| match pat {
+            Pat::Ident(..) => true,
+            _ => false,
+        });
+        let use_strict = use_strict(stmts);
+        if let Some(span) = use_strict {
+            if !is_simple {
+                self.emit(
+                    span,
+                    "Illegal 'use strict' directive in function with non-simple parameter list",
+                );
+            }
+        }
+
+        let strict = self.strict || use_strict.is_some();
+        let strict = replace(&mut self.strict, strict);
+        let labels = replace(&mut self.labels, vec![]);
+        let loop_depth = replace(&mut self.loop_depth, 0);
+        let switch_depth = replace(&mut self.switch_depth, 0);
+
+        self.with_scope(ScopeKind::Fn, |v| {
+            let unique = unique || v.strict || !is_simple;
+            v.declare_params(params, ParamKind::Param, unique);
+
+            for pat in params {
+                pat.visit_with(v);
+            }
+            for stmt in stmts {
+                stmt.visit_with(v);
+            }
+            expr_body.visit_with(v);
+        });
+
+        self.strict = strict;
+        self.labels = labels;
+        self.loop_depth = loop_depth;
+        self.switch_depth = switch_depth;
+    }
+
+    fn visit_loop_body(&mut self, body: &Stmt) {
+        self.loop_depth += 1;
+        body.visit_with(self);
+        self.loop_depth -= 1;
+    }
+}
+
+impl Visit<FnDecl> for Validator {
+    fn visit(&mut self, f: &FnDecl) {
+        // Overloads and ambient declarations do not bind anything.
+        if f.declare || f.function.body.is_none() {
+            return;
+        }
+
+        let is_top_level = self.scopes.last().unwrap().kind == ScopeKind::Fn;
+        if is_top_level && !self.is_module {
+            self.declare_var(&f.ident);
+        } else {
+            self.declare_lexical(&f.ident, true);
+        }
+
+        f.function.visit_with(self);
+    }
+}
+
+impl Visit<Function> for Validator {
+    fn visit(&mut self, f: &Function) {
+        let is_method = replace(&mut self.in_method, false);
+
+        f.decorators.visit_with(self);
+        let params = f.params.iter().map(|p| &p.pat).collect::<Vec<_>>();
+        self.visit_fn(&params, f.body.as_ref(), None, is_method);
+    }
+}
+
+impl Visit<ArrowExpr> for Validator {
+    fn visit(&mut self, f: &ArrowExpr) {
+        let params = f.params.iter().collect::<Vec<_>>();
+        match f.body {
+            BlockStmtOrExpr::BlockStmt(ref body) => self.visit_fn(&params, Some(body), None, true),
+            BlockStmtOrExpr::Expr(ref body) => self.visit_fn(&params, None, Some(body), true),
+        }
+    }
+}
+
+impl Visit<Class> for Validator {
+    fn visit(&mut self, c: &Class) {
+        // All parts of a class are strict mode code.
+        let strict = replace(&mut self.strict, true);
+        c.visit_children(self);
+        self.strict = strict;
+    }
+}
+
+impl Visit<ClassDecl> for Validator {
+    fn visit(&mut self, c: &ClassDecl) {
+        if c.declare {
+            return;
+        }
+
+        if c.ident.sym == js_word!("let") {
+            self.emit(
+                c.ident.span,
+                "`let` cannot be used as a lexically bound name",
+            );
+        }
+        self.declare_lexical(&c.ident, false);
+
+        c.class.visit_with(self);
+    }
+}
+
+impl Visit<ClassMethod> for Validator {
+    fn visit(&mut self, m: &ClassMethod) {
+        m.key.visit_with(self);
+        self.in_method = true;
+        m.function.visit_with(self);
+    }
+}
+
+impl Visit<PrivateMethod> for Validator {
+    fn visit(&mut self, m: &PrivateMethod) {
+        self.in_method = true;
+        m.function.visit_with(self);
+    }
+}
+
+impl Visit<MethodProp> for Validator {
+    fn visit(&mut self, m: &MethodProp) {
+        m.key.visit_with(self);
+        self.in_method = true;
+        m.function.visit_with(self);
+    }
+}
+
+impl Visit<Constructor> for Validator {
+    fn visit(&mut self, c: &Constructor) {
+        let params = c
+            .params
+            .iter()
+            .map(|p| match p {
+                ParamOrTsParamProp::Param(p) => p.pat.clone(),
+                ParamOrTsParamProp::TsParamProp(p) => match p.param {
+                    TsParamPropParam::Ident(ref i) => Pat::Ident(i.clone()),
+                    TsParamPropParam::Assign(ref p) => Pat::Assign(p.clone()),
+                },
+            })
+            .collect::<Vec<_>>();
+        let params = params.iter().collect::<Vec<_>>();
+        self.visit_fn(&params, c.body.as_ref(), None, true);
+    }
+}
+
+impl Visit<StaticBlock> for Validator {
+    fn visit(&mut self, b: &StaticBlock) {
+        self.visit_fn(&[], Some(&b.body), None, true);
+    }
+}
+
+impl Visit<GetterProp> for Validator {
+    fn visit(&mut self, p: &GetterProp) {
+        p.key.visit_with(self);
+        self.visit_fn(&[], p.body.as_ref(), None, true);
+    }
+}
+
+impl Visit<SetterProp> for Validator {
+    fn visit(&mut self, p: &SetterProp) {
+        p.key.visit_with(self);
+        self.visit_fn(&[&p.param], p.body.as_ref(), None, true);
+    }
+}
+
+impl Visit<VarDecl> for Validator {
+    fn visit(&mut self, v: &VarDecl) {
+        if !v.declare {
+            for decl in &v.decls {
+                match v.kind {
+                    VarDeclKind::Var => {
+                        let mut names = vec![];
+                        bound_names(&decl.name, &mut names);
+                        for i in &names {
+                            self.declare_var(i);
+                        }
+                    }
+                    VarDeclKind::Let | VarDeclKind::Const => self.declare_lexical_pat(&decl.name),
+                }
+            }
+        }
+
+        v.visit_children(self);
+    }
+}
+
+impl Visit<BlockStmt> for Validator {
+    fn visit(&mut self, b: &BlockStmt) {
+        self.with_scope(ScopeKind::Block, |v| b.visit_children(v));
+    }
+}
+
+impl Visit<CatchClause> for Validator {
+    fn visit(&mut self, c: &CatchClause) {
+        self.with_scope(ScopeKind::Block, |v| {
+            if let Some(ref param) = c.param {
+                let kind = match param {
+                    Pat::Ident(..) => ParamKind::SimpleCatchParam,
+                    _ => ParamKind::CatchParam,
+                };
+                v.declare_params(&[param], kind, true);
+                param.visit_with(v);
+            }
+
+            // The catch parameter and the block share a scope.
+            for stmt in &c.body.stmts {
+                stmt.visit_with(v);
+            }
+        });
+    }
+}
+
+impl Visit<ForStmt> for Validator {
+    fn visit(&mut self, s: &ForStmt) {
+        self.with_scope(ScopeKind::Block, |v| {
+            s.init.visit_with(v);
+            s.test.visit_with(v);
+            s.update.visit_with(v);
+            v.visit_loop_body(&s.body);
+        });
+    }
+}
+
+impl Visit<ForInStmt> for Validator {
+    fn visit(&mut self, s: &ForInStmt) {
+        self.with_scope(ScopeKind::Block, |v| {
+            s.left.visit_with(v);
+            s.right.visit_with(v);
+            v.visit_loop_body(&s.body);
+        });
+    }
+}
+
+impl Visit<ForOfStmt> for Validator {
+    fn visit(&mut self, s: &ForOfStmt) {
+        self.with_scope(ScopeKind::Block, |v| {
+            s.left.visit_with(v);
+            s.right.visit_with(v);
+            v.visit_loop_body(&s.body);
+        });
+    }
+}
+
+impl Visit<WhileStmt> for Validator {
+    fn visit(&mut self, s: &WhileStmt) {
+        s.test.visit_with(self);
+        self.visit_loop_body(&s.body);
+    }
+}
+
+impl Visit<DoWhileStmt> for Validator {
+    fn visit(&mut self, s: &DoWhileStmt) {
+        self.visit_loop_body(&s.body);
+        s.test.visit_with(self);
+    }
+}
+
+impl Visit<SwitchStmt> for Validator {
+    fn visit(&mut self, s: &SwitchStmt) {
+        s.discriminant.visit_with(self);
+
+        // All cases share a block scope.
+        self.with_scope(ScopeKind::Block, |v| {
+            v.switch_depth += 1;
+            s.cases.visit_with(v);
+            v.switch_depth -= 1;
+        });
+    }
+}
+
+impl Visit<LabeledStmt> for Validator {
+    fn visit(&mut self, s: &LabeledStmt) {
+        fn is_loop(s: &Stmt) -> bool {
+            match s {
+                Stmt::While(..)
+                | Stmt::DoWhile(..)
+                | Stmt::For(..)
+                | Stmt::ForIn(..)
+                | Stmt::ForOf(..) => true,
+                Stmt::Labeled(s) => is_loop(&s.body),
+                _ => false,
+            }
+        }
+
+        self.labels.push((s.label.sym.clone(), is_loop(&s.body)));
+        s.body.visit_with(self);
+        self.labels.pop();
+    }
+}
+
+impl Visit<BreakStmt> for Validator {
+    fn visit(&mut self, s: &BreakStmt) {
+        match s.label {
+            Some(ref label) => {
+                if !self.labels.iter().any(|(l, _)| *l == label.sym) {
+                    self.emit(label.span, &format!("Undefined label `{}`", label.sym));
+                }
+            }
+            None => {
+                if self.loop_depth == 0 && self.switch_depth == 0 {
+                    self.emit(s.span, "Illegal break statement");
+                }
+            }
+        }
+    }
+}
+
+impl Visit<ContinueStmt> for Validator {
+    fn visit(&mut self, s: &ContinueStmt) {
+        match s.label {
+            Some(ref label) => match self.labels.iter().rev().find(|(l, _)| *l == label.sym) {
+                Some(&(_, true)) => {}
+                Some(&(_, false)) => self.emit(
+                    label.span,
+                    &format!(
+                        "Label `{}` is not a label of an iteration statement",
+                        label.sym
+                    ),
+                ),
+                None => self.emit(label.span, &format!("Undefined label `{}`", label.sym)),
+            },
+            None => {
+                if self.loop_depth == 0 {
+                    self.emit(s.span, "Illegal continue statement");
+                }
+            }
+        }
+    }
+}
+
+impl Visit<ObjectLit> for Validator {
+    fn visit(&mut self, obj: &ObjectLit) {
+        let mut proto = None;
+
+        for prop in &obj.props {
+            let span = match *prop {
+                PropOrSpread::Prop(box Prop::KeyValue(KeyValueProp {
+                    key: PropName::Ident(ref i),
+                    ..
+                })) if &*i.sym == "__proto__" => i.span,
+                PropOrSpread::Prop(box Prop::KeyValue(KeyValueProp {
+                    key: PropName::Str(ref s),
+                    ..
+                })) if &*s.value == "__proto__" => s.span,
+                _ => continue,
+            };
+
+            match proto {
+                Some(prev) => HANDLER.with(|handler| {
+                    let mut diag =
+                        handler.struct_span_err(span, "Duplicate __proto__ fields are not allowed");
+                    diag.span_label(prev, "previous definition");
+                    diag.emit();
+                }),
+                None => proto = Some(span),
+            }
+        }
+
+        obj.visit_children(self);
+    }
+}
+
+impl Visit<ImportDecl> for Validator {
+    fn visit(&mut self, import: &ImportDecl) {
+        if import.type_only {
+            return;
+        }
+
+        for s in &import.specifiers {
+            let local = match s {
+                ImportSpecifier::Named(s) => {
+                    if s.is_type_only {
+                        continue;
+                    }
+                    &s.local
+                }
+                ImportSpecifier::Default(s) => &s.local,
+                ImportSpecifier::Namespace(s) => &s.local,
+            };
+            self.declare_lexical(local, false);
+        }
+    }
+}
+
+impl Visit<ExportDecl> for Validator {
+    fn visit(&mut self, export: &ExportDecl) {
+        match export.decl {
+            Decl::Class(ref c) if !c.declare => self.add_export(&c.ident),
+            Decl::Fn(ref f) if !f.declare && f.function.body.is_some() => self.add_export(&f.ident),
+            Decl::Var(ref v) if !v.declare => {
+                let mut names = vec![];
+                for decl in &v.decls {
+                    bound_names(&decl.name, &mut names);
+                }
+                for i in &names {
+                    self.add_export(i);
+                }
+            }
+            _ => {}
+        }
+
+        export.decl.visit_with(self);
+    }
+}
+
+impl Visit<ExportDefaultDecl> for Validator {
+    fn visit(&mut self, export: &ExportDefaultDecl) {
+        match export.decl {
+            DefaultDecl::Fn(FnExpr {
+                function: Function { body: None, .. },
+                ..
+            })
+            | DefaultDecl::TsInterfaceDecl(..) => {}
+            _ => self.add_export(&Ident::new(js_word!("default"), export.span)),
+        }
+
+        // Names of default exported declarations are bound in the module scope.
+        match export.decl {
+            DefaultDecl::Class(ClassExpr {
+                ident: Some(ref i), ..
+            }) => self.declare_lexical(i, false),
+            DefaultDecl::Fn(FnExpr {
+                ident: Some(ref i),
+                ref function,
+            }) if function.body.is_some() => self.declare_lexical(i, true),
+            _ => {}
+        }
+
+        export.decl.visit_with(self);
+    }
+}
+
+impl Visit<ExportDefaultExpr> for Validator {
+    fn visit(&mut self, export: &ExportDefaultExpr) {
+        self.add_export(&Ident::new(js_word!("default"), export.span));
+
+        export.expr.visit_with(self);
+    }
+}
+
+impl Visit<NamedExport> for Validator {
+    fn visit(&mut self, export: &NamedExport) {
+        if export.type_only {
+            return;
+        }
+
+        for s in &export.specifiers {
+            match s {
+                ExportSpecifier::Named(s) => {
+                    self.add_export(s.exported.as_ref().unwrap_or(&s.orig))
+                }
+                ExportSpecifier::Default(s) => self.add_export(&s.exported),
+                ExportSpecifier::Namespace(s) => self.add_export(&s.name),
+            }
+        }
+    }
+}
+
+/// Returns the span of the `"use strict"` directive, if any.
+fn use_strict(stmts: &[Stmt]) -> Option<Span> {
+    stmts
+        .iter()
+        .take_while(|stmt| match **stmt {
+            Stmt::Expr(ExprStmt {
+                expr: box Expr::Lit(Lit::Str(..)),
+                ..
+            }) => true,
+            _ => false,
+        })
+        .find(|stmt| stmt.is_use_strict())
+        .map(|stmt| stmt.span())
+}
+
+/// Collects names bound by `pat`.
+///
+/// Unlike `find_ids`, this does not visit type annotations.
+fn bound_names(pat: &Pat, names: &mut Vec<Ident>) {
+    match pat {
+        Pat::Ident(i) => names.push(i.clone()),
+        Pat::Array(a) => {
+            for elem in a.elems.iter().flatten() {
+                bound_names(elem, names);
+            }
+        }
+        Pat::Rest(r) => bound_names(&r.arg, names),
+        Pat::Object(o) => {
+            for prop in &o.props {
+                match prop {
+                    ObjectPatProp::KeyValue(p) => bound_names(&p.value, names),
+                    ObjectPatProp::Assign(p) => names.push(p.key.clone()),
+                    ObjectPatProp::Rest(r) => bound_names(&r.arg, names),
+                }
+            }
+        }
+        Pat::Assign(a) => bound_names(&a.left, names),
+        Pat::Invalid(..) | Pat::Expr(..) => {}
+    }
+}
@@ -0,0 +1,230 @@
+use super::early_errors;
+use crate::util::HANDLER;
+use swc_common::{FileName, FoldWith};
+use swc_ecma_parser::{Parser, Session, SourceFileInput, Syntax};
+
+/// Returns the errors reported for `src`, or an empty string if there's none.
+fn errors(src: &str, is_module: bool) -> String {
+    let res = ::testing::run_test(false, |cm, handler| {
+        let fm = cm.new_source_file(FileName::Anon, src.into());
+        let mut p = Parser::new(
+            Session { handler },
+            Syntax::default(),
+            SourceFileInput::from(&*fm),
+            None,
+        );
+
+        HANDLER.set(handler, || {
+            if is_module {
+                let m = p.parse_module().map_err(|mut e| e.emit())?;
+                m.fold_with(&mut early_errors());
+            } else {
+                let s = p.parse_script().map_err(|mut e| e.emit())?;
+                s.fold_with(&mut early_errors());
+            }
+
+            Ok(())
+        })?;
+
+        if handler.has_errors() {
+            Err(())
+        } else {
+            Ok(())
+        }
+    });
+
+    match res {
+        Ok(()) => String::new(),
+        Err(stderr) => stderr.to_string(),
+    }
+}
+
+fn ok(src: &str) {
+    let stderr = errors(src, false);
+    assert!(stderr.is_empty(), "Unexpected errors:\n{}", stderr);
+}
+
+fn ok_module(src: &str) {
+    let stderr = errors(src, true);
+    assert!(stderr.is_empty(), "Unexpected errors:\n{}", stderr);
+}
+
+fn err(src: &str, expected: &str) {
+    let stderr = errors(src, false);
+    assert!(
+        stderr.contains(expected),
+        "Expected `{}`, but got:\n{}",
+        expected,
+        stderr
+    );
+}
+
+fn err_module(src: &str, expected: &str) {
+    let stderr = errors(src, true);
+    assert!(
+        stderr.contains(expected),
+        "Expected `{}`, but got:\n{}",
+        expected,
+        stderr
+    );
+}
+
+#[test]
+fn redeclaration() {
+    err("let a; let a;", "Identifier `a` has already been declared");
+    err("let a; var a;", "Identifier `a` has already been declared");
+    err("var a; let a;", "Identifier `a` has already been declared");
+    err("const a = 1; function a() {}", "has already been declared");
+    err("class a {} let a;", "has already been declared");
+    err("let a; { var a; }", "has already been declared");
+    err("{ var a; } let a;", "has already been declared");
+    err("function f(a) { let a; }", "has already been declared");
+    err("for (let i = 0; ;) { var i; }", "has already been declared");
+    err(
+        "switch (a) { case 1: let b; break; case 2: let b; }",
+        "has already been declared",
+    );
+    err("try {} catch (e) { let e; }", "has already been declared");
+    err("try {} catch ([e]) { var e; }", "has already been declared");
+}
+
+#[test]
+fn redeclaration_valid() {
+    ok("var a; var a;");
+    ok("function a() {} var a;");
+    ok("function a() {} function a() {}");
+    ok("{ function a() {} function a() {} }");
+    ok("let a; { let a; }");
+    ok("{ let a; } var a;");
+    ok("function f(a) { var a; }");
+    ok("function f() { let a; } var a;");
+    ok("try {} catch (e) { var e; }");
+    ok("for (let i = 0; ;) {} for (let i = 0; ;) {}");
+    ok("function f(a) { function a() {} }");
+}
+
+#[test]
+fn redeclaration_strict() {
+    err(
+        "'use strict'; { function a() {} function a() {} }",
+        "has already been declared",
+    );
+    err_module("function a() {} var a;", "has already been declared");
+    err_module("import a from 'a'; let a;", "has already been declared");
+    err_module("import { a } from 'a'; let a;", "has already been declared");
+    err_module(
+        "import { b as a } from 'a'; function a() {}",
+        "has already been declared",
+    );
+}
+
+#[test]
+fn duplicate_params() {
+    ok("function f(a, a) {}");
+    err(
+        "'use strict'; function f(a, a) {}",
+        "Duplicate parameter name `a`",
+    );
+    err(
+        "function f(a, a) { 'use strict'; }",
+        "Duplicate parameter name `a`",
+    );
+    err("(a, a) => {}", "Duplicate parameter name `a`");
+    err("function f(a, [a]) {}", "Duplicate parameter name `a`");
+    err("function f(a, b = 1, a) {}", "Duplicate parameter name `a`");
+    err("({ m(a, a) {} })", "Duplicate parameter name `a`");
+    err("(class { m(a, a) {} })", "Duplicate parameter name `a`");
+}
+
+#[test]
+fn use_strict_with_non_simple_params() {
+    err(
+        "function f(a = 1) { 'use strict'; }",
+        "Illegal 'use strict' directive in function with non-simple parameter list",
+    );
+    ok("function f(a) { 'use strict'; }");
+}
+
+#[test]
+fn let_as_lexical_name() {
+    err(
+        "let [let] = [];",
+        "`let` cannot be used as a lexically bound name",
+    );
+    err(
+        "for (const let of []) {}",
+        "`let` cannot be used as a lexically bound name",
+    );
+}
+
+#[test]
+fn break_and_continue() {
+    err("break;", "Illegal break statement");
+    err("continue;", "Illegal continue statement");
+    err(
+        "switch (a) { case 1: continue; }",
+        "Illegal continue statement",
+    );
+    err(
+        "while (a) { function f() { break; } }",
+        "Illegal break statement",
+    );
+    err(
+        "a: { continue a; }",
+        "Label `a` is not a label of an iteration statement",
+    );
+    err("while (a) { break b; }", "Undefined label `b`");
+    err(
+        "a: while (b) { (() => { break a; }); }",
+        "Undefined label `a`",
+    );
+
+    ok("while (a) { break; }");
+    ok("switch (a) { case 1: break; }");
+    ok("a: { break a; }");
+    ok("a: b: while (c) { continue a; }");
+    ok("a: for (;;) { for (;;) { continue a; } }");
+}
+
+#[test]
+fn duplicate_proto() {
+    err(
+        "({ __proto__: a, __proto__: b })",
+        "Duplicate __proto__ fields are not allowed",
+    );
+    err(
+        "({ __proto__: a, '__proto__': b })",
+        "Duplicate __proto__ fields are not allowed",
+    );
+
+    ok("({ __proto__: a, __proto__ })");
+    ok("({ __proto__: a, __proto__() {} })");
+    ok("({ __proto__: a, ['__proto__']: b })");
+}
+
+#[test]
+fn duplicate_exports() {
+    err_module(
+        "export const a = 1; export { a };",
+        "Duplicate export of `a`",
+    );
+    err_module(
+        "let a, b; export { a as c, b as c };",
+        "Duplicate export of `c`",
+    );
+    err_module(
+        "export default 1; export default 2;",
+        "Duplicate export of `default`",
+    );
+    err_module(
+        "let a; export { a as default }; export default function () {}",
+        "Duplicate export of `default`",
+    );
+    err_module(
+        "export { b as a } from 'b'; export function a() {}",
+        "Duplicate export of `a`",
+    );
+
+    ok_module("export const a = 1; export { a as b };");
+    ok_module("export * from 'a'; export * from 'b';");
+}
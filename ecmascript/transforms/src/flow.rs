@@ -0,0 +1,158 @@
+use crate::pass::Pass;
+use swc_atoms::js_word;
+use swc_common::{util::move_map::MoveMap, Fold, FoldWith};
+use swc_ecma_ast::*;
+
+/// Strips flow type annotations out.
+///
+/// Unlike the typescript strip pass, imports are removed only if they are
+/// declared as types, like `import type A from 'a'` or
+/// `import { typeof B } from 'b'`. Flow does not elide unused imports.
+pub fn strip() -> impl Pass {
+    Strip
+}
+
+#[derive(Clone, Copy)]
+struct Strip;
+
+noop_fold_type!(Strip);
+
+impl Fold<Vec<ModuleItem>> for Strip {
+    fn fold(&mut self, items: Vec<ModuleItem>) -> Vec<ModuleItem> {
+        let items = items.fold_children(self);
+
+        items.move_flat_map(|item| match item {
+            ModuleItem::ModuleDecl(ModuleDecl::Import(ImportDecl {
+                type_only: true, ..
+            }))
+            | ModuleItem::ModuleDecl(ModuleDecl::ExportNamed(NamedExport {
+                type_only: true,
+                ..
+            }))
+            | ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultDecl(ExportDefaultDecl {
+                decl: DefaultDecl::TsInterfaceDecl(..),
+                ..
+            })) => None,
+
+            ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl { ref decl, .. }))
+                if is_type_decl(decl) =>
+            {
+                None
+            }
+
+            ModuleItem::ModuleDecl(ModuleDecl::Import(mut import)) => {
+                if import.specifiers.is_empty() {
+                    return Some(ModuleItem::ModuleDecl(ModuleDecl::Import(import)));
+                }
+
+                import.specifiers.retain(|s| match *s {
+                    ImportSpecifier::Named(ImportNamedSpecifier {
+                        is_type_only: true, ..
+                    }) => false,
+                    _ => true,
+                });
+
+                // `import { type A } from 'a'` does not import 'a'.
+                if import.specifiers.is_empty() {
+                    None
+                } else {
+                    Some(ModuleItem::ModuleDecl(ModuleDecl::Import(import)))
+                }
+            }
+
+            ModuleItem::Stmt(Stmt::Decl(ref decl)) if is_type_decl(decl) => None,
+
+            _ => Some(item),
+        })
+    }
+}
+
+impl Fold<Vec<Stmt>> for Strip {
+    fn fold(&mut self, stmts: Vec<Stmt>) -> Vec<Stmt> {
+        let mut stmts = stmts.fold_children(self);
+
+        stmts.retain(|stmt| match *stmt {
+            Stmt::Decl(ref decl) => !is_type_decl(decl),
+            _ => true,
+        });
+
+        stmts
+    }
+}
+
+/// Returns true if `decl` does not exist at runtime.
+fn is_type_decl(decl: &Decl) -> bool {
+    match *decl {
+        Decl::TsInterface(..)
+        | Decl::TsModule(..)
+        | Decl::TsTypeAlias(..)
+        | Decl::Var(VarDecl { declare: true, .. })
+        | Decl::Class(ClassDecl { declare: true, .. })
+        | Decl::Fn(FnDecl { declare: true, .. }) => true,
+        _ => false,
+    }
+}
+
+impl Fold<Class> for Strip {
+    fn fold(&mut self, class: Class) -> Class {
+        let class = class.fold_children(self);
+
+        Class {
+            implements: vec![],
+            body: class.body.move_flat_map(|member| match member {
+                ClassMember::TsIndexSignature(..) => None,
+                _ => Some(member),
+            }),
+            ..class
+        }
+    }
+}
+
+/// Removes `this` from parameter list.
+impl Fold<Vec<Param>> for Strip {
+    fn fold(&mut self, params: Vec<Param>) -> Vec<Param> {
+        let mut params = params.fold_children(self);
+
+        params.retain(|param| match param.pat {
+            Pat::Ident(Ident {
+                sym: js_word!("this"),
+                ..
+            }) => false,
+            _ => true,
+        });
+
+        params
+    }
+}
+
+impl Fold<Ident> for Strip {
+    fn fold(&mut self, i: Ident) -> Ident {
+        Ident {
+            optional: false,
+            ..i.fold_children(self)
+        }
+    }
+}
+
+impl Fold<Expr> for Strip {
+    fn fold(&mut self, expr: Expr) -> Expr {
+        match expr {
+            Expr::TsTypeCast(TsTypeCastExpr { expr, .. }) => (*expr).fold_with(self),
+            _ => expr.fold_children(self),
+        }
+    }
+}
+
+macro_rules! type_to_none {
+    ($($T:ty),*) => {
+        $(
+            impl Fold<Option<$T>> for Strip {
+                fn fold(&mut self, _: Option<$T>) -> Option<$T> {
+                    None
+                }
+            }
+        )*
+    };
+}
+
+type_to_none!(TsTypeAnn, TsTypeParamDecl, TsTypeParamInstantiation);
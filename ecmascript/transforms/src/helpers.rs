@@ -143,6 +143,7 @@ macro_rules! define_helpers {
 
 define_helpers!(Helpers {
     apply_decorated_descriptor: (),
+    apply_decs_2203: (),
     array_with_holes: (),
     array_without_holes: (),
     assert_this_initialized: (),
@@ -152,11 +153,12 @@ define_helpers!(Helpers {
     async_to_generator: (),
     await_async_generator: (await_value),
     await_value: (),
+    check_in_rhs: (),
     class_call_check: (),
     class_name_tdz_error: (),
     class_private_field_get: (),
     class_private_field_loose_base: (),
-    // class_private_field_loose_key: (),
+    class_private_field_loose_key: (),
     class_private_field_set: (),
     class_private_method_get: (),
     class_private_method_set: (),
@@ -210,6 +212,7 @@ define_helpers!(Helpers {
     to_consumable_array: (array_without_holes, iterable_to_array, non_iterable_spread),
     to_primitive: (type_of),
     to_property_key: (type_of, to_primitive),
+    ts_metadata: (),
     type_of: (),
     wrap_async_generator: (async_generator),
     wrap_native_super: (
@@ -240,6 +243,7 @@ impl InjectHelpers {
                     })],
                     src: quote_str!("@swc/helpers"),
                     type_only: false,
+                    attrs: None,
                 }))]
             } else {
                 vec![]
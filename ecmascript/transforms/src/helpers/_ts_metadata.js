@@ -0,0 +1,8 @@
+function _tsMetadata(key, value) {
+  if (typeof Reflect === "object" && typeof Reflect.metadata === "function") {
+    return Reflect.metadata(key, value);
+  }
+
+  // Decorators are called even if `Reflect.metadata` is not available.
+  return function () {};
+}
@@ -33,6 +33,7 @@ impl<'a> Fold<Vec<ModuleItem>> for Operator<'a> {
                             })],
                             src: None,
                             type_only: false,
+                            attrs: None,
                         },
                     )));
                 };
@@ -135,6 +136,7 @@ impl<'a> Fold<Vec<ModuleItem>> for Operator<'a> {
                             specifiers: renamed,
                             src: None,
                             type_only: false,
+                            attrs: None,
                         },
                     )));
                 }
@@ -0,0 +1,1046 @@
+//! Code coverage instrumentation compatible with istanbul.
+//!
+//! Counters are stored in the `__coverage__` global (configurable) using the
+//! format of istanbul's `FileCoverage`, so reports can be generated by `nyc`
+//! or any other tool which understands it.
+use crate::{
+    pass::Pass,
+    util::{
+        constructor::inject_after_super,
+        default_constructor, drop_span,
+        options::{CM, SESSION},
+        prepend_stmts, ExprFactory, IsDirective, StmtLike,
+    },
+};
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::{hash_map::DefaultHasher, BTreeMap},
+    hash::{Hash, Hasher},
+    iter, mem,
+    path::Path,
+    sync::Arc,
+};
+use swc_atoms::JsWord;
+use swc_common::{
+    comments::Comments, BytePos, FileName, Fold, FoldWith, SourceMap, Span, Spanned, DUMMY_SP,
+};
+use swc_ecma_ast::*;
+use swc_ecma_parser::{lexer::Lexer, Parser, SourceFileInput};
+
+#[cfg(test)]
+mod tests;
+
+/// Version of the istanbul coverage schema.
+const COVERAGE_SCHEMA: &str = "1a1c01bbd47fc00a2c39e90264f33305447a3e8d";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+pub struct Config {
+    /// Name of the global variable used to store coverage data.
+    #[serde(default = "default_coverage_variable")]
+    pub coverage_variable: String,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            coverage_variable: default_coverage_variable(),
+        }
+    }
+}
+
+fn default_coverage_variable() -> String {
+    String::from("__coverage__")
+}
+
+/// Injects statement, branch and function counters.
+///
+/// `/* istanbul ignore next */`, `/* istanbul ignore if */` and
+/// `/* istanbul ignore else */` in `comments` are honored.
+///
+/// If `input_source_map` is given, locations of counters are mapped back to
+/// the original source.
+///
+/// This pass should be applied to the input ast, before other transforms.
+pub fn instrument<'a>(
+    cm: Arc<SourceMap>,
+    comments: &'a Comments,
+    input_source_map: Option<&'a sourcemap::SourceMap>,
+    config: Config,
+) -> impl 'a + Pass {
+    Instrument {
+        cm,
+        comments,
+        input_source_map,
+        config,
+        cov_fn: quote_ident!("cov"),
+        data: Default::default(),
+        hoisted: vec![],
+        ctor_counters: vec![],
+        fn_name: None,
+        in_prologue: false,
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+struct Position {
+    line: u32,
+    column: u32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+struct Range {
+    start: Position,
+    end: Position,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct FnMapping {
+    name: String,
+    decl: Range,
+    loc: Range,
+    line: u32,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct BranchMapping {
+    loc: Range,
+    #[serde(rename = "type")]
+    kind: &'static str,
+    locations: Vec<Range>,
+    line: u32,
+}
+
+/// istanbul's `FileCoverage`.
+#[derive(Debug, Default, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct FileCoverage {
+    path: String,
+    statement_map: BTreeMap<usize, Range>,
+    fn_map: BTreeMap<usize, FnMapping>,
+    branch_map: BTreeMap<usize, BranchMapping>,
+    s: BTreeMap<usize, u32>,
+    f: BTreeMap<usize, u32>,
+    b: BTreeMap<usize, Vec<u32>>,
+    #[serde(rename = "_coverageSchema")]
+    coverage_schema: String,
+    hash: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Hint {
+    Next,
+    If,
+    Else,
+}
+
+struct Instrument<'a> {
+    cm: Arc<SourceMap>,
+    comments: &'a Comments,
+    input_source_map: Option<&'a sourcemap::SourceMap>,
+    config: Config,
+    /// `cov_<hash>`
+    cov_fn: Ident,
+    data: FileCoverage,
+    /// Counters which should be inserted before the current statement.
+    hoisted: Vec<Stmt>,
+    /// Counters which should be inserted into the constructor of the current
+    /// class.
+    ctor_counters: Vec<Box<Expr>>,
+    /// Name and span of the name of the next function.
+    fn_name: Option<(JsWord, Span)>,
+    /// True if the next statement list may start with directives.
+    in_prologue: bool,
+}
+
+noop_fold_type!(Instrument<'_>);
+
+impl Instrument<'_> {
+    /// Returns the istanbul hint in leading comments at `pos`.
+    fn hint(&self, pos: BytePos) -> Option<Hint> {
+        let comments = self.comments.leading_comments(pos)?;
+
+        comments.iter().find_map(|c| {
+            let text = c.text.trim();
+            let text = text.trim_start_matches("istanbul").trim_start();
+            if text.len() == c.text.trim().len() {
+                return None;
+            }
+            let text = text.trim_start_matches("ignore").trim_start();
+
+            match text.split(|c: char| !c.is_alphanumeric()).next() {
+                Some("next") => Some(Hint::Next),
+                Some("if") => Some(Hint::If),
+                Some("else") => Some(Hint::Else),
+                _ => None,
+            }
+        })
+    }
+
+    fn is_ignored(&self, span: Span) -> bool {
+        !span.is_dummy() && self.hint(span.lo()) == Some(Hint::Next)
+    }
+
+    /// 1-based line and 0-based column, mapped by the input source map if
+    /// possible.
+    fn position(&self, pos: BytePos) -> Position {
+        let loc = self.cm.lookup_char_pos(pos);
+        let line = loc.line as u32;
+        let column = loc.col.0 as u32;
+
+        if let Some(map) = self.input_source_map {
+            if let Some(token) = map.lookup_token(line - 1, column) {
+                if token.get_dst_line() == line - 1 {
+                    return Position {
+                        line: token.get_src_line() + 1,
+                        column: token.get_src_col() + (column - token.get_dst_col()),
+                    };
+                }
+            }
+        }
+
+        Position { line, column }
+    }
+
+    fn range(&self, span: Span) -> Range {
+        Range {
+            start: self.position(span.lo()),
+            end: self.position(span.hi()),
+        }
+    }
+
+    fn new_statement(&mut self, span: Span) -> usize {
+        let idx = self.data.statement_map.len();
+        let range = self.range(span);
+        self.data.statement_map.insert(idx, range);
+        self.data.s.insert(idx, 0);
+        idx
+    }
+
+    fn new_function(&mut self, name: Option<&str>, decl: Span, body: Span) -> usize {
+        let idx = self.data.fn_map.len();
+        let loc = self.range(body);
+        let mapping = FnMapping {
+            name: match name {
+                Some(name) => name.to_string(),
+                None => format!("(anonymous_{})", idx),
+            },
+            decl: self.range(decl),
+            loc,
+            line: loc.start.line,
+        };
+        self.data.fn_map.insert(idx, mapping);
+        self.data.f.insert(idx, 0);
+        idx
+    }
+
+    fn new_branch(&mut self, kind: &'static str, span: Span) -> usize {
+        let idx = self.data.branch_map.len();
+        let loc = self.range(span);
+        self.data.branch_map.insert(
+            idx,
+            BranchMapping {
+                loc,
+                kind,
+                locations: vec![],
+                line: loc.start.line,
+            },
+        );
+        self.data.b.insert(idx, vec![]);
+        idx
+    }
+
+    /// Adds a path to the branch and returns the counter of it.
+    fn branch_path(&mut self, branch: usize, span: Span) -> Expr {
+        let range = self.range(span);
+        let mapping = self.data.branch_map.get_mut(&branch).unwrap();
+        mapping.locations.push(range);
+        let path = mapping.locations.len() - 1;
+        self.data.b.get_mut(&branch).unwrap().push(0);
+
+        self.counter("b", branch, Some(path))
+    }
+
+    /// `cov_<hash>().s[1]++`
+    fn counter(&self, kind: &str, idx: usize, path: Option<usize>) -> Expr {
+        let num = |value: usize| {
+            Expr::Lit(Lit::Num(Number {
+                span: DUMMY_SP,
+                value: value as f64,
+            }))
+        };
+
+        let data = Expr::Call(CallExpr {
+            span: DUMMY_SP,
+            callee: self.cov_fn.clone().as_callee(),
+            args: vec![],
+            type_args: None,
+        });
+        let mut counter = data.member(quote_ident!(kind)).computed_member(num(idx));
+        if let Some(path) = path {
+            counter = counter.computed_member(num(path));
+        }
+
+        Expr::Update(UpdateExpr {
+            span: DUMMY_SP,
+            op: op!("++"),
+            prefix: false,
+            arg: box counter,
+        })
+    }
+
+    /// Registers a function which is named by `fn_name`.
+    fn enter_fn(&mut self, decl: Span, body: Span) -> usize {
+        let (name, decl) = match self.fn_name.take() {
+            Some((name, span)) => (Some(name), span),
+            None => (None, decl.with_hi(decl.lo() + BytePos(1))),
+        };
+
+        self.new_function(name.as_ref().map(|s| &**s), decl, body)
+    }
+
+    /// Folds `body` and prepends the counter of the function.
+    fn fold_fn_body(&mut self, idx: usize, body: BlockStmt) -> BlockStmt {
+        self.in_prologue = true;
+        let mut body = body.fold_with(self);
+
+        // Directives should stay at the start of the body.
+        let pos = body.stmts.iter().take_while(|s| s.is_directive()).count();
+        body.stmts
+            .insert(pos, self.counter("f", idx, None).into_stmt());
+        body
+    }
+
+    /// Converts `s` to a block statement and folds it.
+    fn fold_block(&mut self, s: Box<Stmt>, branch_counter: Option<Expr>) -> Box<Stmt> {
+        let mut block = match *s {
+            Stmt::Block(block) => block,
+            s => BlockStmt {
+                span: s.span(),
+                stmts: vec![s],
+            },
+        }
+        .fold_with(self);
+
+        if let Some(counter) = branch_counter {
+            block.stmts.insert(0, counter.into_stmt());
+        }
+
+        box Stmt::Block(block)
+    }
+
+    /// Adds counters to the leaves of the logical expression.
+    fn cover_logical(&mut self, e: Expr, branch: usize) -> Expr {
+        match e {
+            Expr::Bin(BinExpr {
+                span,
+                op: op @ op!("&&"),
+                left,
+                right,
+            })
+            | Expr::Bin(BinExpr {
+                span,
+                op: op @ op!("||"),
+                left,
+                right,
+            })
+            | Expr::Bin(BinExpr {
+                span,
+                op: op @ op!("??"),
+                left,
+                right,
+            }) => Expr::Bin(BinExpr {
+                span,
+                op,
+                left: box self.cover_logical(*left, branch),
+                right: box self.cover_logical(*right, branch),
+            }),
+
+            Expr::Paren(ParenExpr { span, expr }) if is_logical(&expr) => Expr::Paren(ParenExpr {
+                span,
+                expr: box self.cover_logical(*expr, branch),
+            }),
+
+            _ => {
+                if self.is_ignored(e.span()) {
+                    return e;
+                }
+
+                let counter = self.branch_path(branch, e.span());
+                *with_counter(counter, box e.fold_with(self))
+            }
+        }
+    }
+
+    /// Returns statements which initialize coverage data.
+    fn header(&mut self, span: Span) -> Vec<Stmt> {
+        let fm = self.cm.lookup_char_pos(span.lo()).file;
+
+        self.data.path = match self.input_source_map {
+            Some(map) if map.get_source_count() == 1 => {
+                let source = map.get_source(0).unwrap();
+                match fm.name {
+                    FileName::Real(ref path) if Path::new(source).is_relative() => path
+                        .parent()
+                        .unwrap_or_else(|| Path::new(""))
+                        .join(source)
+                        .display()
+                        .to_string(),
+                    _ => source.to_string(),
+                }
+            }
+            _ => fm.name.to_string(),
+        };
+        self.data.hash = {
+            let mut hasher = DefaultHasher::new();
+            fm.src.hash(&mut hasher);
+            format!("{:x}", hasher.finish())
+        };
+        self.data.coverage_schema = COVERAGE_SCHEMA.into();
+
+        let src = format!(
+            "function {cov}() {{
+    var path = {path};
+    var hash = {hash};
+    var global = new Function('return this')();
+    var gcv = {gcv};
+    var coverageData = {data};
+    var coverage = global[gcv] || (global[gcv] = {{}});
+    if (!coverage[path] || coverage[path].hash !== hash) {{
+        coverage[path] = coverageData;
+    }}
+    var actualCoverage = coverage[path];
+    {cov} = function () {{
+        return actualCoverage;
+    }};
+    return actualCoverage;
+}}
+{cov}();",
+            cov = self.cov_fn.sym,
+            path = serde_json::to_string(&self.data.path).unwrap(),
+            hash = serde_json::to_string(&self.data.hash).unwrap(),
+            gcv = serde_json::to_string(&self.config.coverage_variable).unwrap(),
+            data = serde_json::to_string(&self.data).unwrap(),
+        );
+
+        let fm = CM.new_source_file(
+            FileName::Custom(format!("<coverage-{}>", self.cov_fn.sym)),
+            src,
+        );
+        let lexer = Lexer::new(
+            *SESSION,
+            Default::default(),
+            Default::default(),
+            SourceFileInput::from(&*fm),
+            None,
+        );
+        let script = Parser::new_from(*SESSION, lexer)
+            .parse_script()
+            .map_err(|mut e| {
+                e.emit();
+            })
+            .expect("failed to parse coverage header");
+
+        drop_span(script.body)
+    }
+
+    fn init(&mut self, span: Span) {
+        let fm = self.cm.lookup_char_pos(span.lo()).file;
+        let mut hasher = DefaultHasher::new();
+        fm.name.to_string().hash(&mut hasher);
+        self.cov_fn = quote_ident!(format!("cov_{:x}", hasher.finish()));
+    }
+}
+
+impl Fold<Module> for Instrument<'_> {
+    fn fold(&mut self, m: Module) -> Module {
+        self.init(m.span);
+
+        self.in_prologue = true;
+        let mut m = m.fold_children(self);
+        let header = self.header(m.span);
+        prepend_stmts(&mut m.body, header.into_iter().map(ModuleItem::Stmt));
+        m
+    }
+}
+
+impl Fold<Script> for Instrument<'_> {
+    fn fold(&mut self, s: Script) -> Script {
+        self.init(s.span);
+
+        self.in_prologue = true;
+        let mut s = s.fold_children(self);
+        let header = self.header(s.span);
+        prepend_stmts(&mut s.body, header.into_iter());
+        s
+    }
+}
+
+impl<T> Fold<Vec<T>> for Instrument<'_>
+where
+    T: StmtLike + IsDirective + Spanned + FoldWith<Self>,
+{
+    fn fold(&mut self, items: Vec<T>) -> Vec<T> {
+        let old = mem::replace(&mut self.hoisted, vec![]);
+        let mut in_prologue = mem::replace(&mut self.in_prologue, false);
+        let mut buf = Vec::with_capacity(items.len());
+
+        for item in items {
+            // Directives are not statements, and counters should not be
+            // inserted before them.
+            if in_prologue && item.is_directive() {
+                buf.push(item);
+                continue;
+            }
+            in_prologue = false;
+
+            if self.is_ignored(item.span()) {
+                buf.push(item);
+                continue;
+            }
+
+            let counter = match item.as_stmt() {
+                Some(s) if is_statement(s) => Some(self.new_statement(s.span())),
+                _ => None,
+            };
+            let item = item.fold_with(self);
+
+            buf.extend(self.hoisted.drain(..).map(T::from_stmt));
+            if let Some(idx) = counter {
+                buf.push(T::from_stmt(self.counter("s", idx, None).into_stmt()));
+            }
+            buf.push(item);
+        }
+
+        self.hoisted = old;
+        buf
+    }
+}
+
+impl Fold<Stmt> for Instrument<'_> {
+    fn fold(&mut self, s: Stmt) -> Stmt {
+        match s {
+            Stmt::For(s) => Stmt::For(ForStmt {
+                init: s.init.fold_with(self),
+                test: s.test.fold_with(self),
+                update: s.update.fold_with(self),
+                body: self.fold_block(s.body, None),
+                ..s
+            }),
+            Stmt::ForIn(s) => Stmt::ForIn(ForInStmt {
+                left: s.left.fold_with(self),
+                right: s.right.fold_with(self),
+                body: self.fold_block(s.body, None),
+                ..s
+            }),
+            Stmt::ForOf(s) => Stmt::ForOf(ForOfStmt {
+                left: s.left.fold_with(self),
+                right: s.right.fold_with(self),
+                body: self.fold_block(s.body, None),
+                ..s
+            }),
+            Stmt::While(s) => Stmt::While(WhileStmt {
+                test: s.test.fold_with(self),
+                body: self.fold_block(s.body, None),
+                ..s
+            }),
+            Stmt::DoWhile(s) => Stmt::DoWhile(DoWhileStmt {
+                body: self.fold_block(s.body, None),
+                test: s.test.fold_with(self),
+                ..s
+            }),
+            Stmt::With(s) => Stmt::With(WithStmt {
+                obj: s.obj.fold_with(self),
+                body: self.fold_block(s.body, None),
+                ..s
+            }),
+            _ => s.fold_children(self),
+        }
+    }
+}
+
+impl Fold<IfStmt> for Instrument<'_> {
+    fn fold(&mut self, s: IfStmt) -> IfStmt {
+        let hint = self.hint(s.span.lo());
+        let branch = self.new_branch("if", s.span);
+        let test = s.test.fold_with(self);
+
+        let cons = if hint == Some(Hint::If) {
+            s.cons
+        } else {
+            let counter = self.branch_path(branch, s.span);
+            self.fold_block(s.cons, Some(counter))
+        };
+
+        let alt = if hint == Some(Hint::Else) {
+            s.alt
+        } else {
+            let counter = self.branch_path(branch, s.span);
+            let alt = s.alt.unwrap_or_else(|| {
+                box Stmt::Block(BlockStmt {
+                    span: DUMMY_SP,
+                    stmts: vec![],
+                })
+            });
+            Some(self.fold_block(alt, Some(counter)))
+        };
+
+        IfStmt {
+            test,
+            cons,
+            alt,
+            ..s
+        }
+    }
+}
+
+impl Fold<SwitchStmt> for Instrument<'_> {
+    fn fold(&mut self, s: SwitchStmt) -> SwitchStmt {
+        let branch = self.new_branch("switch", s.span);
+        let discriminant = s.discriminant.fold_with(self);
+
+        let cases = s
+            .cases
+            .into_iter()
+            .map(|case| {
+                if self.is_ignored(case.span) {
+                    return case;
+                }
+
+                let counter = self.branch_path(branch, case.span);
+                let mut case = case.fold_children(self);
+                case.cons.insert(0, counter.into_stmt());
+                case
+            })
+            .collect();
+
+        SwitchStmt {
+            discriminant,
+            cases,
+            ..s
+        }
+    }
+}
+
+impl Fold<Expr> for Instrument<'_> {
+    fn fold(&mut self, e: Expr) -> Expr {
+        if self.is_ignored(e.span()) {
+            return e;
+        }
+
+        match e {
+            Expr::Bin(..) if is_logical(&e) => {
+                let branch = self.new_branch("binary-expr", e.span());
+                self.cover_logical(e, branch)
+            }
+
+            Expr::Cond(CondExpr {
+                span,
+                test,
+                cons,
+                alt,
+            }) => {
+                let branch = self.new_branch("cond-expr", span);
+                let test = test.fold_with(self);
+
+                let mut cover = |e: Box<Expr>| {
+                    if self.is_ignored(e.span()) {
+                        return e;
+                    }
+                    let counter = self.branch_path(branch, e.span());
+                    with_counter(counter, e.fold_with(self))
+                };
+                let cons = cover(cons);
+                let alt = cover(alt);
+
+                Expr::Cond(CondExpr {
+                    span,
+                    test,
+                    cons,
+                    alt,
+                })
+            }
+
+            _ => e.fold_children(self),
+        }
+    }
+}
+
+impl Fold<AssignPat> for Instrument<'_> {
+    fn fold(&mut self, p: AssignPat) -> AssignPat {
+        let left = p.left.fold_with(self);
+        if self.is_ignored(p.right.span()) {
+            return AssignPat { left, ..p };
+        }
+
+        let branch = self.new_branch("default-arg", p.right.span());
+        let counter = self.branch_path(branch, p.right.span());
+        let right = match *p.right {
+            // Wrapping functions with a sequence expression changes their names,
+            // so the default is counted when it's called instead.
+            Expr::Fn(..) | Expr::Arrow(..) | Expr::Class(..) => {
+                counter_in_body(counter, p.right.fold_with(self))
+            }
+            _ => with_counter(counter, p.right.fold_with(self)),
+        };
+
+        AssignPat { left, right, ..p }
+    }
+}
+
+impl Fold<VarDeclarator> for Instrument<'_> {
+    fn fold(&mut self, d: VarDeclarator) -> VarDeclarator {
+        let name = d.name.fold_with(self);
+
+        let init = match d.init {
+            Some(init) if !self.is_ignored(init.span()) => {
+                let idx = self.new_statement(init.span());
+                let counter = self.counter("s", idx, None);
+
+                match *init {
+                    // Wrapping functions with a sequence expression changes their names.
+                    Expr::Fn(..) | Expr::Arrow(..) | Expr::Class(..) => {
+                        self.hoisted.push(counter.into_stmt());
+                        Some(init.fold_with(self))
+                    }
+                    _ => Some(with_counter(counter, init.fold_with(self))),
+                }
+            }
+            init => init,
+        };
+
+        VarDeclarator { name, init, ..d }
+    }
+}
+
+impl Fold<ClassProp> for Instrument<'_> {
+    fn fold(&mut self, p: ClassProp) -> ClassProp {
+        let key = p.key.fold_with(self);
+
+        let value = match p.value {
+            Some(value) if !self.is_ignored(value.span()) => {
+                let idx = self.new_statement(value.span());
+                let counter = self.counter("s", idx, None);
+
+                match *value {
+                    // Wrapping functions with a sequence expression changes their names.
+                    Expr::Fn(..) | Expr::Arrow(..) | Expr::Class(..) => {
+                        if p.is_static {
+                            self.hoisted.push(counter.into_stmt());
+                        } else {
+                            self.ctor_counters.push(box counter);
+                        }
+                        Some(value.fold_with(self))
+                    }
+                    _ => Some(with_counter(counter, value.fold_with(self))),
+                }
+            }
+            value => value,
+        };
+
+        ClassProp { key, value, ..p }
+    }
+}
+
+/// Counters of instance fields are inserted into the constructor.
+impl Fold<Class> for Instrument<'_> {
+    fn fold(&mut self, c: Class) -> Class {
+        let old = mem::replace(&mut self.ctor_counters, vec![]);
+        let c = c.fold_children(self);
+        let counters = mem::replace(&mut self.ctor_counters, old);
+
+        if counters.is_empty() {
+            return c;
+        }
+        counters_in_constructor(c, counters)
+    }
+}
+
+impl Fold<FnDecl> for Instrument<'_> {
+    fn fold(&mut self, f: FnDecl) -> FnDecl {
+        self.fn_name = Some((f.ident.sym.clone(), f.ident.span));
+
+        FnDecl {
+            function: f.function.fold_with(self),
+            ..f
+        }
+    }
+}
+
+impl Fold<FnExpr> for Instrument<'_> {
+    fn fold(&mut self, f: FnExpr) -> FnExpr {
+        self.fn_name = f.ident.as_ref().map(|i| (i.sym.clone(), i.span));
+
+        FnExpr {
+            function: f.function.fold_with(self),
+            ..f
+        }
+    }
+}
+
+impl Fold<ClassMethod> for Instrument<'_> {
+    fn fold(&mut self, m: ClassMethod) -> ClassMethod {
+        let key = m.key.fold_with(self);
+        self.fn_name = prop_name(&key);
+
+        ClassMethod {
+            key,
+            function: m.function.fold_with(self),
+            ..m
+        }
+    }
+}
+
+impl Fold<PrivateMethod> for Instrument<'_> {
+    fn fold(&mut self, m: PrivateMethod) -> PrivateMethod {
+        self.fn_name = Some((format!("#{}", m.key.id.sym).into(), m.key.span));
+
+        PrivateMethod {
+            function: m.function.fold_with(self),
+            ..m
+        }
+    }
+}
+
+impl Fold<MethodProp> for Instrument<'_> {
+    fn fold(&mut self, m: MethodProp) -> MethodProp {
+        let key = m.key.fold_with(self);
+        self.fn_name = prop_name(&key);
+
+        MethodProp {
+            key,
+            function: m.function.fold_with(self),
+        }
+    }
+}
+
+impl Fold<Function> for Instrument<'_> {
+    fn fold(&mut self, f: Function) -> Function {
+        let body = match f.body {
+            Some(body) => body,
+            None => {
+                self.fn_name = None;
+                return f;
+            }
+        };
+        let idx = self.enter_fn(f.span, body.span);
+
+        Function {
+            decorators: f.decorators.fold_with(self),
+            params: f.params.fold_with(self),
+            body: Some(self.fold_fn_body(idx, body)),
+            ..f
+        }
+    }
+}
+
+impl Fold<ArrowExpr> for Instrument<'_> {
+    fn fold(&mut self, f: ArrowExpr) -> ArrowExpr {
+        let idx = self.enter_fn(f.span, f.body.span());
+        let params = f.params.fold_with(self);
+
+        let body = match f.body {
+            BlockStmtOrExpr::BlockStmt(body) => {
+                BlockStmtOrExpr::BlockStmt(self.fold_fn_body(idx, body))
+            }
+            // `() => (cov().f[0]++, cov().s[0]++, expr)`
+            BlockStmtOrExpr::Expr(expr) => {
+                let mut exprs = vec![box self.counter("f", idx, None)];
+                if !self.is_ignored(expr.span()) {
+                    let idx = self.new_statement(expr.span());
+                    exprs.push(box self.counter("s", idx, None));
+                }
+                exprs.push(expr.fold_with(self));
+
+                BlockStmtOrExpr::Expr(
+                    box Expr::Seq(SeqExpr {
+                        span: DUMMY_SP,
+                        exprs,
+                    })
+                    .wrap_with_paren(),
+                )
+            }
+        };
+
+        ArrowExpr { params, body, ..f }
+    }
+}
+
+impl Fold<Constructor> for Instrument<'_> {
+    fn fold(&mut self, c: Constructor) -> Constructor {
+        let body = match c.body {
+            Some(body) => body,
+            None => return c,
+        };
+        self.fn_name = Some(("constructor".into(), c.key.span()));
+        let idx = self.enter_fn(c.span, body.span);
+
+        Constructor {
+            params: c.params.fold_with(self),
+            body: Some(self.fold_fn_body(idx, body)),
+            ..c
+        }
+    }
+}
+
+impl Fold<GetterProp> for Instrument<'_> {
+    fn fold(&mut self, p: GetterProp) -> GetterProp {
+        let key = p.key.fold_with(self);
+        let body = match p.body {
+            Some(body) => body,
+            None => return GetterProp { key, ..p },
+        };
+        self.fn_name = prop_name(&key);
+        let idx = self.enter_fn(p.span, body.span);
+
+        GetterProp {
+            key,
+            body: Some(self.fold_fn_body(idx, body)),
+            ..p
+        }
+    }
+}
+
+impl Fold<SetterProp> for Instrument<'_> {
+    fn fold(&mut self, p: SetterProp) -> SetterProp {
+        let key = p.key.fold_with(self);
+        let body = match p.body {
+            Some(body) => body,
+            None => return SetterProp { key, ..p },
+        };
+        self.fn_name = prop_name(&key);
+        let idx = self.enter_fn(p.span, body.span);
+
+        SetterProp {
+            key,
+            param: p.param.fold_with(self),
+            body: Some(self.fold_fn_body(idx, body)),
+            ..p
+        }
+    }
+}
+
+impl Fold<ClassMember> for Instrument<'_> {
+    fn fold(&mut self, m: ClassMember) -> ClassMember {
+        if self.is_ignored(m.span()) {
+            return m;
+        }
+
+        m.fold_children(self)
+    }
+}
+
+impl Fold<PropOrSpread> for Instrument<'_> {
+    fn fold(&mut self, p: PropOrSpread) -> PropOrSpread {
+        if self.is_ignored(p.span()) {
+            return p;
+        }
+
+        p.fold_children(self)
+    }
+}
+
+/// Returns true if a counter should be inserted before `s`.
+fn is_statement(s: &Stmt) -> bool {
+    match s {
+        Stmt::Expr(..)
+        | Stmt::Break(..)
+        | Stmt::Continue(..)
+        | Stmt::Debugger(..)
+        | Stmt::Return(..)
+        | Stmt::Throw(..)
+        | Stmt::Try(..)
+        | Stmt::If(..)
+        | Stmt::For(..)
+        | Stmt::ForIn(..)
+        | Stmt::ForOf(..)
+        | Stmt::While(..)
+        | Stmt::DoWhile(..)
+        | Stmt::Switch(..)
+        | Stmt::With(..)
+        | Stmt::Labeled(..)
+        | Stmt::Decl(Decl::Class(..)) => true,
+        _ => false,
+    }
+}
+
+/// `(counter, expr)`
+fn with_counter(counter: Expr, expr: Box<Expr>) -> Box<Expr> {
+    box Expr::Seq(SeqExpr {
+        span: DUMMY_SP,
+        exprs: vec![box counter, expr],
+    })
+    .wrap_with_paren()
+}
+
+/// Inserts `counter` into the body of the function or the constructor of the
+/// class `e`.
+fn counter_in_body(counter: Expr, e: Box<Expr>) -> Box<Expr> {
+    match *e {
+        Expr::Fn(mut f) => {
+            if let Some(body) = &mut f.function.body {
+                prepend_stmts(&mut body.stmts, iter::once(counter.into_stmt()));
+            }
+            box Expr::Fn(f)
+        }
+        Expr::Arrow(f) => {
+            let body = match f.body {
+                BlockStmtOrExpr::BlockStmt(mut body) => {
+                    prepend_stmts(&mut body.stmts, iter::once(counter.into_stmt()));
+                    BlockStmtOrExpr::BlockStmt(body)
+                }
+                BlockStmtOrExpr::Expr(expr) => BlockStmtOrExpr::Expr(with_counter(counter, expr)),
+            };
+            box Expr::Arrow(ArrowExpr { body, ..f })
+        }
+        Expr::Class(c) => box Expr::Class(ClassExpr {
+            class: counters_in_constructor(c.class, vec![box counter]),
+            ..c
+        }),
+        e => with_counter(counter, box e),
+    }
+}
+
+/// Inserts `counters` into the constructor of `class`, which is created if it
+/// does not exist.
+#[allow(clippy::vec_box)]
+fn counters_in_constructor(mut class: Class, counters: Vec<Box<Expr>>) -> Class {
+    let pos = class.body.iter().position(|m| match m {
+        ClassMember::Constructor(Constructor { body: Some(..), .. }) => true,
+        _ => false,
+    });
+
+    let c = match pos {
+        Some(pos) => match class.body.remove(pos) {
+            ClassMember::Constructor(c) => c,
+            _ => unreachable!(),
+        },
+        None => default_constructor(class.super_class.is_some()),
+    };
+    let pos = pos.unwrap_or_else(|| class.body.len());
+    class.body.insert(
+        pos,
+        ClassMember::Constructor(inject_after_super(c, counters)),
+    );
+
+    class
+}
+
+fn is_logical(e: &Expr) -> bool {
+    match e {
+        Expr::Bin(BinExpr { op, .. }) => match op {
+            op!("&&") | op!("||") | op!("??") => true,
+            _ => false,
+        },
+        _ => false,
+    }
+}
+
+fn prop_name(key: &PropName) -> Option<(JsWord, Span)> {
+    match key {
+        PropName::Ident(i) => Some((i.sym.clone(), i.span)),
+        PropName::Str(s) => Some((s.value.clone(), s.span)),
+        PropName::Num(n) => Some((n.value.to_string().into(), n.span)),
+        PropName::Computed(..) => None,
+    }
+}
@@ -0,0 +1,208 @@
+use super::*;
+use crate::tests::Tester;
+use sourcemap::SourceMapBuilder;
+use swc_ecma_parser::{EsConfig, Syntax};
+
+/// Instruments `src` and returns the output and the collected coverage data.
+fn run(src: &str, input_source_map: Option<&sourcemap::SourceMap>) -> (String, FileCoverage) {
+    let mut res = None;
+
+    Tester::run(|tester| {
+        let syntax = Syntax::Es(EsConfig {
+            class_props: true,
+            ..Default::default()
+        });
+        let module = tester.with_parser("input.js", syntax, src, |p| {
+            p.parse_module().map_err(|mut e| {
+                e.emit();
+            })
+        })?;
+
+        let mut pass = Instrument {
+            cm: tester.cm.clone(),
+            comments: &tester.comments,
+            input_source_map,
+            config: Default::default(),
+            cov_fn: quote_ident!("cov"),
+            data: Default::default(),
+            hoisted: vec![],
+            ctor_counters: vec![],
+            fn_name: None,
+            in_prologue: false,
+        };
+        let module = module.fold_with(&mut pass);
+        let data = pass.data;
+
+        res = Some((tester.print(&module), data));
+        Ok(())
+    });
+
+    res.unwrap()
+}
+
+fn pos(line: u32, column: u32) -> Position {
+    Position { line, column }
+}
+
+#[test]
+fn counters() {
+    let (code, data) = run(
+        "function foo(a, b) {
+    if (a && b) {
+        return a ? 1 : 2;
+    }
+    const c = () => b;
+    return c();
+}",
+        None,
+    );
+
+    assert_eq!(data.s.len(), 5);
+    assert_eq!(data.f.len(), 2);
+    assert_eq!(data.fn_map[&0].name, "foo");
+    assert_eq!(data.fn_map[&1].name, "(anonymous_1)");
+
+    let kinds = data
+        .branch_map
+        .values()
+        .map(|b| (b.kind, b.locations.len()))
+        .collect::<Vec<_>>();
+    assert_eq!(kinds, vec![("if", 2), ("binary-expr", 2), ("cond-expr", 2)]);
+    assert!(data.b.values().all(|b| b.iter().all(|&count| count == 0)));
+
+    assert!(code.contains("cov_"));
+    assert!(code.contains("__coverage__"));
+    assert!(code.contains(".f[0]++"));
+    assert!(code.contains(".b[1][1]++"));
+}
+
+#[test]
+fn directives() {
+    let (code, data) = run(
+        "'use strict';
+function foo() {
+    'use strict';
+    'use asm';
+    bar();
+    'not a directive';
+}",
+        None,
+    );
+
+    // `bar()` and the last string literal.
+    assert_eq!(data.s.len(), 2);
+    // The header is inserted after the directive.
+    assert!(code.find("use strict").unwrap() < code.find("cov_").unwrap());
+
+    let body = &code[code.find("function foo()").unwrap()..];
+    let counter = body.find(".f[0]++").unwrap();
+    assert!(body.find("use strict").unwrap() < counter);
+    assert!(body.find("use asm").unwrap() < counter);
+}
+
+#[test]
+fn ignore_next() {
+    let (_, data) = run(
+        "foo();
+/* istanbul ignore next */
+function bar() {
+    baz();
+}
+var a = /* istanbul ignore next */ function () {};",
+        None,
+    );
+
+    assert_eq!(data.s.len(), 1);
+    assert_eq!(data.f.len(), 0);
+}
+
+#[test]
+fn ignore_if_else() {
+    let (code, data) = run(
+        "/* istanbul ignore if */
+if (a) {
+    foo();
+}
+/* istanbul ignore else */
+if (b) {
+    bar();
+} else {
+    baz();
+}",
+        None,
+    );
+
+    assert_eq!(data.branch_map[&0].locations.len(), 1);
+    assert_eq!(data.branch_map[&1].locations.len(), 1);
+    // `foo()`, `baz()` are not counted.
+    assert_eq!(data.s.len(), 3);
+    assert!(!code.contains(".b[0][1]"));
+}
+
+#[test]
+fn locations() {
+    let (_, data) = run("foo();\n  bar(a || b);", None);
+
+    assert_eq!(
+        data.statement_map[&1],
+        Range {
+            start: pos(2, 2),
+            end: pos(2, 14),
+        }
+    );
+    assert_eq!(data.branch_map[&0].locations[1].start, pos(2, 11));
+}
+
+#[test]
+fn input_source_map() {
+    let mut builder = SourceMapBuilder::new(None);
+    let src = builder.add_source("original.ts");
+    builder.add_raw(0, 0, 9, 4, Some(src), None);
+    builder.add_raw(1, 0, 12, 0, Some(src), None);
+    let map = builder.into_sourcemap();
+
+    let (_, data) = run("foo();\nbar();", Some(&map));
+
+    assert_eq!(data.statement_map[&0].start, pos(10, 4));
+    assert_eq!(data.statement_map[&0].end, pos(10, 10));
+    assert_eq!(data.statement_map[&1].start, pos(13, 0));
+    assert_eq!(data.path, "original.ts");
+}
+
+#[test]
+fn function_names() {
+    let (code, data) = run(
+        "function foo(a = () => {}, b = function () {}) {}
+class Foo {
+    c = () => {};
+    static d = function () {};
+}",
+        None,
+    );
+    let code = code.split_whitespace().collect::<String>();
+
+    // Anonymous functions are not wrapped with counters, so they are named
+    // after the parameter or the field.
+    assert!(code.contains("a=()=>{"));
+    assert!(code.contains("b=function(){"));
+    assert!(code.contains("c=()=>{"));
+    assert!(code.contains("d=function(){"));
+    // The counter of `c` is in the constructor.
+    assert!(code.contains("constructor(){"));
+
+    assert_eq!(data.s.len(), 3);
+    assert_eq!(data.f.len(), 5);
+    assert_eq!(data.branch_map.len(), 2);
+}
+
+#[test]
+fn arrow_expression_body() {
+    let (code, data) = run("const f = () => 1;", None);
+
+    assert_eq!(data.f.len(), 1);
+    assert_eq!(data.s.len(), 2);
+    assert!(!code.contains("return"));
+    let code = code.split_whitespace().collect::<String>();
+    assert!(code.contains(".f[0]++,"));
+    assert!(code.contains(".s[1]++,1)"));
+}
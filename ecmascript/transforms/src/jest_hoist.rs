@@ -0,0 +1,374 @@
+use crate::{
+    pass::Pass,
+    util::{
+        prepend_stmts,
+        semantic::{analyze, BindingId, ScopeKind, SemanticModel},
+        StmtLike, HANDLER,
+    },
+};
+use swc_common::{Fold, FoldWith, Span};
+use swc_ecma_ast::*;
+
+#[cfg(test)]
+mod tests;
+
+/// Methods of `jest` which are hoisted.
+const HOIST_METHODS: &[&str] = &[
+    "mock",
+    "unmock",
+    "enableAutomock",
+    "disableAutomock",
+    "deepUnmock",
+];
+
+/// `babel-plugin-jest-hoist`
+///
+/// Moves calls like `jest.mock()` to the top of the enclosing block, so that
+/// modules are mocked before they are required. This should be applied after
+/// the module transforms.
+///
+/// Only calls on the global `jest` and on `jest` imported from
+/// `@jest/globals` are hoisted. The `require()` of `@jest/globals` is hoisted
+/// along with them.
+///
+/// Factories of `jest.mock()` may only reference global variables and
+/// variables prefixed with `mock` (case insensitive), because they are
+/// evaluated before other variables are initialized.
+///
+/// # Example
+///
+/// ## In
+///
+/// ```js
+/// var _foo = require("foo");
+/// jest.mock("foo");
+/// ```
+///
+/// ## Out
+///
+/// ```js
+/// jest.mock("foo");
+/// var _foo = require("foo");
+/// ```
+pub fn jest_hoist() -> impl Pass {
+    JestHoist {
+        model: None,
+        jest: vec![],
+        globals: vec![],
+    }
+}
+
+struct JestHoist {
+    model: Option<SemanticModel>,
+    /// Bindings of `jest` imported from `@jest/globals`.
+    jest: Vec<BindingId>,
+    /// Bindings of `@jest/globals` itself, like `_globals` of
+    /// `_globals.jest.mock()` created by the module transforms.
+    globals: Vec<BindingId>,
+}
+
+noop_fold_type!(JestHoist);
+
+impl Fold<Module> for JestHoist {
+    fn fold(&mut self, m: Module) -> Module {
+        let program = Program::Module(m);
+        self.model = Some(analyze(&program));
+
+        let m = match program {
+            Program::Module(m) => {
+                for item in &m.body {
+                    match *item {
+                        ModuleItem::ModuleDecl(ModuleDecl::Import(ref import)) => {
+                            self.collect_import(import)
+                        }
+                        ModuleItem::Stmt(ref stmt) => self.collect_require(stmt),
+                        _ => {}
+                    }
+                }
+                m.fold_children(self)
+            }
+            _ => unreachable!(),
+        };
+        self.model = None;
+        self.jest.clear();
+        self.globals.clear();
+        m
+    }
+}
+
+impl Fold<Script> for JestHoist {
+    fn fold(&mut self, s: Script) -> Script {
+        let program = Program::Script(s);
+        self.model = Some(analyze(&program));
+
+        let s = match program {
+            Program::Script(s) => {
+                for stmt in &s.body {
+                    self.collect_require(stmt);
+                }
+                s.fold_children(self)
+            }
+            _ => unreachable!(),
+        };
+        self.model = None;
+        self.jest.clear();
+        self.globals.clear();
+        s
+    }
+}
+
+impl<T> Fold<Vec<T>> for JestHoist
+where
+    T: StmtLike + FoldWith<Self>,
+{
+    fn fold(&mut self, items: Vec<T>) -> Vec<T> {
+        let items = items.fold_children(self);
+
+        let mut hoisted = vec![];
+        let mut rest = Vec::with_capacity(items.len());
+        let mut requires = vec![];
+        let has_hoisted = items.iter().any(|item| match item.as_stmt() {
+            Some(Stmt::Expr(ExprStmt { expr, .. })) => self.is_hoisted_call(expr),
+            _ => false,
+        });
+        for item in items {
+            match item.as_stmt() {
+                Some(Stmt::Expr(ExprStmt { expr, .. })) if self.is_hoisted_call(expr) => {
+                    self.check_factories(expr);
+                    hoisted.push(item)
+                }
+                // `_globals.jest.mock()` should not be hoisted above `_globals`.
+                Some(Stmt::Decl(Decl::Var(var))) if has_hoisted && self.requires_globals(var) => {
+                    requires.push(item)
+                }
+                _ => rest.push(item),
+            }
+        }
+
+        prepend_stmts(&mut rest, requires.into_iter().chain(hoisted));
+        rest
+    }
+}
+
+impl JestHoist {
+    fn binding_of(&self, i: &Ident) -> Option<BindingId> {
+        self.model.as_ref().and_then(|model| model.binding_of(i))
+    }
+
+    /// Records `jest` and namespaces imported from `@jest/globals`.
+    fn collect_import(&mut self, import: &ImportDecl) {
+        if &*import.src.value != "@jest/globals" {
+            return;
+        }
+
+        for s in &import.specifiers {
+            let (local, is_jest) = match *s {
+                ImportSpecifier::Named(ref s) => (
+                    &s.local,
+                    &*s.imported.as_ref().unwrap_or(&s.local).sym == "jest",
+                ),
+                ImportSpecifier::Namespace(ref s) => (&s.local, false),
+                ImportSpecifier::Default(..) => continue,
+            };
+
+            if let Some(id) = self.binding_of(local) {
+                if is_jest {
+                    self.jest.push(id);
+                } else {
+                    self.globals.push(id);
+                }
+            }
+        }
+    }
+
+    /// Records variables initialized with `require('@jest/globals')`, which
+    /// is what the module transforms emit for the imports.
+    fn collect_require(&mut self, stmt: &Stmt) {
+        let var = match *stmt {
+            Stmt::Decl(Decl::Var(ref var)) => var,
+            _ => return,
+        };
+
+        for decl in &var.decls {
+            match decl.init {
+                Some(ref init) if is_globals_require(init) => {}
+                _ => continue,
+            }
+
+            match decl.name {
+                Pat::Ident(ref i) => {
+                    if let Some(id) = self.binding_of(i) {
+                        self.globals.push(id);
+                    }
+                }
+                Pat::Object(ref obj) => {
+                    for prop in &obj.props {
+                        let local = match *prop {
+                            ObjectPatProp::Assign(AssignPatProp { ref key, .. })
+                                if &*key.sym == "jest" =>
+                            {
+                                key
+                            }
+                            ObjectPatProp::KeyValue(KeyValuePatProp {
+                                key: PropName::Ident(ref key),
+                                value: box Pat::Ident(ref local),
+                            }) if &*key.sym == "jest" => local,
+                            _ => continue,
+                        };
+                        if let Some(id) = self.binding_of(local) {
+                            self.jest.push(id);
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    /// Returns true if `var` declares only bindings of `@jest/globals`.
+    fn requires_globals(&self, var: &VarDecl) -> bool {
+        !self.globals.is_empty()
+            && var.decls.iter().all(|decl| match decl.name {
+                Pat::Ident(ref i) => self
+                    .binding_of(i)
+                    .map_or(false, |id| self.globals.contains(&id)),
+                _ => false,
+            })
+    }
+
+    /// Returns true if `e` is a call chain like `jest.mock("a").unmock("b")`.
+    fn is_hoisted_call(&self, e: &Expr) -> bool {
+        match split_call(e) {
+            Some((_, obj, method)) if HOIST_METHODS.contains(&&*method.sym) => {
+                self.is_jest(obj) || self.is_hoisted_call(obj)
+            }
+            _ => false,
+        }
+    }
+
+    /// Returns true if `e` is the global `jest` or `jest` of `@jest/globals`.
+    fn is_jest(&self, e: &Expr) -> bool {
+        match *e {
+            Expr::Ident(ref i) if &*i.sym == "jest" => match self.binding_of(i) {
+                Some(id) => self.jest.contains(&id),
+                None => true,
+            },
+            Expr::Member(MemberExpr {
+                obj: ExprOrSuper::Expr(box Expr::Ident(ref obj)),
+                prop: box Expr::Ident(ref prop),
+                computed: false,
+                ..
+            }) if &*prop.sym == "jest" => self
+                .binding_of(obj)
+                .map_or(false, |id| self.globals.contains(&id)),
+            _ => false,
+        }
+    }
+
+    /// Reports out-of-scope variables referenced by factories of
+    /// `jest.mock()` in the call chain `e`.
+    fn check_factories(&self, e: &Expr) {
+        let (call, obj, method) = match split_call(e) {
+            Some(v) => v,
+            None => return,
+        };
+        self.check_factories(obj);
+
+        if &*method.sym != "mock" {
+            return;
+        }
+        let span = match call.args.get(1).map(|arg| &*arg.expr) {
+            Some(Expr::Fn(FnExpr { function, .. })) => function.span,
+            Some(Expr::Arrow(ArrowExpr { span, .. })) => *span,
+            _ => return,
+        };
+        let model = match self.model {
+            Some(ref model) => model,
+            None => return,
+        };
+        let scope = match model
+            .scopes()
+            .find(|(_, scope)| scope.kind == ScopeKind::Fn && scope.span == span)
+        {
+            Some((_, scope)) => scope,
+            None => return,
+        };
+
+        for &id in &scope.captures {
+            let binding = model.binding(id);
+            if binding.name.to_lowercase().starts_with("mock") {
+                continue;
+            }
+
+            let reference = binding
+                .references
+                .iter()
+                .map(|&r| model.reference(r).span)
+                .find(|&r| span.contains(r))
+                .unwrap_or(span);
+            emit_invalid_access(reference, &binding.name);
+        }
+    }
+}
+
+fn emit_invalid_access(span: Span, name: &str) {
+    HANDLER.with(|handler| {
+        handler
+            .struct_span_err(
+                span,
+                "The module factory of `jest.mock()` is not allowed to reference any out-of-scope \
+                 variables",
+            )
+            .note(&format!("Invalid variable access: {}", name))
+            .note(
+                "Variables prefixed with `mock` (case insensitive) are permitted, because the \
+                 factory is evaluated lazily",
+            )
+            .emit()
+    });
+}
+
+/// Returns true if `e` is `require('@jest/globals')`, possibly wrapped by
+/// helpers like `_interopRequireWildcard()`.
+fn is_globals_require(e: &Expr) -> bool {
+    let call = match *e {
+        Expr::Call(ref call) => call,
+        _ => return false,
+    };
+    let arg = match call.args.first() {
+        Some(ExprOrSpread { spread: None, expr }) => &**expr,
+        _ => return false,
+    };
+
+    match call.callee {
+        ExprOrSuper::Expr(box Expr::Ident(ref callee)) if &*callee.sym == "require" => match *arg {
+            Expr::Lit(Lit::Str(ref s)) => &*s.value == "@jest/globals",
+            _ => false,
+        },
+        _ => is_globals_require(arg),
+    }
+}
+
+/// Splits `obj.method()` into the call, `obj` and `method`.
+fn split_call(e: &Expr) -> Option<(&CallExpr, &Expr, &Ident)> {
+    let call = match *e {
+        Expr::Call(ref call) => call,
+        _ => return None,
+    };
+    let member = match call.callee {
+        ExprOrSuper::Expr(ref callee) => match **callee {
+            Expr::Member(ref member) if !member.computed => member,
+            _ => return None,
+        },
+        _ => return None,
+    };
+    let obj = match member.obj {
+        ExprOrSuper::Expr(ref obj) => &**obj,
+        _ => return None,
+    };
+
+    match *member.prop {
+        Expr::Ident(ref method) => Some((call, obj, method)),
+        _ => None,
+    }
+}
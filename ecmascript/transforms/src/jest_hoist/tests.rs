@@ -0,0 +1,78 @@
+use super::jest_hoist;
+use crate::util::HANDLER;
+use swc_common::{FileName, FoldWith};
+use swc_ecma_parser::{Parser, Session, SourceFileInput, Syntax};
+
+/// Returns the errors reported for `src`, or an empty string if there's none.
+fn errors(src: &str) -> String {
+    let res = ::testing::run_test(false, |cm, handler| {
+        let fm = cm.new_source_file(FileName::Anon, src.into());
+        let mut p = Parser::new(
+            Session { handler },
+            Syntax::default(),
+            SourceFileInput::from(&*fm),
+            None,
+        );
+
+        HANDLER.set(handler, || {
+            let m = p.parse_module().map_err(|mut e| e.emit())?;
+            m.fold_with(&mut jest_hoist());
+
+            Ok(())
+        })?;
+
+        if handler.has_errors() {
+            Err(())
+        } else {
+            Ok(())
+        }
+    });
+
+    match res {
+        Ok(()) => String::new(),
+        Err(stderr) => stderr.to_string(),
+    }
+}
+
+#[test]
+fn allowed_references() {
+    assert_eq!(
+        errors(
+            "const mockFoo = 1, MOCK_BAR = 2;
+jest.mock('a', () => {
+    const local = 1;
+    return { mockFoo, MOCK_BAR, local, m: Math, r: require('b') };
+});
+jest.mock('c', function () {
+    return mockFoo;
+});"
+        ),
+        ""
+    );
+}
+
+#[test]
+fn out_of_scope_variable() {
+    let err = errors(
+        "import foo from 'foo';
+jest.mock('a', () => ({ foo }));",
+    );
+
+    assert!(err.contains("not allowed to reference any out-of-scope variables"));
+    assert!(err.contains("Invalid variable access: foo"));
+}
+
+#[test]
+fn chained() {
+    let err = errors(
+        "const bar = 1;
+jest.unmock('a').mock('b', () => bar);",
+    );
+
+    assert!(err.contains("Invalid variable access: bar"));
+}
+
+#[test]
+fn no_factory() {
+    assert_eq!(errors("const foo = 1; jest.mock('a', foo);"), "");
+}
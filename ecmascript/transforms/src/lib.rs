@@ -12,9 +12,13 @@
 extern crate swc_ecma_utils;
 
 pub use self::{
+    assumptions::Assumptions,
     const_modules::const_modules,
+    early_errors::early_errors,
     fixer::fixer,
     hygiene::hygiene,
+    instrument::instrument,
+    jest_hoist::jest_hoist,
     resolver::{resolver, resolver_with_mark},
 };
 
@@ -29,10 +33,15 @@ mod quote;
 pub mod helpers;
 #[macro_use]
 mod hygiene;
+mod assumptions;
 pub mod compat;
 mod const_modules;
 pub mod debug;
+mod early_errors;
 mod fixer;
+pub mod flow;
+pub mod instrument;
+mod jest_hoist;
 pub mod modules;
 pub mod optimization;
 pub mod pass;
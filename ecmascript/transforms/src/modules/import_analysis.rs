@@ -1,4 +1,4 @@
-use super::util::Scope;
+use super::util::{is_json_default_import, Scope};
 use crate::pass::Pass;
 use swc_atoms::js_word;
 use swc_common::{Fold, Visit, VisitWith};
@@ -74,6 +74,8 @@ impl Visit<ImportDecl> for ImportAnalyzer {
         if import.specifiers.is_empty() {
             // import 'foo';
             //   -> require('foo');
+        } else if is_json_default_import(import) {
+            // JSON modules are required without interop.
         } else if import.specifiers.len() == 1
             && match import.specifiers[0] {
                 ImportSpecifier::Namespace(..) => true,
@@ -0,0 +1,303 @@
+use crate::pass::Pass;
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::BTreeMap,
+    path::{Component, Path, PathBuf},
+};
+use swc_atoms::js_word;
+use swc_common::{Fold, FoldWith};
+use swc_ecma_ast::*;
+
+/// Mirrors `compilerOptions.baseUrl` and `compilerOptions.paths` of
+/// `tsconfig.json`.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+pub struct Config {
+    /// Directory used to resolve non-relative module names.
+    #[serde(default)]
+    pub base_url: Option<PathBuf>,
+
+    /// Pattern to list of locations, relative to `base_url` or to the
+    /// directory of the config if `base_url` is not set.
+    ///
+    /// Patterns may contain at most one `*`.
+    #[serde(default)]
+    pub paths: BTreeMap<String, Vec<String>>,
+}
+
+/// Rewrites module specifiers matched by `paths` (or resolvable from
+/// `base_url`) to paths relative to `file`.
+///
+/// This rewrites
+///
+///  - `import 'foo'`
+///  - `export * from 'foo'`
+///  - `export { a } from 'foo'`
+///  - `import('foo')`
+///  - `require('foo')`
+///
+/// and should run before module transforms like `common_js`.
+///
+/// `config_dir` is the directory of the config declaring `paths`, which is
+/// used to resolve them if `base_url` is not set, like `tsc`.
+pub fn paths(config: Config, config_dir: PathBuf, file: PathBuf) -> impl Pass {
+    let paths_base = config.base_url.clone().unwrap_or(config_dir);
+
+    let mut paths = config
+        .paths
+        .into_iter()
+        .map(|(pattern, targets)| {
+            let (prefix, suffix) = match pattern.find('*') {
+                Some(idx) => (
+                    pattern[..idx].to_string(),
+                    Some(pattern[idx + 1..].to_string()),
+                ),
+                None => (pattern, None),
+            };
+
+            PathPattern {
+                prefix,
+                suffix,
+                targets,
+            }
+        })
+        .collect::<Vec<_>>();
+    // Exact matches have priority over wildcards, and longer prefixes have
+    // priority over shorter ones.
+    paths.sort_by(|a, b| {
+        a.suffix
+            .is_some()
+            .cmp(&b.suffix.is_some())
+            .then_with(|| b.prefix.len().cmp(&a.prefix.len()))
+    });
+
+    Paths {
+        base_url: config.base_url,
+        paths_base,
+        paths,
+        file,
+    }
+}
+
+#[derive(Debug)]
+struct PathPattern {
+    prefix: String,
+    /// `None` if the pattern does not contain a `*`.
+    suffix: Option<String>,
+    targets: Vec<String>,
+}
+
+impl PathPattern {
+    /// Returns the part matched by `*`.
+    fn matches<'a>(&self, src: &'a str) -> Option<&'a str> {
+        match self.suffix {
+            None => {
+                if src == self.prefix {
+                    Some("")
+                } else {
+                    None
+                }
+            }
+            Some(ref suffix) => {
+                if src.len() >= self.prefix.len() + suffix.len()
+                    && src.starts_with(&*self.prefix)
+                    && src.ends_with(&**suffix)
+                {
+                    Some(&src[self.prefix.len()..src.len() - suffix.len()])
+                } else {
+                    None
+                }
+            }
+        }
+    }
+}
+
+struct Paths {
+    base_url: Option<PathBuf>,
+    /// Directory used to resolve targets of `paths`.
+    paths_base: PathBuf,
+    paths: Vec<PathPattern>,
+    file: PathBuf,
+}
+
+noop_fold_type!(Paths);
+
+/// Extensions tried while checking if a path points to a module.
+const EXTENSIONS: &[&str] = &["ts", "tsx", "d.ts", "js", "jsx", "json"];
+
+fn exists(path: &Path) -> bool {
+    if path.is_file() {
+        return true;
+    }
+
+    let file_name = match path.file_name() {
+        Some(v) => v.to_string_lossy(),
+        None => return false,
+    };
+
+    EXTENSIONS.iter().any(|ext| {
+        path.with_file_name(format!("{}.{}", file_name, ext))
+            .is_file()
+            || path.join(format!("index.{}", ext)).is_file()
+    })
+}
+
+impl Paths {
+    fn resolve(&self, src: &str) -> Option<PathBuf> {
+        if src.starts_with("./") || src.starts_with("../") || Path::new(src).is_absolute() {
+            return None;
+        }
+
+        for pattern in &self.paths {
+            let matched = match pattern.matches(src) {
+                Some(v) => v,
+                None => continue,
+            };
+
+            let candidates = pattern
+                .targets
+                .iter()
+                .map(|target| self.paths_base.join(target.replacen('*', matched, 1)))
+                .collect::<Vec<_>>();
+
+            // Like tsc, we use the first candidate which exists.
+            return candidates
+                .iter()
+                .find(|path| exists(path))
+                .or_else(|| candidates.first())
+                .cloned();
+        }
+
+        // Non-relative module names are resolved from `baseUrl` only if the
+        // module exists. Otherwise, it's a package.
+        let path = self.base_url.as_ref()?.join(src);
+        if exists(&path) {
+            return Some(path);
+        }
+
+        None
+    }
+
+    fn rewrite(&self, src: Str) -> Str {
+        let path = match self.resolve(&src.value) {
+            Some(v) => v,
+            None => return src,
+        };
+        let dir = self.file.parent().unwrap_or_else(|| Path::new(""));
+        let rel = relative_path(dir, &path);
+
+        let mut value = rel.to_string_lossy().replace('\\', "/");
+        // Emitted files are javascript files.
+        for ext in &[".d.ts", ".ts", ".tsx"] {
+            if value.ends_with(ext) {
+                value.truncate(value.len() - ext.len());
+                break;
+            }
+        }
+        if !value.starts_with("../") {
+            value = format!("./{}", value);
+        }
+
+        Str {
+            value: value.into(),
+            has_escape: false,
+            ..src
+        }
+    }
+}
+
+/// Returns relative path from `base` to `path`.
+fn relative_path(base: &Path, path: &Path) -> PathBuf {
+    let base = normalize(base);
+    let path = normalize(path);
+
+    let common = base
+        .iter()
+        .zip(path.iter())
+        .take_while(|(a, b)| a == b)
+        .count();
+
+    let mut rel = PathBuf::new();
+    for _ in common..base.len() {
+        rel.push("..");
+    }
+    for c in &path[common..] {
+        rel.push(c);
+    }
+
+    rel
+}
+
+/// Removes `.` and `..` from `path`.
+fn normalize(path: &Path) -> Vec<Component> {
+    let mut v: Vec<Component> = vec![];
+
+    for c in path.components() {
+        match c {
+            Component::CurDir => {}
+            Component::ParentDir => match v.last() {
+                Some(Component::Normal(..)) => {
+                    v.pop();
+                }
+                _ => v.push(c),
+            },
+            _ => v.push(c),
+        }
+    }
+
+    v
+}
+
+impl Fold<ImportDecl> for Paths {
+    fn fold(&mut self, import: ImportDecl) -> ImportDecl {
+        ImportDecl {
+            src: self.rewrite(import.src),
+            ..import
+        }
+    }
+}
+
+impl Fold<ExportAll> for Paths {
+    fn fold(&mut self, export: ExportAll) -> ExportAll {
+        ExportAll {
+            src: self.rewrite(export.src),
+            ..export
+        }
+    }
+}
+
+impl Fold<NamedExport> for Paths {
+    fn fold(&mut self, export: NamedExport) -> NamedExport {
+        NamedExport {
+            src: export.src.map(|src| self.rewrite(src)),
+            ..export
+        }
+    }
+}
+
+impl Fold<CallExpr> for Paths {
+    fn fold(&mut self, e: CallExpr) -> CallExpr {
+        let mut e = e.fold_children(self);
+
+        let is_import_or_require = match e.callee {
+            ExprOrSuper::Expr(box Expr::Ident(Ident { ref sym, .. })) => {
+                *sym == js_word!("import") || *sym == js_word!("require")
+            }
+            _ => false,
+        };
+        if !is_import_or_require {
+            return e;
+        }
+
+        // The second argument of `import()` is options like `{ with: { .. } }`.
+        match e.args.first_mut() {
+            Some(ExprOrSpread {
+                spread: None,
+                expr: box Expr::Lit(Lit::Str(ref mut src)),
+            }) => *src = self.rewrite(src.clone()),
+            _ => {}
+        }
+
+        e
+    }
+}
@@ -301,6 +301,29 @@ impl Scope {
             if &*import.src.value != "@swc/helpers" {
                 self.import_types.insert(import.src.value, true);
             }
+        } else if is_json_default_import(&import) {
+            // import data from './data.json' with { type: 'json' };
+            //   -> var _dataJson = require('./data.json');
+            //
+            // The default export of a JSON module is the parsed value itself, so
+            // `data` refers to the result of `require` without interop.
+            self.imports
+                .entry(import.src.value.clone())
+                .or_insert_with(|| {
+                    Some((
+                        local_name_for_src(&import.src.value),
+                        import.src.span.apply_mark(Mark::fresh(Mark::root())),
+                    ))
+                });
+
+            for s in import.specifiers {
+                if let ImportSpecifier::Default(i) = s {
+                    self.idents.insert(
+                        (i.local.sym.clone(), i.local.span.ctxt()),
+                        (import.src.value.clone(), "".into()),
+                    );
+                }
+            }
         } else {
             self.imports
                 .entry(import.src.value.clone())
@@ -663,6 +686,40 @@ pub(super) fn make_require_call(mark: Mark, src: JsWord) -> Expr {
     })
 }
 
+/// Returns true for `import data from './data.json' with { type: 'json' }` (or
+/// the `assert` form).
+pub(super) fn is_json_default_import(import: &ImportDecl) -> bool {
+    let attrs = match import.attrs {
+        Some(ref attrs) => attrs,
+        None => return false,
+    };
+
+    if import.specifiers.is_empty()
+        || import.specifiers.iter().any(|s| match *s {
+            ImportSpecifier::Default(..) => false,
+            _ => true,
+        })
+    {
+        return false;
+    }
+
+    attrs.obj.props.iter().any(|prop| match *prop {
+        PropOrSpread::Prop(box Prop::KeyValue(KeyValueProp {
+            ref key,
+            value: box Expr::Lit(Lit::Str(Str { ref value, .. })),
+        })) => {
+            let key = match *key {
+                PropName::Ident(ref i) => &i.sym,
+                PropName::Str(ref s) => &s.value,
+                _ => return false,
+            };
+
+            *key == js_word!("type") && &**value == "json"
+        }
+        _ => false,
+    })
+}
+
 pub(super) fn local_name_for_src(src: &JsWord) -> JsWord {
     if !src.contains('/') {
         return format!("_{}", src.to_camel_case()).into();
@@ -1,10 +1,13 @@
 pub use self::{
     class_properties::class_properties, decorators::decorators, export::export,
+    function_bind::function_bind, import_attributes::import_attributes,
     nullish_coalescing::nullish_coalescing, opt_chaining::optional_chaining,
 };
 
-mod class_properties;
+pub mod class_properties;
 pub mod decorators;
 mod export;
-mod nullish_coalescing;
-mod opt_chaining;
+mod function_bind;
+mod import_attributes;
+pub mod nullish_coalescing;
+pub mod opt_chaining;
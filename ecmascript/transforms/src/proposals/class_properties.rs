@@ -1,6 +1,6 @@
 use self::{
     class_name_tdz::ClassNameTdzFolder,
-    private_field::FieldAccessFolder,
+    private_field::{FieldAccessFolder, PrivateInCollector},
     this_in_static::ThisInStaticFolder,
     used_name::{UsedNameCollector, UsedNameRenamer},
 };
@@ -12,6 +12,7 @@ use crate::{
         undefined, ExprFactory, ModuleItemLike, StmtLike,
     },
 };
+use serde::Deserialize;
 use std::collections::HashSet;
 use swc_atoms::JsWord;
 use swc_common::{Fold, FoldWith, Mark, Spanned, VisitWith, DUMMY_SP};
@@ -29,12 +30,29 @@ mod used_name;
 /// # Impl note
 ///
 /// We use custom helper to handle export defaul class
-pub fn class_properties() -> impl Pass {
-    ClassProperties { mark: Mark::root() }
+pub fn class_properties(c: Config) -> impl Pass {
+    ClassProperties {
+        c,
+        mark: Mark::root(),
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Config {
+    /// Assign public fields instead of using `_defineProperty`.
+    #[serde(default)]
+    pub set_public_fields: bool,
+
+    /// Store private fields as non-enumerable properties with unique keys
+    /// instead of in `WeakMap`s.
+    #[serde(default)]
+    pub private_as_properties: bool,
 }
 
 #[derive(Clone)]
 struct ClassProperties {
+    c: Config,
     mark: Mark,
 }
 
@@ -83,6 +101,7 @@ where
                                             .into()],
                                             src: None,
                                             type_only: false,
+                                            attrs: None,
                                         },
                                     )) {
                                         Ok(t) => t,
@@ -266,13 +285,63 @@ impl ClassProperties {
         let mut used_names = vec![];
         let mut used_key_names = vec![];
         let mut statics = HashSet::default();
+        let mut private_methods = HashSet::default();
+        let mut private_in = HashSet::default();
+        class.body.visit_with(&mut PrivateInCollector {
+            names: &mut private_in,
+        });
 
         for member in class.body {
             match member {
                 ClassMember::PrivateMethod(..) | ClassMember::TsIndexSignature(..) => {
+                    if let ClassMember::PrivateMethod(ref m) = member {
+                        if m.is_static {
+                            statics.insert(m.key.id.sym.clone());
+                        }
+                        private_methods.insert(m.key.id.sym.clone());
+                    }
                     members.push(member)
                 }
 
+                ClassMember::StaticBlock(StaticBlock { body, .. }) => {
+                    // `static { ... }` is evaluated in place of a static field,
+                    // with `this` bound to the class.
+                    let body = body
+                        .fold_with(&mut SuperFieldAccessFolder {
+                            class_name: &ident,
+                            vars: &mut vars,
+                            constructor_this_mark: None,
+                            is_static: true,
+                            folding_constructor: false,
+                            in_injected_define_property_call: false,
+                            in_nested_scope: false,
+                            this_alias_mark: None,
+                            constant_super: false,
+                        })
+                        .fold_with(&mut ThisInStaticFolder {
+                            ident: ident.clone(),
+                        });
+
+                    extra_stmts.push(
+                        CallExpr {
+                            span: DUMMY_SP,
+                            callee: ArrowExpr {
+                                span: DUMMY_SP,
+                                params: vec![],
+                                body: BlockStmtOrExpr::BlockStmt(body),
+                                is_async: false,
+                                is_generator: false,
+                                type_params: Default::default(),
+                                return_type: Default::default(),
+                            }
+                            .as_callee(),
+                            args: vec![],
+                            type_args: Default::default(),
+                        }
+                        .into_stmt(),
+                    )
+                }
+
                 ClassMember::Method(method) => {
                     // we handle computed key here to preserve the execution order
                     let key = match method.key {
@@ -315,6 +384,10 @@ impl ClassProperties {
                         });
                     }
 
+                    let ident_key = match *prop.key {
+                        Expr::Ident(ref i) if !prop.computed => Some(i.clone()),
+                        _ => None,
+                    };
                     let key = match *prop.key {
                         Expr::Ident(ref i) if !prop.computed => Lit::Str(Str {
                             span: i.span,
@@ -348,44 +421,61 @@ impl ClassProperties {
                         }
                     };
 
-                    let value = prop.value.unwrap_or_else(|| undefined(prop_span)).as_arg();
-
-                    let callee = helper!(define_property, "defineProperty");
+                    let value = prop.value.unwrap_or_else(|| undefined(prop_span));
+                    let value = if prop.is_static {
+                        value
+                            .fold_with(&mut SuperFieldAccessFolder {
+                                class_name: &ident,
+                                vars: &mut vars,
+                                constructor_this_mark: None,
+                                is_static: true,
+                                folding_constructor: false,
+                                in_injected_define_property_call: false,
+                                in_nested_scope: false,
+                                this_alias_mark: None,
+                                constant_super: false,
+                            })
+                            .fold_with(&mut ThisInStaticFolder {
+                                ident: ident.clone(),
+                            })
+                    } else {
+                        value
+                    };
+                    let obj = if prop.is_static {
+                        Expr::Ident(ident.clone())
+                    } else {
+                        Expr::This(ThisExpr { span: DUMMY_SP })
+                    };
 
-                    if prop.is_static {
-                        extra_stmts.push(
-                            CallExpr {
+                    let expr = if self.c.set_public_fields {
+                        // this.foo = value
+                        Expr::Assign(AssignExpr {
+                            span: DUMMY_SP,
+                            left: PatOrExpr::Expr(box Expr::Member(MemberExpr {
                                 span: DUMMY_SP,
-                                callee,
-                                args: vec![
-                                    ident.clone().as_arg(),
-                                    key,
-                                    value
-                                        .fold_with(&mut SuperFieldAccessFolder {
-                                            class_name: &ident,
-                                            vars: &mut vars,
-                                            constructor_this_mark: None,
-                                            is_static: true,
-                                            folding_constructor: false,
-                                            in_injected_define_property_call: false,
-                                            in_nested_scope: false,
-                                            this_alias_mark: None,
-                                        })
-                                        .fold_with(&mut ThisInStaticFolder {
-                                            ident: ident.clone(),
-                                        }),
-                                ],
-                                type_args: Default::default(),
-                            }
-                            .into_stmt(),
-                        )
+                                obj: obj.as_obj(),
+                                computed: ident_key.is_none(),
+                                prop: match ident_key {
+                                    Some(i) => box Expr::Ident(i),
+                                    None => key.expr,
+                                },
+                            })),
+                            op: op!("="),
+                            right: value,
+                        })
                     } else {
-                        constructor_exprs.push(box Expr::Call(CallExpr {
+                        Expr::Call(CallExpr {
                             span: DUMMY_SP,
-                            callee,
-                            args: vec![ThisExpr { span: DUMMY_SP }.as_arg(), key, value],
+                            callee: helper!(define_property, "defineProperty"),
+                            args: vec![obj.as_arg(), key, value.as_arg()],
                             type_args: Default::default(),
-                        }));
+                        })
+                    };
+
+                    if prop.is_static {
+                        extra_stmts.push(expr.into_stmt())
+                    } else {
+                        constructor_exprs.push(box expr);
                     }
                 }
                 ClassMember::PrivateProp(prop) => {
@@ -394,7 +484,7 @@ impl ClassProperties {
                         statics.insert(prop.key.id.sym.clone());
                     }
 
-                    let ident = Ident::new(
+                    let private_ident = Ident::new(
                         format!("_{}", prop.key.id.sym).into(),
                         // We use `self.mark` for private variables.
                         prop.key.span.apply_mark(self.mark),
@@ -404,6 +494,75 @@ impl ClassProperties {
                     });
                     let value = prop.value.unwrap_or_else(|| undefined(prop_span));
 
+                    if self.c.private_as_properties {
+                        // var _x = _classPrivateFieldLooseKey("x");
+                        extra_stmts.push(Stmt::Decl(Decl::Var(VarDecl {
+                            span: DUMMY_SP,
+                            kind: VarDeclKind::Var,
+                            declare: false,
+                            decls: vec![VarDeclarator {
+                                span: DUMMY_SP,
+                                definite: false,
+                                name: Pat::Ident(private_ident.clone()),
+                                init: Some(box Expr::Call(CallExpr {
+                                    span: DUMMY_SP,
+                                    callee: helper!(
+                                        class_private_field_loose_key,
+                                        "classPrivateFieldLooseKey"
+                                    ),
+                                    args: vec![Lit::Str(Str {
+                                        span: prop.key.id.span,
+                                        value: prop.key.id.sym.clone(),
+                                        has_escape: false,
+                                    })
+                                    .as_arg()],
+                                    type_args: Default::default(),
+                                })),
+                            }],
+                        })));
+
+                        // Object.defineProperty(this, _x, { writable: true, value: value })
+                        let define = Expr::Call(CallExpr {
+                            span: DUMMY_SP,
+                            callee: quote_ident!("Object")
+                                .member(quote_ident!("defineProperty"))
+                                .as_callee(),
+                            args: vec![
+                                if prop.is_static {
+                                    ident.clone().as_arg()
+                                } else {
+                                    ThisExpr { span: DUMMY_SP }.as_arg()
+                                },
+                                private_ident.as_arg(),
+                                ObjectLit {
+                                    span: DUMMY_SP,
+                                    props: vec![
+                                        PropOrSpread::Prop(box Prop::KeyValue(KeyValueProp {
+                                            key: PropName::Ident(quote_ident!("writable")),
+                                            value: box Expr::Lit(Lit::Bool(Bool {
+                                                span: DUMMY_SP,
+                                                value: true,
+                                            })),
+                                        })),
+                                        PropOrSpread::Prop(box Prop::KeyValue(KeyValueProp {
+                                            key: PropName::Ident(quote_ident!("value")),
+                                            value,
+                                        })),
+                                    ],
+                                }
+                                .as_arg(),
+                            ],
+                            type_args: Default::default(),
+                        });
+
+                        if prop.is_static {
+                            extra_stmts.push(define.into_stmt());
+                        } else {
+                            constructor_exprs.push(box define);
+                        }
+                        continue;
+                    }
+
                     let extra_init = if prop.is_static {
                         box Expr::Object(ObjectLit {
                             span: DUMMY_SP,
@@ -424,7 +583,10 @@ impl ClassProperties {
                     } else {
                         constructor_exprs.push(box Expr::Call(CallExpr {
                             span: DUMMY_SP,
-                            callee: ident.clone().member(quote_ident!("set")).as_callee(),
+                            callee: private_ident
+                                .clone()
+                                .member(quote_ident!("set"))
+                                .as_callee(),
                             args: vec![
                                 ThisExpr { span: DUMMY_SP }.as_arg(),
                                 ObjectLit {
@@ -465,7 +627,7 @@ impl ClassProperties {
                         decls: vec![VarDeclarator {
                             span: DUMMY_SP,
                             definite: false,
-                            name: Pat::Ident(ident.clone()),
+                            name: Pat::Ident(private_ident),
                             init: Some(extra_init),
                         }],
                     })));
@@ -475,6 +637,49 @@ impl ClassProperties {
             }
         }
 
+        // `#m in obj` can't be lowered to a `WeakMap` lookup for methods, so
+        // instances are added to a `WeakSet` before their fields are
+        // initialized.
+        let brand = if private_methods
+            .iter()
+            .any(|m| !statics.contains(m) && private_in.contains(m))
+        {
+            let brand = private_ident!(format!("_{}_brand", ident.sym));
+
+            extra_stmts.insert(
+                0,
+                Stmt::Decl(Decl::Var(VarDecl {
+                    span: DUMMY_SP,
+                    kind: VarDeclKind::Var,
+                    declare: false,
+                    decls: vec![VarDeclarator {
+                        span: DUMMY_SP,
+                        definite: false,
+                        name: Pat::Ident(brand.clone()),
+                        init: Some(box Expr::New(NewExpr {
+                            span: DUMMY_SP,
+                            callee: box Expr::Ident(quote_ident!("WeakSet")),
+                            args: Some(vec![]),
+                            type_args: Default::default(),
+                        })),
+                    }],
+                })),
+            );
+            constructor_exprs.insert(
+                0,
+                box Expr::Call(CallExpr {
+                    span: DUMMY_SP,
+                    callee: brand.clone().member(quote_ident!("add")).as_callee(),
+                    args: vec![ThisExpr { span: DUMMY_SP }.as_arg()],
+                    type_args: Default::default(),
+                }),
+            );
+
+            Some(brand)
+        } else {
+            None
+        };
+
         let constructor =
             self.process_constructor(constructor, has_super, &used_names, constructor_exprs);
         if let Some(c) = constructor {
@@ -484,9 +689,23 @@ impl ClassProperties {
         let members = members.fold_with(&mut FieldAccessFolder {
             mark: self.mark,
             statics: &statics,
+            private_methods: &private_methods,
+            brand: brand.as_ref(),
+            vars: vec![],
+            class_name: &ident,
+            in_assign_pat: false,
+            private_as_properties: self.c.private_as_properties,
+        });
+        // Initializers of static fields may access private fields.
+        let extra_stmts = extra_stmts.fold_with(&mut FieldAccessFolder {
+            mark: self.mark,
+            statics: &statics,
+            private_methods: &private_methods,
+            brand: brand.as_ref(),
             vars: vec![],
             class_name: &ident,
             in_assign_pat: false,
+            private_as_properties: self.c.private_as_properties,
         });
 
         (
@@ -1,7 +1,7 @@
 use crate::util::{alias_ident_for, alias_if_required, prepend, ExprFactory};
 use std::{collections::HashSet, iter, mem};
 use swc_atoms::JsWord;
-use swc_common::{Fold, FoldWith, Mark, Spanned, DUMMY_SP};
+use swc_common::{Fold, FoldWith, Mark, Span, Spanned, Visit, VisitWith, DUMMY_SP};
 use swc_ecma_ast::*;
 
 pub(super) struct FieldAccessFolder<'a> {
@@ -9,13 +9,24 @@ pub(super) struct FieldAccessFolder<'a> {
     pub class_name: &'a Ident,
     pub vars: Vec<VarDeclarator>,
     pub statics: &'a HashSet<JsWord>,
+    /// Private methods are not lowered by this pass.
+    pub private_methods: &'a HashSet<JsWord>,
+    /// `WeakSet` of instances with private methods, used for `#m in obj`.
+    pub brand: Option<&'a Ident>,
     pub in_assign_pat: bool,
+    /// Private fields are non-enumerable properties with keys created by
+    /// `_classPrivateFieldLooseKey`.
+    pub private_as_properties: bool,
 }
 
 noop_fold_type!(FieldAccessFolder<'_>);
 
 impl<'a> Fold<Expr> for FieldAccessFolder<'a> {
     fn fold(&mut self, e: Expr) -> Expr {
+        if self.private_as_properties {
+            return self.fold_loose(e);
+        }
+
         match e {
             Expr::Update(UpdateExpr {
                 span,
@@ -208,10 +219,11 @@ impl<'a> Fold<Expr> for FieldAccessFolder<'a> {
 
                 let var = alias_ident_for(&obj, "_ref");
 
-                let this = if match *obj {
+                let is_this = match *obj {
                     Expr::This(..) => true,
                     _ => false,
-                } {
+                };
+                let this = if is_this {
                     ThisExpr { span: DUMMY_SP }.as_arg()
                 } else if op == op!("=") {
                     obj.as_arg()
@@ -231,8 +243,51 @@ impl<'a> Fold<Expr> for FieldAccessFolder<'a> {
                     .as_arg()
                 };
 
-                let value = if op == op!("=") {
-                    right.as_arg()
+                let logical_op = match op {
+                    op!("&&=") => Some(op!("&&")),
+                    op!("||=") => Some(op!("||")),
+                    op!("??=") => Some(op!("??")),
+                    _ => None,
+                };
+                // `obj.#x ||= v` => `get(_ref = obj, _x) || set(_ref, _x, v)`, so
+                // neither `v` nor the setter is evaluated if the value is kept.
+                let (get, this) = match logical_op {
+                    Some(..) => {
+                        let get = if is_static {
+                            Expr::Call(CallExpr {
+                                span: DUMMY_SP,
+                                callee: helper!(
+                                    class_static_private_field_spec_get,
+                                    "classStaticPrivateFieldSpecGet"
+                                ),
+                                args: vec![
+                                    this,
+                                    self.class_name.clone().as_arg(),
+                                    ident.clone().as_arg(),
+                                ],
+                                type_args: Default::default(),
+                            })
+                        } else {
+                            Expr::Call(CallExpr {
+                                span: DUMMY_SP,
+                                callee: helper!(class_private_field_get, "classPrivateFieldGet"),
+                                args: vec![this, ident.clone().as_arg()],
+                                type_args: Default::default(),
+                            })
+                        };
+                        let this = if is_this {
+                            ThisExpr { span: DUMMY_SP }.as_arg()
+                        } else {
+                            var.clone().as_arg()
+                        };
+
+                        (Some(get), this)
+                    }
+                    None => (None, this),
+                };
+
+                let value = if op == op!("=") || logical_op.is_some() {
+                    right.fold_with(self).as_arg()
                 } else {
                     let left = box self.fold_private_get(left, Some(var)).0;
 
@@ -240,7 +295,7 @@ impl<'a> Fold<Expr> for FieldAccessFolder<'a> {
                         span: DUMMY_SP,
                         left,
                         op: match op {
-                            op!("=") => unreachable!(),
+                            op!("=") | op!("&&=") | op!("||=") | op!("??=") => unreachable!(),
 
                             op!("+=") => op!(bin, "+"),
                             op!("-=") => op!(bin, "-"),
@@ -260,7 +315,7 @@ impl<'a> Fold<Expr> for FieldAccessFolder<'a> {
                     .as_arg()
                 };
 
-                if is_static {
+                let set = if is_static {
                     Expr::Call(CallExpr {
                         span: DUMMY_SP,
                         callee: helper!(
@@ -286,6 +341,16 @@ impl<'a> Fold<Expr> for FieldAccessFolder<'a> {
 
                         type_args: Default::default(),
                     })
+                };
+
+                match (logical_op, get) {
+                    (Some(op), Some(get)) => Expr::Bin(BinExpr {
+                        span,
+                        left: box get,
+                        op,
+                        right: box set,
+                    }),
+                    _ => set,
                 }
             }
 
@@ -333,6 +398,48 @@ impl<'a> Fold<Expr> for FieldAccessFolder<'a> {
                 }
             }
             Expr::Member(e) => self.fold_private_get(e, None).0,
+
+            // `#x in obj`
+            Expr::Bin(BinExpr {
+                span,
+                op: op!("in"),
+                left: box Expr::PrivateName(n),
+                right,
+            }) => {
+                if self.private_methods.contains(&n.id.sym) {
+                    return self.fold_private_method_in(span, &n, right);
+                }
+
+                let right = box Expr::Call(CallExpr {
+                    span: DUMMY_SP,
+                    callee: helper!(check_in_rhs, "checkInRHS"),
+                    args: vec![right.fold_with(self).as_arg()],
+                    type_args: Default::default(),
+                });
+
+                if self.statics.contains(&n.id.sym) {
+                    // Only the class itself has static private fields.
+                    Expr::Bin(BinExpr {
+                        span,
+                        op: op!("==="),
+                        left: right,
+                        right: box Expr::Ident(self.class_name.clone()),
+                    })
+                } else {
+                    let ident = Ident::new(
+                        format!("_{}", n.id.sym).into(),
+                        n.id.span.apply_mark(self.mark),
+                    );
+
+                    Expr::Call(CallExpr {
+                        span,
+                        callee: ident.member(quote_ident!("has")).as_callee(),
+                        args: vec![right.as_arg()],
+                        type_args: Default::default(),
+                    })
+                }
+            }
+
             _ => e.fold_children(self),
         }
     }
@@ -361,6 +468,107 @@ impl Fold<Pat> for FieldAccessFolder<'_> {
 }
 
 impl<'a> FieldAccessFolder<'a> {
+    /// Private fields are plain properties in loose mode, so only the access
+    /// itself is replaced.
+    ///
+    /// - `obj.#x` => `_classPrivateFieldLooseBase(obj, _x)[_x]`
+    /// - `#x in obj` => `Object.prototype.hasOwnProperty.call(obj, _x)`
+    fn fold_loose(&mut self, e: Expr) -> Expr {
+        match e {
+            Expr::Member(MemberExpr {
+                span,
+                obj: ExprOrSuper::Expr(obj),
+                prop: box Expr::PrivateName(n),
+                ..
+            }) if !self.private_methods.contains(&n.id.sym) => {
+                let ident = Ident::new(
+                    format!("_{}", n.id.sym).into(),
+                    n.id.span.apply_mark(self.mark),
+                );
+
+                Expr::Member(MemberExpr {
+                    span,
+                    obj: CallExpr {
+                        span: DUMMY_SP,
+                        callee: helper!(
+                            class_private_field_loose_base,
+                            "classPrivateFieldLooseBase"
+                        ),
+                        args: vec![obj.fold_with(self).as_arg(), ident.clone().as_arg()],
+                        type_args: Default::default(),
+                    }
+                    .as_obj(),
+                    prop: box Expr::Ident(ident),
+                    computed: true,
+                })
+            }
+
+            Expr::Bin(BinExpr {
+                span,
+                op: op!("in"),
+                left: box Expr::PrivateName(n),
+                right,
+            }) => {
+                if self.private_methods.contains(&n.id.sym) {
+                    return self.fold_private_method_in(span, &n, right);
+                }
+
+                let ident = Ident::new(
+                    format!("_{}", n.id.sym).into(),
+                    n.id.span.apply_mark(self.mark),
+                );
+
+                Expr::Call(CallExpr {
+                    span,
+                    callee: quote_ident!("Object")
+                        .member(quote_ident!("prototype"))
+                        .member(quote_ident!("hasOwnProperty"))
+                        .member(quote_ident!("call"))
+                        .as_callee(),
+                    args: vec![right.fold_with(self).as_arg(), ident.as_arg()],
+                    type_args: Default::default(),
+                })
+            }
+
+            _ => e.fold_children(self),
+        }
+    }
+
+    /// Private methods stay in the class, so `#m in obj` is checked against
+    /// the class itself for static methods and against the brand of the
+    /// class for instance methods.
+    ///
+    /// - `#m in obj` => `_brand.has(_checkInRHS(obj))`
+    fn fold_private_method_in(&mut self, span: Span, n: &PrivateName, right: Box<Expr>) -> Expr {
+        let right = box Expr::Call(CallExpr {
+            span: DUMMY_SP,
+            callee: helper!(check_in_rhs, "checkInRHS"),
+            args: vec![right.fold_with(self).as_arg()],
+            type_args: Default::default(),
+        });
+
+        if self.statics.contains(&n.id.sym) {
+            return Expr::Bin(BinExpr {
+                span,
+                op: op!("==="),
+                left: right,
+                right: box Expr::Ident(self.class_name.clone()),
+            });
+        }
+
+        let brand = self
+            .brand
+            .expect("classes with `#m in obj` should have a brand")
+            .clone();
+
+        Expr::Call(CallExpr {
+            span,
+            callee: brand.member(quote_ident!("has")).as_callee(),
+            args: vec![right.as_arg()],
+            type_args: Default::default(),
+        })
+    }
+
     /// Returns `(expr, thisObject)`
     ///
     ///   - `obj_alias`: If alias is already declared, this method will use
@@ -535,3 +743,25 @@ macro_rules! take_vars {
 
 take_vars!(Function);
 take_vars!(Constructor);
+
+/// Collects private names used in `#x in obj`.
+pub(super) struct PrivateInCollector<'a> {
+    pub names: &'a mut HashSet<JsWord>,
+}
+
+noop_visit_type!(PrivateInCollector<'_>);
+
+impl Visit<BinExpr> for PrivateInCollector<'_> {
+    fn visit(&mut self, e: &BinExpr) {
+        if let BinExpr {
+            op: op!("in"),
+            left: box Expr::PrivateName(ref n),
+            ..
+        } = *e
+        {
+            self.names.insert(n.id.sym.clone());
+        }
+
+        e.visit_children(self);
+    }
+}
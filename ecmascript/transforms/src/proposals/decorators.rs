@@ -1,17 +1,20 @@
-use self::legacy::Legacy;
+use self::{decorator_2022_03::Decorator202203, legacy::Legacy};
 use crate::{
     pass::Pass,
     util::{
         alias_ident_for, constructor::inject_after_super, prop_name_to_expr_value, undefined,
-        ExprFactory, IdentExt,
+        ExprFactory, IdentExt, HANDLER,
     },
 };
 use either::Either;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use std::iter;
-use swc_common::{Fold, FoldWith, Spanned, Visit, VisitWith, DUMMY_SP};
+use swc_common::{
+    util::move_map::MoveMap, Fold, FoldWith, Span, Spanned, Visit, VisitWith, DUMMY_SP,
+};
 use swc_ecma_ast::*;
 
+mod decorator_2022_03;
 mod legacy;
 mod usage;
 
@@ -59,15 +62,40 @@ pub fn decorators(c: Config) -> impl Pass {
     if c.legacy {
         Either::Left(Legacy::default())
     } else {
-        Either::Right(Decorators {
-            is_in_strict: false,
-        })
+        match c.version {
+            DecoratorVersion::V201809 => Either::Right(Either::Left(Decorators {
+                is_in_strict: false,
+            })),
+            DecoratorVersion::V202203 => Either::Right(Either::Right(Decorator202203::default())),
+        }
     }
 }
 
 #[derive(Debug, Default, Deserialize)]
 pub struct Config {
     pub legacy: bool,
+
+    /// Ignored if `legacy` is true.
+    #[serde(default)]
+    pub version: DecoratorVersion,
+}
+
+/// Version of the decorators proposal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DecoratorVersion {
+    /// Stage 2 decorators, built on the `_decorate` helper.
+    #[serde(rename = "2018-09")]
+    V201809,
+
+    /// Stage 3 decorators with `context` objects and `accessor` fields.
+    #[serde(rename = "2022-03")]
+    V202203,
+}
+
+impl Default for DecoratorVersion {
+    fn default() -> Self {
+        DecoratorVersion::V201809
+    }
 }
 
 #[derive(Debug, Default)]
@@ -137,6 +165,7 @@ impl Fold<Vec<ModuleItem>> for Decorators {
                             .into()],
                             src: None,
                             type_only: false,
+                            attrs: None,
                         },
                     )));
                 }
@@ -308,6 +337,17 @@ impl Decorators {
             }
         };
 
+        // Static blocks are not class elements, so they are evaluated once
+        // `_decorate` has finished the class.
+        let mut static_blocks = vec![];
+        class.body = class.body.move_flat_map(|member| match member {
+            ClassMember::StaticBlock(block) => {
+                static_blocks.push(block);
+                None
+            }
+            _ => Some(member),
+        });
+
         macro_rules! fold_method {
             ($method:expr, $fn_name:expr, $key_prop_value:expr) => {{
                 let fn_name = $fn_name;
@@ -392,6 +432,7 @@ impl Decorators {
                 match member {
                     ClassMember::Constructor(_) => unreachable!("multiple constructor?"),
                     ClassMember::TsIndexSignature(_) => None,
+                    ClassMember::StaticBlock(_) => unreachable!("static blocks are removed above"),
                     ClassMember::Method(method) => {
                         let fn_name = match method.key {
                             PropName::Ident(ref i) => Some(i.clone()),
@@ -507,7 +548,8 @@ impl Decorators {
             .map(Some)
             .collect();
 
-        Expr::Call(make_decorate_call(
+        let class_ident = ident.clone();
+        let decorate_call = Expr::Call(make_decorate_call(
             class.decorators,
             iter::once({
                 // function(_initialize) {}
@@ -576,7 +618,84 @@ impl Decorators {
                 .as_arg()
             })
             .chain(super_class_expr.map(|e| e.as_arg())),
-        ))
+        ));
+
+        if static_blocks.is_empty() {
+            return decorate_call;
+        }
+
+        // function (A) {
+        //   (function () { ... }).call(A);
+        //   return A;
+        // }(_decorate(...))
+        let stmts = static_blocks
+            .into_iter()
+            .map(|block| {
+                if let Some(span) = find_super(&block.body) {
+                    HANDLER.with(|handler| {
+                        handler
+                            .struct_span_err(
+                                span,
+                                "`super` in static blocks of decorated classes is not supported",
+                            )
+                            .emit()
+                    });
+                }
+
+                CallExpr {
+                    span: block.span,
+                    callee: FnExpr {
+                        ident: None,
+                        function: Function {
+                            span: DUMMY_SP,
+                            params: vec![],
+                            decorators: vec![],
+                            body: Some(block.body),
+                            is_async: false,
+                            is_generator: false,
+                            type_params: Default::default(),
+                            return_type: Default::default(),
+                        },
+                    }
+                    .member(quote_ident!("call"))
+                    .as_callee(),
+                    args: vec![class_ident.clone().as_arg()],
+                    type_args: Default::default(),
+                }
+                .into_stmt()
+            })
+            .chain(iter::once(Stmt::Return(ReturnStmt {
+                span: DUMMY_SP,
+                arg: Some(box Expr::Ident(class_ident.clone())),
+            })))
+            .collect();
+
+        Expr::Call(CallExpr {
+            span: DUMMY_SP,
+            callee: FnExpr {
+                ident: None,
+                function: Function {
+                    span: DUMMY_SP,
+                    params: vec![Param {
+                        span: DUMMY_SP,
+                        decorators: vec![],
+                        pat: Pat::Ident(class_ident),
+                    }],
+                    decorators: vec![],
+                    body: Some(BlockStmt {
+                        span: DUMMY_SP,
+                        stmts,
+                    }),
+                    is_async: false,
+                    is_generator: false,
+                    type_params: Default::default(),
+                    return_type: Default::default(),
+                },
+            }
+            .as_callee(),
+            args: vec![decorate_call.as_arg()],
+            type_args: Default::default(),
+        })
     }
 }
 
@@ -612,6 +731,32 @@ impl Visit<Decorator> for DecoratorFinder {
     }
 }
 
+/// Finds `super` which refers to the home object of a static block.
+struct SuperFinder {
+    found: Option<Span>,
+}
+
+impl Visit<Super> for SuperFinder {
+    fn visit(&mut self, s: &Super) {
+        self.found = Some(s.span);
+    }
+}
+
+/// Functions and classes have their own `super`.
+impl Visit<Function> for SuperFinder {
+    fn visit(&mut self, _: &Function) {}
+}
+
+impl Visit<Class> for SuperFinder {
+    fn visit(&mut self, _: &Class) {}
+}
+
+fn find_super(body: &BlockStmt) -> Option<Span> {
+    let mut v = SuperFinder { found: None };
+    body.visit_with(&mut v);
+    v.found
+}
+
 fn contains_decorator<N>(node: &N) -> bool
 where
     N: VisitWith<DecoratorFinder>,
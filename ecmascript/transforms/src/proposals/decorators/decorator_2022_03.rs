@@ -0,0 +1,1032 @@
+use crate::util::{
+    constructor::inject_after_super, default_constructor, undefined, ExprFactory, StmtLike,
+};
+use std::{collections::HashSet, mem::replace};
+use swc_atoms::JsWord;
+use swc_common::{Fold, FoldWith, Span, Spanned, DUMMY_SP};
+use swc_ecma_ast::*;
+
+/// Decorators as specified by the 2022-03 version of the proposal.
+///
+/// # Example
+///
+/// ## In
+///
+/// ```js
+/// class Foo {
+///   @dec a = 1;
+///   @dec m() {}
+///   @dec accessor b;
+/// }
+/// ```
+///
+/// ## Out
+///
+/// ```js
+/// var _init_a, _init_b, _initProto;
+/// let Foo = class Foo {
+///   static #_ = [_init_a, _init_b, _initProto] = _applyDecs2203(Foo, [
+///     [dec, 0, "a"],
+///     [dec, 2, "m"],
+///     [dec, 1, "b"]
+///   ], []);
+///   a = (_initProto(this), _init_a(this, 1));
+///   m() {}
+///   #_b = _init_b(this);
+///   get b() { return this.#_b; }
+///   set b(v) { this.#_b = v; }
+/// };
+/// ```
+///
+/// `accessor` fields are lowered even if they are not decorated.
+#[derive(Debug, Default)]
+pub(super) struct Decorator202203 {
+    /// Hoisted variables, declared right before the statement containing the
+    /// class, so each evaluation of the class gets its own temporaries.
+    vars: Vec<VarDeclarator>,
+    exports: Vec<ExportSpecifier>,
+}
+
+noop_fold_type!(Decorator202203);
+
+/// Kinds understood by the `applyDecs2203` helper.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum DecoratorKind {
+    Field = 0,
+    Accessor = 1,
+    Method = 2,
+    Getter = 3,
+    Setter = 4,
+}
+
+impl From<MethodKind> for DecoratorKind {
+    fn from(kind: MethodKind) -> Self {
+        match kind {
+            MethodKind::Method => DecoratorKind::Method,
+            MethodKind::Getter => DecoratorKind::Getter,
+            MethodKind::Setter => DecoratorKind::Setter,
+        }
+    }
+}
+
+impl Fold<Module> for Decorator202203 {
+    fn fold(&mut self, m: Module) -> Module {
+        let mut m = m.fold_children(self);
+
+        if !self.exports.is_empty() {
+            m.body.push(
+                ModuleDecl::ExportNamed(NamedExport {
+                    span: DUMMY_SP,
+                    specifiers: replace(&mut self.exports, Default::default()),
+                    src: None,
+                    type_only: false,
+                    attrs: None,
+                })
+                .into(),
+            );
+        }
+
+        m
+    }
+}
+
+impl<T> Fold<Vec<T>> for Decorator202203
+where
+    T: FoldWith<Self> + StmtLike,
+{
+    fn fold(&mut self, stmts: Vec<T>) -> Vec<T> {
+        let old = replace(&mut self.vars, Default::default());
+        let mut buf = Vec::with_capacity(stmts.len() + 1);
+
+        for stmt in stmts {
+            let stmt = stmt.fold_with(self);
+
+            if !self.vars.is_empty() {
+                buf.push(T::from_stmt(Stmt::Decl(Decl::Var(VarDecl {
+                    span: DUMMY_SP,
+                    kind: VarDeclKind::Var,
+                    decls: replace(&mut self.vars, Default::default()),
+                    declare: false,
+                }))));
+            }
+
+            buf.push(stmt);
+        }
+
+        self.vars = old;
+        buf
+    }
+}
+
+/// Temporaries of a class in the expression body of an arrow function are
+/// declared in the body, which is converted to a block statement.
+impl Fold<BlockStmtOrExpr> for Decorator202203 {
+    fn fold(&mut self, body: BlockStmtOrExpr) -> BlockStmtOrExpr {
+        match body {
+            BlockStmtOrExpr::Expr(expr) => {
+                let old = replace(&mut self.vars, Default::default());
+                let expr = expr.fold_with(self);
+                let vars = replace(&mut self.vars, old);
+
+                if vars.is_empty() {
+                    return BlockStmtOrExpr::Expr(expr);
+                }
+
+                BlockStmtOrExpr::BlockStmt(BlockStmt {
+                    span: expr.span(),
+                    stmts: vec![
+                        Stmt::Decl(Decl::Var(VarDecl {
+                            span: DUMMY_SP,
+                            kind: VarDeclKind::Var,
+                            decls: vars,
+                            declare: false,
+                        })),
+                        Stmt::Return(ReturnStmt {
+                            span: DUMMY_SP,
+                            arg: Some(expr),
+                        }),
+                    ],
+                })
+            }
+            _ => body.fold_children(self),
+        }
+    }
+}
+
+impl Fold<ModuleItem> for Decorator202203 {
+    fn fold(&mut self, item: ModuleItem) -> ModuleItem {
+        let item: ModuleItem = item.fold_children(self);
+
+        match item {
+            ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultDecl(ExportDefaultDecl {
+                decl: DefaultDecl::Class(ClassExpr { ident, class }),
+                span,
+            })) => {
+                if !should_transform(&class) {
+                    return ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultDecl(
+                        ExportDefaultDecl {
+                            span,
+                            decl: DefaultDecl::Class(ClassExpr { ident, class }),
+                        },
+                    ));
+                }
+
+                let export_ident = ident.clone().unwrap_or_else(|| private_ident!("_class"));
+                let expr = self.handle(Some(export_ident.clone()), class);
+
+                self.exports
+                    .push(ExportSpecifier::Named(ExportNamedSpecifier {
+                        span: DUMMY_SP,
+                        orig: export_ident.clone(),
+                        exported: Some(quote_ident!("default")),
+                    }));
+
+                ModuleItem::Stmt(Stmt::Decl(Decl::Var(VarDecl {
+                    span,
+                    kind: VarDeclKind::Let,
+                    declare: false,
+                    decls: vec![VarDeclarator {
+                        span: DUMMY_SP,
+                        name: Pat::Ident(export_ident),
+                        init: Some(expr),
+                        definite: false,
+                    }],
+                })))
+            }
+
+            _ => item,
+        }
+    }
+}
+
+impl Fold<Decl> for Decorator202203 {
+    fn fold(&mut self, decl: Decl) -> Decl {
+        let decl: Decl = decl.fold_children(self);
+
+        match decl {
+            Decl::Class(ClassDecl {
+                ident,
+                class,
+                declare: false,
+            }) if should_transform(&class) => {
+                let expr = self.handle(Some(ident.clone()), class);
+
+                Decl::Var(VarDecl {
+                    span: DUMMY_SP,
+                    kind: VarDeclKind::Let,
+                    declare: false,
+                    decls: vec![VarDeclarator {
+                        span: DUMMY_SP,
+                        name: Pat::Ident(ident),
+                        init: Some(expr),
+                        definite: false,
+                    }],
+                })
+            }
+
+            _ => decl,
+        }
+    }
+}
+
+impl Fold<Expr> for Decorator202203 {
+    fn fold(&mut self, e: Expr) -> Expr {
+        let e: Expr = e.fold_children(self);
+
+        match e {
+            Expr::Class(ClassExpr { ident, class }) if should_transform(&class) => {
+                *self.handle(ident, class)
+            }
+
+            _ => e,
+        }
+    }
+}
+
+/// Returns true if `class` has a decorator or an `accessor` field.
+fn should_transform(class: &Class) -> bool {
+    !class.decorators.is_empty()
+        || class.body.iter().any(|member| match *member {
+            ClassMember::ClassProp(ref p) => p.is_accessor || !p.decorators.is_empty(),
+            ClassMember::PrivateProp(ref p) => p.is_accessor || !p.decorators.is_empty(),
+            ClassMember::Method(ref m) => !m.function.decorators.is_empty(),
+            ClassMember::PrivateMethod(ref m) => !m.function.decorators.is_empty(),
+            _ => false,
+        })
+}
+
+/// State used while transforming a class.
+struct ClassState {
+    ident: Ident,
+    /// Expressions evaluated before the class.
+    pre: Vec<Box<Expr>>,
+    /// Private names declared in the class.
+    private_names: HashSet<JsWord>,
+    dec_infos: Vec<Option<ExprOrSpread>>,
+    /// Elements of the array pattern used to store outputs of
+    /// `applyDecs2203`.
+    outputs: Vec<Option<Pat>>,
+    has_proto_init: bool,
+    has_static_init: bool,
+}
+
+impl ClassState {
+    /// Returns `#base`, or `#base2`, `#base3`, ... if it's already used.
+    fn private_name(&mut self, base: &str) -> PrivateName {
+        let mut name: JsWord = base.into();
+        let mut i = 2;
+        while self.private_names.contains(&name) {
+            name = format!("{}{}", base, i).into();
+            i += 1;
+        }
+        self.private_names.insert(name.clone());
+
+        PrivateName {
+            span: DUMMY_SP,
+            id: Ident::new(name, DUMMY_SP),
+        }
+    }
+
+    /// `this` for instance elements, and the class itself for static
+    /// elements.
+    fn receiver(&self, is_static: bool) -> Box<Expr> {
+        if is_static {
+            box Expr::Ident(self.ident.clone())
+        } else {
+            box Expr::This(ThisExpr { span: DUMMY_SP })
+        }
+    }
+
+    fn add_output(&mut self, ident: &Ident) {
+        self.outputs.push(Some(Pat::Ident(ident.clone())));
+    }
+
+    fn add_dec_info(
+        &mut self,
+        decs: Box<Expr>,
+        kind: DecoratorKind,
+        is_static: bool,
+        name: Box<Expr>,
+        extra: Vec<Box<Expr>>,
+    ) {
+        let kind = kind as u8 + if is_static { 5 } else { 0 };
+
+        if kind % 5 != DecoratorKind::Field as u8 {
+            if is_static {
+                self.has_static_init = true;
+            } else {
+                self.has_proto_init = true;
+            }
+        }
+
+        self.dec_infos.push(Some(
+            ArrayLit {
+                span: DUMMY_SP,
+                elems: vec![
+                    Some(decs.as_arg()),
+                    Some(
+                        Lit::Num(Number {
+                            span: DUMMY_SP,
+                            value: kind as f64,
+                        })
+                        .as_arg(),
+                    ),
+                    Some(name.as_arg()),
+                ]
+                .into_iter()
+                .chain(extra.into_iter().map(|e| Some(e.as_arg())))
+                .collect(),
+            }
+            .as_arg(),
+        ));
+    }
+}
+
+impl Decorator202203 {
+    fn declare(&mut self, ident: Ident) -> Ident {
+        self.vars.push(VarDeclarator {
+            span: DUMMY_SP,
+            name: Pat::Ident(ident.clone()),
+            init: None,
+            definite: false,
+        });
+
+        ident
+    }
+
+    /// Evaluates `expr` before the class, and returns a reference to the
+    /// value.
+    fn hoist(&mut self, state: &mut ClassState, expr: Box<Expr>, name: &str) -> Box<Expr> {
+        match *expr {
+            Expr::Ident(..) | Expr::Lit(..) => expr,
+            _ => {
+                let ident = self.declare(private_ident!(name));
+                state.pre.push(box Expr::Assign(AssignExpr {
+                    span: DUMMY_SP,
+                    op: op!("="),
+                    left: PatOrExpr::Pat(box Pat::Ident(ident.clone())),
+                    right: expr,
+                }));
+
+                box Expr::Ident(ident)
+            }
+        }
+    }
+
+    /// Returns the decorator if there's only one decorator, or an array of
+    /// decorators.
+    fn decorators(&mut self, state: &mut ClassState, decorators: Vec<Decorator>) -> Box<Expr> {
+        let mut decs = decorators
+            .into_iter()
+            .map(|dec| self.hoist(state, dec.expr, "_dec"))
+            .collect::<Vec<_>>();
+
+        if decs.len() == 1 {
+            return decs.remove(0);
+        }
+
+        box Expr::Array(ArrayLit {
+            span: DUMMY_SP,
+            elems: decs.into_iter().map(|dec| Some(dec.as_arg())).collect(),
+        })
+    }
+
+    fn handle(&mut self, ident: Option<Ident>, mut class: Class) -> Box<Expr> {
+        let ident = ident.unwrap_or_else(|| private_ident!("_class"));
+
+        let private_names = class
+            .body
+            .iter()
+            .filter_map(|member| match *member {
+                ClassMember::PrivateProp(ref p) => Some(p.key.id.sym.clone()),
+                ClassMember::PrivateMethod(ref m) => Some(m.key.id.sym.clone()),
+                _ => None,
+            })
+            .collect();
+
+        let mut state = ClassState {
+            ident: ident.clone(),
+            pre: vec![],
+            private_names,
+            dec_infos: vec![],
+            outputs: vec![],
+            has_proto_init: false,
+            has_static_init: false,
+        };
+
+        // Class decorators are evaluated first.
+        let class_decs = replace(&mut class.decorators, vec![])
+            .into_iter()
+            .map(|dec| Some(self.hoist(&mut state, dec.expr, "_dec").as_arg()))
+            .collect::<Vec<_>>();
+
+        let mut body = Vec::with_capacity(class.body.len());
+        for member in replace(&mut class.body, vec![]) {
+            self.handle_member(&mut state, member, &mut body);
+        }
+
+        if state.has_proto_init {
+            let init_proto = self.declare(private_ident!("_initProto"));
+            state.add_output(&init_proto);
+            inject_proto_init(&mut body, init_proto, class.super_class.is_some());
+        }
+
+        let init_static = if state.has_static_init {
+            let init_static = self.declare(private_ident!("_initStatic"));
+            state.add_output(&init_static);
+            Some(init_static)
+        } else {
+            None
+        };
+
+        let class_init = if class_decs.is_empty() {
+            None
+        } else {
+            let new_class = self.declare(private_ident!(format!("_{}", ident.sym)));
+            let init_class = self.declare(private_ident!("_initClass"));
+            state.add_output(&new_class);
+            state.add_output(&init_class);
+            Some((new_class, init_class))
+        };
+
+        if !state.dec_infos.is_empty() || class_init.is_some() {
+            // static #_ = ([...] = _applyDecs2203(Foo, [...], [...]), _initStatic(Foo));
+            let apply = box Expr::Assign(AssignExpr {
+                span: DUMMY_SP,
+                op: op!("="),
+                left: PatOrExpr::Pat(box Pat::Array(ArrayPat {
+                    span: DUMMY_SP,
+                    elems: replace(&mut state.outputs, vec![]),
+                    optional: false,
+                    type_ann: None,
+                })),
+                right: box Expr::Call(CallExpr {
+                    span: DUMMY_SP,
+                    callee: helper!(apply_decs_2203, "applyDecs2203"),
+                    args: vec![
+                        ident.clone().as_arg(),
+                        ArrayLit {
+                            span: DUMMY_SP,
+                            elems: replace(&mut state.dec_infos, vec![]),
+                        }
+                        .as_arg(),
+                        ArrayLit {
+                            span: DUMMY_SP,
+                            elems: class_decs,
+                        }
+                        .as_arg(),
+                    ],
+                    type_args: Default::default(),
+                }),
+            });
+            let value = match init_static {
+                Some(init_static) => box Expr::Seq(SeqExpr {
+                    span: DUMMY_SP,
+                    exprs: vec![
+                        apply,
+                        box Expr::Call(CallExpr {
+                            span: DUMMY_SP,
+                            callee: init_static.as_callee(),
+                            args: vec![ident.clone().as_arg()],
+                            type_args: Default::default(),
+                        }),
+                    ],
+                }),
+                None => apply,
+            };
+
+            body.insert(
+                0,
+                ClassMember::PrivateProp(PrivateProp {
+                    span: DUMMY_SP,
+                    key: state.private_name("_"),
+                    value: Some(value),
+                    type_ann: None,
+                    is_static: true,
+                    decorators: vec![],
+                    computed: false,
+                    accessibility: None,
+                    is_abstract: false,
+                    is_optional: false,
+                    readonly: false,
+                    definite: false,
+                    is_accessor: false,
+                }),
+            );
+        }
+
+        let class = box Expr::Class(ClassExpr {
+            ident: Some(ident),
+            class: Class { body, ..class },
+        });
+
+        let mut exprs = state.pre;
+        exprs.push(class);
+        if let Some((new_class, init_class)) = class_init {
+            exprs.push(box Expr::Call(CallExpr {
+                span: DUMMY_SP,
+                callee: init_class.as_callee(),
+                args: vec![],
+                type_args: Default::default(),
+            }));
+            exprs.push(box Expr::Ident(new_class));
+        }
+
+        if exprs.len() == 1 {
+            return exprs.remove(0);
+        }
+
+        box Expr::Seq(SeqExpr {
+            span: DUMMY_SP,
+            exprs,
+        })
+    }
+
+    fn handle_member(
+        &mut self,
+        state: &mut ClassState,
+        member: ClassMember,
+        body: &mut Vec<ClassMember>,
+    ) {
+        match member {
+            ClassMember::ClassProp(mut p) => {
+                if p.decorators.is_empty() && !p.is_accessor {
+                    body.push(ClassMember::ClassProp(p));
+                    return;
+                }
+
+                let decs = if p.decorators.is_empty() {
+                    None
+                } else {
+                    Some(self.decorators(state, replace(&mut p.decorators, vec![])))
+                };
+
+                let simple = match *p.key {
+                    Expr::Ident(ref i) if !p.computed => Some((
+                        box Expr::Lit(Lit::Str(Str {
+                            span: i.span,
+                            value: i.sym.clone(),
+                            has_escape: false,
+                        })),
+                        PropName::Ident(i.clone()),
+                    )),
+                    Expr::Lit(Lit::Str(ref s)) if !p.computed => {
+                        Some((box Expr::Lit(Lit::Str(s.clone())), PropName::Str(s.clone())))
+                    }
+                    Expr::Lit(Lit::Num(n)) if !p.computed => {
+                        Some((box Expr::Lit(Lit::Num(n)), PropName::Num(n)))
+                    }
+                    _ => None,
+                };
+                let (name, key) = match simple {
+                    Some(v) => v,
+                    None => {
+                        let key = self.hoist(
+                            state,
+                            replace(&mut p.key, undefined(DUMMY_SP)),
+                            "_computedKey",
+                        );
+                        p.key = key.clone();
+                        p.computed = true;
+
+                        (
+                            key.clone(),
+                            PropName::Computed(ComputedPropName {
+                                span: DUMMY_SP,
+                                expr: key,
+                            }),
+                        )
+                    }
+                };
+                let hint = name_hint(&name);
+
+                let init = decs.map(|decs| {
+                    let init = self.declare(private_ident!(format!("_init_{}", hint)));
+                    state.add_dec_info(
+                        decs,
+                        if p.is_accessor {
+                            DecoratorKind::Accessor
+                        } else {
+                            DecoratorKind::Field
+                        },
+                        p.is_static,
+                        name,
+                        vec![],
+                    );
+                    state.add_output(&init);
+                    init
+                });
+                let value = match init {
+                    Some(init) => Some(init_call(init, state.receiver(p.is_static), p.value)),
+                    None => p.value,
+                };
+
+                if !p.is_accessor {
+                    body.push(ClassMember::ClassProp(ClassProp {
+                        value,
+                        decorators: vec![],
+                        ..p
+                    }));
+                    return;
+                }
+
+                let storage = state.private_name(&format!("_{}", hint));
+                body.push(storage_prop(p.span, storage.clone(), value, p.is_static));
+
+                let get = getter_fn(this_member(storage.clone()));
+                let set = setter_fn(|v| assign_stmt(this_member(storage), v));
+                body.push(ClassMember::Method(ClassMethod {
+                    span: p.span,
+                    key: key.clone(),
+                    function: get,
+                    kind: MethodKind::Getter,
+                    is_static: p.is_static,
+                    accessibility: p.accessibility,
+                    is_abstract: false,
+                    is_optional: false,
+                }));
+                body.push(ClassMember::Method(ClassMethod {
+                    span: p.span,
+                    key,
+                    function: set,
+                    kind: MethodKind::Setter,
+                    is_static: p.is_static,
+                    accessibility: p.accessibility,
+                    is_abstract: false,
+                    is_optional: false,
+                }));
+            }
+
+            ClassMember::PrivateProp(mut p) => {
+                if p.decorators.is_empty() && !p.is_accessor {
+                    body.push(ClassMember::PrivateProp(p));
+                    return;
+                }
+
+                let decs = if p.decorators.is_empty() {
+                    None
+                } else {
+                    Some(self.decorators(state, replace(&mut p.decorators, vec![])))
+                };
+                let sym = p.key.id.sym.clone();
+                let name = box Expr::Lit(Lit::Str(Str {
+                    span: p.key.id.span,
+                    value: sym.clone(),
+                    has_escape: false,
+                }));
+
+                if !p.is_accessor {
+                    if let Some(decs) = decs {
+                        let init = self.declare(private_ident!(format!("_init_{}", sym)));
+                        state.add_dec_info(
+                            decs,
+                            DecoratorKind::Field,
+                            p.is_static,
+                            name,
+                            vec![
+                                box Expr::Fn(FnExpr {
+                                    ident: None,
+                                    function: getter_fn(this_member(p.key.clone())),
+                                }),
+                                box Expr::Fn(FnExpr {
+                                    ident: None,
+                                    function: setter_fn(|v| {
+                                        assign_stmt(this_member(p.key.clone()), v)
+                                    }),
+                                }),
+                            ],
+                        );
+                        state.add_output(&init);
+                        p.value = Some(init_call(init, state.receiver(p.is_static), p.value));
+                    }
+
+                    body.push(ClassMember::PrivateProp(p));
+                    return;
+                }
+
+                let storage = state.private_name(&format!("_{}", sym));
+                let (value, get, set) = match decs {
+                    Some(decs) => {
+                        let init = self.declare(private_ident!(format!("_init_{}", sym)));
+                        let get = self.declare(private_ident!(format!("_get_{}", sym)));
+                        let set = self.declare(private_ident!(format!("_set_{}", sym)));
+                        state.add_dec_info(
+                            decs,
+                            DecoratorKind::Accessor,
+                            p.is_static,
+                            name,
+                            vec![
+                                box Expr::Fn(FnExpr {
+                                    ident: None,
+                                    function: getter_fn(this_member(storage.clone())),
+                                }),
+                                box Expr::Fn(FnExpr {
+                                    ident: None,
+                                    function: setter_fn(|v| {
+                                        assign_stmt(this_member(storage.clone()), v)
+                                    }),
+                                }),
+                            ],
+                        );
+                        state.add_output(&init);
+                        state.add_output(&get);
+                        state.add_output(&set);
+
+                        // get #a() { return _get_a(this); }
+                        // set #a(v) { _set_a(this, v); }
+                        (
+                            Some(init_call(init, state.receiver(p.is_static), p.value)),
+                            getter_fn(call(get, vec![this()])),
+                            setter_fn(|v| call(set, vec![this(), v]).into_stmt()),
+                        )
+                    }
+                    None => (
+                        p.value,
+                        getter_fn(this_member(storage.clone())),
+                        setter_fn(|v| assign_stmt(this_member(storage.clone()), v)),
+                    ),
+                };
+
+                body.push(storage_prop(p.span, storage, value, p.is_static));
+                body.push(ClassMember::PrivateMethod(PrivateMethod {
+                    span: p.span,
+                    key: p.key.clone(),
+                    function: get,
+                    kind: MethodKind::Getter,
+                    is_static: p.is_static,
+                    accessibility: None,
+                    is_abstract: false,
+                    is_optional: false,
+                }));
+                body.push(ClassMember::PrivateMethod(PrivateMethod {
+                    span: p.span,
+                    key: p.key,
+                    function: set,
+                    kind: MethodKind::Setter,
+                    is_static: p.is_static,
+                    accessibility: None,
+                    is_abstract: false,
+                    is_optional: false,
+                }));
+            }
+
+            ClassMember::Method(mut m) => {
+                if m.function.decorators.is_empty() {
+                    body.push(ClassMember::Method(m));
+                    return;
+                }
+
+                let decs = self.decorators(state, replace(&mut m.function.decorators, vec![]));
+                let name = match m.key {
+                    PropName::Ident(ref i) => box Expr::Lit(Lit::Str(Str {
+                        span: i.span,
+                        value: i.sym.clone(),
+                        has_escape: false,
+                    })),
+                    PropName::Str(ref s) => box Expr::Lit(Lit::Str(s.clone())),
+                    PropName::Num(n) => box Expr::Lit(Lit::Num(n)),
+                    PropName::Computed(ref mut c) => {
+                        let key = self.hoist(
+                            state,
+                            replace(&mut c.expr, undefined(DUMMY_SP)),
+                            "_computedKey",
+                        );
+                        c.expr = key.clone();
+                        key
+                    }
+                };
+
+                state.add_dec_info(decs, m.kind.into(), m.is_static, name, vec![]);
+                body.push(ClassMember::Method(m));
+            }
+
+            ClassMember::PrivateMethod(mut m) => {
+                if m.function.decorators.is_empty() {
+                    body.push(ClassMember::PrivateMethod(m));
+                    return;
+                }
+
+                let decs = self.decorators(state, replace(&mut m.function.decorators, vec![]));
+                let sym = m.key.id.sym.clone();
+                let name = box Expr::Lit(Lit::Str(Str {
+                    span: m.key.id.span,
+                    value: sym.clone(),
+                    has_escape: false,
+                }));
+                let call_ident = self.declare(private_ident!(format!("_call_{}", sym)));
+
+                let original = replace(
+                    &mut m.function,
+                    getter_fn(box Expr::Ident(call_ident.clone())),
+                );
+                state.add_dec_info(
+                    decs,
+                    m.kind.into(),
+                    m.is_static,
+                    name,
+                    vec![box Expr::Fn(FnExpr {
+                        ident: None,
+                        function: original,
+                    })],
+                );
+                state.add_output(&call_ident);
+
+                match m.kind {
+                    // get #m() { return _call_m; }
+                    MethodKind::Method => {
+                        m.kind = MethodKind::Getter;
+                    }
+                    // get #g() { return _call_g(this); }
+                    MethodKind::Getter => {
+                        m.function = getter_fn(call(call_ident, vec![this()]));
+                    }
+                    // set #s(v) { _call_s(this, v); }
+                    MethodKind::Setter => {
+                        m.function = setter_fn(|v| call(call_ident, vec![this(), v]).into_stmt());
+                    }
+                }
+
+                body.push(ClassMember::PrivateMethod(m));
+            }
+
+            _ => body.push(member),
+        }
+    }
+}
+
+/// Calls `_initProto(this)` before initializing the first instance field, or
+/// in the constructor.
+fn inject_proto_init(body: &mut Vec<ClassMember>, init_proto: Ident, has_super: bool) {
+    let init_call = box Expr::Call(CallExpr {
+        span: DUMMY_SP,
+        callee: init_proto.as_callee(),
+        args: vec![this().as_arg()],
+        type_args: Default::default(),
+    });
+
+    for member in body.iter_mut() {
+        let value = match *member {
+            ClassMember::ClassProp(ClassProp {
+                is_static: false,
+                ref mut value,
+                ..
+            })
+            | ClassMember::PrivateProp(PrivateProp {
+                is_static: false,
+                ref mut value,
+                ..
+            }) => value,
+            _ => continue,
+        };
+
+        let prev = value.take().unwrap_or_else(|| undefined(DUMMY_SP));
+        *value = Some(box Expr::Seq(SeqExpr {
+            span: DUMMY_SP,
+            exprs: vec![init_call, prev],
+        }));
+        return;
+    }
+
+    let pos = body.iter().position(|member| match *member {
+        ClassMember::Constructor(Constructor { body: Some(..), .. }) => true,
+        _ => false,
+    });
+
+    match pos {
+        Some(pos) => {
+            let c = match body.remove(pos) {
+                ClassMember::Constructor(c) => c,
+                _ => unreachable!(),
+            };
+            body.insert(
+                pos,
+                ClassMember::Constructor(inject_after_super(c, vec![init_call])),
+            );
+        }
+        None => body.push(ClassMember::Constructor(inject_after_super(
+            default_constructor(has_super),
+            vec![init_call],
+        ))),
+    }
+}
+
+/// Creates a valid identifier from the name of an element.
+fn name_hint(name: &Expr) -> String {
+    let s = match *name {
+        Expr::Lit(Lit::Str(ref s)) => s.value.to_string(),
+        Expr::Lit(Lit::Num(ref n)) => n.value.to_string(),
+        Expr::Ident(ref i) => i.sym.to_string(),
+        _ => String::new(),
+    };
+    let s = s
+        .chars()
+        .filter(|c| c.is_ascii_alphanumeric() || *c == '_' || *c == '$')
+        .collect::<String>();
+
+    if s.is_empty() {
+        "key".into()
+    } else {
+        s
+    }
+}
+
+fn this() -> Box<Expr> {
+    box Expr::This(ThisExpr { span: DUMMY_SP })
+}
+
+fn this_member(name: PrivateName) -> Box<Expr> {
+    box ThisExpr { span: DUMMY_SP }.member(name)
+}
+
+fn call(callee: Ident, args: Vec<Box<Expr>>) -> Box<Expr> {
+    box Expr::Call(CallExpr {
+        span: DUMMY_SP,
+        callee: callee.as_callee(),
+        args: args.into_iter().map(|arg| arg.as_arg()).collect(),
+        type_args: Default::default(),
+    })
+}
+
+/// `_init_a(this, value)`
+fn init_call(init: Ident, receiver: Box<Expr>, value: Option<Box<Expr>>) -> Box<Expr> {
+    call(init, Some(receiver).into_iter().chain(value).collect())
+}
+
+fn assign_stmt(left: Box<Expr>, right: Box<Expr>) -> Stmt {
+    Expr::Assign(AssignExpr {
+        span: DUMMY_SP,
+        op: op!("="),
+        left: PatOrExpr::Expr(left),
+        right,
+    })
+    .into_stmt()
+}
+
+fn storage_prop(
+    span: Span,
+    key: PrivateName,
+    value: Option<Box<Expr>>,
+    is_static: bool,
+) -> ClassMember {
+    ClassMember::PrivateProp(PrivateProp {
+        span,
+        key,
+        value,
+        type_ann: None,
+        is_static,
+        decorators: vec![],
+        computed: false,
+        accessibility: None,
+        is_abstract: false,
+        is_optional: false,
+        readonly: false,
+        definite: false,
+        is_accessor: false,
+    })
+}
+
+/// `function () { return value; }`
+fn getter_fn(value: Box<Expr>) -> Function {
+    Function {
+        span: DUMMY_SP,
+        params: vec![],
+        decorators: vec![],
+        body: Some(BlockStmt {
+            span: DUMMY_SP,
+            stmts: vec![Stmt::Return(ReturnStmt {
+                span: DUMMY_SP,
+                arg: Some(value),
+            })],
+        }),
+        is_generator: false,
+        is_async: false,
+        type_params: None,
+        return_type: None,
+    }
+}
+
+/// `function (v) { stmt }`
+fn setter_fn<F>(op: F) -> Function
+where
+    F: FnOnce(Box<Expr>) -> Stmt,
+{
+    let v = private_ident!("v");
+
+    Function {
+        span: DUMMY_SP,
+        params: vec![Param {
+            span: DUMMY_SP,
+            decorators: vec![],
+            pat: Pat::Ident(v.clone()),
+        }],
+        decorators: vec![],
+        body: Some(BlockStmt {
+            span: DUMMY_SP,
+            stmts: vec![op(box Expr::Ident(v))],
+        }),
+        is_generator: false,
+        is_async: false,
+        type_params: None,
+        return_type: None,
+    }
+}
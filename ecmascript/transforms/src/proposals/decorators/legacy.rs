@@ -40,6 +40,7 @@ impl Fold<Module> for Legacy {
                 specifiers: replace(&mut self.exports, Default::default()),
                 src: None,
                 type_only: false,
+                attrs: None,
             });
 
             m.body.push(decl.into());
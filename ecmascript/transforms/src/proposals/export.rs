@@ -49,6 +49,7 @@ impl Fold<Vec<ModuleItem>> for ExportDefaultFrom {
                                     .clone()
                                     .expect("`export default from` requires source"),
                                 type_only: false,
+                                attrs: export.attrs.clone(),
                             })));
                             extra_stmts.push(ModuleItem::ModuleDecl(ModuleDecl::ExportNamed(
                                 NamedExport {
@@ -62,6 +63,7 @@ impl Fold<Vec<ModuleItem>> for ExportDefaultFrom {
                                     )],
                                     src: None,
                                     type_only: false,
+                                    attrs: None,
                                 },
                             )));
                         }
@@ -81,6 +83,7 @@ impl Fold<Vec<ModuleItem>> for ExportDefaultFrom {
                                     .clone()
                                     .expect("`export default from` requires source"),
                                 type_only: false,
+                                attrs: export.attrs.clone(),
                             })));
                             extra_stmts.push(ModuleItem::ModuleDecl(ModuleDecl::ExportNamed(
                                 NamedExport {
@@ -94,6 +97,7 @@ impl Fold<Vec<ModuleItem>> for ExportDefaultFrom {
                                     )],
                                     src: None,
                                     type_only: false,
+                                    attrs: None,
                                 },
                             )));
                         }
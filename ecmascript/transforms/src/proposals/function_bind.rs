@@ -0,0 +1,216 @@
+use crate::{
+    pass::Pass,
+    util::{prepend, ExprFactory, StmtLike},
+};
+use std::{fmt::Debug, iter, mem};
+use swc_common::{Fold, FoldWith, Span, Spanned, DUMMY_SP};
+use swc_ecma_ast::*;
+
+/// `@babel/plugin-proposal-function-bind`
+///
+/// # Example
+///
+/// ## In
+///
+/// ```js
+/// obj::func;
+/// obj::func(val);
+/// ::obj.func;
+/// ::obj.func(val);
+/// ```
+///
+/// ## Out
+///
+/// ```js
+/// func.bind(obj);
+/// func.call(obj, val);
+/// obj.func.bind(obj);
+/// obj.func.call(obj, val);
+/// ```
+pub fn function_bind() -> impl Pass {
+    FnBind::default()
+}
+
+#[derive(Debug, Default)]
+struct FnBind {
+    vars: Vec<VarDeclarator>,
+}
+
+noop_fold_type!(FnBind);
+
+impl<T> Fold<Vec<T>> for FnBind
+where
+    T: Debug + StmtLike + FoldWith<Self>,
+{
+    fn fold(&mut self, stmts: Vec<T>) -> Vec<T> {
+        // This is to support nested block statements
+        let old = mem::replace(&mut self.vars, vec![]);
+
+        let mut stmts = stmts.fold_children(self);
+
+        if !self.vars.is_empty() {
+            prepend(
+                &mut stmts,
+                T::from_stmt(Stmt::Decl(Decl::Var(VarDecl {
+                    span: DUMMY_SP,
+                    declare: false,
+                    kind: VarDeclKind::Var,
+                    decls: mem::replace(&mut self.vars, vec![]),
+                }))),
+            );
+        }
+
+        self.vars = old;
+        stmts
+    }
+}
+
+impl Fold<Expr> for FnBind {
+    fn fold(&mut self, e: Expr) -> Expr {
+        match e {
+            // obj::func(arg) => func.call(obj, arg)
+            Expr::Call(CallExpr {
+                span,
+                callee: ExprOrSuper::Expr(box Expr::Bind(bind)),
+                args,
+                type_args,
+            }) => {
+                let bind = bind.fold_children(self);
+                let args = args.fold_with(self);
+                let (callee, context) = self.infer_context(bind);
+
+                Expr::Call(CallExpr {
+                    span,
+                    callee: callee.member(quote_ident!("call")).as_callee(),
+                    args: iter::once(context.as_arg()).chain(args).collect(),
+                    type_args,
+                })
+            }
+
+            // obj::func => func.bind(obj)
+            Expr::Bind(bind) => {
+                let bind = bind.fold_children(self);
+                let span = bind.span;
+                let (callee, context) = self.infer_context(bind);
+
+                Expr::Call(CallExpr {
+                    span,
+                    callee: callee.member(quote_ident!("bind")).as_callee(),
+                    args: vec![context.as_arg()],
+                    type_args: None,
+                })
+            }
+
+            _ => e.fold_children(self),
+        }
+    }
+}
+
+impl FnBind {
+    /// Returns the function to bind and the context.
+    ///
+    /// If the object is not static, it's stored in a temporary variable so
+    /// that it's evaluated only once and before the callee.
+    fn infer_context(&mut self, bind: BindExpr) -> (Expr, Expr) {
+        match bind.obj {
+            Some(box obj) => {
+                if let Some(context) = static_context(&obj) {
+                    return (*bind.callee, context);
+                }
+
+                let ctx = self.declare_context(obj.span());
+                let callee = Expr::Seq(SeqExpr {
+                    span: DUMMY_SP,
+                    exprs: vec![
+                        box Expr::Assign(AssignExpr {
+                            span: DUMMY_SP,
+                            left: PatOrExpr::Pat(box Pat::Ident(ctx.clone())),
+                            op: op!("="),
+                            right: box obj,
+                        }),
+                        bind.callee,
+                    ],
+                });
+
+                (callee, Expr::Ident(ctx))
+            }
+
+            // `::obj.func`
+            None => match *bind.callee {
+                Expr::Member(MemberExpr {
+                    span,
+                    obj: ExprOrSuper::Super(s),
+                    prop,
+                    computed,
+                }) => (
+                    Expr::Member(MemberExpr {
+                        span,
+                        obj: ExprOrSuper::Super(s),
+                        prop,
+                        computed,
+                    }),
+                    Expr::This(ThisExpr { span: s.span }),
+                ),
+
+                Expr::Member(MemberExpr {
+                    span,
+                    obj: ExprOrSuper::Expr(box obj),
+                    prop,
+                    computed,
+                }) => {
+                    if let Some(context) = static_context(&obj) {
+                        return (
+                            Expr::Member(MemberExpr {
+                                span,
+                                obj: ExprOrSuper::Expr(box obj),
+                                prop,
+                                computed,
+                            }),
+                            context,
+                        );
+                    }
+
+                    let ctx = self.declare_context(obj.span());
+                    let obj = Expr::Assign(AssignExpr {
+                        span: DUMMY_SP,
+                        left: PatOrExpr::Pat(box Pat::Ident(ctx.clone())),
+                        op: op!("="),
+                        right: box obj,
+                    });
+
+                    (
+                        Expr::Member(MemberExpr {
+                            span,
+                            obj: ExprOrSuper::Expr(box obj),
+                            prop,
+                            computed,
+                        }),
+                        Expr::Ident(ctx),
+                    )
+                }
+
+                _ => unreachable!("parser ensures callee of `::obj.func` is a member expression"),
+            },
+        }
+    }
+
+    fn declare_context(&mut self, span: Span) -> Ident {
+        let i = private_ident!(span, "_context");
+        self.vars.push(VarDeclarator {
+            span,
+            definite: false,
+            name: Pat::Ident(i.clone()),
+            init: None,
+        });
+        i
+    }
+}
+
+/// Returns an expression which can be used as a context without evaluating
+/// `obj` again.
+fn static_context(obj: &Expr) -> Option<Expr> {
+    match *obj {
+        Expr::This(..) | Expr::Ident(..) | Expr::Lit(..) => Some(obj.clone()),
+        _ => None,
+    }
+}
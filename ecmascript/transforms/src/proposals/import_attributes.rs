@@ -0,0 +1,64 @@
+use crate::pass::Pass;
+use swc_atoms::js_word;
+use swc_common::{Fold, FoldWith};
+use swc_ecma_ast::*;
+
+/// Removes import attributes, which no target supports yet.
+///
+/// - `import data from './data.json' with { type: 'json' }` => `import data
+///   from './data.json'`
+/// - `import('./data.json', { with: { type: 'json' } })` =>
+///   `import('./data.json')`
+///
+/// Module transforms read the attributes, so this should run after them.
+pub fn import_attributes() -> impl Pass {
+    ImportAttributes
+}
+
+#[derive(Clone, Copy)]
+struct ImportAttributes;
+
+noop_fold_type!(ImportAttributes);
+
+impl Fold<ImportDecl> for ImportAttributes {
+    fn fold(&mut self, import: ImportDecl) -> ImportDecl {
+        ImportDecl {
+            attrs: None,
+            ..import
+        }
+    }
+}
+
+impl Fold<NamedExport> for ImportAttributes {
+    fn fold(&mut self, export: NamedExport) -> NamedExport {
+        NamedExport {
+            attrs: None,
+            ..export
+        }
+    }
+}
+
+impl Fold<ExportAll> for ImportAttributes {
+    fn fold(&mut self, export: ExportAll) -> ExportAll {
+        ExportAll {
+            attrs: None,
+            ..export
+        }
+    }
+}
+
+impl Fold<CallExpr> for ImportAttributes {
+    fn fold(&mut self, e: CallExpr) -> CallExpr {
+        let mut e = e.fold_children(self);
+
+        if let ExprOrSuper::Expr(box Expr::Ident(Ident {
+            sym: js_word!("import"),
+            ..
+        })) = e.callee
+        {
+            e.args.truncate(1);
+        }
+
+        e
+    }
+}
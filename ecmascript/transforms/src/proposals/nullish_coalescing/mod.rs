@@ -2,6 +2,7 @@ use crate::{
     pass::Pass,
     util::{alias_if_required, undefined, StmtLike},
 };
+use serde::Deserialize;
 use std::mem::replace;
 use swc_common::{Fold, FoldWith, DUMMY_SP};
 use swc_ecma_ast::*;
@@ -9,12 +10,24 @@ use swc_ecma_ast::*;
 #[cfg(test)]
 mod tests;
 
-pub fn nullish_coalescing() -> impl Pass + 'static {
-    NullishCoalescing::default()
+pub fn nullish_coalescing(c: Config) -> impl Pass + 'static {
+    NullishCoalescing {
+        c,
+        vars: Default::default(),
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Config {
+    /// Use `a != null` instead of `a !== null && a !== void 0`.
+    #[serde(default)]
+    pub no_document_all: bool,
 }
 
 #[derive(Debug, Default)]
 struct NullishCoalescing {
+    c: Config,
     vars: Vec<VarDeclarator>,
 }
 
@@ -78,9 +91,15 @@ impl Fold<Expr> for NullishCoalescing {
                     Expr::Ident(l.clone())
                 };
 
-                return Expr::Cond(CondExpr {
-                    span,
-                    test: box Expr::Bin(BinExpr {
+                let test = if self.c.no_document_all {
+                    box Expr::Bin(BinExpr {
+                        span: DUMMY_SP,
+                        left: box var_expr,
+                        op: op!("!="),
+                        right: box Expr::Lit(Lit::Null(Null { span: DUMMY_SP })),
+                    })
+                } else {
+                    box Expr::Bin(BinExpr {
                         span: DUMMY_SP,
                         left: box Expr::Bin(BinExpr {
                             span: DUMMY_SP,
@@ -95,7 +114,12 @@ impl Fold<Expr> for NullishCoalescing {
                             op: op!("!=="),
                             right: undefined(DUMMY_SP),
                         }),
-                    }),
+                    })
+                };
+
+                return Expr::Cond(CondExpr {
+                    span,
+                    test,
                     cons: box Expr::Ident(l.clone()),
                     alt: right,
                 });
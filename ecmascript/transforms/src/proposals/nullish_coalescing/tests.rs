@@ -2,7 +2,7 @@ use super::*;
 use swc_ecma_parser::{EsConfig, Syntax};
 
 fn tr(_: ()) -> impl Pass {
-    nullish_coalescing()
+    nullish_coalescing(Default::default())
 }
 
 fn syntax() -> Syntax {
@@ -106,3 +106,23 @@ function foo() {
 
 "#
 );
+
+test!(
+    syntax(),
+    |_| nullish_coalescing(Config {
+        no_document_all: true
+    }),
+    assumptions_no_document_all,
+    r#"
+function foo(opts) {
+  var foo = opts.foo ?? "default";
+}
+"#,
+    r#"
+function foo(opts) {
+  var _foo;
+
+  var foo = (_foo = opts.foo) != null ? _foo : "default";
+}
+"#
+);
@@ -2,16 +2,29 @@ use crate::{
     pass::Pass,
     util::{prepend, undefined, ExprFactory, StmtLike},
 };
+use serde::Deserialize;
 use std::{fmt::Debug, iter::once, mem};
-use swc_common::{Fold, FoldWith, Spanned, DUMMY_SP};
+use swc_common::{Fold, FoldWith, Span, Spanned, DUMMY_SP};
 use swc_ecma_ast::*;
 
-pub fn optional_chaining() -> impl Pass {
-    OptChaining::default()
+pub fn optional_chaining(c: Config) -> impl Pass {
+    OptChaining {
+        c,
+        vars: Default::default(),
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Config {
+    /// Use `a == null` instead of `a === null || a === void 0`.
+    #[serde(default)]
+    pub no_document_all: bool,
 }
 
 #[derive(Debug, Default)]
 struct OptChaining {
+    c: Config,
     vars: Vec<VarDeclarator>,
 }
 
@@ -263,24 +276,7 @@ impl OptChaining {
                     }
                 };
 
-                let right = validate!(box Expr::Bin(BinExpr {
-                    span: DUMMY_SP,
-                    left: right,
-                    op: op!("==="),
-                    right: undefined(span),
-                }));
-
-                let test = validate!(box Expr::Bin(BinExpr {
-                    span,
-                    left: box Expr::Bin(BinExpr {
-                        span: obj_span,
-                        left,
-                        op: op!("==="),
-                        right: box Expr::Lit(Lit::Null(Null { span: DUMMY_SP })),
-                    }),
-                    op: op!("||"),
-                    right,
-                }));
+                let test = validate!(self.test_nullish(span, obj_span, left, right));
 
                 validate!(CondExpr {
                     span,
@@ -346,22 +342,7 @@ impl OptChaining {
                     }
                 };
 
-                let test = box Expr::Bin(BinExpr {
-                    span,
-                    left: box Expr::Bin(BinExpr {
-                        span: DUMMY_SP,
-                        left,
-                        op: op!("==="),
-                        right: box Expr::Lit(Lit::Null(Null { span: DUMMY_SP })),
-                    }),
-                    op: op!("||"),
-                    right: box Expr::Bin(BinExpr {
-                        span: DUMMY_SP,
-                        left: right,
-                        op: op!("==="),
-                        right: undefined(span),
-                    }),
-                });
+                let test = self.test_nullish(span, DUMMY_SP, left, right);
 
                 validate!(CondExpr {
                     span: DUMMY_SP,
@@ -373,4 +354,40 @@ impl OptChaining {
             _ => unreachable!("TsOptChain.expr = {:?}", e.expr),
         }
     }
+
+    /// Creates `left === null || right === void 0`, where `right` is the
+    /// value of `left`.
+    fn test_nullish(
+        &self,
+        span: Span,
+        left_span: Span,
+        left: Box<Expr>,
+        right: Box<Expr>,
+    ) -> Box<Expr> {
+        if self.c.no_document_all {
+            return box Expr::Bin(BinExpr {
+                span,
+                left,
+                op: op!("=="),
+                right: box Expr::Lit(Lit::Null(Null { span: DUMMY_SP })),
+            });
+        }
+
+        box Expr::Bin(BinExpr {
+            span,
+            left: box Expr::Bin(BinExpr {
+                span: left_span,
+                left,
+                op: op!("==="),
+                right: box Expr::Lit(Lit::Null(Null { span: DUMMY_SP })),
+            }),
+            op: op!("||"),
+            right: box Expr::Bin(BinExpr {
+                span: DUMMY_SP,
+                left: right,
+                op: op!("==="),
+                right: undefined(span),
+            }),
+        })
+    }
 }
@@ -295,6 +295,25 @@ impl Fold<MethodProp> for Resolver<'_> {
     }
 }
 
+/// Static blocks are scoped like functions, so `var`s declared in them are
+/// not hoisted out of the class.
+impl Fold<StaticBlock> for Resolver<'_> {
+    fn fold(&mut self, b: StaticBlock) -> StaticBlock {
+        let child_mark = Mark::fresh(self.mark);
+
+        // Child folder
+        let mut child = Resolver::new(
+            child_mark,
+            Scope::new(ScopeKind::Fn, Some(&self.current)),
+            None,
+        );
+
+        let body = b.body.fold_children(&mut child);
+
+        StaticBlock { body, ..b }
+    }
+}
+
 impl<'a> Fold<FnDecl> for Resolver<'a> {
     fn fold(&mut self, node: FnDecl) -> FnDecl {
         // We don't fold this as Hoister handles this.
@@ -595,6 +614,12 @@ impl Fold<ArrowExpr> for Hoister<'_, '_> {
     }
 }
 
+impl Fold<StaticBlock> for Hoister<'_, '_> {
+    fn fold(&mut self, node: StaticBlock) -> StaticBlock {
+        node
+    }
+}
+
 impl Fold<VarDecl> for Hoister<'_, '_> {
     fn fold(&mut self, node: VarDecl) -> VarDecl {
         if node.kind != VarDeclKind::Var {
@@ -8,6 +8,10 @@ use swc_common::{util::move_map::MoveMap, Fold, FoldWith, Spanned, Visit, VisitW
 use swc_ecma_ast::*;
 use swc_ecma_utils::{ident::IdentLike, Id};
 
+pub use self::decorator_metadata::decorator_metadata;
+
+mod decorator_metadata;
+
 /// Strips type annotations out.
 pub fn strip() -> impl Pass {
     Strip::default()
@@ -340,6 +344,14 @@ impl Fold<Vec<ModuleItem>> for Strip {
                         }),
                     ..
                 }))
+                | ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl {
+                    decl: Decl::Var(VarDecl { declare: true, .. }),
+                    ..
+                }))
+                | ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl {
+                    decl: Decl::Class(ClassDecl { declare: true, .. }),
+                    ..
+                }))
                 | ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultDecl(ExportDefaultDecl {
                     decl:
                         DefaultDecl::Fn(FnExpr {
@@ -560,6 +572,9 @@ impl Fold<ImportDecl> for Strip {
                 self.was_side_effect_import = import.specifiers.is_empty();
 
                 import.specifiers.retain(|s| match *s {
+                    ImportSpecifier::Named(ImportNamedSpecifier {
+                        is_type_only: true, ..
+                    }) => false,
                     ImportSpecifier::Default(ImportDefaultSpecifier { ref local, .. })
                     | ImportSpecifier::Named(ImportNamedSpecifier { ref local, .. }) => {
                         let entry = self
@@ -0,0 +1,527 @@
+use crate::{pass::Pass, util::ExprFactory};
+use fxhash::FxHashMap;
+use swc_atoms::{js_word, JsWord};
+use swc_common::{util::move_map::MoveMap, Fold, FoldWith, DUMMY_SP};
+use swc_ecma_ast::*;
+
+/// Emits `design:type`, `design:paramtypes` and `design:returntype` metadata
+/// for decorated classes and class members, like `emitDecoratorMetadata` of
+/// `tsc`.
+///
+/// This pass should be applied before `strip`, as it uses type annotations.
+/// `Reflect.metadata` is called only if it exists.
+///
+/// # Example
+///
+/// ## In
+///
+/// ```ts
+/// class Foo {
+///     @dec
+///     method(a: string): number {}
+/// }
+/// ```
+///
+/// ## Out
+///
+/// ```ts
+/// class Foo {
+///     @dec
+///     @_tsMetadata("design:type", Function)
+///     @_tsMetadata("design:paramtypes", [String])
+///     @_tsMetadata("design:returntype", Number)
+///     method(a: string): number {}
+/// }
+/// ```
+pub fn decorator_metadata() -> impl Pass {
+    DecoratorMetadata::default()
+}
+
+#[derive(Default)]
+struct DecoratorMetadata {
+    /// Declarations of each enclosing statement list, innermost last.
+    ///
+    /// The resolver does not handle types, so names are resolved here.
+    scopes: Vec<FxHashMap<JsWord, DeclKind>>,
+    /// Type parameters of enclosing classes and methods.
+    type_params: Vec<JsWord>,
+}
+
+noop_fold_type!(DecoratorMetadata);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum DeclKind {
+    /// Interfaces and type aliases.
+    Type,
+    /// Classes, functions and variables.
+    Value,
+    NumberEnum,
+    StringEnum,
+    MixedEnum,
+}
+
+impl Fold<Vec<ModuleItem>> for DecoratorMetadata {
+    fn fold(&mut self, items: Vec<ModuleItem>) -> Vec<ModuleItem> {
+        let mut decls = FxHashMap::default();
+        for item in &items {
+            match *item {
+                ModuleItem::Stmt(Stmt::Decl(ref decl))
+                | ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl { ref decl, .. })) => {
+                    collect_decl(&mut decls, decl)
+                }
+                ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultDecl(ref export)) => {
+                    match export.decl {
+                        DefaultDecl::Class(ClassExpr {
+                            ident: Some(ref i), ..
+                        })
+                        | DefaultDecl::Fn(FnExpr {
+                            ident: Some(ref i), ..
+                        }) => add_decl(&mut decls, &i.sym, DeclKind::Value),
+                        DefaultDecl::TsInterfaceDecl(ref i) => {
+                            add_decl(&mut decls, &i.id.sym, DeclKind::Type)
+                        }
+                        _ => {}
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        self.scopes.push(decls);
+        let items = items.fold_children(self);
+        self.scopes.pop();
+
+        items
+    }
+}
+
+impl Fold<Vec<Stmt>> for DecoratorMetadata {
+    fn fold(&mut self, stmts: Vec<Stmt>) -> Vec<Stmt> {
+        let mut decls = FxHashMap::default();
+        for stmt in &stmts {
+            if let Stmt::Decl(ref decl) = *stmt {
+                collect_decl(&mut decls, decl);
+            }
+        }
+
+        self.scopes.push(decls);
+        let stmts = stmts.fold_children(self);
+        self.scopes.pop();
+
+        stmts
+    }
+}
+
+impl Fold<Class> for DecoratorMetadata {
+    fn fold(&mut self, class: Class) -> Class {
+        let len = self.type_params.len();
+        self.type_params
+            .extend(type_param_names(&class.type_params));
+
+        let mut class = class.fold_children(self);
+        class.body = class.body.move_map(|member| self.handle_member(member));
+
+        let constructor = class.body.iter().find_map(|member| match *member {
+            ClassMember::Constructor(ref c) if c.body.is_some() => Some(c),
+            _ => None,
+        });
+        if let Some(c) = constructor {
+            let has_param_decorator = c.params.iter().any(|param| match *param {
+                ParamOrTsParamProp::Param(ref p) => !p.decorators.is_empty(),
+                ParamOrTsParamProp::TsParamProp(ref p) => !p.decorators.is_empty(),
+            });
+
+            if !class.decorators.is_empty() || has_param_decorator {
+                let types = c
+                    .params
+                    .iter()
+                    .map(|param| match *param {
+                        ParamOrTsParamProp::Param(ref p) => self.serialize_param(&p.pat),
+                        ParamOrTsParamProp::TsParamProp(ref p) => match p.param {
+                            TsParamPropParam::Ident(ref i) => {
+                                self.serialize_type_ann(i.type_ann.as_ref())
+                            }
+                            TsParamPropParam::Assign(ref p) => self.serialize_assign_pat(p),
+                        },
+                    })
+                    .collect();
+
+                class
+                    .decorators
+                    .push(metadata("design:paramtypes", array(types)));
+            }
+        }
+
+        self.type_params.truncate(len);
+        class
+    }
+}
+
+impl DecoratorMetadata {
+    fn handle_member(&mut self, member: ClassMember) -> ClassMember {
+        match member {
+            ClassMember::Method(mut m) => {
+                self.handle_fn(m.kind, &mut m.function);
+                ClassMember::Method(m)
+            }
+            ClassMember::PrivateMethod(mut m) => {
+                self.handle_fn(m.kind, &mut m.function);
+                ClassMember::PrivateMethod(m)
+            }
+            ClassMember::ClassProp(mut p) => {
+                if !p.decorators.is_empty() {
+                    let ty = self.serialize_type_ann(p.type_ann.as_ref());
+                    p.decorators.push(metadata("design:type", ty));
+                }
+                ClassMember::ClassProp(p)
+            }
+            ClassMember::PrivateProp(mut p) => {
+                if !p.decorators.is_empty() {
+                    let ty = self.serialize_type_ann(p.type_ann.as_ref());
+                    p.decorators.push(metadata("design:type", ty));
+                }
+                ClassMember::PrivateProp(p)
+            }
+            _ => member,
+        }
+    }
+
+    fn handle_fn(&mut self, kind: MethodKind, f: &mut Function) {
+        let has_param_decorator = f.params.iter().any(|p| !p.decorators.is_empty());
+        if f.decorators.is_empty() && !has_param_decorator {
+            return;
+        }
+
+        let len = self.type_params.len();
+        self.type_params.extend(type_param_names(&f.type_params));
+
+        let params: Vec<_> = f
+            .params
+            .iter()
+            .filter(|p| !is_this_param(&p.pat))
+            .map(|p| self.serialize_param(&p.pat))
+            .collect();
+
+        let decorators = match kind {
+            MethodKind::Method => {
+                let return_type = match f.return_type {
+                    Some(ref ty) => self.serialize_type(Some(&ty.type_ann)),
+                    None if f.is_async => global("Promise"),
+                    None => void_0(),
+                };
+
+                vec![
+                    metadata("design:type", global("Function")),
+                    metadata("design:paramtypes", array(params)),
+                    metadata("design:returntype", return_type),
+                ]
+            }
+            MethodKind::Getter => vec![metadata(
+                "design:type",
+                self.serialize_type_ann(f.return_type.as_ref()),
+            )],
+            MethodKind::Setter => vec![
+                metadata(
+                    "design:type",
+                    params.first().cloned().unwrap_or_else(object),
+                ),
+                metadata("design:paramtypes", array(params)),
+            ],
+        };
+        f.decorators.extend(decorators);
+
+        self.type_params.truncate(len);
+    }
+
+    fn serialize_param(&self, pat: &Pat) -> Box<Expr> {
+        match *pat {
+            Pat::Ident(ref i) => self.serialize_type_ann(i.type_ann.as_ref()),
+            Pat::Array(ref p) => self.serialize_type_ann(p.type_ann.as_ref()),
+            Pat::Object(ref p) => self.serialize_type_ann(p.type_ann.as_ref()),
+            Pat::Assign(ref p) => self.serialize_assign_pat(p),
+            Pat::Rest(ref p) => {
+                let ty = p.type_ann.as_ref().or_else(|| match *p.arg {
+                    Pat::Ident(ref i) => i.type_ann.as_ref(),
+                    _ => None,
+                });
+
+                // The type of a rest parameter is the type of its elements.
+                match ty.map(|ty| &*ty.type_ann) {
+                    Some(TsType::TsArrayType(ref arr)) => self.serialize_type(Some(&arr.elem_type)),
+                    ty => self.serialize_type(ty),
+                }
+            }
+            _ => object(),
+        }
+    }
+
+    fn serialize_assign_pat(&self, p: &AssignPat) -> Box<Expr> {
+        match p.type_ann {
+            Some(..) => self.serialize_type_ann(p.type_ann.as_ref()),
+            None => self.serialize_param(&p.left),
+        }
+    }
+
+    fn serialize_type_ann(&self, ty: Option<&TsTypeAnn>) -> Box<Expr> {
+        self.serialize_type(ty.map(|ty| &*ty.type_ann))
+    }
+
+    /// Serializes a type like `tsc` does, without type information.
+    fn serialize_type(&self, ty: Option<&TsType>) -> Box<Expr> {
+        let ty = match ty {
+            Some(ty) => ty,
+            None => return object(),
+        };
+
+        match *ty {
+            TsType::TsKeywordType(ref k) => match k.kind {
+                TsKeywordTypeKind::TsAnyKeyword
+                | TsKeywordTypeKind::TsUnknownKeyword
+                | TsKeywordTypeKind::TsObjectKeyword => object(),
+                TsKeywordTypeKind::TsNumberKeyword => global("Number"),
+                TsKeywordTypeKind::TsStringKeyword => global("String"),
+                TsKeywordTypeKind::TsBooleanKeyword => global("Boolean"),
+                TsKeywordTypeKind::TsBigIntKeyword => global("BigInt"),
+                TsKeywordTypeKind::TsSymbolKeyword => global("Symbol"),
+                TsKeywordTypeKind::TsVoidKeyword
+                | TsKeywordTypeKind::TsUndefinedKeyword
+                | TsKeywordTypeKind::TsNullKeyword
+                | TsKeywordTypeKind::TsNeverKeyword => void_0(),
+            },
+            TsType::TsFnOrConstructorType(..) => global("Function"),
+            TsType::TsArrayType(..) | TsType::TsTupleType(..) => global("Array"),
+            TsType::TsTypePredicate(..) => global("Boolean"),
+            TsType::TsLitType(ref lit) => match lit.lit {
+                TsLit::Str(..) | TsLit::Tpl(..) => global("String"),
+                TsLit::Number(..) => global("Number"),
+                TsLit::Bool(..) => global("Boolean"),
+            },
+            TsType::TsParenthesizedType(ref ty) => self.serialize_type(Some(&ty.type_ann)),
+            TsType::TsTypeOperator(TsTypeOperator {
+                op: TsTypeOperatorOp::ReadOnly,
+                ref type_ann,
+                ..
+            }) => self.serialize_type(Some(type_ann)),
+            TsType::TsUnionOrIntersectionType(TsUnionOrIntersectionType::TsUnionType(ref u)) => {
+                self.serialize_union(&u.types)
+            }
+            TsType::TsUnionOrIntersectionType(TsUnionOrIntersectionType::TsIntersectionType(
+                ref i,
+            )) => self.serialize_union(&i.types),
+            TsType::TsTypeRef(ref r) => self.serialize_type_ref(&r.type_name),
+            _ => object(),
+        }
+    }
+
+    /// Returns the type if all types are serialized to a same constructor, and
+    /// `Object` otherwise.
+    fn serialize_union(&self, types: &[Box<TsType>]) -> Box<Expr> {
+        let mut serialized: Option<Box<Expr>> = None;
+
+        for ty in types {
+            let mut ty = &**ty;
+            while let TsType::TsParenthesizedType(ref p) = *ty {
+                ty = &p.type_ann;
+            }
+
+            if let TsType::TsKeywordType(ref k) = *ty {
+                match k.kind {
+                    TsKeywordTypeKind::TsNeverKeyword
+                    | TsKeywordTypeKind::TsNullKeyword
+                    | TsKeywordTypeKind::TsUndefinedKeyword => continue,
+                    _ => {}
+                }
+            }
+
+            let ty = self.serialize_type(Some(ty));
+            if is_object(&ty) {
+                return ty;
+            }
+            match serialized {
+                Some(ref prev) => match (&**prev, &*ty) {
+                    (Expr::Ident(ref prev), Expr::Ident(ref ty)) if prev.sym == ty.sym => {}
+                    _ => return object(),
+                },
+                None => serialized = Some(ty),
+            }
+        }
+
+        serialized.unwrap_or_else(void_0)
+    }
+
+    fn serialize_type_ref(&self, name: &TsEntityName) -> Box<Expr> {
+        match *name {
+            TsEntityName::Ident(ref i) => {
+                if self.type_params.contains(&i.sym) {
+                    return object();
+                }
+
+                let kind = self.scopes.iter().rev().find_map(|decls| decls.get(&i.sym));
+                match kind {
+                    Some(DeclKind::Type) | Some(DeclKind::MixedEnum) => return object(),
+                    Some(DeclKind::NumberEnum) => return global("Number"),
+                    Some(DeclKind::StringEnum) => return global("String"),
+                    Some(DeclKind::Value) => return box Expr::Ident(i.clone()),
+                    None => {}
+                }
+            }
+            TsEntityName::TsQualifiedName(..) => {}
+        }
+
+        // typeof A === "undefined" || typeof A.B === "undefined" ? Object : A.B
+        let mut test: Option<Box<Expr>> = None;
+        let value = entity_to_expr(name, &mut |e| {
+            let is_undefined = UnaryExpr {
+                span: DUMMY_SP,
+                op: op!("typeof"),
+                arg: box e.clone(),
+            }
+            .make_eq(quote_str!("undefined"));
+
+            test = Some(match test.take() {
+                Some(left) => box (*left).make_bin(op!("||"), is_undefined),
+                None => box is_undefined,
+            });
+        });
+
+        box Expr::Cond(CondExpr {
+            span: DUMMY_SP,
+            test: test.unwrap(),
+            cons: object(),
+            alt: box value,
+        })
+    }
+}
+
+/// Converts `name` to an expression, calling `op` for each prefix.
+fn entity_to_expr<F>(name: &TsEntityName, op: &mut F) -> Expr
+where
+    F: FnMut(&Expr),
+{
+    let e = match *name {
+        TsEntityName::Ident(ref i) => Expr::Ident(Ident::new(i.sym.clone(), i.span)),
+        TsEntityName::TsQualifiedName(ref q) => {
+            entity_to_expr(&q.left, op).member(Ident::new(q.right.sym.clone(), q.right.span))
+        }
+    };
+    op(&e);
+    e
+}
+
+fn type_param_names(params: &Option<TsTypeParamDecl>) -> Vec<JsWord> {
+    params
+        .iter()
+        .flat_map(|decl| decl.params.iter().map(|p| p.name.sym.clone()))
+        .collect()
+}
+
+fn is_this_param(pat: &Pat) -> bool {
+    match *pat {
+        Pat::Ident(Ident {
+            sym: js_word!("this"),
+            ..
+        }) => true,
+        _ => false,
+    }
+}
+
+/// `@_tsMetadata(key, value)`
+fn metadata(key: &str, value: Box<Expr>) -> Decorator {
+    Decorator {
+        span: DUMMY_SP,
+        expr: box Expr::Call(CallExpr {
+            span: DUMMY_SP,
+            callee: helper!(ts_metadata, "tsMetadata"),
+            args: vec![
+                quote_str!(key).as_arg(),
+                ExprOrSpread {
+                    spread: None,
+                    expr: value,
+                },
+            ],
+            type_args: Default::default(),
+        }),
+    }
+}
+
+fn array(elems: Vec<Box<Expr>>) -> Box<Expr> {
+    box Expr::Array(ArrayLit {
+        span: DUMMY_SP,
+        elems: elems
+            .into_iter()
+            .map(|expr| Some(ExprOrSpread { spread: None, expr }))
+            .collect(),
+    })
+}
+
+fn is_object(e: &Expr) -> bool {
+    match *e {
+        Expr::Ident(Ident {
+            sym: js_word!("Object"),
+            ..
+        }) => true,
+        _ => false,
+    }
+}
+
+fn global(name: &str) -> Box<Expr> {
+    box Expr::Ident(quote_ident!(name))
+}
+
+fn object() -> Box<Expr> {
+    global("Object")
+}
+
+fn void_0() -> Box<Expr> {
+    box Expr::Unary(UnaryExpr {
+        span: DUMMY_SP,
+        op: op!("void"),
+        arg: box Expr::Lit(Lit::Num(Number {
+            span: DUMMY_SP,
+            value: 0.0,
+        })),
+    })
+}
+
+fn add_decl(decls: &mut FxHashMap<JsWord, DeclKind>, sym: &JsWord, kind: DeclKind) {
+    // Values have priority over types, as they can be merged.
+    match decls.get(sym) {
+        Some(DeclKind::Value) => {}
+        Some(_) if kind == DeclKind::Type => {}
+        _ => {
+            decls.insert(sym.clone(), kind);
+        }
+    }
+}
+
+fn collect_decl(decls: &mut FxHashMap<JsWord, DeclKind>, decl: &Decl) {
+    match *decl {
+        Decl::Class(ref c) => add_decl(decls, &c.ident.sym, DeclKind::Value),
+        Decl::Fn(ref f) => add_decl(decls, &f.ident.sym, DeclKind::Value),
+        Decl::Var(ref v) => {
+            for decl in &v.decls {
+                if let Pat::Ident(ref i) = decl.name {
+                    add_decl(decls, &i.sym, DeclKind::Value);
+                }
+            }
+        }
+        Decl::TsInterface(ref i) => add_decl(decls, &i.id.sym, DeclKind::Type),
+        Decl::TsTypeAlias(ref a) => add_decl(decls, &a.id.sym, DeclKind::Type),
+        Decl::TsEnum(ref e) => {
+            let is_string = |m: &TsEnumMember| match m.init {
+                Some(box Expr::Lit(Lit::Str(..))) | Some(box Expr::Tpl(..)) => true,
+                _ => false,
+            };
+
+            let kind = if e.members.iter().all(is_string) && !e.members.is_empty() {
+                DeclKind::StringEnum
+            } else if e.members.iter().any(is_string) {
+                DeclKind::MixedEnum
+            } else {
+                DeclKind::NumberEnum
+            };
+            add_decl(decls, &e.id.sym, kind);
+        }
+        Decl::TsModule(..) => {}
+    }
+}
@@ -6,7 +6,7 @@
 use swc_common::chain;
 use swc_ecma_parser::{EsConfig, Syntax};
 use swc_ecma_transforms::{
-    compat::es2015::{arrow, block_scoping, spread, Classes},
+    compat::es2015::{arrow, block_scoping, classes, spread, Classes},
     pass::Pass,
     react::jsx,
     resolver,
@@ -6109,3 +6109,82 @@ with (env) {
 
 "#
 );
+
+test!(
+    syntax(),
+    |_| Classes::new(classes::Config {
+        constant_super: true,
+        no_class_calls: true,
+    }),
+    assumptions_constant_super_no_class_calls,
+    r#"
+class Obj extends Base {
+  get() {
+    return super.test();
+  }
+  set() {
+    super.test = 3;
+  }
+}
+"#,
+    r#"
+let Obj =
+/*#__PURE__*/
+function (Base) {
+  'use strict';
+  _inherits(Obj, Base);
+
+  function Obj() {
+    return _possibleConstructorReturn(this, _getPrototypeOf(Obj).apply(this, arguments));
+  }
+
+  _createClass(Obj, [{
+    key: "get",
+    value: function get() {
+      return _getPrototypeOf(Obj.prototype).test.call(this);
+    }
+  }, {
+    key: "set",
+    value: function set() {
+      this["test"] = 3;
+    }
+  }]);
+
+  return Obj;
+}(Base);
+"#
+);
+
+test_exec!(
+    syntax(),
+    |_| tr(),
+    super_logical_assignment_exec,
+    r#"
+let sets = 0;
+
+class Base {
+  get x() {
+    return this._x;
+  }
+
+  set x(v) {
+    sets++;
+    this._x = v;
+  }
+}
+
+class Foo extends Base {
+  run() {
+    this._x = 1;
+    super.x ||= 2;
+    super.x &&= 3;
+    super.x ??= 4;
+    super.x += 1;
+    return super.x;
+  }
+}
+
+expect(new Foo().run()).toBe(4);
+expect(sets).toBe(2);
+"#
+);
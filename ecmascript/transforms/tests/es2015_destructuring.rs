@@ -555,7 +555,10 @@ function foo(bar) {
 // destructuring_function_key_with_object_rest_spread
 test_exec!(
     syntax(),
-    |_| chain!(object_rest_spread(), destructuring(Default::default())),
+    |_| chain!(
+        object_rest_spread(Default::default()),
+        destructuring(Default::default())
+    ),
     destructuring_function_key_with_object_rest_spread_exec,
     r#"
 const { [(() => 1)()]: a, ...rest } = { 1: "a" };
@@ -599,7 +602,7 @@ test!(
         parameters(),
         destructuring(Default::default()),
         block_scoping(),
-        object_rest_spread(),
+        object_rest_spread(Default::default()),
     ),
     destructuring_for_of,
     r#"
@@ -634,7 +637,7 @@ test!(
         parameters(),
         destructuring(Default::default()),
         block_scoping(),
-        object_rest_spread(),
+        object_rest_spread(Default::default()),
     ),
     destructuring_object_basic,
     r#"
@@ -660,7 +663,7 @@ test!(
         parameters(),
         destructuring(Default::default()),
         block_scoping(),
-        object_rest_spread(),
+        object_rest_spread(Default::default()),
     ),
     destructuring_assignment_arrow_function_block,
     r#"
@@ -685,7 +688,7 @@ test_exec!(
         parameters(),
         destructuring(Default::default()),
         block_scoping(),
-        object_rest_spread(),
+        object_rest_spread(Default::default()),
     ),
     destructuring_non_iterable_exec,
     r#"
@@ -712,7 +715,7 @@ test_exec!(
         parameters(),
         destructuring(Default::default()),
         block_scoping(),
-        object_rest_spread(),
+        object_rest_spread(Default::default()),
     ),
     destructuring_empty_object_pattern_exec,
     r#"
@@ -733,7 +736,7 @@ test_exec!(
         parameters(),
         destructuring(Default::default()),
         block_scoping(),
-        object_rest_spread(),
+        object_rest_spread(Default::default()),
     ),
     destructuring_chained_exec,
     r#"
@@ -751,7 +754,7 @@ expect(d).toBe(4);
 test_exec!(
     syntax(),
     |_| chain!(
-        object_rest_spread(),
+        object_rest_spread(Default::default()),
         spread(spread::Config {
             ..Default::default()
         }),
@@ -809,7 +812,7 @@ test_exec!(
         parameters(),
         destructuring(Default::default()),
         block_scoping(),
-        object_rest_spread(),
+        object_rest_spread(Default::default()),
     ),
     destructuring_issue_5090_exec,
     r#"
@@ -836,7 +839,7 @@ test_exec!(
         parameters(),
         destructuring(Default::default()),
         block_scoping(),
-        object_rest_spread(),
+        object_rest_spread(Default::default()),
     ),
     destructuring_default_precedence_exec,
     r#"
@@ -921,7 +924,7 @@ test!(
         parameters(),
         destructuring(Default::default()),
         block_scoping(),
-        object_rest_spread(),
+        object_rest_spread(Default::default()),
     ),
     destructuring_parameters,
     r#"
@@ -988,7 +991,7 @@ test!(
         parameters(),
         destructuring(Default::default()),
         block_scoping(),
-        object_rest_spread(),
+        object_rest_spread(Default::default()),
     ),
     destructuring_array_unpack_optimisation,
     r#"
@@ -1069,7 +1072,7 @@ test!(
         parameters(),
         destructuring(Default::default()),
         block_scoping(),
-        object_rest_spread(),
+        object_rest_spread(Default::default()),
     ),
     destructuring_known_array,
     r#"
@@ -1089,14 +1092,14 @@ var x = z[0],
 test!(
     syntax(),
     |_| chain!(
-        object_rest_spread(),
+        object_rest_spread(Default::default()),
         spread(spread::Config {
             ..Default::default()
         }),
         parameters(),
         destructuring(Default::default()),
         block_scoping(),
-        object_rest_spread(),
+        object_rest_spread(Default::default()),
     ),
     destructuring_es7_object_rest,
     r#"
@@ -1150,7 +1153,7 @@ expect(oldCourses).toEqual([]);
 test!(
     syntax(),
     |_| chain!(
-        object_rest_spread(),
+        object_rest_spread(Default::default()),
         spread(spread::Config {
             ..Default::default()
         }),
@@ -1174,7 +1177,7 @@ var tmp = z.x, ref = tmp === void 0 ? {} : tmp, y = ref.y;
 test!(
     syntax(),
     |_| chain!(
-        object_rest_spread(),
+        object_rest_spread(Default::default()),
         spread(spread::Config {
             ..Default::default()
         }),
@@ -1208,7 +1211,7 @@ var ref = [0, 1, 2, 3, 4, 5, 6],
 test!(
     syntax(),
     |_| chain!(
-        object_rest_spread(),
+        object_rest_spread(Default::default()),
         spread(spread::Config {
             ..Default::default()
         }),
@@ -1244,7 +1247,7 @@ function isSorted(param) {
 test!(
     syntax(),
     |_| chain!(
-        object_rest_spread(),
+        object_rest_spread(Default::default()),
         spread(spread::Config {
             ..Default::default()
         }),
@@ -1280,7 +1283,7 @@ test!(
             ..Default::default()
         }),
         block_scoping(),
-        object_rest_spread()
+        object_rest_spread(Default::default())
     ),
     destructuring_assignment_statement,
     r#"
@@ -1302,7 +1305,7 @@ test!(
         parameters(),
         destructuring(Default::default()),
         block_scoping(),
-        object_rest_spread(),
+        object_rest_spread(Default::default()),
     ),
     destructuring_array,
     r#"
@@ -1327,7 +1330,7 @@ test!(
         parameters(),
         destructuring(Default::default()),
         block_scoping(),
-        object_rest_spread(),
+        object_rest_spread(Default::default()),
     ),
     destructuring_assignment_arrow_function_no_block,
     r#"
@@ -1344,14 +1347,14 @@ var ref;
 test!(
     syntax(),
     |_| chain!(
-        object_rest_spread(),
+        object_rest_spread(Default::default()),
         spread(spread::Config {
             ..Default::default()
         }),
         parameters(),
         destructuring(Default::default()),
         block_scoping(),
-        object_rest_spread(),
+        object_rest_spread(Default::default()),
     ),
     destructuring_issue_9834,
     r#"
@@ -1386,7 +1389,10 @@ var key = prefix + 'state',
 // destructuring_number_key_with_object_rest_spread
 test_exec!(
     syntax(),
-    |_| chain!(object_rest_spread(), destructuring(Default::default())),
+    |_| chain!(
+        object_rest_spread(Default::default()),
+        destructuring(Default::default())
+    ),
     destructuring_number_key_with_object_rest_spread_exec,
     r#"
 const foo = {
@@ -1413,7 +1419,7 @@ test!(
         parameters(),
         destructuring(Default::default()),
         block_scoping(),
-        object_rest_spread(),
+        object_rest_spread(Default::default()),
     ),
     destructuring_for_in,
     r#"
@@ -1448,7 +1454,7 @@ test!(
         parameters(),
         destructuring(Default::default()),
         block_scoping(),
-        object_rest_spread(),
+        object_rest_spread(Default::default()),
     ),
     destructuring_issue_5744,
     r#"
@@ -1472,7 +1478,7 @@ test_exec!(
         parameters(),
         destructuring(Default::default()),
         block_scoping(),
-        object_rest_spread(),
+        object_rest_spread(Default::default()),
     ),
     destructuring_spread_generator_exec,
     r#"
@@ -158,7 +158,10 @@ test!(
     syntax(),
     |_| chain!(
         resolver(),
-        decorators(decorators::Config { legacy: true }),
+        decorators(decorators::Config {
+            legacy: true,
+            ..Default::default()
+        }),
         Classes::default(),
         function_name(),
     ),
@@ -279,7 +282,10 @@ test!(
     syntax(),
     |_| chain!(
         resolver(),
-        decorators(decorators::Config { legacy: true }),
+        decorators(decorators::Config {
+            legacy: true,
+            ..Default::default()
+        }),
         Classes::default(),
         function_name(),
     ),
@@ -337,7 +343,10 @@ test!(
     syntax(),
     |_| chain!(
         resolver(),
-        decorators(decorators::Config { legacy: true }),
+        decorators(decorators::Config {
+            legacy: true,
+            ..Default::default()
+        }),
         Classes::default(),
         function_name(),
         common_js(Mark::fresh(Mark::root()), Default::default())
@@ -460,7 +469,10 @@ test!(
     syntax(),
     |_| chain!(
         resolver(),
-        decorators(decorators::Config { legacy: true }),
+        decorators(decorators::Config {
+            legacy: true,
+            ..Default::default()
+        }),
         Classes::default(),
         function_name(),
     ),
@@ -486,7 +498,10 @@ test!(
     syntax(),
     |_| chain!(
         resolver(),
-        decorators(decorators::Config { legacy: true }),
+        decorators(decorators::Config {
+            legacy: true,
+            ..Default::default()
+        }),
         Classes::default(),
         function_name(),
     ),
@@ -544,7 +559,10 @@ test!(
     syntax(),
     |_| chain!(
         resolver(),
-        decorators(decorators::Config { legacy: true }),
+        decorators(decorators::Config {
+            legacy: true,
+            ..Default::default()
+        }),
         Classes::default(),
         function_name(),
     ),
@@ -642,7 +660,10 @@ test!(
         resolver(),
         function_name(),
         Classes::default(),
-        decorators(decorators::Config { legacy: true })
+        decorators(decorators::Config {
+            legacy: true,
+            ..Default::default()
+        })
     ),
     function_name_object,
     r#"
@@ -700,7 +721,10 @@ test!(
         resolver(),
         function_name(),
         Classes::default(),
-        decorators(decorators::Config { legacy: true })
+        decorators(decorators::Config {
+            legacy: true,
+            ..Default::default()
+        })
     ),
     function_name_export,
     r#"
@@ -759,7 +783,10 @@ test!(
     syntax(),
     |_| chain!(
         resolver(),
-        decorators(decorators::Config { legacy: true }),
+        decorators(decorators::Config {
+            legacy: true,
+            ..Default::default()
+        }),
         function_name(),
         Classes::default(),
     ),
@@ -827,7 +854,10 @@ test!(
     syntax(),
     |_| chain!(
         resolver(),
-        decorators(decorators::Config { legacy: true }),
+        decorators(decorators::Config {
+            legacy: true,
+            ..Default::default()
+        }),
         Classes::default(),
         function_name(),
         common_js(Mark::fresh(Mark::root()), Default::default()),
@@ -879,7 +909,10 @@ test!(
         resolver(),
         function_name(),
         Classes::default(),
-        decorators(decorators::Config { legacy: true })
+        decorators(decorators::Config {
+            legacy: true,
+            ..Default::default()
+        })
     ),
     function_name_eval,
     r#"
@@ -907,7 +940,10 @@ test!(
         resolver(),
         function_name(),
         Classes::default(),
-        decorators(decorators::Config { legacy: true }),
+        decorators(decorators::Config {
+            legacy: true,
+            ..Default::default()
+        }),
         common_js(Mark::fresh(Mark::root()), Default::default())
     ),
     function_name_modules_3,
@@ -961,7 +997,10 @@ test!(
     syntax(),
     |_| chain!(
         resolver(),
-        decorators(decorators::Config { legacy: true }),
+        decorators(decorators::Config {
+            legacy: true,
+            ..Default::default()
+        }),
         Classes::default(),
         function_name(),
     ),
@@ -1120,7 +1159,10 @@ test!(
     syntax(),
     |_| chain!(
         resolver(),
-        decorators(decorators::Config { legacy: true }),
+        decorators(decorators::Config {
+            legacy: true,
+            ..Default::default()
+        }),
         function_name(),
         Classes::default()
     ),
@@ -1185,7 +1227,10 @@ test!(
     syntax(),
     |_| chain!(
         resolver(),
-        decorators(decorators::Config { legacy: true }),
+        decorators(decorators::Config {
+            legacy: true,
+            ..Default::default()
+        }),
         Classes::default(),
         function_name(),
     ),
@@ -1210,7 +1255,10 @@ test!(
     syntax(),
     |_| chain!(
         resolver(),
-        decorators(decorators::Config { legacy: true }),
+        decorators(decorators::Config {
+            legacy: true,
+            ..Default::default()
+        }),
         Classes::default(),
         function_name(),
     ),
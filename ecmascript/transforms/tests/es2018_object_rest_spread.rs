@@ -23,7 +23,7 @@ fn syntax() -> Syntax {
 }
 
 fn tr() -> impl Fold<Module> {
-    object_rest_spread()
+    object_rest_spread(Default::default())
 }
 
 test!(
@@ -2794,3 +2794,17 @@ const _ref2 = foo((_param)=>{
 
 "#
 );
+
+test!(
+    syntax(),
+    |_| object_rest_spread(object_rest_spread::Config {
+        set_spread_properties: true,
+        ignore_to_primitive_hint: true,
+    }),
+    assumptions_set_spread_properties,
+    "const { [a]: b, ...c } = d; const e = { ...f, g };",
+    "const { [a]: b } = d, c = _objectWithoutProperties(d, [a].map(function (key) {
+    return typeof key === \"symbol\" ? key : key + \"\";
+}));
+const e = _extends({}, f, { g });"
+);
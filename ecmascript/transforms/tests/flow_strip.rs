@@ -0,0 +1,107 @@
+#![feature(box_syntax)]
+#![feature(test)]
+#![feature(box_patterns)]
+#![feature(specialization)]
+
+use swc_ecma_parser::{FlowConfig, Syntax};
+use swc_ecma_transforms::flow::strip;
+
+#[macro_use]
+mod common;
+
+fn syntax() -> Syntax {
+    Syntax::Flow(FlowConfig {
+        all: true,
+        ..Default::default()
+    })
+}
+
+macro_rules! to {
+    ($name:ident, $from:expr, $to:expr) => {
+        test!(syntax(), |_| strip(), $name, $from, $to, ok_if_code_eq);
+    };
+}
+
+to!(
+    type_alias,
+    "type A = ?{| +a: string, b?: Array<number> |};
+export type B = (string, number) => void;
+opaque type C: string = string;
+export opaque type D = number;",
+    ""
+);
+
+to!(
+    annotations,
+    "function foo<T: Object = {}>(a: T, b?: ?string, ...c: Array<mixed>): T %checks {
+    const d: number = 1;
+    return a;
+}",
+    "function foo(a, b, ...c) {
+    const d = 1;
+    return a;
+}"
+);
+
+to!(
+    arrow_return_type,
+    "const f = (a: number): (number => string) => String;",
+    "const f = (a) => String;"
+);
+
+to!(
+    interface,
+    "interface A { a: string; [key: string]: number }",
+    ""
+);
+
+to!(
+    import_type,
+    "import type A from 'a';
+import typeof B from 'b';
+import { type C, typeof D, e } from 'c';
+e();",
+    "import { e } from 'c';
+e();"
+);
+
+to!(
+    declare,
+    "declare var a: number;
+declare function b(): void;
+declare class C { d(): string }
+declare module 'e' {
+    declare export function f(): void;
+}
+declare export var g: string;",
+    ""
+);
+
+to!(type_cast, "const a = (b: any);", "const a = b;");
+
+to!(
+    call_type_args,
+    "const a = f<string>(b);
+new C<number>();",
+    "const a = f(b);
+new C();"
+);
+
+to!(
+    import_used_as_type,
+    "import foo from 'x';
+import { bar } from 'y';
+const a: foo = 1;
+function f(b: bar) {}",
+    "import foo from 'x';
+import { bar } from 'y';
+const a = 1;
+function f(b) {}"
+);
+
+to!(
+    import_only_types,
+    "import { type A, typeof B } from 'a';
+import 'b';",
+    "import 'b';"
+);
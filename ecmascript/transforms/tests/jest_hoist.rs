@@ -0,0 +1,140 @@
+#![feature(box_syntax)]
+#![feature(test)]
+#![feature(box_patterns)]
+#![feature(specialization)]
+
+use swc_common::{chain, Mark};
+use swc_ecma_parser::Syntax;
+use swc_ecma_transforms::{
+    jest_hoist,
+    modules::common_js::{common_js, Config},
+    pass::Pass,
+    resolver_with_mark,
+};
+
+#[macro_use]
+mod common;
+
+fn syntax() -> Syntax {
+    Default::default()
+}
+
+fn tr() -> impl Pass {
+    let mark = Mark::fresh(Mark::root());
+
+    chain!(
+        resolver_with_mark(mark),
+        common_js(mark, Config::default()),
+        jest_hoist()
+    )
+}
+
+test!(
+    syntax(),
+    |_| tr(),
+    hoist_above_imports,
+    "import foo from 'foo';
+jest.mock('foo');
+foo;",
+    "'use strict';
+jest.mock('foo');
+var _foo = _interopRequireDefault(require('foo'));
+_foo.default;"
+);
+
+test!(
+    syntax(),
+    |_| tr(),
+    keep_order,
+    "import 'a';
+jest.unmock('a');
+import 'b';
+jest.enableAutomock();
+jest.mock('b').mock('c');",
+    "'use strict';
+jest.unmock('a');
+jest.enableAutomock();
+jest.mock('b').mock('c');
+require('a');
+require('b');"
+);
+
+test!(
+    syntax(),
+    |_| tr(),
+    factory_with_mock_prefix,
+    "import foo from 'foo';
+const mockBar = 1;
+jest.mock('foo', () => ({ bar: mockBar, baz: Math.max(1, 2) }));",
+    "'use strict';
+jest.mock('foo', ()=>({
+        bar: mockBar,
+        baz: Math.max(1, 2)
+    })
+);
+var _foo = _interopRequireDefault(require('foo'));
+const mockBar = 1;"
+);
+
+test!(
+    syntax(),
+    |_| tr(),
+    nested_block,
+    "import 'a';
+describe('a', () => {
+    foo();
+    jest.mock('b');
+});",
+    "'use strict';
+require('a');
+describe('a', ()=>{
+    jest.mock('b');
+    foo();
+});"
+);
+
+test!(
+    syntax(),
+    |_| tr(),
+    ignore_other_calls,
+    "import 'a';
+jest.fn();
+foo.mock('b');",
+    "'use strict';
+require('a');
+jest.fn();
+foo.mock('b');"
+);
+
+test!(
+    syntax(),
+    |_| tr(),
+    jest_from_globals,
+    "import foo from 'foo';
+import { jest } from '@jest/globals';
+jest.mock('foo');
+foo;",
+    "'use strict';
+var _globals = require('@jest/globals');
+_globals.jest.mock('foo');
+var _foo = _interopRequireDefault(require('foo'));
+_foo.default;"
+);
+
+test!(
+    syntax(),
+    |_| tr(),
+    ignore_local_jest,
+    "import 'a';
+const jest = { mock() {} };
+foo();
+jest.mock('a');",
+    "'use strict';
+require('a');
+const jest = {
+    mock () {
+    }
+};
+foo();
+jest.mock('a');"
+);
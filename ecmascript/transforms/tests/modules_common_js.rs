@@ -117,10 +117,10 @@ test!(
     |_| chain!(
         typescript::strip(),
         decorators(Default::default()),
-        class_properties(),
+        class_properties(Default::default()),
         export(),
         simplifier(Default::default()),
-        compat::es2018(),
+        compat::es2018(Default::default()),
         compat::es2017(),
         compat::es2016(),
         compat::es2015(Mark::fresh(Mark::root()), Default::default()),
@@ -4155,3 +4155,23 @@ const resources = [
       }
   ];"
 );
+
+test!(
+    ::swc_ecma_parser::Syntax::Es(::swc_ecma_parser::EsConfig {
+        import_attributes: true,
+        ..Default::default()
+    }),
+    |_| tr(Default::default()),
+    import_attributes_json,
+    "
+import data from './data.json' with { type: 'json' };
+import style from './style.css' assert { type: 'css' };
+console.log(data, style);
+",
+    "
+'use strict';
+var _dataJson = require('./data.json');
+var _styleCss = _interopRequireDefault(require('./style.css'));
+console.log(_dataJson, _styleCss.default);
+"
+);
@@ -0,0 +1,157 @@
+#![feature(box_syntax)]
+#![feature(test)]
+#![feature(box_patterns)]
+#![feature(specialization)]
+
+use std::path::PathBuf;
+use swc_common::{chain, Mark};
+use swc_ecma_parser::{EsConfig, Syntax};
+use swc_ecma_transforms::modules::{
+    common_js::{self, common_js},
+    paths::{paths, Config},
+};
+
+#[macro_use]
+mod common;
+
+fn syntax() -> Syntax {
+    Syntax::Es(EsConfig {
+        dynamic_import: true,
+        ..Default::default()
+    })
+}
+
+fn tr() -> impl swc_common::Fold<swc_ecma_ast::Module> {
+    let mut config = Config {
+        base_url: Some(PathBuf::from("/project")),
+        ..Default::default()
+    };
+    config
+        .paths
+        .insert("@app/*".into(), vec!["src/app/*".into()]);
+    config
+        .paths
+        .insert("@utils".into(), vec!["src/utils/index.ts".into()]);
+    config
+        .paths
+        .insert("@app/special".into(), vec!["src/special".into()]);
+
+    paths(
+        config,
+        PathBuf::from("/project"),
+        PathBuf::from("/project/src/pages/index.ts"),
+    )
+}
+
+/// `paths` without `baseUrl`, declared in `/project/config`.
+fn tr_without_base_url(file: &str) -> impl swc_common::Fold<swc_ecma_ast::Module> {
+    let mut config = Config::default();
+    config
+        .paths
+        .insert("@app/*".into(), vec!["../src/app/*".into()]);
+
+    paths(
+        config,
+        PathBuf::from("/project/config"),
+        PathBuf::from(file),
+    )
+}
+
+test!(
+    syntax(),
+    |_| tr(),
+    import_wildcard,
+    "import foo from '@app/foo';
+import { bar } from '@app/bar/baz';",
+    "import foo from '../app/foo';
+import { bar } from '../app/bar/baz';"
+);
+
+test!(
+    syntax(),
+    |_| tr(),
+    import_exact,
+    "import utils from '@utils';
+import special from '@app/special';",
+    "import utils from '../utils/index';
+import special from '../special';"
+);
+
+test!(
+    syntax(),
+    |_| tr(),
+    export_from,
+    "export * from '@app/foo';
+export { a } from '@app/bar';
+export { b };",
+    "export * from '../app/foo';
+export { a } from '../app/bar';
+export { b };"
+);
+
+test!(
+    syntax(),
+    |_| tr(),
+    dynamic_import_and_require,
+    "import('@app/foo');
+require('@app/bar');
+require(foo);",
+    "import('../app/foo');
+require('../app/bar');
+require(foo);"
+);
+
+test!(
+    syntax(),
+    |_| tr(),
+    dynamic_import_with_options,
+    "import('@app/foo', { with: { type: 'json' } });
+foo(require('@app/bar'));",
+    "import('../app/foo', { with: { type: 'json' } });
+foo(require('../app/bar'));"
+);
+
+test!(
+    syntax(),
+    |_| tr(),
+    unmatched,
+    "import React from 'react';
+import foo from './foo';",
+    "import React from 'react';
+import foo from './foo';"
+);
+
+test!(
+    syntax(),
+    |_| chain!(
+        tr(),
+        common_js(
+            Mark::fresh(Mark::root()),
+            common_js::Config {
+                strict_mode: false,
+                ..Default::default()
+            }
+        )
+    ),
+    common_js_sees_rewritten,
+    "import '@app/foo';",
+    "require('../app/foo');"
+);
+
+test!(
+    syntax(),
+    |_| tr_without_base_url("/project/src/index.ts"),
+    without_base_url,
+    "import foo from '@app/foo';
+import bar from 'bar';",
+    "import foo from './app/foo';
+import bar from 'bar';"
+);
+
+test!(
+    syntax(),
+    |_| tr_without_base_url("/project/src/pages/nested/index.ts"),
+    without_base_url_nested,
+    "import foo from '@app/foo';",
+    "import foo from '../../app/foo';"
+);
@@ -33,11 +33,22 @@ fn syntax() -> Syntax {
     })
 }
 
+fn syntax_2022() -> Syntax {
+    Syntax::Es(EsConfig {
+        class_private_props: true,
+        class_private_methods: true,
+        class_props: true,
+        static_blocks: true,
+        private_in_object: true,
+        ..Default::default()
+    })
+}
+
 fn tr() -> impl Pass {
     chain!(
         resolver(),
         function_name(),
-        class_properties(),
+        class_properties(Default::default()),
         Classes::default(),
         block_scoping(),
         ReservedWord {
@@ -2823,7 +2834,7 @@ var _x = {
 
 test!(
     syntax(),
-    |_| chain!(resolver(), class_properties()),
+    |_| chain!(resolver(), class_properties(Default::default())),
     issue_308,
     "function bar(props) {}
 class Foo {
@@ -2851,7 +2862,11 @@ class Foo{
 
 test!(
     syntax(),
-    |_| chain!(resolver(), class_properties(), Classes::default()),
+    |_| chain!(
+        resolver(),
+        class_properties(Default::default()),
+        Classes::default()
+    ),
     issue_342,
     "class Foo {
   constructor(bar) {
@@ -2876,7 +2891,11 @@ let Foo = function Foo(bar) {
 
 test!(
     syntax(),
-    |_| chain!(resolver(), class_properties(), block_scoping()),
+    |_| chain!(
+        resolver(),
+        class_properties(Default::default()),
+        block_scoping()
+    ),
     issue_443,
     "
 const MODE = 1;
@@ -2901,7 +2920,7 @@ _defineProperty(foo, 'MODE', MODE);"
 // public_regression_t7364
 test!(
     syntax(),
-    |_| chain!(class_properties(), async_to_generator()),
+    |_| chain!(class_properties(Default::default()), async_to_generator()),
     public_regression_t7364,
     r#"
 class MyClass {
@@ -2975,7 +2994,7 @@ export { MyClass3 as default }
 // private_regression_t6719
 test!(
     syntax(),
-    |_| chain!(class_properties(), block_scoping()),
+    |_| chain!(class_properties(Default::default()), block_scoping()),
     private_regression_t6719,
     r#"
 function withContext(ComposedComponent) {
@@ -3061,7 +3080,7 @@ function withContext(ComposedComponent) {
 // private_reevaluated
 test!(
     syntax(),
-    |_| chain!(class_properties(), block_scoping()),
+    |_| chain!(class_properties(Default::default()), block_scoping()),
     private_reevaluated,
     r#"
 function classFactory() {
@@ -3125,7 +3144,7 @@ function classFactory() {
 // private_static
 test!(
     syntax(),
-    |_| chain!(class_properties(), block_scoping()),
+    |_| chain!(class_properties(Default::default()), block_scoping()),
     private_static,
     r#"
 class Foo {
@@ -3171,7 +3190,11 @@ expect(Foo.test()).toBe("foo");
 // private_destructuring_object_pattern_1
 test!(
     syntax(),
-    |_| chain!(class_properties(), Classes::default(), block_scoping()),
+    |_| chain!(
+        class_properties(Default::default()),
+        Classes::default(),
+        block_scoping()
+    ),
     private_destructuring_object_pattern_1,
     r#"
 class Foo {
@@ -3210,7 +3233,7 @@ var _client = new WeakMap();
 // private_static_inherited
 test!(
     syntax(),
-    |_| chain!(class_properties(), block_scoping()),
+    |_| chain!(class_properties(Default::default()), block_scoping()),
     private_static_inherited,
     r#"
 class Base {
@@ -3289,7 +3312,7 @@ class Sub2 extends Base {}
 // private_destructuring_object_pattern_1_exec
 test_exec!(
     syntax(),
-    |_| class_properties(),
+    |_| class_properties(Default::default()),
     private_destructuring_object_pattern_1_exec,
     r#"
 class Foo {
@@ -3316,7 +3339,7 @@ expect(foo.z).toBe('bar');
 // private_static_undefined
 test!(
     syntax(),
-    |_| chain!(class_properties(), block_scoping()),
+    |_| chain!(class_properties(Default::default()), block_scoping()),
     private_static_undefined,
     r#"
 class Foo {
@@ -3355,7 +3378,11 @@ var _bar = {
 // private_destructuring_array_pattern
 test!(
     syntax(),
-    |_| chain!(class_properties(), Classes::default(), block_scoping()),
+    |_| chain!(
+        class_properties(Default::default()),
+        Classes::default(),
+        block_scoping()
+    ),
     private_destructuring_array_pattern,
     r#"
 class Foo {
@@ -3389,7 +3416,7 @@ var _client = new WeakMap();
 // private_regression_t2983
 test!(
     syntax(),
-    |_| chain!(class_properties(), block_scoping()),
+    |_| chain!(class_properties(Default::default()), block_scoping()),
     private_regression_t2983,
     r#"
 call(class {
@@ -3426,7 +3453,11 @@ export { _class as default }
 // private_regression_t7364
 test!(
     syntax(),
-    |_| chain!(class_properties(), async_to_generator(), block_scoping()),
+    |_| chain!(
+        class_properties(Default::default()),
+        async_to_generator(),
+        block_scoping()
+    ),
     private_regression_t7364,
     r#"
 class MyClass {
@@ -3512,7 +3543,11 @@ export { MyClass3 as default }
 // private_destructuring_array_pattern_1
 test!(
     syntax(),
-    |_| chain!(class_properties(), Classes::default(), block_scoping()),
+    |_| chain!(
+        class_properties(Default::default()),
+        Classes::default(),
+        block_scoping()
+    ),
     private_destructuring_array_pattern_1,
     r#"
 class Foo {
@@ -3550,8 +3585,11 @@ test!(
     ignore,
     syntax(),
     |_| chain!(
-        decorators(decorators::Config { legacy: true }),
-        class_properties(),
+        decorators(decorators::Config {
+            legacy: true,
+            ..Default::default()
+        }),
+        class_properties(Default::default()),
         Classes::default(),
     ),
     decorators_legacy_interop_strict,
@@ -3602,7 +3640,7 @@ let A = (_class = (_temp = function A() {
 // regression_8882_exec
 test_exec!(
     syntax(),
-    |_| class_properties(),
+    |_| class_properties(Default::default()),
     regression_8882_exec,
     r#"
 const classes = [];
@@ -3740,7 +3778,7 @@ for(let i=0; i<= 10; ++i) {
 // private_static_export
 test!(
     syntax(),
-    |_| chain!(class_properties(), block_scoping()),
+    |_| chain!(class_properties(Default::default()), block_scoping()),
     private_static_export,
     r#"
 export class MyClass {
@@ -3771,7 +3809,7 @@ export { MyClass2 as default }
 // static_property_tdz_edgest_case
 test!(
     syntax(),
-    |_| chain!(class_properties(), Classes::default()),
+    |_| chain!(class_properties(Default::default()), Classes::default()),
     static_property_tdz_edgest_case,
     r#"
 class A {
@@ -3798,7 +3836,7 @@ _defineProperty(A, _x, void 0);
 // regression_6153
 test!(
     syntax(),
-    |_| chain!(class_properties(), arrow()),
+    |_| chain!(class_properties(Default::default()), arrow()),
     regression_6153,
     r#"
 () => {
@@ -3899,7 +3937,7 @@ var qux = (function () {
 // regression_7371
 test!(
     syntax(),
-    |_| chain!(class_properties(), arrow()),
+    |_| chain!(class_properties(Default::default()), arrow()),
     regression_7371,
     r#"
 "use strict";
@@ -4109,7 +4147,11 @@ new ComputedField();
 // private_canonical
 test!(
     syntax(),
-    |_| chain!(class_properties(), Classes::default(), block_scoping()),
+    |_| chain!(
+        class_properties(Default::default()),
+        Classes::default(),
+        block_scoping()
+    ),
     private_canonical,
     r#"
 class Point {
@@ -4197,7 +4239,7 @@ var _y = new WeakMap();
 // regression_8882
 test!(
     syntax(),
-    |_| class_properties(),
+    |_| class_properties(Default::default()),
     regression_8882,
     r#"
 const classes = [];
@@ -4249,7 +4291,7 @@ for(let i = 0; i <= 10; ++i){
 // compile_to_class_constructor_collision_ignores_types
 test!(
     ts(),
-    |_| chain!(typescript::strip(), class_properties()),
+    |_| chain!(typescript::strip(), class_properties(Default::default())),
     compile_to_class_constructor_collision_ignores_types,
     r#"
 class C {
@@ -4275,7 +4317,11 @@ class C {
 // private_destructuring_array_pattern_3
 test!(
     syntax(),
-    |_| chain!(class_properties(), Classes::default(), block_scoping()),
+    |_| chain!(
+        class_properties(Default::default()),
+        Classes::default(),
+        block_scoping()
+    ),
     private_destructuring_array_pattern_3,
     r#"
 class Foo {
@@ -4308,7 +4354,7 @@ var _client = new WeakMap();
 // public_static_super_exec
 test_exec!(
     syntax(),
-    |_| class_properties(),
+    |_| class_properties(Default::default()),
     public_static_super_exec,
     r#"
 class A {
@@ -4333,7 +4379,11 @@ expect(getPropA()).toBe(1);
 // private_destructuring_array_pattern_2
 test!(
     syntax(),
-    |_| chain!(class_properties(), Classes::default(), block_scoping()),
+    |_| chain!(
+        class_properties(Default::default()),
+        Classes::default(),
+        block_scoping()
+    ),
     private_destructuring_array_pattern_2,
     r#"
 class Foo {
@@ -4366,7 +4416,7 @@ var _client = new WeakMap();
 // private_non_block_arrow_func
 test!(
     syntax(),
-    |_| chain!(class_properties(), block_scoping()),
+    |_| chain!(class_properties(Default::default()), block_scoping()),
     private_non_block_arrow_func,
     r#"
 export default param =>
@@ -4406,7 +4456,7 @@ export default ((param)=>{
 // regression_8110
 test!(
     syntax(),
-    |_| class_properties(),
+    |_| class_properties(Default::default()),
     regression_8110,
     r#"
 const field = Symbol('field');
@@ -4435,8 +4485,11 @@ test!(
     ignore,
     syntax(),
     |_| chain!(
-        decorators(decorators::Config { legacy: true }),
-        class_properties(),
+        decorators(decorators::Config {
+            legacy: true,
+            ..Default::default()
+        }),
+        class_properties(Default::default()),
         Classes::default()
     ),
     decorators_legacy_interop_local_define_property,
@@ -4493,7 +4546,7 @@ let A = (_class = (_temp = function A() {
 // public_computed_without_block_exec
 test_exec!(
     syntax(),
-    |_| class_properties(),
+    |_| class_properties(Default::default()),
     public_computed_without_block_exec,
     r#"
 const createClass = (k) => class { [k()] = 2 };
@@ -4508,7 +4561,7 @@ expect(instance.foo).toBe(2);
 test!(
     syntax(),
     |_| chain!(
-        class_properties(),
+        class_properties(Default::default()),
         exponentation(),
         Classes::default(),
         block_scoping(),
@@ -4540,7 +4593,7 @@ var _bar = new WeakMap();
 // static_property_tdz_general
 test!(
     syntax(),
-    |_| chain!(class_properties(), Classes::default()),
+    |_| chain!(class_properties(Default::default()), Classes::default()),
     static_property_tdz_general,
     r#"
 class C {
@@ -4565,7 +4618,7 @@ _defineProperty(C, _ref, 3);
 // public_native_classes
 test!(
     syntax(),
-    |_| chain!(class_properties(), block_scoping()),
+    |_| chain!(class_properties(Default::default()), block_scoping()),
     public_native_classes,
     r#"
 class Foo {
@@ -4617,7 +4670,7 @@ test!(
     // Seems useless, while being hard to implement.
     ignore,
     syntax(),
-    |_| chain!(class_properties(), block_scoping()),
+    |_| chain!(class_properties(Default::default()), block_scoping()),
     private_static_infer_name,
     r#"
 var Foo = class {
@@ -4639,7 +4692,7 @@ var Foo = (_temp = _class = class Foo {}, _num = {
 // regression_7951
 test!(
     syntax(),
-    |_| chain!(resolver(), class_properties()),
+    |_| chain!(resolver(), class_properties(Default::default())),
     regression_7951,
     r#"
 export class Foo extends Bar {
@@ -4665,7 +4718,7 @@ _defineProperty(Foo, "foo", {});
 // private_native_classes
 test!(
     syntax(),
-    |_| chain!(class_properties(), block_scoping()),
+    |_| chain!(class_properties(Default::default()), block_scoping()),
     private_native_classes,
     r#"
 class Foo {
@@ -4713,7 +4766,11 @@ var _bar = new WeakMap();
 // public_computed_without_block
 test!(
     syntax(),
-    |_| chain!(class_properties(), Classes::default(), block_scoping()),
+    |_| chain!(
+        class_properties(Default::default()),
+        Classes::default(),
+        block_scoping()
+    ),
     public_computed_without_block,
     r#"
 const createClass = (k) => class { [k()] = 2 };
@@ -4736,7 +4793,7 @@ var createClass = (k)=>{
 // private_destructuring_array_pattern_2_exec
 test_exec!(
     syntax(),
-    |_| class_properties(),
+    |_| class_properties(Default::default()),
     private_destructuring_array_pattern_2_exec,
     r#"
 class Foo {
@@ -4761,7 +4818,11 @@ expect(foo.getClient()).toEqual(['bar', 'baz', 'quu']);
 // public_static_super
 test!(
     syntax(),
-    |_| chain!(class_properties(), Classes::default(), block_scoping()),
+    |_| chain!(
+        class_properties(Default::default()),
+        Classes::default(),
+        block_scoping()
+    ),
     public_static_super,
     r#"
 class A {
@@ -4809,7 +4870,7 @@ _defineProperty(B, "getPropA", () => _get(_getPrototypeOf(B), "prop", B));
 // private_destructuring_array_pattern_exec
 test_exec!(
     syntax(),
-    |_| class_properties(),
+    |_| class_properties(Default::default()),
     private_destructuring_array_pattern_exec,
     r#"
 class Foo {
@@ -4833,7 +4894,7 @@ expect(foo.getClient()).toBe('bar');
 // private_destructuring_array_pattern_1_exec
 test_exec!(
     syntax(),
-    |_| class_properties(),
+    |_| class_properties(Default::default()),
     private_destructuring_array_pattern_1_exec,
     r#"
 class Foo {
@@ -4856,3 +4917,345 @@ expect(foo.y).toBe('bar');
 
 "#
 );
+
+test!(
+    syntax_2022(),
+    |_| class_properties(Default::default()),
+    private_in_object,
+    r#"
+class Foo {
+  #x = 1;
+
+  static has(obj) {
+    return #x in obj;
+  }
+}
+"#,
+    r#"
+class Foo {
+  static has(obj) {
+    return _x.has(_checkInRHS(obj));
+  }
+  constructor() {
+    _x.set(this, {
+      writable: true,
+      value: 1
+    });
+  }
+}
+
+var _x = new WeakMap();
+"#
+);
+
+test!(
+    syntax_2022(),
+    |_| class_properties(Default::default()),
+    private_in_object_static,
+    r#"
+class Foo {
+  static #x = 1;
+
+  static has(obj) {
+    return #x in obj;
+  }
+}
+"#,
+    r#"
+class Foo {
+  static has(obj) {
+    return _checkInRHS(obj) === Foo;
+  }
+}
+
+var _x = {
+  writable: true,
+  value: 1
+};
+"#
+);
+
+test_exec!(
+    syntax_2022(),
+    |_| tr(),
+    private_in_object_exec,
+    r#"
+class Foo {
+  #x;
+  static #y;
+
+  static hasX(obj) {
+    return #x in obj;
+  }
+
+  static hasY(obj) {
+    return #y in obj;
+  }
+}
+
+expect(Foo.hasX(new Foo())).toBe(true);
+expect(Foo.hasX({})).toBe(false);
+expect(Foo.hasY(Foo)).toBe(true);
+expect(Foo.hasY(new Foo())).toBe(false);
+expect(() => Foo.hasX(1)).toThrow(TypeError);
+"#
+);
+
+test!(
+    syntax_2022(),
+    |_| class_properties(Default::default()),
+    private_in_object_method,
+    r#"
+class Foo {
+  #x = 1;
+
+  #m() {}
+
+  static #s() {}
+
+  static has(obj) {
+    return #m in obj;
+  }
+
+  static hasStatic(obj) {
+    return #s in obj;
+  }
+}
+"#,
+    r#"
+class Foo {
+  #m() {}
+
+  static #s() {}
+
+  static has(obj) {
+    return _Foo_brand.has(_checkInRHS(obj));
+  }
+
+  static hasStatic(obj) {
+    return _checkInRHS(obj) === Foo;
+  }
+
+  constructor() {
+    _Foo_brand.add(this);
+    _x.set(this, {
+      writable: true,
+      value: 1
+    });
+  }
+}
+
+var _Foo_brand = new WeakSet();
+var _x = new WeakMap();
+"#
+);
+
+test_exec!(
+    syntax_2022(),
+    |_| tr(),
+    private_in_object_method_exec,
+    r#"
+class Foo {
+  #m() {}
+
+  static #s() {}
+
+  static has(obj) {
+    return #m in obj;
+  }
+
+  static hasStatic(obj) {
+    return #s in obj;
+  }
+}
+
+class Bar extends Foo {}
+
+expect(Foo.has(new Foo())).toBe(true);
+expect(Foo.has(new Bar())).toBe(true);
+expect(Foo.has({})).toBe(false);
+expect(Foo.hasStatic(Foo)).toBe(true);
+expect(Foo.hasStatic(Bar)).toBe(false);
+expect(() => Foo.has(1)).toThrow(TypeError);
+"#
+);
+
+test!(
+    syntax_2022(),
+    |_| class_properties(Default::default()),
+    static_block,
+    r#"
+class Foo {
+  static bar = 1;
+
+  static {
+    this.baz = this.bar + 1;
+  }
+}
+"#,
+    r#"
+class Foo {}
+
+_defineProperty(Foo, "bar", 1);
+
+(() => {
+  Foo.baz = Foo.bar + 1;
+})();
+"#
+);
+
+test_exec!(
+    syntax_2022(),
+    |_| tr(),
+    static_block_exec,
+    r#"
+const order = [];
+
+class Foo {
+  static a = order.push("a");
+
+  static {
+    order.push("block");
+    this.b = this.a + 1;
+  }
+
+  static c = order.push("c");
+}
+
+expect(order).toEqual(["a", "block", "c"]);
+expect(Foo.b).toBe(2);
+"#
+);
+
+test!(
+    syntax_2022(),
+    |_| class_properties(class_properties::Config {
+        set_public_fields: true,
+        private_as_properties: true,
+    }),
+    assumptions_set_public_fields_private_as_properties,
+    r#"
+class Foo {
+  x = 1;
+  static y = 2;
+  #z = 3;
+  static #w = 4;
+
+  get() {
+    return this.#z + Foo.#w;
+  }
+
+  static has(obj) {
+    return #z in obj;
+  }
+}
+"#,
+    r#"
+class Foo {
+  get() {
+    return _classPrivateFieldLooseBase(this, _z)[_z] + _classPrivateFieldLooseBase(Foo, _w)[_w];
+  }
+
+  static has(obj) {
+    return Object.prototype.hasOwnProperty.call(obj, _z);
+  }
+
+  constructor() {
+    this.x = 1;
+    Object.defineProperty(this, _z, {
+      writable: true,
+      value: 3
+    });
+  }
+}
+
+Foo.y = 2;
+var _z = _classPrivateFieldLooseKey("z");
+var _w = _classPrivateFieldLooseKey("w");
+Object.defineProperty(Foo, _w, {
+  writable: true,
+  value: 4
+});
+"#
+);
+
+test_exec!(
+    syntax_2022(),
+    |_| chain!(
+        class_properties(class_properties::Config {
+            set_public_fields: true,
+            private_as_properties: true,
+        }),
+        Classes::default(),
+        block_scoping()
+    ),
+    assumptions_private_as_properties_exec,
+    r#"
+class Foo {
+  #x = 1;
+  static #y = 2;
+
+  inc() {
+    this.#x++;
+    this.#x += 2;
+    return this.#x;
+  }
+
+  static y() {
+    return Foo.#y;
+  }
+
+  static hasX(obj) {
+    return #x in obj;
+  }
+}
+
+const foo = new Foo();
+expect(foo.inc()).toBe(4);
+expect(Foo.y()).toBe(2);
+expect(Foo.hasX(foo)).toBe(true);
+expect(Foo.hasX({})).toBe(false);
+expect(Object.keys(foo)).toEqual([]);
+expect(() => Foo.prototype.inc.call({})).toThrow(TypeError);
+"#
+);
+
+test_exec!(
+    syntax(),
+    |_| tr(),
+    private_logical_assignment_exec,
+    r#"
+let calls = 0;
+function value(v) {
+  calls++;
+  return v;
+}
+
+class Foo {
+  #a = 1;
+  #b = 0;
+  #c = null;
+  static #d = undefined;
+
+  run() {
+    this.#a ||= value(2);
+    this.#b &&= value(3);
+    this.#c ??= value(4);
+    return [this.#a, this.#b, this.#c];
+  }
+
+  static run(objs) {
+    let i = 0;
+    objs[i++].#a &&= value(5);
+    Foo.#d ??= value(6);
+    Foo.#d ??= value(7);
+    return [i, objs[0].#a, Foo.#d];
+  }
+}
+
+expect(new Foo().run()).toEqual([1, 0, 4]);
+expect(calls).toBe(1);
+expect(Foo.run([new Foo()])).toEqual([1, 5, 6]);
+expect(calls).toBe(3);
+"#
+);
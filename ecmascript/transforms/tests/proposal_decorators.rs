@@ -19,17 +19,24 @@ fn syntax(decorators_before_export: bool) -> Syntax {
         decorators_before_export,
         decorators: true,
         class_props: true,
+        static_blocks: true,
         ..Default::default()
     })
 }
 
 fn tr() -> impl Pass {
-    chain!(decorators(Default::default()), class_properties(),)
+    chain!(
+        decorators(Default::default()),
+        class_properties(Default::default()),
+    )
 }
 
 /// Folder for `transformation_*` tests
 fn transformation() -> impl Pass {
-    chain!(decorators(Default::default()), class_properties(),)
+    chain!(
+        decorators(Default::default()),
+        class_properties(Default::default()),
+    )
 }
 
 // transformation_declaration
@@ -60,6 +67,70 @@ let A = _decorate([dec()], function (_initialize) {
 
 "#
 );
+// transformation_static_block
+test!(
+    syntax(false),
+    |_| transformation(),
+    transformation_static_block,
+    r#"
+@dec
+class A {
+  static {
+    this.x = 1;
+  }
+}
+"#,
+    r#"
+let A = function (A) {
+  (function () {
+    this.x = 1;
+  }).call(A);
+  return A;
+}(_decorate([dec], function (_initialize) {
+  class A {
+    constructor() {
+      _initialize(this);
+    }
+
+  }
+
+  return {
+    F: A,
+    d: []
+  };
+}));
+"#
+);
+
+// transformation_static_block_exec
+test_exec!(
+    syntax(false),
+    |_| transformation(),
+    transformation_static_block_exec,
+    r#"
+const log = [];
+
+function dec() {
+  log.push("dec");
+}
+
+@dec
+class A {
+  static x = 1;
+
+  static {
+    log.push("block");
+    this.y = this.x + 1;
+    this.self = A;
+  }
+}
+
+expect(log).toEqual(["dec", "block"]);
+expect(A.y).toBe(2);
+expect(A.self).toBe(A);
+"#
+);
+
 // transformation_initialize_after_super_multiple
 test!(
     syntax(false),
@@ -2032,8 +2103,11 @@ let A = _decorate([], function(_initialize) {
 test_exec!(
     syntax(true),
     |_| chain!(
-        decorators(decorators::Config { legacy: true }),
-        class_properties(),
+        decorators(decorators::Config {
+            legacy: true,
+            ..Default::default()
+        }),
+        class_properties(Default::default()),
     ),
     legacy_class_constructors_return_new_constructor_exec,
     r#"
@@ -2059,7 +2133,10 @@ test!(
     syntax(true),
     |_| chain!(
         typescript::strip(),
-        decorators(decorators::Config { legacy: true })
+        decorators(decorators::Config {
+            legacy: true,
+            ..Default::default()
+        })
     ),
     legacy_regression_10264,
     r#"
@@ -2464,7 +2541,10 @@ export { _class1 as default }
 // legacy_decl_to_expression_class_decorators
 test!(
     syntax(false),
-    |_| decorators(Config { legacy: true }),
+    |_| decorators(Config {
+        legacy: true,
+        ..Default::default()
+    }),
     legacy_decl_to_expression_class_decorators,
     r#"
 export default @dec class A {}
@@ -2483,8 +2563,11 @@ let B = dec((_class1 = class B{
 test_exec!(
     syntax(false),
     |_| chain!(
-        decorators(decorators::Config { legacy: true }),
-        class_properties(),
+        decorators(decorators::Config {
+            legacy: true,
+            ..Default::default()
+        }),
+        class_properties(Default::default()),
     ),
     legacy_class_prototype_methods_numeric_props_exec,
     r#"
@@ -2506,8 +2589,11 @@ class Example {
 test_exec!(
     syntax(false),
     |_| chain!(
-        decorators(decorators::Config { legacy: true }),
-        class_properties(),
+        decorators(decorators::Config {
+            legacy: true,
+            ..Default::default()
+        }),
+        class_properties(Default::default()),
     ),
     legacy_class_static_properties_mutate_descriptor_exec,
     r#"
@@ -2618,8 +2704,11 @@ expect(Example._).toBe("__8__");
 test_exec!(
     syntax(false),
     |_| chain!(
-        decorators(decorators::Config { legacy: true }),
-        class_properties(),
+        decorators(decorators::Config {
+            legacy: true,
+            ..Default::default()
+        }),
+        class_properties(Default::default()),
     ),
     legacy_class_static_methods_string_props_exec,
     r#"
@@ -2641,8 +2730,11 @@ class Example {
 test_exec!(
     syntax(false),
     |_| chain!(
-        decorators(decorators::Config { legacy: true }),
-        class_properties(),
+        decorators(decorators::Config {
+            legacy: true,
+            ..Default::default()
+        }),
+        class_properties(Default::default()),
     ),
     legacy_class_prototype_properties_string_literal_properties_exec,
     r#"
@@ -2683,8 +2775,11 @@ expect(descs["a-prop"].configurable).toBeTruthy();
 test_exec!(
     syntax(false),
     |_| chain!(
-        decorators(decorators::Config { legacy: true }),
-        class_properties(),
+        decorators(decorators::Config {
+            legacy: true,
+            ..Default::default()
+        }),
+        class_properties(Default::default()),
     ),
     legacy_class_prototype_methods_mutate_descriptor_exec,
     r#"
@@ -2813,8 +2908,11 @@ test_exec!(
     ignore,
     syntax(false),
     |_| chain!(
-        decorators(decorators::Config { legacy: true }),
-        class_properties(),
+        decorators(decorators::Config {
+            legacy: true,
+            ..Default::default()
+        }),
+        class_properties(Default::default()),
     ),
     legacy_object_properties_numeric_props_exec,
     r#"
@@ -2835,7 +2933,10 @@ const inst = {
 // legacy_decl_to_expression_method_decorators
 test!(
     syntax(false),
-    |_| decorators(Config { legacy: true }),
+    |_| decorators(Config {
+        legacy: true,
+        ..Default::default()
+    }),
     legacy_decl_to_expression_method_decorators,
     r#"
 export default class A {
@@ -2864,8 +2965,11 @@ export { A as default }
 test_exec!(
     syntax(false),
     |_| chain!(
-        decorators(decorators::Config { legacy: true }),
-        class_properties(),
+        decorators(decorators::Config {
+            legacy: true,
+            ..Default::default()
+        }),
+        class_properties(Default::default()),
     ),
     legacy_class_prototype_properties_return_descriptor_exec,
     r#"
@@ -2978,8 +3082,11 @@ test_exec!(
     ignore,
     syntax(false),
     |_| chain!(
-        decorators(decorators::Config { legacy: true }),
-        class_properties(),
+        decorators(decorators::Config {
+            legacy: true,
+            ..Default::default()
+        }),
+        class_properties(Default::default()),
     ),
     legacy_object_properties_string_props_exec,
     r#"
@@ -3003,8 +3110,11 @@ test_exec!(
     ignore,
     syntax(false),
     |_| chain!(
-        decorators(decorators::Config { legacy: true }),
-        class_properties(),
+        decorators(decorators::Config {
+            legacy: true,
+            ..Default::default()
+        }),
+        class_properties(Default::default()),
     ),
     legacy_object_properties_return_descriptor_exec,
     r#"
@@ -3113,8 +3223,11 @@ expect(inst._).toBe("__8__");
 test_exec!(
     syntax(false),
     |_| chain!(
-        decorators(decorators::Config { legacy: true }),
-        class_properties(),
+        decorators(decorators::Config {
+            legacy: true,
+            ..Default::default()
+        }),
+        class_properties(Default::default()),
     ),
     legacy_class_prototype_methods_string_props_exec,
     r#"
@@ -3136,8 +3249,11 @@ class Example {
 test!(
     syntax(false),
     |_| chain!(
-        decorators(decorators::Config { legacy: true }),
-        class_properties(),
+        decorators(decorators::Config {
+            legacy: true,
+            ..Default::default()
+        }),
+        class_properties(Default::default()),
     ),
     legacy_regression_8041,
     r#"
@@ -3164,8 +3280,11 @@ export { _class1 as default }
 test_exec!(
     syntax(false),
     |_| chain!(
-        decorators(decorators::Config { legacy: true }),
-        class_properties(),
+        decorators(decorators::Config {
+            legacy: true,
+            ..Default::default()
+        }),
+        class_properties(Default::default()),
     ),
     legacy_class_prototype_methods_return_descriptor_exec,
     r#"
@@ -3296,8 +3415,11 @@ test_exec!(
     ignore,
     syntax(false),
     |_| chain!(
-        decorators(decorators::Config { legacy: true }),
-        class_properties(),
+        decorators(decorators::Config {
+            legacy: true,
+            ..Default::default()
+        }),
+        class_properties(Default::default()),
     ),
     legacy_object_ordering_reverse_order_exec,
     r#"
@@ -3337,8 +3459,11 @@ test_exec!(
     ignore,
     syntax(false),
     |_| chain!(
-        decorators(decorators::Config { legacy: true }),
-        class_properties(),
+        decorators(decorators::Config {
+            legacy: true,
+            ..Default::default()
+        }),
+        class_properties(Default::default()),
     ),
     legacy_object_methods_numeric_props_exec,
     r#"
@@ -3361,8 +3486,11 @@ const inst = {
 test_exec!(
     syntax(false),
     |_| chain!(
-        decorators(decorators::Config { legacy: true }),
-        class_properties(),
+        decorators(decorators::Config {
+            legacy: true,
+            ..Default::default()
+        }),
+        class_properties(Default::default()),
     ),
     legacy_class_static_properties_return_descriptor_exec,
     r#"
@@ -3478,8 +3606,11 @@ test_exec!(
     ignore,
     syntax(true),
     |_| chain!(
-        decorators(decorators::Config { legacy: true }),
-        class_properties(),
+        decorators(decorators::Config {
+            legacy: true,
+            ..Default::default()
+        }),
+        class_properties(Default::default()),
     ),
     legacy_class_export_default_exec,
     r#"
@@ -3505,8 +3636,11 @@ expect(calls).toEqual(["Foo"]);
 test_exec!(
     syntax(true),
     |_| chain!(
-        decorators(decorators::Config { legacy: true }),
-        class_properties(),
+        decorators(decorators::Config {
+            legacy: true,
+            ..Default::default()
+        }),
+        class_properties(Default::default()),
     ),
     legacy_class_ordering_reverse_order_exec,
     r#"
@@ -3549,8 +3683,11 @@ test_exec!(
     ignore,
     syntax(true),
     |_| chain!(
-        decorators(decorators::Config { legacy: true }),
-        class_properties(),
+        decorators(decorators::Config {
+            legacy: true,
+            ..Default::default()
+        }),
+        class_properties(Default::default()),
     ),
     legacy_object_methods_mutate_descriptor_exec,
     r#"
@@ -3675,8 +3812,11 @@ expect(inst._()).toBe("__8__");
 test_exec!(
     syntax(false),
     |_| chain!(
-        decorators(decorators::Config { legacy: true }),
-        class_properties(),
+        decorators(decorators::Config {
+            legacy: true,
+            ..Default::default()
+        }),
+        class_properties(Default::default()),
     ),
     legacy_class_static_methods_return_descriptor_exec,
     r#"
@@ -3804,8 +3944,11 @@ test_exec!(
     ignore,
     syntax(false),
     |_| chain!(
-        decorators(decorators::Config { legacy: true }),
-        class_properties(),
+        decorators(decorators::Config {
+            legacy: true,
+            ..Default::default()
+        }),
+        class_properties(Default::default()),
     ),
     legacy_object_methods_return_descriptor_exec,
     r#"
@@ -3932,8 +4075,11 @@ test_exec!(
     ignore,
     syntax(false),
     |_| chain!(
-        decorators(decorators::Config { legacy: true }),
-        class_properties(),
+        decorators(decorators::Config {
+            legacy: true,
+            ..Default::default()
+        }),
+        class_properties(Default::default()),
     ),
     legacy_object_methods_string_props_exec,
     r#"
@@ -3957,8 +4103,11 @@ const inst = {
 test_exec!(
     syntax(false),
     |_| chain!(
-        decorators(decorators::Config { legacy: true }),
-        class_properties(),
+        decorators(decorators::Config {
+            legacy: true,
+            ..Default::default()
+        }),
+        class_properties(Default::default()),
     ),
     legacy_class_prototype_properties_child_classes_properties_exec,
     r#"
@@ -3997,8 +4146,11 @@ expect(inst.prop2).toBe("__4__");
 test_exec!(
     syntax(false),
     |_| chain!(
-        decorators(decorators::Config { legacy: true }),
-        class_properties(),
+        decorators(decorators::Config {
+            legacy: true,
+            ..Default::default()
+        }),
+        class_properties(Default::default()),
     ),
     legacy_class_static_methods_mutate_descriptor_exec,
     r#"
@@ -4122,7 +4274,10 @@ expect(Example._()).toBe("__8__");
 // legacy_regression_8512
 test_exec!(
     syntax(false),
-    |_| decorators(Config { legacy: true }),
+    |_| decorators(Config {
+        legacy: true,
+        ..Default::default()
+    }),
     legacy_regression_8512_exec,
     r#"
 function dec(Class, key, desc) {
@@ -4139,7 +4294,10 @@ class Foo {
 
 test!(
     syntax(false),
-    |_| decorators(Config { legacy: true }),
+    |_| decorators(Config {
+        legacy: true,
+        ..Default::default()
+    }),
     issue_591_1,
     "
 export class Example {
@@ -4173,7 +4331,10 @@ export let Example = ((_class = class Example{
 
 test!(
     syntax(false),
-    |_| decorators(Config { legacy: true }),
+    |_| decorators(Config {
+        legacy: true,
+        ..Default::default()
+    }),
     issue_591_2,
     "class Example {
   @foo() bar = '1';
@@ -0,0 +1,234 @@
+#![feature(box_syntax)]
+#![feature(test)]
+#![feature(box_patterns)]
+#![feature(specialization)]
+
+use swc_ecma_parser::{EsConfig, Syntax};
+use swc_ecma_transforms::{
+    pass::Pass,
+    proposals::decorators::{decorators, Config, DecoratorVersion},
+};
+
+#[macro_use]
+mod common;
+
+fn syntax() -> Syntax {
+    Syntax::Es(EsConfig {
+        decorators: true,
+        class_private_props: true,
+        class_private_methods: true,
+        class_props: true,
+        ..Default::default()
+    })
+}
+
+fn tr() -> impl Pass {
+    decorators(Config {
+        legacy: false,
+        version: DecoratorVersion::V202203,
+    })
+}
+
+test!(
+    syntax(),
+    |_| tr(),
+    public_elements,
+    r#"
+class Foo {
+  @dec a = 1;
+  @dec m() {}
+  @dec accessor b;
+}
+"#,
+    r#"
+var _init_a, _init_b, _initProto;
+let Foo = class Foo {
+    static #_ = [_init_a, _init_b, _initProto] = _applyDecs2203(Foo, [
+        [dec, 0, "a"],
+        [dec, 2, "m"],
+        [dec, 1, "b"]
+    ], []);
+    a = (_initProto(this), _init_a(this, 1));
+    m() {}
+    #_b = _init_b(this);
+    get b() {
+        return this.#_b;
+    }
+    set b(v) {
+        this.#_b = v;
+    }
+};
+"#,
+    ok_if_code_eq
+);
+
+test!(
+    syntax(),
+    |_| tr(),
+    private_elements,
+    r#"
+class Foo {
+  @dec #a = 1;
+  @dec #m() {}
+  @dec accessor #b;
+}
+"#,
+    r#"
+var _init_a, _call_m, _init_b, _get_b, _set_b, _initProto;
+let Foo = class Foo {
+    static #_ = [_init_a, _call_m, _init_b, _get_b, _set_b, _initProto] = _applyDecs2203(Foo, [
+        [dec, 0, "a", function() {
+            return this.#a;
+        }, function(v) {
+            this.#a = v;
+        }],
+        [dec, 2, "m", function() {}],
+        [dec, 1, "b", function() {
+            return this.#_b;
+        }, function(v) {
+            this.#_b = v;
+        }]
+    ], []);
+    #a = (_initProto(this), _init_a(this, 1));
+    get #m() {
+        return _call_m;
+    }
+    #_b = _init_b(this);
+    get #b() {
+        return _get_b(this);
+    }
+    set #b(v) {
+        _set_b(this, v);
+    }
+};
+"#,
+    ok_if_code_eq
+);
+
+test!(
+    syntax(),
+    |_| tr(),
+    class_and_static_elements,
+    r#"
+@dec
+class Foo {
+  @dec2() static m() {}
+}
+"#,
+    r#"
+var _dec, _initStatic, _Foo, _initClass;
+let Foo = (_dec = dec2(), class Foo {
+    static #_ = ([_initStatic, _Foo, _initClass] = _applyDecs2203(Foo, [
+        [_dec, 7, "m"]
+    ], [dec]), _initStatic(Foo));
+    static m() {}
+}, _initClass(), _Foo);
+"#,
+    ok_if_code_eq
+);
+
+test!(
+    syntax(),
+    |_| tr(),
+    proto_init_in_constructor,
+    r#"
+class Foo extends Bar {
+  @dec get x() { return 1; }
+}
+"#,
+    r#"
+var _initProto;
+let Foo = class Foo extends Bar {
+    static #_ = [_initProto] = _applyDecs2203(Foo, [
+        [dec, 3, "x"]
+    ], []);
+    get x() {
+        return 1;
+    }
+    constructor(...args) {
+        super(...args);
+        _initProto(this);
+    }
+};
+"#,
+    ok_if_code_eq
+);
+
+test!(
+    syntax(),
+    |_| tr(),
+    undecorated_accessor,
+    r#"
+class Foo {
+  accessor a = 1;
+  static accessor b;
+}
+"#,
+    r#"
+let Foo = class Foo {
+    #_a = 1;
+    get a() {
+        return this.#_a;
+    }
+    set a(v) {
+        this.#_a = v;
+    }
+    static #_b;
+    static get b() {
+        return this.#_b;
+    }
+    static set b(v) {
+        this.#_b = v;
+    }
+};
+"#,
+    ok_if_code_eq
+);
+
+test!(
+    syntax(),
+    |_| tr(),
+    temporaries_in_factory,
+    r#"
+function factory(dec) {
+  return class Foo {
+    @dec a = 1;
+  };
+}
+"#,
+    r#"
+function factory(dec) {
+    var _init_a;
+    return class Foo {
+        static #_ = [_init_a] = _applyDecs2203(Foo, [
+            [dec, 0, "a"]
+        ], []);
+        a = _init_a(this, 1);
+    };
+}
+"#,
+    ok_if_code_eq
+);
+
+test_exec!(
+    syntax(),
+    |_| tr(),
+    temporaries_in_factory_exec,
+    r#"
+function factory(value) {
+  function dec() {
+    return () => value;
+  }
+
+  return class {
+    @dec a = 0;
+  };
+}
+
+const A = factory(1);
+const B = factory(2);
+
+expect(new A().a).toBe(1);
+expect(new B().a).toBe(2);
+"#
+);
@@ -0,0 +1,77 @@
+#![feature(box_syntax)]
+#![feature(test)]
+#![feature(box_patterns)]
+#![feature(specialization)]
+
+use swc_ecma_parser::{EsConfig, Syntax};
+use swc_ecma_transforms::{pass::Pass, proposals::function_bind};
+
+#[macro_use]
+mod common;
+
+fn tr() -> impl Pass {
+    function_bind()
+}
+
+fn syntax() -> Syntax {
+    Syntax::Es(EsConfig {
+        fn_bind: true,
+        ..Default::default()
+    })
+}
+
+test!(syntax(), |_| tr(), bind, "obj::func;", "func.bind(obj);");
+
+test!(
+    syntax(),
+    |_| tr(),
+    call,
+    "obj::func(a, b);",
+    "func.call(obj, a, b);"
+);
+
+test!(
+    syntax(),
+    |_| tr(),
+    method_bind,
+    "::obj.func; ::obj[key](a);",
+    "obj.func.bind(obj); obj[key].call(obj, a);"
+);
+
+test!(
+    syntax(),
+    |_| tr(),
+    this_and_super,
+    "class A extends B { foo() { this::bar; ::super.baz(); } }",
+    "class A extends B { foo() { bar.bind(this); super.baz.call(this); } }"
+);
+
+test!(
+    syntax(),
+    |_| tr(),
+    complex_object,
+    "a.b::func(); ::a.b.c;",
+    "var _context, _context1;
+(_context = a.b, func).call(_context);
+(_context1 = a.b).c.bind(_context1);"
+);
+
+test!(
+    syntax(),
+    |_| tr(),
+    chained,
+    "obj::foo(a)::bar(b);",
+    "var _context;
+(_context = foo.call(obj, a), bar).call(_context, b);"
+);
+
+test!(
+    syntax(),
+    |_| tr(),
+    nested_block,
+    "function f() { return getObj()::func; }",
+    "function f() {
+    var _context;
+    return (_context = getObj(), func).bind(_context);
+}"
+);
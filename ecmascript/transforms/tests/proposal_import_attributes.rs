@@ -0,0 +1,60 @@
+#![feature(box_syntax)]
+#![feature(test)]
+#![feature(box_patterns)]
+#![feature(specialization)]
+
+use swc_ecma_parser::{EsConfig, Syntax};
+use swc_ecma_transforms::proposals::import_attributes;
+
+#[macro_use]
+mod common;
+
+fn syntax() -> Syntax {
+    Syntax::Es(EsConfig {
+        import_attributes: true,
+        dynamic_import: true,
+        ..Default::default()
+    })
+}
+
+test!(
+    syntax(),
+    |_| import_attributes(),
+    import_decl,
+    r#"
+import data from './data.json' with { type: 'json' };
+import './style.css' assert { type: 'css' };
+"#,
+    r#"
+import data from './data.json';
+import './style.css';
+"#
+);
+
+test!(
+    syntax(),
+    |_| import_attributes(),
+    export_from,
+    r#"
+export { default as data } from './data.json' with { type: 'json' };
+export * from './other.json' with { type: 'json' };
+"#,
+    r#"
+export { default as data } from './data.json';
+export * from './other.json';
+"#
+);
+
+test!(
+    syntax(),
+    |_| import_attributes(),
+    dynamic_import,
+    r#"
+const data = import('./data.json', { with: { type: 'json' } });
+const other = import('./other.json');
+"#,
+    r#"
+const data = import('./data.json');
+const other = import('./other.json');
+"#
+);
@@ -4,13 +4,16 @@
 #![feature(specialization)]
 
 use swc_ecma_parser::{Syntax, TsConfig};
-use swc_ecma_transforms::{pass::Pass, proposals::optional_chaining};
+use swc_ecma_transforms::{
+    pass::Pass,
+    proposals::{opt_chaining, optional_chaining},
+};
 
 #[macro_use]
 mod common;
 
 fn tr(_: ()) -> impl Pass {
-    optional_chaining()
+    optional_chaining(Default::default())
 }
 
 fn syntax() -> Syntax {
@@ -571,3 +574,16 @@ test!(
     "var ref;
 (ref = test.a) === null || ref === void 0 ? void 0 : ref.b.c.d.e.f.g.h.i"
 );
+
+test!(
+    syntax(),
+    |_| optional_chaining(opt_chaining::Config {
+        no_document_all: true
+    }),
+    assumptions_no_document_all,
+    "a?.b; test.a?.b.c; foo?.()",
+    "var ref;
+a == null ? void 0 : a.b;
+(ref = test.a) == null ? void 0 : ref.b.c;
+foo == null ? void 0 : foo();"
+);
@@ -0,0 +1,197 @@
+#![feature(box_syntax)]
+#![feature(test)]
+#![feature(box_patterns)]
+#![feature(specialization)]
+
+use swc_ecma_parser::{Syntax, TsConfig};
+use swc_ecma_transforms::typescript::decorator_metadata;
+
+#[macro_use]
+mod common;
+
+fn syntax() -> Syntax {
+    Syntax::Typescript(TsConfig {
+        decorators: true,
+        ..Default::default()
+    })
+}
+
+test!(
+    syntax(),
+    |_| decorator_metadata(),
+    method,
+    r#"
+class Foo {
+    @dec
+    method(a: string, b: number[], ...rest: boolean[]): void {}
+}
+"#,
+    r#"
+class Foo {
+    @dec
+    @_tsMetadata("design:type", Function)
+    @_tsMetadata("design:paramtypes", [String, Array, Boolean])
+    @_tsMetadata("design:returntype", void 0)
+    method(a: string, b: number[], ...rest: boolean[]): void {}
+}
+"#
+);
+
+test!(
+    syntax(),
+    |_| decorator_metadata(),
+    async_method_without_return_type,
+    r#"
+class Foo {
+    @dec
+    async method(this: Foo, a) {}
+}
+"#,
+    r#"
+class Foo {
+    @dec
+    @_tsMetadata("design:type", Function)
+    @_tsMetadata("design:paramtypes", [Object])
+    @_tsMetadata("design:returntype", Promise)
+    async method(this: Foo, a) {}
+}
+"#
+);
+
+test!(
+    syntax(),
+    |_| decorator_metadata(),
+    class_constructor,
+    r#"
+@dec
+class Foo {
+    constructor(private a: Bar, @inject() b: string | null, c?: A.B) {}
+}
+"#,
+    r#"
+@dec
+@_tsMetadata("design:paramtypes", [
+    typeof Bar === "undefined" ? Object : Bar,
+    String,
+    typeof A === "undefined" || typeof A.B === "undefined" ? Object : A.B
+])
+class Foo {
+    constructor(private a: Bar, @inject() b: string | null, c?: A.B) {}
+}
+"#
+);
+
+test!(
+    syntax(),
+    |_| decorator_metadata(),
+    accessors_and_properties,
+    r#"
+class Foo {
+    @dec
+    prop: "a" | "b";
+    @dec
+    other;
+    @dec
+    get value(): number { return 1; }
+    @dec
+    set value(v: number) {}
+}
+"#,
+    r#"
+class Foo {
+    @dec
+    @_tsMetadata("design:type", String)
+    prop: "a" | "b";
+    @dec
+    @_tsMetadata("design:type", Object)
+    other;
+    @dec
+    @_tsMetadata("design:type", Number)
+    get value(): number { return 1; }
+    @dec
+    @_tsMetadata("design:type", Number)
+    @_tsMetadata("design:paramtypes", [Number])
+    set value(v: number) {}
+}
+"#
+);
+
+test!(
+    syntax(),
+    |_| decorator_metadata(),
+    local_declarations,
+    r#"
+interface I {}
+enum E { A, B }
+enum S { A = "a" }
+class Local {}
+class Foo<T> {
+    @dec
+    method(a: I, b: E, c: S, d: Local, e: T, f: string | number) {}
+}
+"#,
+    r#"
+interface I {}
+enum E { A, B }
+enum S { A = "a" }
+class Local {}
+class Foo<T> {
+    @dec
+    @_tsMetadata("design:type", Function)
+    @_tsMetadata("design:paramtypes", [Object, Number, String, Local, Object, Object])
+    @_tsMetadata("design:returntype", void 0)
+    method(a: I, b: E, c: S, d: Local, e: T, f: string | number) {}
+}
+"#
+);
+
+test!(
+    syntax(),
+    |_| decorator_metadata(),
+    undecorated,
+    r#"
+class Foo {
+    constructor(a: string) {}
+    method(a: string) {}
+}
+"#,
+    r#"
+class Foo {
+    constructor(a: string) {}
+    method(a: string) {}
+}
+"#
+);
+
+test!(
+    syntax(),
+    |_| decorator_metadata(),
+    declarations_in_other_scopes,
+    r#"
+function a() {
+    class E {}
+}
+function b() {
+    enum E { A, B }
+    class Foo {
+        @dec
+        method(e: E) {}
+    }
+}
+"#,
+    r#"
+function a() {
+    class E {}
+}
+function b() {
+    enum E { A, B }
+    class Foo {
+        @dec
+        @_tsMetadata("design:type", Function)
+        @_tsMetadata("design:paramtypes", [Number])
+        @_tsMetadata("design:returntype", void 0)
+        method(e: E) {}
+    }
+}
+"#
+);
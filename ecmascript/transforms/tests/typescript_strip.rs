@@ -418,6 +418,25 @@ to!(module_01, "module 'foo'{ }", "");
 
 to!(declare_01, "declare var env: FOO", "");
 
+to!(export_declare_var, "export declare var env: FOO", "");
+
+to!(
+    export_declare_class,
+    "export declare class Foo {
+    bar(): void;
+}
+export class Baz {}",
+    "export class Baz {
+}"
+);
+
+to!(
+    export_declare_const,
+    "export declare const a: number, b: string;
+export const c = 1;",
+    "export const c = 1;"
+);
+
 to!(import_equals, "import A = B.C", "");
 
 to!(
@@ -39,6 +39,7 @@ pub mod ident;
 pub mod load;
 pub mod options;
 pub mod resolve;
+pub mod semantic;
 mod value;
 pub mod var;
 
@@ -949,6 +950,8 @@ pub trait ExprExt {
             | Expr::TsTypeAssertion(TsTypeAssertion { ref expr, .. })
             | Expr::TsTypeCast(TsTypeCastExpr { ref expr, .. }) => expr.may_have_side_effects(),
             Expr::OptChain(ref e) => e.expr.may_have_side_effects(),
+            // Evaluates a member expression
+            Expr::Bind(_) => true,
 
             Expr::Invalid(..) => unreachable!(),
         }
@@ -1179,6 +1182,7 @@ not_lit!(TsConstAssertion);
 
 not_lit!(PrivateName);
 not_lit!(OptChainExpr);
+not_lit!(BindExpr);
 
 not_lit!(SpreadElement);
 not_lit!(Invalid);
@@ -1437,6 +1441,18 @@ pub trait IsDirective {
             _ => false,
         }
     }
+
+    /// Returns true if this is a string literal statement, which is a
+    /// directive if it's a part of a directive prologue.
+    fn is_directive(&self) -> bool {
+        match self.as_ref() {
+            Some(&Stmt::Expr(ExprStmt {
+                expr: box Expr::Lit(Lit::Str(..)),
+                ..
+            })) => true,
+            _ => false,
+        }
+    }
 }
 
 impl IsDirective for Stmt {
@@ -1445,6 +1461,15 @@ impl IsDirective for Stmt {
     }
 }
 
+impl IsDirective for ModuleItem {
+    fn as_ref(&self) -> Option<&Stmt> {
+        match *self {
+            ModuleItem::Stmt(ref s) => Some(s),
+            _ => None,
+        }
+    }
+}
+
 pub trait IdentExt {
     fn prefix(&self, prefix: &str) -> Ident;
 
@@ -1561,6 +1586,17 @@ impl<'a> UsageFinder<'a> {
 scoped_thread_local!(pub static HANDLER: Handler);
 scoped_thread_local!(pub static COMMENTS: Comments);
 
+/// Moves leading comments of a node at `from` to a synthesized node at `to`.
+///
+/// This is a no-op if comments are not available.
+pub fn move_leading_comments(from: Span, to: Span) {
+    if from.lo() == to.lo() || to.is_dummy() || !COMMENTS.is_set() {
+        return;
+    }
+
+    COMMENTS.with(|c| c.move_leading(from.lo(), to.lo()))
+}
+
 /// make a new expression which evaluates `val` preserving side effects, if any.
 pub fn preserve_effects<I>(span: Span, val: Expr, exprs: I) -> Expr
 where
@@ -1591,7 +1627,7 @@ where
                 ..
             }) if *sym == js_word!("Date") && args.is_empty() => {}
             Expr::New(_) => v.push(box expr),
-            Expr::Member(_) => v.push(box expr),
+            Expr::Member(_) | Expr::Bind(_) => v.push(box expr),
 
             // We are at here because we could not determine value of test.
             //TODO: Drop values if it does not have side effects.
@@ -0,0 +1,821 @@
+//! Semantic model of a program.
+//!
+//! [analyze] builds a scope tree with every binding and every reference of a
+//! [Program], so tools like linters can query bindings instead of
+//! reimplementing the scoping rules.
+//!
+//! The model is built from names and does not depend on [SyntaxContext]s, so
+//! it can be built with or without running `resolver`.
+//!
+//! Limitations:
+//!
+//!  - Types of typescript are ignored, so only value references are recorded.
+//!  - Bodies of typescript namespaces are not analyzed.
+//!  - Function declarations in blocks are scoped to the block, even in sloppy
+//!    mode.
+//!
+//! [SyntaxContext]:swc_common::SyntaxContext
+use std::collections::HashMap;
+use swc_atoms::JsWord;
+use swc_common::{Span, Visit, VisitWith};
+use swc_ecma_ast::*;
+
+#[cfg(test)]
+mod tests;
+
+/// Builds a semantic model for `program`.
+pub fn analyze(program: &Program) -> SemanticModel {
+    let mut a = Analyzer {
+        model: SemanticModel::default(),
+        cur: ScopeId(0),
+    };
+
+    match program {
+        Program::Module(m) => {
+            a.cur = a.model.new_scope(ScopeKind::Module, m.span, None);
+            m.body.visit_with(&mut a);
+        }
+        Program::Script(s) => {
+            a.cur = a.model.new_scope(ScopeKind::Script, s.span, None);
+            s.body.visit_with(&mut a);
+        }
+    }
+
+    a.model.resolve();
+    a.model
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct ScopeId(usize);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct BindingId(usize);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct ReferenceId(usize);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScopeKind {
+    Module,
+    Script,
+    /// Functions, arrow functions, methods, getters, setters and
+    /// constructors.
+    ///
+    /// Parameters and the body share the scope.
+    Fn,
+    /// Block statements, `for` statements and `switch` statements.
+    Block,
+    /// Catch clauses.
+    ///
+    /// The parameter and the body share the scope.
+    Catch,
+    /// Class bodies.
+    ///
+    /// Name of a class expression is bound in this scope.
+    Class,
+}
+
+impl ScopeKind {
+    /// Returns true if `var` declarations are bound in the scope.
+    pub fn is_var_scope(self) -> bool {
+        match self {
+            ScopeKind::Module | ScopeKind::Script | ScopeKind::Fn => true,
+            _ => false,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BindingKind {
+    Var,
+    Let,
+    Const,
+    /// Function declaration, or the name of a function expression.
+    Fn,
+    /// Class declaration, or the name of a class expression.
+    Class,
+    Param,
+    CatchParam,
+    Import,
+    /// Typescript enum.
+    Enum,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReferenceKind {
+    /// `a`
+    Read,
+    /// `a = 1`, `[a] = arr`, `for (a of arr);`
+    Write,
+    /// `a++`, `a += 1`
+    ReadWrite,
+}
+
+impl ReferenceKind {
+    pub fn is_read(self) -> bool {
+        self != ReferenceKind::Write
+    }
+
+    pub fn is_write(self) -> bool {
+        self != ReferenceKind::Read
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Scope {
+    pub kind: ScopeKind,
+    pub span: Span,
+    pub parent: Option<ScopeId>,
+    pub children: Vec<ScopeId>,
+    /// Bindings declared in this scope, in declaration order.
+    pub bindings: Vec<BindingId>,
+    /// Bindings declared outside of this scope and referenced from this scope
+    /// or a descendant scope.
+    ///
+    /// This is only populated for [ScopeKind::Fn].
+    pub captures: Vec<BindingId>,
+
+    names: HashMap<JsWord, BindingId>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Binding {
+    pub name: JsWord,
+    /// Span of the first declaration.
+    pub span: Span,
+    pub kind: BindingKind,
+    pub scope: ScopeId,
+    /// References resolved to this binding.
+    ///
+    /// Declarations, including initializers of variables, are not
+    /// references.
+    pub references: Vec<ReferenceId>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Reference {
+    pub name: JsWord,
+    pub span: Span,
+    pub kind: ReferenceKind,
+    /// Scope the reference appears in.
+    pub scope: ScopeId,
+    /// `None` if the reference is not resolved, which means it refers to a
+    /// global variable.
+    pub binding: Option<BindingId>,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct SemanticModel {
+    scopes: Vec<Scope>,
+    bindings: Vec<Binding>,
+    references: Vec<Reference>,
+    unresolved: Vec<ReferenceId>,
+    /// Span of declaring or referencing identifiers to bindings.
+    by_span: HashMap<Span, BindingId>,
+}
+
+impl SemanticModel {
+    /// Scope of the module or the script.
+    pub fn root(&self) -> ScopeId {
+        ScopeId(0)
+    }
+
+    pub fn scope(&self, id: ScopeId) -> &Scope {
+        &self.scopes[id.0]
+    }
+
+    pub fn binding(&self, id: BindingId) -> &Binding {
+        &self.bindings[id.0]
+    }
+
+    pub fn reference(&self, id: ReferenceId) -> &Reference {
+        &self.references[id.0]
+    }
+
+    pub fn scopes(&self) -> impl Iterator<Item = (ScopeId, &Scope)> {
+        self.scopes.iter().enumerate().map(|(i, s)| (ScopeId(i), s))
+    }
+
+    pub fn bindings(&self) -> impl Iterator<Item = (BindingId, &Binding)> {
+        self.bindings
+            .iter()
+            .enumerate()
+            .map(|(i, b)| (BindingId(i), b))
+    }
+
+    pub fn references(&self) -> impl Iterator<Item = (ReferenceId, &Reference)> {
+        self.references
+            .iter()
+            .enumerate()
+            .map(|(i, r)| (ReferenceId(i), r))
+    }
+
+    /// References which are not resolved to a binding.
+    pub fn unresolved(&self) -> impl Iterator<Item = (ReferenceId, &Reference)> {
+        self.unresolved
+            .iter()
+            .map(move |&id| (id, &self.references[id.0]))
+    }
+
+    /// Names of global variables referenced by the program, without
+    /// duplicates.
+    pub fn globals(&self) -> Vec<&JsWord> {
+        let mut globals = self.unresolved().map(|(_, r)| &r.name).collect::<Vec<_>>();
+        globals.sort();
+        globals.dedup();
+        globals
+    }
+
+    /// Finds a binding named `name` visible from `scope`.
+    pub fn lookup(&self, scope: ScopeId, name: &JsWord) -> Option<BindingId> {
+        let mut cur = Some(scope);
+        while let Some(id) = cur {
+            let scope = self.scope(id);
+            if let Some(&binding) = scope.names.get(name) {
+                return Some(binding);
+            }
+            cur = scope.parent;
+        }
+
+        None
+    }
+
+    /// Returns the binding declared or referenced by `i`.
+    ///
+    /// Identifiers are matched by span, so this does not work for
+    /// identifiers created by transforms.
+    pub fn binding_of(&self, i: &Ident) -> Option<BindingId> {
+        self.by_span.get(&i.span).copied()
+    }
+
+    fn new_scope(&mut self, kind: ScopeKind, span: Span, parent: Option<ScopeId>) -> ScopeId {
+        let id = ScopeId(self.scopes.len());
+        self.scopes.push(Scope {
+            kind,
+            span,
+            parent,
+            children: vec![],
+            bindings: vec![],
+            captures: vec![],
+            names: Default::default(),
+        });
+        if let Some(parent) = parent {
+            self.scopes[parent.0].children.push(id);
+        }
+        id
+    }
+
+    fn declare(&mut self, scope: ScopeId, i: &Ident, kind: BindingKind) -> BindingId {
+        // `var a; var a;` declares one binding.
+        let id = match self.scopes[scope.0].names.get(&i.sym) {
+            Some(&id) => id,
+            None => {
+                let id = BindingId(self.bindings.len());
+                self.bindings.push(Binding {
+                    name: i.sym.clone(),
+                    span: i.span,
+                    kind,
+                    scope,
+                    references: vec![],
+                });
+                let scope = &mut self.scopes[scope.0];
+                scope.names.insert(i.sym.clone(), id);
+                scope.bindings.push(id);
+                id
+            }
+        };
+        self.by_span.insert(i.span, id);
+        id
+    }
+
+    /// References are resolved after visiting the whole program because
+    /// declarations are hoisted.
+    fn resolve(&mut self) {
+        for idx in 0..self.references.len() {
+            let r = &self.references[idx];
+            let binding = match self.lookup(r.scope, &r.name) {
+                Some(binding) => binding,
+                None => {
+                    self.unresolved.push(ReferenceId(idx));
+                    continue;
+                }
+            };
+
+            let binding_scope = self.bindings[binding.0].scope;
+            let mut cur = Some(r.scope);
+            while let Some(id) = cur {
+                if id == binding_scope {
+                    break;
+                }
+                let scope = &mut self.scopes[id.0];
+                if scope.kind == ScopeKind::Fn && !scope.captures.contains(&binding) {
+                    scope.captures.push(binding);
+                }
+                cur = scope.parent;
+            }
+
+            self.references[idx].binding = Some(binding);
+            self.by_span.insert(self.references[idx].span, binding);
+            self.bindings[binding.0].references.push(ReferenceId(idx));
+        }
+    }
+}
+
+struct Analyzer {
+    model: SemanticModel,
+    cur: ScopeId,
+}
+
+impl Analyzer {
+    fn with_scope<F>(&mut self, kind: ScopeKind, span: Span, op: F)
+    where
+        F: FnOnce(&mut Self),
+    {
+        let parent = self.cur;
+        self.cur = self.model.new_scope(kind, span, Some(parent));
+        op(self);
+        self.cur = parent;
+    }
+
+    fn var_scope(&self) -> ScopeId {
+        let mut cur = self.cur;
+        loop {
+            let scope = self.model.scope(cur);
+            if scope.kind.is_var_scope() {
+                return cur;
+            }
+            cur = scope.parent.unwrap();
+        }
+    }
+
+    fn add_ref(&mut self, i: &Ident, kind: ReferenceKind) {
+        self.model.references.push(Reference {
+            name: i.sym.clone(),
+            span: i.span,
+            kind,
+            scope: self.cur,
+            binding: None,
+        });
+    }
+
+    /// Declares names bound by `pat` and visits default values and computed
+    /// keys.
+    fn declare_pat(&mut self, scope: ScopeId, pat: &Pat, kind: BindingKind) {
+        match pat {
+            Pat::Ident(i) => {
+                self.model.declare(scope, i, kind);
+            }
+            Pat::Array(a) => {
+                for elem in a.elems.iter().flatten() {
+                    self.declare_pat(scope, elem, kind);
+                }
+            }
+            Pat::Rest(r) => self.declare_pat(scope, &r.arg, kind),
+            Pat::Object(o) => {
+                for prop in &o.props {
+                    match prop {
+                        ObjectPatProp::KeyValue(p) => {
+                            p.key.visit_with(self);
+                            self.declare_pat(scope, &p.value, kind);
+                        }
+                        ObjectPatProp::Assign(p) => {
+                            self.model.declare(scope, &p.key, kind);
+                            p.value.visit_with(self);
+                        }
+                        ObjectPatProp::Rest(r) => self.declare_pat(scope, &r.arg, kind),
+                    }
+                }
+            }
+            Pat::Assign(a) => {
+                self.declare_pat(scope, &a.left, kind);
+                a.right.visit_with(self);
+            }
+            Pat::Expr(e) => e.visit_with(self),
+            Pat::Invalid(..) => {}
+        }
+    }
+
+    /// Records references for the target of an assignment.
+    fn visit_target(&mut self, pat: &Pat, kind: ReferenceKind) {
+        match pat {
+            Pat::Ident(i) => self.add_ref(i, kind),
+            Pat::Array(a) => {
+                for elem in a.elems.iter().flatten() {
+                    self.visit_target(elem, kind);
+                }
+            }
+            Pat::Rest(r) => self.visit_target(&r.arg, kind),
+            Pat::Object(o) => {
+                for prop in &o.props {
+                    match prop {
+                        ObjectPatProp::KeyValue(p) => {
+                            p.key.visit_with(self);
+                            self.visit_target(&p.value, kind);
+                        }
+                        ObjectPatProp::Assign(p) => {
+                            self.add_ref(&p.key, kind);
+                            p.value.visit_with(self);
+                        }
+                        ObjectPatProp::Rest(r) => self.visit_target(&r.arg, kind),
+                    }
+                }
+            }
+            Pat::Assign(a) => {
+                self.visit_target(&a.left, kind);
+                a.right.visit_with(self);
+            }
+            Pat::Expr(e) => self.visit_target_expr(e, kind),
+            Pat::Invalid(..) => {}
+        }
+    }
+
+    fn visit_target_expr(&mut self, e: &Expr, kind: ReferenceKind) {
+        match e {
+            Expr::Ident(i) => self.add_ref(i, kind),
+            Expr::Paren(e) => self.visit_target_expr(&e.expr, kind),
+            _ => e.visit_with(self),
+        }
+    }
+
+    /// Parameters and the body of a function share the scope.
+    fn visit_fn(&mut self, span: Span, name: Option<&Ident>, params: &[&Pat], body: &[Stmt]) {
+        self.with_scope(ScopeKind::Fn, span, |a| {
+            if let Some(name) = name {
+                a.model.declare(a.cur, name, BindingKind::Fn);
+            }
+            for pat in params {
+                a.declare_pat(a.cur, pat, BindingKind::Param);
+            }
+            body.visit_with(a);
+        });
+    }
+
+    fn visit_function(&mut self, f: &Function, name: Option<&Ident>) {
+        f.decorators.visit_with(self);
+        for param in &f.params {
+            param.decorators.visit_with(self);
+        }
+
+        let params = f.params.iter().map(|p| &p.pat).collect::<Vec<_>>();
+        let body = f.body.as_ref().map(|b| &*b.stmts).unwrap_or(&[]);
+        self.visit_fn(f.span, name, &params, body);
+    }
+
+    fn visit_class(&mut self, c: &Class, name: Option<&Ident>) {
+        c.decorators.visit_with(self);
+        c.super_class.visit_with(self);
+
+        self.with_scope(ScopeKind::Class, c.span, |a| {
+            if let Some(name) = name {
+                a.model.declare(a.cur, name, BindingKind::Class);
+            }
+            c.body.visit_with(a);
+        });
+    }
+}
+
+impl Visit<Expr> for Analyzer {
+    fn visit(&mut self, e: &Expr) {
+        match e {
+            Expr::Ident(i) => self.add_ref(i, ReferenceKind::Read),
+            _ => e.visit_children(self),
+        }
+    }
+}
+
+impl Visit<AssignExpr> for Analyzer {
+    fn visit(&mut self, e: &AssignExpr) {
+        let kind = if e.op == AssignOp::Assign {
+            ReferenceKind::Write
+        } else {
+            ReferenceKind::ReadWrite
+        };
+
+        match &e.left {
+            PatOrExpr::Pat(pat) => self.visit_target(pat, kind),
+            PatOrExpr::Expr(expr) => self.visit_target_expr(expr, kind),
+        }
+        e.right.visit_with(self);
+    }
+}
+
+impl Visit<UpdateExpr> for Analyzer {
+    fn visit(&mut self, e: &UpdateExpr) {
+        self.visit_target_expr(&e.arg, ReferenceKind::ReadWrite);
+    }
+}
+
+impl Visit<MemberExpr> for Analyzer {
+    fn visit(&mut self, e: &MemberExpr) {
+        e.obj.visit_with(self);
+        if e.computed {
+            e.prop.visit_with(self);
+        }
+    }
+}
+
+impl Visit<Prop> for Analyzer {
+    fn visit(&mut self, p: &Prop) {
+        match p {
+            Prop::Shorthand(i) => self.add_ref(i, ReferenceKind::Read),
+            _ => p.visit_children(self),
+        }
+    }
+}
+
+impl Visit<ClassProp> for Analyzer {
+    fn visit(&mut self, p: &ClassProp) {
+        p.decorators.visit_with(self);
+        if p.computed {
+            p.key.visit_with(self);
+        }
+        p.value.visit_with(self);
+    }
+}
+
+impl Visit<JSXElementName> for Analyzer {
+    fn visit(&mut self, n: &JSXElementName) {
+        match n {
+            // Lowercase names are intrinsic elements.
+            JSXElementName::Ident(i) if !i.sym.starts_with(|c: char| c.is_ascii_lowercase()) => {
+                self.add_ref(i, ReferenceKind::Read)
+            }
+            JSXElementName::JSXMemberExpr(e) => {
+                let mut obj = &e.obj;
+                loop {
+                    match obj {
+                        JSXObject::JSXMemberExpr(e) => obj = &e.obj,
+                        JSXObject::Ident(i) => {
+                            self.add_ref(i, ReferenceKind::Read);
+                            break;
+                        }
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+impl Visit<FnDecl> for Analyzer {
+    fn visit(&mut self, f: &FnDecl) {
+        if f.declare {
+            return;
+        }
+
+        self.model.declare(self.cur, &f.ident, BindingKind::Fn);
+        self.visit_function(&f.function, None);
+    }
+}
+
+impl Visit<FnExpr> for Analyzer {
+    fn visit(&mut self, f: &FnExpr) {
+        self.visit_function(&f.function, f.ident.as_ref());
+    }
+}
+
+impl Visit<Function> for Analyzer {
+    fn visit(&mut self, f: &Function) {
+        self.visit_function(f, None);
+    }
+}
+
+impl Visit<ArrowExpr> for Analyzer {
+    fn visit(&mut self, f: &ArrowExpr) {
+        let params = f.params.iter().collect::<Vec<_>>();
+        match &f.body {
+            BlockStmtOrExpr::BlockStmt(body) => self.visit_fn(f.span, None, &params, &body.stmts),
+            BlockStmtOrExpr::Expr(body) => self.with_scope(ScopeKind::Fn, f.span, |a| {
+                for pat in &params {
+                    a.declare_pat(a.cur, pat, BindingKind::Param);
+                }
+                body.visit_with(a);
+            }),
+        }
+    }
+}
+
+impl Visit<Constructor> for Analyzer {
+    fn visit(&mut self, c: &Constructor) {
+        c.key.visit_with(self);
+
+        let params = c
+            .params
+            .iter()
+            .map(|p| match p {
+                ParamOrTsParamProp::Param(p) => p.pat.clone(),
+                ParamOrTsParamProp::TsParamProp(p) => match &p.param {
+                    TsParamPropParam::Ident(i) => Pat::Ident(i.clone()),
+                    TsParamPropParam::Assign(p) => Pat::Assign(p.clone()),
+                },
+            })
+            .collect::<Vec<_>>();
+        let params = params.iter().collect::<Vec<_>>();
+        let body = c.body.as_ref().map(|b| &*b.stmts).unwrap_or(&[]);
+        self.visit_fn(c.span, None, &params, body);
+    }
+}
+
+impl Visit<StaticBlock> for Analyzer {
+    fn visit(&mut self, b: &StaticBlock) {
+        self.visit_fn(b.span, None, &[], &b.body.stmts);
+    }
+}
+
+impl Visit<GetterProp> for Analyzer {
+    fn visit(&mut self, p: &GetterProp) {
+        p.key.visit_with(self);
+        let body = p.body.as_ref().map(|b| &*b.stmts).unwrap_or(&[]);
+        self.visit_fn(p.span, None, &[], body);
+    }
+}
+
+impl Visit<SetterProp> for Analyzer {
+    fn visit(&mut self, p: &SetterProp) {
+        p.key.visit_with(self);
+        let body = p.body.as_ref().map(|b| &*b.stmts).unwrap_or(&[]);
+        self.visit_fn(p.span, None, &[&p.param], body);
+    }
+}
+
+impl Visit<ClassDecl> for Analyzer {
+    fn visit(&mut self, c: &ClassDecl) {
+        if c.declare {
+            return;
+        }
+
+        self.model.declare(self.cur, &c.ident, BindingKind::Class);
+        self.visit_class(&c.class, None);
+    }
+}
+
+impl Visit<ClassExpr> for Analyzer {
+    fn visit(&mut self, c: &ClassExpr) {
+        self.visit_class(&c.class, c.ident.as_ref());
+    }
+}
+
+impl Visit<VarDecl> for Analyzer {
+    fn visit(&mut self, v: &VarDecl) {
+        if v.declare {
+            return;
+        }
+
+        let (scope, kind) = match v.kind {
+            VarDeclKind::Var => (self.var_scope(), BindingKind::Var),
+            VarDeclKind::Let => (self.cur, BindingKind::Let),
+            VarDeclKind::Const => (self.cur, BindingKind::Const),
+        };
+
+        for decl in &v.decls {
+            self.declare_pat(scope, &decl.name, kind);
+            decl.init.visit_with(self);
+        }
+    }
+}
+
+impl Visit<BlockStmt> for Analyzer {
+    fn visit(&mut self, b: &BlockStmt) {
+        self.with_scope(ScopeKind::Block, b.span, |a| b.stmts.visit_with(a));
+    }
+}
+
+impl Visit<CatchClause> for Analyzer {
+    fn visit(&mut self, c: &CatchClause) {
+        self.with_scope(ScopeKind::Catch, c.span, |a| {
+            if let Some(param) = &c.param {
+                a.declare_pat(a.cur, param, BindingKind::CatchParam);
+            }
+            c.body.stmts.visit_with(a);
+        });
+    }
+}
+
+impl Visit<ForStmt> for Analyzer {
+    fn visit(&mut self, s: &ForStmt) {
+        self.with_scope(ScopeKind::Block, s.span, |a| s.visit_children(a));
+    }
+}
+
+impl Visit<ForInStmt> for Analyzer {
+    fn visit(&mut self, s: &ForInStmt) {
+        self.with_scope(ScopeKind::Block, s.span, |a| {
+            match &s.left {
+                VarDeclOrPat::VarDecl(v) => v.visit_with(a),
+                VarDeclOrPat::Pat(pat) => a.visit_target(pat, ReferenceKind::Write),
+            }
+            s.right.visit_with(a);
+            s.body.visit_with(a);
+        });
+    }
+}
+
+impl Visit<ForOfStmt> for Analyzer {
+    fn visit(&mut self, s: &ForOfStmt) {
+        self.with_scope(ScopeKind::Block, s.span, |a| {
+            match &s.left {
+                VarDeclOrPat::VarDecl(v) => v.visit_with(a),
+                VarDeclOrPat::Pat(pat) => a.visit_target(pat, ReferenceKind::Write),
+            }
+            s.right.visit_with(a);
+            s.body.visit_with(a);
+        });
+    }
+}
+
+impl Visit<SwitchStmt> for Analyzer {
+    fn visit(&mut self, s: &SwitchStmt) {
+        s.discriminant.visit_with(self);
+        self.with_scope(ScopeKind::Block, s.span, |a| s.cases.visit_with(a));
+    }
+}
+
+impl Visit<ImportDecl> for Analyzer {
+    fn visit(&mut self, import: &ImportDecl) {
+        if import.type_only {
+            return;
+        }
+
+        for s in &import.specifiers {
+            let local = match s {
+                ImportSpecifier::Named(s) if s.is_type_only => continue,
+                ImportSpecifier::Named(s) => &s.local,
+                ImportSpecifier::Default(s) => &s.local,
+                ImportSpecifier::Namespace(s) => &s.local,
+            };
+            self.model.declare(self.cur, local, BindingKind::Import);
+        }
+    }
+}
+
+impl Visit<TsImportEqualsDecl> for Analyzer {
+    fn visit(&mut self, import: &TsImportEqualsDecl) {
+        self.model
+            .declare(self.cur, &import.id, BindingKind::Import);
+    }
+}
+
+impl Visit<NamedExport> for Analyzer {
+    fn visit(&mut self, export: &NamedExport) {
+        // Re-exports do not reference local bindings.
+        if export.src.is_some() || export.type_only {
+            return;
+        }
+
+        for s in &export.specifiers {
+            if let ExportSpecifier::Named(s) = s {
+                self.add_ref(&s.orig, ReferenceKind::Read);
+            }
+        }
+    }
+}
+
+impl Visit<ExportDefaultDecl> for Analyzer {
+    fn visit(&mut self, export: &ExportDefaultDecl) {
+        match &export.decl {
+            DefaultDecl::Class(c) => {
+                if let Some(i) = &c.ident {
+                    self.model.declare(self.cur, i, BindingKind::Class);
+                }
+                self.visit_class(&c.class, None);
+            }
+            DefaultDecl::Fn(f) => {
+                if let Some(i) = &f.ident {
+                    self.model.declare(self.cur, i, BindingKind::Fn);
+                }
+                self.visit_function(&f.function, None);
+            }
+            DefaultDecl::TsInterfaceDecl(..) => {}
+        }
+    }
+}
+
+impl Visit<TsEnumDecl> for Analyzer {
+    fn visit(&mut self, e: &TsEnumDecl) {
+        if e.declare {
+            return;
+        }
+
+        self.model.declare(self.cur, &e.id, BindingKind::Enum);
+    }
+}
+
+macro_rules! noop {
+    ($($T:ty),*) => {
+        $(
+            impl Visit<$T> for Analyzer {
+                fn visit(&mut self, _: &$T) {}
+            }
+        )*
+    };
+}
+
+noop!(
+    TsType,
+    TsTypeAnn,
+    TsTypeParamDecl,
+    TsTypeParamInstantiation,
+    TsInterfaceDecl,
+    TsTypeAliasDecl,
+    TsModuleDecl
+);
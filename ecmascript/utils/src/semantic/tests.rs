@@ -0,0 +1,152 @@
+use super::*;
+use swc_common::FileName;
+use swc_ecma_parser::{Parser, Session, SourceFileInput, Syntax};
+
+fn model(src: &str) -> SemanticModel {
+    ::testing::run_test(false, |cm, handler| {
+        let fm = cm.new_source_file(FileName::Anon, src.into());
+        let mut p = Parser::new(
+            Session { handler },
+            Syntax::default(),
+            SourceFileInput::from(&*fm),
+            None,
+        );
+        let m = p.parse_module().map_err(|mut e| e.emit())?;
+
+        Ok(analyze(&Program::Module(m)))
+    })
+    .unwrap()
+}
+
+fn binding<'a>(m: &'a SemanticModel, name: &str) -> &'a Binding {
+    let mut found = m.bindings().filter(|(_, b)| &*b.name == name);
+    let (_, b) = found.next().expect("binding not found");
+    assert!(found.next().is_none(), "multiple bindings named `{}`", name);
+    b
+}
+
+fn kinds(m: &SemanticModel, b: &Binding) -> Vec<ReferenceKind> {
+    b.references.iter().map(|&r| m.reference(r).kind).collect()
+}
+
+#[test]
+fn binding_kinds() {
+    let m = model(
+        "import a from 'a';
+        var b; let c; const d = 1;
+        function e(f, { g = 1 }) {}
+        class h {}
+        try {} catch (i) {}",
+    );
+
+    assert_eq!(binding(&m, "a").kind, BindingKind::Import);
+    assert_eq!(binding(&m, "b").kind, BindingKind::Var);
+    assert_eq!(binding(&m, "c").kind, BindingKind::Let);
+    assert_eq!(binding(&m, "d").kind, BindingKind::Const);
+    assert_eq!(binding(&m, "e").kind, BindingKind::Fn);
+    assert_eq!(binding(&m, "f").kind, BindingKind::Param);
+    assert_eq!(binding(&m, "g").kind, BindingKind::Param);
+    assert_eq!(binding(&m, "h").kind, BindingKind::Class);
+    assert_eq!(binding(&m, "i").kind, BindingKind::CatchParam);
+}
+
+#[test]
+fn var_is_hoisted() {
+    let m = model("function f() { a; { var a; let b; } }");
+
+    let a = binding(&m, "a");
+    let b = binding(&m, "b");
+    assert_eq!(m.scope(a.scope).kind, ScopeKind::Fn);
+    assert_eq!(m.scope(b.scope).kind, ScopeKind::Block);
+    assert_eq!(a.references.len(), 1);
+    assert_eq!(m.globals(), Vec::<&JsWord>::new());
+}
+
+#[test]
+fn read_write() {
+    let m = model("let a; a; a = 1; a += 1; a++; [a] = []; for (a of []);");
+
+    assert_eq!(
+        kinds(&m, binding(&m, "a")),
+        vec![
+            ReferenceKind::Read,
+            ReferenceKind::Write,
+            ReferenceKind::ReadWrite,
+            ReferenceKind::ReadWrite,
+            ReferenceKind::Write,
+            ReferenceKind::Write,
+        ]
+    );
+}
+
+#[test]
+fn shadowing() {
+    let m = model("let a; function f(a) { a; } a;");
+
+    let outer = m.lookup(m.root(), &"a".into()).unwrap();
+    assert_eq!(m.binding(outer).references.len(), 1);
+
+    let params = m
+        .bindings()
+        .filter(|(_, b)| b.kind == BindingKind::Param)
+        .collect::<Vec<_>>();
+    assert_eq!(params.len(), 1);
+    assert_eq!(params[0].1.references.len(), 1);
+}
+
+#[test]
+fn captures() {
+    let m = model("let a, b; function f() { let b; return () => a + b; }");
+
+    let a = m.lookup(m.root(), &"a".into()).unwrap();
+    let fns = m
+        .scopes()
+        .filter(|(_, s)| s.kind == ScopeKind::Fn)
+        .collect::<Vec<_>>();
+    assert_eq!(fns.len(), 2);
+
+    // Both `f` and the arrow function capture `a`, but the inner `b` is
+    // captured only by the arrow function.
+    assert_eq!(fns[0].1.captures, vec![a]);
+    assert_eq!(fns[1].1.captures.len(), 2);
+}
+
+#[test]
+fn globals() {
+    let m = model(
+        "let a = { b, c: d };
+        e.f;
+        g[h];
+        export { a };",
+    );
+
+    let globals = m.globals().into_iter().map(|s| &**s).collect::<Vec<_>>();
+    assert_eq!(globals, vec!["b", "d", "e", "g", "h"]);
+    assert_eq!(binding(&m, "a").references.len(), 1);
+}
+
+#[test]
+fn binding_of() {
+    let m = model("let a; a;");
+
+    let (_, r) = m.references().next().unwrap();
+    let i = Ident::new(r.name.clone(), r.span);
+    assert_eq!(m.binding_of(&i), r.binding);
+    assert!(r.binding.is_some());
+}
+
+#[test]
+fn named_imports() {
+    let m = model(
+        "import a, { b, c as d } from 'a';
+        import * as e from 'e';
+        a; b; d; e; c;",
+    );
+
+    for name in &["a", "b", "d", "e"] {
+        let b = binding(&m, name);
+        assert_eq!(b.kind, BindingKind::Import);
+        assert_eq!(b.references.len(), 1);
+    }
+    assert_eq!(m.globals(), vec![&JsWord::from("c")]);
+}
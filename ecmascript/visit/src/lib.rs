@@ -29,6 +29,7 @@ define!({
         ClassProp(ClassProp),
         PrivateProp(PrivateProp),
         TsIndexSignature(TsIndexSignature),
+        StaticBlock(StaticBlock),
     }
 
     pub struct ClassProp {
@@ -44,6 +45,7 @@ define!({
         pub is_optional: bool,
         pub readonly: bool,
         pub definite: bool,
+        pub is_accessor: bool,
     }
     pub struct PrivateProp {
         pub span: Span,
@@ -58,6 +60,7 @@ define!({
         pub is_optional: bool,
         pub readonly: bool,
         pub definite: bool,
+        pub is_accessor: bool,
     }
     pub struct ClassMethod {
         pub span: Span,
@@ -87,6 +90,10 @@ define!({
         pub accessibility: Option<Accessibility>,
         pub is_optional: bool,
     }
+    pub struct StaticBlock {
+        pub span: Span,
+        pub body: BlockStmt,
+    }
     pub struct Decorator {
         pub span: Span,
         pub expr: Box<Expr>,
@@ -168,6 +175,7 @@ define!({
         TsAs(TsAsExpr),
         PrivateName(PrivateName),
         OptChain(OptChainExpr),
+        Bind(BindExpr),
         Invalid(Invalid),
     }
     pub struct ThisExpr {
@@ -315,6 +323,11 @@ define!({
         pub span: Span,
         pub expr: Box<Expr>,
     }
+    pub struct BindExpr {
+        pub span: Span,
+        pub obj: Option<Box<Expr>>,
+        pub callee: Box<Expr>,
+    }
     pub struct Function {
         pub params: Vec<Param>,
         pub decorators: Vec<Decorator>,
@@ -516,16 +529,28 @@ define!({
         pub specifiers: Vec<ImportSpecifier>,
         pub src: Str,
         pub type_only: bool,
+        pub attrs: Option<ImportAttributes>,
     }
     pub struct ExportAll {
         pub span: Span,
         pub src: Str,
+        pub attrs: Option<ImportAttributes>,
     }
     pub struct NamedExport {
         pub span: Span,
         pub specifiers: Vec<ExportSpecifier>,
         pub src: Option<Str>,
         pub type_only: bool,
+        pub attrs: Option<ImportAttributes>,
+    }
+    pub struct ImportAttributes {
+        pub span: Span,
+        pub kind: ImportAttributesKind,
+        pub obj: ObjectLit,
+    }
+    pub enum ImportAttributesKind {
+        With,
+        Assert,
     }
     pub struct ExportDefaultDecl {
         pub span: Span,
@@ -553,6 +578,7 @@ define!({
         pub span: Span,
         pub local: Ident,
         pub imported: Option<Ident>,
+        pub is_type_only: bool,
     }
     pub enum ExportSpecifier {
         Namespace(ExportNamespaceSpecifier),
@@ -612,6 +638,9 @@ define!({
         BitXorAssign,
         BitAndAssign,
         ExpAssign,
+        AndAssign,
+        OrAssign,
+        NullishAssign,
     }
     pub enum UpdateOp {
         PlusPlus,
@@ -1000,6 +1029,7 @@ define!({
         pub params: Vec<TsFnParam>,
         pub type_params: Option<TsTypeParamDecl>,
         pub type_ann: TsTypeAnn,
+        pub is_abstract: bool,
     }
     pub struct TsTypeRef {
         pub span: Span,
@@ -1040,7 +1070,12 @@ define!({
     }
     pub struct TsTupleType {
         pub span: Span,
-        pub elem_types: Vec<Box<TsType>>,
+        pub elem_types: Vec<TsTupleElement>,
+    }
+    pub struct TsTupleElement {
+        pub span: Span,
+        pub label: Option<Pat>,
+        pub ty: Box<TsType>,
     }
     pub struct TsOptionalType {
         pub span: Span,
@@ -1102,6 +1137,7 @@ define!({
         pub span: Span,
         pub readonly: Option<TruePlusMinus>,
         pub type_param: TsTypeParam,
+        pub name_type: Option<Box<TsType>>,
         pub optional: Option<TruePlusMinus>,
         pub type_ann: Option<Box<TsType>>,
     }
@@ -1113,7 +1149,12 @@ define!({
         Number(Number),
         Str(Str),
         Bool(Bool),
-        Tpl(Tpl),
+        Tpl(TsTplLitType),
+    }
+    pub struct TsTplLitType {
+        pub span: Span,
+        pub types: Vec<Box<TsType>>,
+        pub quasis: Vec<TplElement>,
     }
     pub struct TsInterfaceDecl {
         pub span: Span,
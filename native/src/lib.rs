@@ -17,6 +17,7 @@ use std::{
     sync::Arc,
 };
 use swc::{
+    binary,
     common::{self, errors::Handler, FileName, FilePathMapping, SourceFile, SourceMap},
     config::{Options, ParseOptions, SourceMapsConfig},
     ecmascript::ast::Program,
@@ -43,6 +44,8 @@ fn init(_cx: MethodContext<JsUndefined>) -> NeonResult<ArcCompiler> {
 enum Input {
     /// json string
     Program(String),
+    /// Program encoded by `binary::encode_program`
+    Binary(Vec<u8>),
     /// Raw source code.
     Source(Arc<SourceFile>),
     /// File
@@ -55,6 +58,30 @@ struct TransformTask {
     options: Options,
 }
 
+fn buffer_to_vec<'a>(cx: &mut impl Context<'a>, buf: Handle<JsBuffer>) -> Vec<u8> {
+    let guard = cx.lock();
+    let data = buf.borrow(&guard);
+    data.as_slice::<u8>().to_vec()
+}
+
+/// Deserializes a program passed as a json string or as a buffer encoded by
+/// `binary::encode_program`.
+fn program_arg(cx: &mut MethodContext<JsCompiler>, i: i32) -> NeonResult<Program> {
+    let arg = cx.argument::<JsValue>(i)?;
+    let program = match arg.downcast::<JsBuffer>() {
+        Ok(buf) => binary::decode_program(&buffer_to_vec(cx, buf)),
+        Err(..) => {
+            let s = arg.downcast_or_throw::<JsString, _>(cx)?.value();
+            serde_json::from_str(&s).context("failed to deserialize Program")
+        }
+    };
+
+    match program {
+        Ok(program) => Ok(program),
+        Err(err) => cx.throw_error(format!("{:?}", err)),
+    }
+}
+
 fn complete_output<'a>(
     mut cx: impl Context<'a>,
     result: Result<TransformOutput, Error>,
@@ -79,6 +106,12 @@ impl Task for TransformTask {
                 self.c.process_js(program, None, &self.options)
             }
 
+            Input::Binary(ref bytes) => {
+                let program = binary::decode_program(bytes)?;
+                // TODO: Source map
+                self.c.process_js(program, None, &self.options)
+            }
+
             Input::File(ref path) => {
                 let fm = self.c.cm.load_file(path).context("failed to read module")?;
                 self.c.process_js_file(fm, &self.options)
@@ -109,14 +142,24 @@ where
         c = this.borrow(&guard).clone();
     };
 
-    let s = cx.argument::<JsString>(0)?.value();
+    let input = cx.argument::<JsValue>(0)?;
     let is_module = cx.argument::<JsBoolean>(1)?;
     let options_arg = cx.argument::<JsValue>(2)?;
 
     let options: Options = neon_serde::from_value(&mut cx, options_arg)?;
     let callback = cx.argument::<JsFunction>(3)?;
 
-    let task = op(&c, s, is_module.value(), options);
+    let task = match input.downcast::<JsBuffer>() {
+        Ok(buf) => TransformTask {
+            c: c.clone(),
+            input: Input::Binary(buffer_to_vec(&mut cx, buf)),
+            options,
+        },
+        Err(..) => {
+            let s = input.downcast_or_throw::<JsString, _>(&mut cx)?.value();
+            op(&c, s, is_module.value(), options)
+        }
+    };
     task.schedule(callback);
 
     Ok(cx.undefined().upcast())
@@ -126,8 +169,12 @@ fn exec_transform<F>(mut cx: MethodContext<JsCompiler>, op: F) -> JsResult<JsVal
 where
     F: FnOnce(&Compiler, String, &Options) -> Result<Arc<SourceFile>, Error>,
 {
-    let s = cx.argument::<JsString>(0)?;
     let is_module = cx.argument::<JsBoolean>(1)?;
+    let (program, src) = if is_module.value() {
+        (Some(program_arg(&mut cx, 0)?), None)
+    } else {
+        (None, Some(cx.argument::<JsString>(0)?.value()))
+    };
     let options: Options = match cx.argument_opt(2) {
         Some(v) => neon_serde::from_value(&mut cx, v)?,
         None => {
@@ -140,14 +187,13 @@ where
     let output = {
         let guard = cx.lock();
         let c = this.borrow(&guard);
-        c.run(|| {
-            if is_module.value() {
-                let program: Program =
-                    serde_json::from_str(&s.value()).expect("failed to deserialize Program");
+        c.run(|| match (program, src) {
+            (Some(program), _) => {
                 // TODO: Source map
                 c.process_js(program, None, &options)
-            } else {
-                let fm = op(&c, s.value(), &options).expect("failed to create fm");
+            }
+            (None, src) => {
+                let fm = op(&c, src.unwrap_or_default(), &options).expect("failed to create fm");
                 c.process_js_file(fm, &options)
             }
         })
@@ -231,12 +277,20 @@ fn complete_parse<'a>(
     result: Result<Program, Error>,
     c: &Compiler,
 ) -> JsResult<'a, JsValue> {
-    c.run(|| match result {
-        Ok(program) => Ok(cx
-            .string(serde_json::to_string(&program).expect("failed to serialize Program"))
-            .upcast()),
-        Err(err) => cx.throw_error(format!("{:?}", err)),
-    })
+    c.run(
+        || match result.and_then(|program| binary::encode_program(&program)) {
+            Ok(bytes) => {
+                let mut buf = JsBuffer::new(&mut cx, bytes.len() as u32)?;
+                {
+                    let guard = cx.lock();
+                    let data = buf.borrow_mut(&guard);
+                    data.as_mut_slice::<u8>().copy_from_slice(&bytes);
+                }
+                Ok(buf.upcast())
+            }
+            Err(err) => cx.throw_error(format!("{:?}", err)),
+        },
+    )
 }
 
 impl Task for ParseTask {
@@ -440,8 +494,7 @@ impl Task for PrintTask {
                     .config
                     .clone()
                     .unwrap_or_default()
-                    .minify
-                    .unwrap_or(false),
+                    .codegen_config(),
             )
         })
     }
@@ -456,9 +509,7 @@ impl Task for PrintTask {
 }
 
 fn print(mut cx: MethodContext<JsCompiler>) -> JsResult<JsValue> {
-    let program = cx.argument::<JsString>(0)?;
-    let program: Program =
-        serde_json::from_str(&program.value()).expect("failed to deserialize Program");
+    let program = program_arg(&mut cx, 0)?;
 
     let options = cx.argument::<JsValue>(1)?;
     let options: Options = neon_serde::from_value(&mut cx, options)?;
@@ -490,9 +541,7 @@ fn print_sync(mut cx: MethodContext<JsCompiler>) -> JsResult<JsValue> {
         c = compiler.clone();
     }
     c.run(|| {
-        let program = cx.argument::<JsString>(0)?;
-        let program: Program =
-            serde_json::from_str(&program.value()).expect("failed to deserialize Program");
+        let program = program_arg(&mut cx, 0)?;
 
         let options = cx.argument::<JsValue>(1)?;
         let options: Options = neon_serde::from_value(&mut cx, options)?;
@@ -507,7 +556,7 @@ fn print_sync(mut cx: MethodContext<JsCompiler>) -> JsResult<JsValue> {
                     .clone()
                     .unwrap_or(SourceMapsConfig::Bool(false)),
                 None,
-                options.config.unwrap_or_default().minify.unwrap_or(false),
+                options.config.unwrap_or_default().codegen_config(),
             )
         };
         complete_output(cx, result)
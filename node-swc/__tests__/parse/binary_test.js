@@ -0,0 +1,36 @@
+const swc = require("../../../");
+
+const src = `import foo from "foo";
+const a = 1.5, b = -2, c = 4294967295;
+foo("bar", [a, b, c], { d: null, e: true });`;
+
+it("should round-trip programs through the binary encoding", () => {
+  const m = swc.parseSync(src);
+  const decoded = swc.decodeProgram(swc.encodeProgram(m));
+
+  expect(decoded).toEqual(m);
+});
+
+it("should decode lazily", () => {
+  const m = swc.parseSync(src, { lazy: true });
+
+  expect(m.type).toBe(`Module`);
+  expect(m.body).toHaveLength(3);
+  expect(m.body[1].declarations[0].init.value).toBe(1.5);
+  expect(m.body[1].declarations[2].init.value).toBe(4294967295);
+  expect(JSON.parse(JSON.stringify(m))).toEqual(swc.parseSync(src));
+});
+
+it("should allow modifying lazily decoded programs", () => {
+  const m = swc.parseSync(`foo(1)`, { lazy: true });
+  m.body[0].expression.arguments[0].expression.value = 2;
+
+  expect(swc.printSync(m).code.trim()).toBe(`foo(2);`);
+});
+
+it("should transform lazily decoded programs", () => {
+  const m = swc.parseSync(`const a = () => 1;`, { lazy: true });
+  const out = swc.transformSync(m, {});
+
+  expect(out.code.trim()).toContain(`var a = function()`);
+});
@@ -0,0 +1,317 @@
+/**
+ * Binary encoding of programs transferred between the native binding and
+ * javascript. See `src/binary.rs` for the layout.
+ */
+import { Program } from "./types";
+
+const MAGIC = "SWCB";
+const VERSION = 1;
+
+const NULL = 0;
+const FALSE = 1;
+const TRUE = 2;
+const I32 = 3;
+const U32 = 4;
+const F64 = 5;
+const STRING = 6;
+const ARRAY = 7;
+const OBJECT = 8;
+
+export interface DecodeOptions {
+  /**
+   * Decode a node only when a property of its parent is accessed, so that
+   * reading a few nodes does not materialize the whole tree.
+   *
+   * Defaults to false.
+   */
+  lazy?: boolean;
+}
+
+class Reader {
+  readonly strings: string[] = [];
+  readonly view: DataView;
+  root: number;
+
+  constructor(readonly buf: Buffer) {
+    this.view = new DataView(buf.buffer, buf.byteOffset, buf.byteLength);
+    if (buf.toString("latin1", 0, MAGIC.length) !== MAGIC) {
+      throw new Error("invalid binary program: bad magic number");
+    }
+    const version = buf[MAGIC.length];
+    if (version !== VERSION) {
+      throw new Error(`unsupported version of binary program: ${version}`);
+    }
+
+    let pos = MAGIC.length + 1;
+    const count = this.u32(pos);
+    pos += 4;
+    for (let i = 0; i < count; i++) {
+      const len = this.u32(pos);
+      pos += 4;
+      this.strings.push(buf.toString("utf8", pos, pos + len));
+      pos += len;
+    }
+    this.root = pos;
+  }
+
+  u32(pos: number): number {
+    return this.view.getUint32(pos, true);
+  }
+
+  /**
+   * Returns the position after the value at `pos`.
+   */
+  skip(pos: number): number {
+    switch (this.buf[pos]) {
+      case NULL:
+      case FALSE:
+      case TRUE:
+        return pos + 1;
+      case I32:
+      case U32:
+      case STRING:
+        return pos + 5;
+      case F64:
+        return pos + 9;
+      case ARRAY:
+      case OBJECT:
+        return pos + 9 + this.u32(pos + 5);
+      default:
+        throw new Error(`invalid tag in binary program: ${this.buf[pos]}`);
+    }
+  }
+
+  /**
+   * Decodes the value at `pos`. Containers are decoded lazily if `lazy` is
+   * true.
+   */
+  value(pos: number, lazy: boolean): any {
+    switch (this.buf[pos]) {
+      case NULL:
+        return null;
+      case FALSE:
+        return false;
+      case TRUE:
+        return true;
+      case I32:
+        return this.view.getInt32(pos + 1, true);
+      case U32:
+        return this.u32(pos + 1);
+      case F64:
+        return this.view.getFloat64(pos + 1, true);
+      case STRING:
+        return this.strings[this.u32(pos + 1)];
+      case ARRAY: {
+        const count = this.u32(pos + 1);
+        const items = new Array(count);
+        let cur = pos + 9;
+        for (let i = 0; i < count; i++) {
+          items[i] = this.value(cur, lazy);
+          cur = this.skip(cur);
+        }
+        return items;
+      }
+      case OBJECT:
+        return lazy ? this.lazyObject(pos) : this.object(pos);
+      default:
+        throw new Error(`invalid tag in binary program: ${this.buf[pos]}`);
+    }
+  }
+
+  object(pos: number): any {
+    const count = this.u32(pos + 1);
+    const obj: any = {};
+    let cur = pos + 9;
+    for (let i = 0; i < count; i++) {
+      const key = this.strings[this.u32(cur)];
+      obj[key] = this.value(cur + 4, false);
+      cur = this.skip(cur + 4);
+    }
+    return obj;
+  }
+
+  /**
+   * Creates an object whose properties are decoded on the first access.
+   */
+  lazyObject(pos: number): any {
+    const count = this.u32(pos + 1);
+    const obj: any = {};
+    let cur = pos + 9;
+    for (let i = 0; i < count; i++) {
+      const key = this.strings[this.u32(cur)];
+      const valuePos = cur + 4;
+      Object.defineProperty(obj, key, {
+        configurable: true,
+        enumerable: true,
+        get: () => {
+          const value = this.value(valuePos, true);
+          Object.defineProperty(obj, key, {
+            configurable: true,
+            enumerable: true,
+            writable: true,
+            value
+          });
+          return value;
+        },
+        set: (value: any) => {
+          Object.defineProperty(obj, key, {
+            configurable: true,
+            enumerable: true,
+            writable: true,
+            value
+          });
+        }
+      });
+      cur = this.skip(valuePos);
+    }
+    return obj;
+  }
+}
+
+/**
+ * Decodes a program encoded by the native binding.
+ */
+export function decodeProgram<T = Program>(
+  buf: Buffer,
+  options?: DecodeOptions
+): T {
+  const r = new Reader(buf);
+  return r.value(r.root, !!(options && options.lazy));
+}
+
+class Writer {
+  private buf: Buffer = Buffer.alloc(1024);
+  private len = 0;
+  private readonly strings = new Map<string, number>();
+
+  reserve(n: number) {
+    if (this.len + n <= this.buf.length) {
+      return;
+    }
+    let size = this.buf.length * 2;
+    while (size < this.len + n) {
+      size *= 2;
+    }
+    const buf = Buffer.alloc(size);
+    this.buf.copy(buf, 0, 0, this.len);
+    this.buf = buf;
+  }
+
+  u8(v: number) {
+    this.reserve(1);
+    this.buf[this.len++] = v;
+  }
+
+  u32(v: number) {
+    this.reserve(4);
+    this.buf.writeUInt32LE(v, this.len);
+    this.len += 4;
+  }
+
+  collect(value: any) {
+    if (typeof value === "string") {
+      this.intern(value);
+    } else if (Array.isArray(value)) {
+      for (const item of value) {
+        this.collect(item);
+      }
+    } else if (value !== null && typeof value === "object") {
+      for (const key of Object.keys(value)) {
+        if (value[key] === undefined) {
+          continue;
+        }
+        this.intern(key);
+        this.collect(value[key]);
+      }
+    }
+  }
+
+  intern(s: string) {
+    if (!this.strings.has(s)) {
+      this.strings.set(s, this.strings.size);
+    }
+  }
+
+  value(value: any) {
+    if (value === null || value === undefined) {
+      this.u8(NULL);
+    } else if (value === false) {
+      this.u8(FALSE);
+    } else if (value === true) {
+      this.u8(TRUE);
+    } else if (typeof value === "number") {
+      if ((value | 0) === value && !Object.is(value, -0)) {
+        this.u8(I32);
+        this.reserve(4);
+        this.buf.writeInt32LE(value, this.len);
+        this.len += 4;
+      } else if (value >>> 0 === value) {
+        this.u8(U32);
+        this.u32(value);
+      } else {
+        this.u8(F64);
+        this.reserve(8);
+        this.buf.writeDoubleLE(value, this.len);
+        this.len += 8;
+      }
+    } else if (typeof value === "string") {
+      this.u8(STRING);
+      this.u32(this.strings.get(value)!);
+    } else if (Array.isArray(value)) {
+      const pos = this.startContainer(ARRAY, value.length);
+      for (const item of value) {
+        this.value(item);
+      }
+      this.endContainer(pos);
+    } else {
+      const keys = Object.keys(value).filter(k => value[k] !== undefined);
+      const pos = this.startContainer(OBJECT, keys.length);
+      for (const key of keys) {
+        this.u32(this.strings.get(key)!);
+        this.value(value[key]);
+      }
+      this.endContainer(pos);
+    }
+  }
+
+  startContainer(tag: number, count: number): number {
+    this.u8(tag);
+    this.u32(count);
+    const pos = this.len;
+    this.u32(0);
+    return pos;
+  }
+
+  endContainer(pos: number) {
+    this.buf.writeUInt32LE(this.len - pos - 4, pos);
+  }
+
+  finish(root: any): Buffer {
+    const header = Buffer.from(MAGIC, "latin1");
+    this.reserve(header.length + 1);
+    header.copy(this.buf, this.len);
+    this.len += header.length;
+    this.u8(VERSION);
+
+    this.u32(this.strings.size);
+    for (const s of this.strings.keys()) {
+      const len = Buffer.byteLength(s);
+      this.u32(len);
+      this.reserve(len);
+      this.buf.write(s, this.len);
+      this.len += len;
+    }
+
+    this.value(root);
+    return this.buf.slice(0, this.len);
+  }
+}
+
+/**
+ * Encodes a program for the native binding.
+ */
+export function encodeProgram(program: Program): Buffer {
+  const w = new Writer();
+  w.collect(program);
+  return w.finish(program);
+}
@@ -9,6 +9,8 @@ import {
 } from "./types";
 export * from "./types";
 import { wrapNativeSuper } from "./util";
+import { decodeProgram, encodeProgram, DecodeOptions } from "./binary";
+export { decodeProgram, encodeProgram, DecodeOptions } from "./binary";
 
 const native = require("./native");
 
@@ -38,13 +40,12 @@ export class Compiler extends wrapNativeSuper(native.Compiler) {
   ): Promise<Script>;
   parse(src: string, options?: ParseOptions): Promise<Module>;
   parse(src: string, options?: ParseOptions): Promise<Program> {
-    options = options || { syntax: "ecmascript" };
-    options.syntax = options.syntax || "ecmascript";
+    const [opts, decodeOptions] = splitParseOptions(options);
 
     return new Promise((resolve, reject) => {
-      super.parse(src, options, (err: any, value: string) => {
+      super.parse(src, opts, (err: any, value: Buffer) => {
         if (!!err) return reject(err);
-        resolve(JSON.parse(value));
+        resolve(decodeProgram(value, decodeOptions));
       });
     });
   }
@@ -52,9 +53,8 @@ export class Compiler extends wrapNativeSuper(native.Compiler) {
   parseSync(src: string, options: ParseOptions & { isModule: false }): Script;
   parseSync(src: string, options?: ParseOptions): Module;
   parseSync(src: string, options?: ParseOptions): Program {
-    options = options || { syntax: "ecmascript" };
-    options.syntax = options.syntax || "ecmascript";
-    return JSON.parse(super.parseSync(src, options));
+    const [opts, decodeOptions] = splitParseOptions(options);
+    return decodeProgram(super.parseSync(src, opts), decodeOptions);
   }
 
   parseFile(
@@ -63,13 +63,12 @@ export class Compiler extends wrapNativeSuper(native.Compiler) {
   ): Promise<Script>;
   parseFile(path: string, options?: ParseOptions): Promise<Module>;
   parseFile(path: string, options?: ParseOptions): Promise<Program> {
-    options = options || { syntax: "ecmascript" };
-    options.syntax = options.syntax || "ecmascript";
+    const [opts, decodeOptions] = splitParseOptions(options);
 
     return new Promise((resolve, reject) => {
-      super.parseFile(path, options, (err: any, value: string) => {
+      super.parseFile(path, opts, (err: any, value: Buffer) => {
         if (!!err) return reject(err);
-        resolve(JSON.parse(value));
+        resolve(decodeProgram(value, decodeOptions));
       });
     });
   }
@@ -80,9 +79,8 @@ export class Compiler extends wrapNativeSuper(native.Compiler) {
   ): Script;
   parseFileSync(path: string, options?: ParseOptions): Module;
   parseFileSync(path: string, options?: ParseOptions): Program {
-    options = options || { syntax: "ecmascript" };
-    options.syntax = options.syntax || "ecmascript";
-    return JSON.parse(super.parseFileSync(path, options));
+    const [opts, decodeOptions] = splitParseOptions(options);
+    return decodeProgram(super.parseFileSync(path, opts), decodeOptions);
   }
 
   /**
@@ -93,7 +91,7 @@ export class Compiler extends wrapNativeSuper(native.Compiler) {
     options = options || {};
 
     return new Promise((resolve, reject) => {
-      super.print(JSON.stringify(m), options, (err: any, value: Output) => {
+      super.print(encodeProgram(m), options, (err: any, value: Output) => {
         if (!!err) return reject(err);
         resolve(value);
       });
@@ -107,7 +105,7 @@ export class Compiler extends wrapNativeSuper(native.Compiler) {
   printSync(m: Program, options?: Options): Output {
     options = options || {};
 
-    return super.printSync(JSON.stringify(m), options);
+    return super.printSync(encodeProgram(m), options);
   }
 
   async transform(src: string | Program, options?: Options): Promise<Output> {
@@ -127,7 +125,7 @@ export class Compiler extends wrapNativeSuper(native.Compiler) {
 
     return new Promise((resolve, reject) => {
       super.transform(
-        isModule ? JSON.stringify(src) : src,
+        isModule ? encodeProgram(src as Program) : src,
         isModule,
         options,
         (err: any, value: Output) => {
@@ -152,7 +150,7 @@ export class Compiler extends wrapNativeSuper(native.Compiler) {
     }
 
     return super.transformSync(
-      isModule ? JSON.stringify(src) : src,
+      isModule ? encodeProgram(src as Program) : src,
       isModule,
       options
     );
@@ -197,6 +195,18 @@ export class Compiler extends wrapNativeSuper(native.Compiler) {
   }
 }
 
+/**
+ * Splits options of the parser from options of `decodeProgram`, which are not
+ * known to the native binding.
+ */
+function splitParseOptions(
+  options?: ParseOptions
+): [ParseOptions, DecodeOptions] {
+  const { lazy, ...opts } = options || ({ syntax: "ecmascript" } as ParseOptions);
+  opts.syntax = opts.syntax || "ecmascript";
+  return [opts as ParseOptions, { lazy }];
+}
+
 const compiler = new Compiler();
 
 export function parse(
@@ -9,6 +9,12 @@ export type ParseOptions = ParserConfig & {
    * Defaults to es3.
    */
   target?: JscTarget;
+  /**
+   * Decode nodes of the returned program only when they are accessed.
+   *
+   * This is faster for plugins which read only a few nodes.
+   */
+  lazy?: boolean;
 };
 
 /**
@@ -189,6 +195,36 @@ export interface Options extends Config {
   plugin?: Plugin;
 
   isModule?: boolean;
+
+  /**
+   * `true` to load `compilerOptions` from the nearest `tsconfig.json`,
+   * or a path to `tsconfig.json`.
+   *
+   * Values from `.swcrc` have priority over `tsconfig.json`.
+   *
+   * Defaults to `false`.
+   */
+  tsconfig?: boolean | string;
+
+  /**
+   * Cache outputs on disk. Cached outputs are reused if the source text,
+   * resolved config and the version of swc are same.
+   */
+  cache?: CacheConfig;
+}
+
+export interface CacheConfig {
+  /**
+   * Directory used to store cached outputs.
+   */
+  dir: string;
+
+  /**
+   * Maximum total size of cached outputs, in bytes.
+   *
+   * Defaults to 256MiB.
+   */
+  maxSize?: number;
 }
 
 export interface CallerOptions {
@@ -202,17 +238,67 @@ export type Swcrc = Config | Config[];
  * .swcrc
  */
 export interface Config {
+  /**
+   * Regex(es) of files this config applies to.
+   */
+  test?: string | string[];
+  /**
+   * Regex(es) of files this config does not apply to.
+   */
+  exclude?: string | string[];
   env?: EnvConfig;
   jsc?: JscConfig;
   module?: ModuleConfig;
   minify?: boolean;
+  lint?: LintConfig;
+  /**
+   * Injects istanbul-compatible coverage counters.
+   */
+  instrument?: InstrumentConfig;
+  /**
+   * Configs merged over this one if the key matches `envName`.
+   */
+  envs?: { [envName: string]: Config };
+  /**
+   * Configs merged over this one if `test` and `exclude` of the item match
+   * the file.
+   */
+  overrides?: Config[];
+}
+
+export type LintRuleSeverity = "off" | "warn" | "error";
+
+export interface LintConfig {
+  rules?: {
+    "no-undef"?: LintRuleSeverity;
+    "no-unused-vars"?: LintRuleSeverity;
+    "no-dupe-keys"?: LintRuleSeverity;
+    "no-unreachable"?: LintRuleSeverity;
+    "no-self-assign"?: LintRuleSeverity;
+    "no-const-assign"?: LintRuleSeverity;
+    "valid-typeof"?: LintRuleSeverity;
+    "no-debugger"?: LintRuleSeverity;
+  };
+  /**
+   * Names of global variables which are defined by the environment.
+   */
+  globals?: string[];
+}
+
+export interface InstrumentConfig {
+  /**
+   * Name of the global variable used to store coverage data.
+   *
+   * Defaults to `__coverage__`.
+   */
+  coverageVariable?: string;
 }
 
 /**
  * Configuration ported from babel-preset-env
  */
 export interface EnvConfig {
-  mode?: "usage" | "entry";
+  mode?: "usage" | "entry" | "pure";
   debug?: boolean;
   dynamicImport?: boolean;
 
@@ -261,6 +347,127 @@ export interface JscConfig {
    * Defaults to `es3` (which enableds **all** pass).
    */
   target?: JscTarget;
+
+  /**
+   * Same as `compilerOptions.baseUrl` of `tsconfig.json`.
+   */
+  baseUrl?: string;
+
+  /**
+   * Same as `compilerOptions.paths` of `tsconfig.json`.
+   *
+   * Matched import specifiers are rewritten to relative paths.
+   */
+  paths?: { [from: string]: string[] };
+
+  /**
+   * Report early errors which require scope analysis, like redeclaration of
+   * lexically declared names.
+   *
+   * Defaults to `false`.
+   */
+  earlyErrors?: boolean;
+
+  /**
+   * Comments to keep in the output.
+   *
+   * - `"all"`: Keep all comments.
+   * - `"some"` or `"license"`: Keep `/*! ... *\/` and comments containing
+   *   `@license` or `@preserve`.
+   * - `"none"`: Drop all comments.
+   * - `"/pattern/"` or `{ regex: "pattern" }`: Keep comments whose text
+   *   matches the regex.
+   *
+   * Defaults to `"all"`, or to keeping only `/*! ... *\/` if `minify` is
+   * enabled.
+   */
+  comments?: "all" | "some" | "license" | "none" | string | { regex: string };
+
+  output?: OutputConfig;
+
+  /**
+   * Assumptions about the input code, which allow smaller output. Same as
+   * `assumptions` of babel.
+   */
+  assumptions?: Assumptions;
+
+  /**
+   * Emit import attributes like `with { type: "json" }` as written. They are
+   * removed by default, as no target supports them yet.
+   *
+   * Defaults to `false`.
+   */
+  keepImportAttributes?: boolean;
+}
+
+export interface Assumptions {
+  /**
+   * Iterables used by spread, `for-of` and array destructuring are arrays.
+   */
+  iterableIsArray?: boolean;
+
+  /**
+   * Class fields are assigned instead of being defined.
+   */
+  setPublicClassFields?: boolean;
+
+  /**
+   * `document.all` is not used, so `== null` checks for both `null` and
+   * `undefined`.
+   */
+  noDocumentAll?: boolean;
+
+  /**
+   * The super class of a class is not changed after the class is defined.
+   */
+  constantSuper?: boolean;
+
+  /**
+   * Properties of object spreads are assigned instead of being defined.
+   */
+  setSpreadProperties?: boolean;
+
+  /**
+   * Private fields are stored as non-enumerable properties instead of in
+   * `WeakMap`s.
+   */
+  privateFieldsAsProperties?: boolean;
+
+  /**
+   * Objects are converted to property keys without the `"string"` hint of
+   * `Symbol.toPrimitive`.
+   */
+  ignoreToPrimitiveHint?: boolean;
+
+  /**
+   * Classes are never called without `new`.
+   */
+  noClassCalls?: boolean;
+}
+
+export interface OutputConfig {
+  /**
+   * Escape non-ASCII characters. `\u{...}` escapes are used only if
+   * `jsc.target` is es2015 or newer.
+   *
+   * Defaults to `false`.
+   */
+  asciiOnly?: boolean;
+
+  /**
+   * The other quote is used if it requires fewer escapes.
+   *
+   * Defaults to `"single"`.
+   */
+  quoteStyle?: "single" | "double";
+
+  /**
+   * Print numbers and strings as written in the input, unless it's not
+   * allowed by `jsc.target` or `asciiOnly`.
+   *
+   * Defaults to `false`.
+   */
+  keepRaw?: boolean;
 }
 
 export type JscTarget =
@@ -272,7 +479,7 @@ export type JscTarget =
   | "es2018"
   | "es2019";
 
-export type ParserConfig = TsParserConfig | EsParserConfig;
+export type ParserConfig = TsParserConfig | EsParserConfig | FlowParserConfig;
 export interface TsParserConfig {
   syntax: "typescript";
   /**
@@ -287,6 +494,41 @@ export interface TsParserConfig {
    * Defaults to `false`
    */
   dynamicImport?: boolean;
+  /**
+   * Defaults to `false`
+   */
+  staticBlocks?: boolean;
+  /**
+   * Defaults to `false`
+   */
+  privateInObject?: boolean;
+  /**
+   * Defaults to `false`
+   */
+  importAttributes?: boolean;
+}
+
+export interface FlowParserConfig {
+  syntax: "flow";
+  /**
+   * Defaults to `false`.
+   */
+  jsx?: boolean;
+  /**
+   * Parse ambiguous syntax like `f<T>(x)` as flow even if the file does not
+   * start with a `@flow` pragma.
+   *
+   * Defaults to `false`.
+   */
+  all?: boolean;
+  /**
+   * Defaults to `false`.
+   */
+  decorators?: boolean;
+  /**
+   * Defaults to `false`
+   */
+  dynamicImport?: boolean;
 }
 
 export interface EsParserConfig {
@@ -331,6 +573,18 @@ export interface EsParserConfig {
    * Defaults to `false`
    */
   nullishCoalescing?: boolean;
+  /**
+   * Defaults to `false`
+   */
+  staticBlocks?: boolean;
+  /**
+   * Defaults to `false`
+   */
+  privateInObject?: boolean;
+  /**
+   * Defaults to `false`
+   */
+  importAttributes?: boolean;
 }
 
 /**
@@ -348,6 +602,26 @@ export interface TransformConfig {
    * Defaults to null, which skips optimizer pass.
    */
   optimizer?: OptimizerConfig;
+
+  legacyDecorator?: boolean;
+
+  /**
+   * Version of the decorators proposal. Ignored if `legacyDecorator` is true.
+   *
+   * Defaults to `"2018-09"`.
+   */
+  decoratorVersion?: "2018-09" | "2022-03";
+
+  /**
+   * Emits `design:type`, `design:paramtypes` and `design:returntype` metadata
+   * for decorated classes. Typescript only.
+   */
+  decoratorMetadata?: boolean;
+  /**
+   * Hoists `jest.mock()` and similar calls above imports, like
+   * `babel-plugin-jest-hoist`.
+   */
+  jestHoist?: boolean;
 }
 
 export interface ReactConfig {
@@ -561,6 +835,7 @@ export type ClassMember =
   | PrivateMethod
   | ClassProperty
   | PrivateProperty
+  | StaticBlock
   | TsIndexSignature;
 
 export interface ClassPropertyBase extends Node, HasSpan, HasDecorator {
@@ -641,6 +916,12 @@ export interface PrivateMethod extends ClassMethodBase {
   key: PrivateName;
 }
 
+export interface StaticBlock extends Node, HasSpan {
+  type: "StaticBlock";
+
+  body: BlockStatement;
+}
+
 export interface Decorator extends Node, HasSpan {
   type: "Decorator";
 
@@ -733,6 +1014,7 @@ export type Expression =
   | TsAsExpression
   | PrivateName
   | OptionalChainingExpression
+  | BindExpression
   | Invalid;
 
 interface ExpressionBase extends Node, HasSpan { }
@@ -745,6 +1027,15 @@ export interface OptionalChainingExpression extends ExpressionBase {
   expr: Expression;
 }
 
+export interface BindExpression extends ExpressionBase {
+  type: "BindExpression";
+  /**
+   * `null` for `::obj.func`.
+   */
+  object?: Expression;
+  callee: Expression;
+}
+
 export interface ThisExpression extends ExpressionBase {
   type: "ThisExpression";
 }
@@ -1164,6 +1455,19 @@ export interface ImportDeclaration extends Node, HasSpan {
   specifiers: ImporSpecifier[];
 
   source: StringLiteral;
+
+  attributes?: ImportAttributes;
+}
+
+/**
+ * `with { type: "json" }` or `assert { type: "json" }`
+ */
+export interface ImportAttributes extends Node, HasSpan {
+  type: "ImportAttributes";
+
+  kind: "with" | "assert";
+
+  attributes: ObjectExpression;
 }
 
 export type ImporSpecifier =
@@ -1175,6 +1479,8 @@ export interface ExportAllDeclaration extends Node, HasSpan {
   type: "ExportAllDeclaration";
 
   source: StringLiteral;
+
+  attributes?: ImportAttributes;
 }
 
 /**
@@ -1187,6 +1493,8 @@ export interface ExportNamedDeclaration extends Node, HasSpan {
   specifiers: ExportSpecifier[];
 
   source?: StringLiteral;
+
+  attributes?: ImportAttributes;
 }
 
 export interface ExportDefaultDeclaration extends Node, HasSpan {
@@ -1809,6 +2117,7 @@ export interface TsConstructorType extends Node, HasSpan {
 
   typeParams: TsTypeParameterDeclaration;
   typeAnnotation: TsTypeAnnotation;
+  abstract: boolean;
 }
 
 export interface TsTypeReference extends Node, HasSpan {
@@ -1861,7 +2170,17 @@ export interface TsArrayType extends Node, HasSpan {
 export interface TsTupleType extends Node, HasSpan {
   type: "TsTupleType";
 
-  elemTypes: TsType[];
+  elemTypes: TsTupleElement[];
+}
+
+export interface TsTupleElement extends Node, HasSpan {
+  type: "TsTupleElement";
+
+  /**
+   * `Identifier` for `name: T` and `name?: T`, `RestElement` for `...name: T`.
+   */
+  label?: Pattern;
+  ty: TsType;
 }
 
 export interface TsOptionalType extends Node, HasSpan {
@@ -1934,6 +2253,7 @@ export interface TsMappedType extends Node, HasSpan {
 
   readonly: TruePlusMinus;
   typeParam: TsTypeParameter;
+  nameType?: TsType;
   optional: TruePlusMinus;
   typeAnnotation: TsType;
 }
@@ -1944,7 +2264,18 @@ export interface TsLiteralType extends Node, HasSpan {
   literal: TsLiteral;
 }
 
-export type TsLiteral = NumericLiteral | StringLiteral | BooleanLiteral | TemplateLiteral;
+export type TsLiteral =
+  | NumericLiteral
+  | StringLiteral
+  | BooleanLiteral
+  | TsTemplateLiteralType;
+
+export interface TsTemplateLiteralType extends Node, HasSpan {
+  type: "TemplateLiteral";
+
+  types: TsType[];
+  quasis: TemplateElement[];
+}
 
 // // ================
 // // TypeScript declarations
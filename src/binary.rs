@@ -0,0 +1,902 @@
+//! Binary encoding of programs transferred between rust and javascript.
+//!
+//! The format has the data model of json, but strings are stored once in a
+//! table and containers are prefixed with their byte length, so a reader can
+//! skip nodes it does not need. `node-swc/src/binary.ts` implements the same
+//! format.
+//!
+//! ```text
+//! file   := "SWCB" version:u8 count:u32 (len:u32 utf8)* value
+//! value  := 0x00                                  // null
+//!         | 0x01 | 0x02                           // false, true
+//!         | 0x03 i32 | 0x04 u32 | 0x05 f64
+//!         | 0x06 index:u32                        // string
+//!         | 0x07 count:u32 len:u32 value*         // array
+//!         | 0x08 count:u32 len:u32 (key:u32 value)* // object
+//! ```
+//!
+//! All numbers are little endian. `len` is the byte length of the contents
+//! after it.
+//!
+//! Values are written and read directly by the serde implementations of the
+//! ast types, in the same shape `serde_json` would produce.
+use crate::ecmascript::ast::Program;
+use anyhow::{bail, Context, Error};
+use serde::{
+    de::{
+        self, value::BorrowedStrDeserializer, DeserializeSeed, EnumAccess, MapAccess, SeqAccess,
+        VariantAccess, Visitor,
+    },
+    forward_to_deserialize_any,
+    ser::{self, Impossible},
+    Deserialize, Serialize,
+};
+use std::{collections::HashMap, convert::TryInto, fmt};
+
+const MAGIC: &[u8] = b"SWCB";
+const VERSION: u8 = 1;
+
+const NULL: u8 = 0;
+const FALSE: u8 = 1;
+const TRUE: u8 = 2;
+const I32: u8 = 3;
+const U32: u8 = 4;
+const F64: u8 = 5;
+const STRING: u8 = 6;
+const ARRAY: u8 = 7;
+const OBJECT: u8 = 8;
+
+pub fn encode_program(program: &Program) -> Result<Vec<u8>, Error> {
+    let mut enc = Encoder::default();
+    program
+        .serialize(&mut enc)
+        .context("failed to serialize Program")?;
+
+    let bytes = enc.table.iter().map(|s| 4 + s.len()).sum::<usize>();
+    let mut buf = Vec::with_capacity(MAGIC.len() + 5 + bytes + enc.buf.len());
+    buf.extend_from_slice(MAGIC);
+    buf.push(VERSION);
+    write_u32(&mut buf, enc.table.len());
+    for s in &enc.table {
+        write_u32(&mut buf, s.len());
+        buf.extend_from_slice(s.as_bytes());
+    }
+    buf.extend_from_slice(&enc.buf);
+
+    Ok(buf)
+}
+
+pub fn decode_program(bytes: &[u8]) -> Result<Program, Error> {
+    let mut de = Decoder {
+        bytes,
+        pos: 0,
+        table: vec![],
+    };
+    if de.take(MAGIC.len())? != MAGIC {
+        bail!("invalid binary program: bad magic number")
+    }
+    let version = de.u8()?;
+    if version != VERSION {
+        bail!("unsupported version of binary program: {}", version)
+    }
+
+    let count = de.u32()?;
+    de.table.reserve(count as usize);
+    for _ in 0..count {
+        let len = de.u32()? as usize;
+        let s = std::str::from_utf8(de.take(len)?).context("invalid string in binary program")?;
+        de.table.push(s);
+    }
+
+    let program = Program::deserialize(&mut de).context("failed to deserialize Program")?;
+    if de.pos != bytes.len() {
+        bail!("invalid binary program: trailing bytes")
+    }
+
+    Ok(program)
+}
+
+#[derive(Debug)]
+struct CodecError(String);
+
+impl fmt::Display for CodecError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)
+    }
+}
+
+impl std::error::Error for CodecError {}
+
+impl ser::Error for CodecError {
+    fn custom<T: fmt::Display>(msg: T) -> Self {
+        CodecError(msg.to_string())
+    }
+}
+
+impl de::Error for CodecError {
+    fn custom<T: fmt::Display>(msg: T) -> Self {
+        CodecError(msg.to_string())
+    }
+}
+
+fn write_u32(buf: &mut Vec<u8>, v: usize) {
+    buf.extend_from_slice(&(v as u32).to_le_bytes());
+}
+
+#[derive(Default)]
+struct Encoder {
+    /// Encoded value, without the header and the string table.
+    buf: Vec<u8>,
+    table: Vec<String>,
+    indices: HashMap<String, usize>,
+}
+
+impl Encoder {
+    fn index(&mut self, s: &str) -> usize {
+        if let Some(&idx) = self.indices.get(s) {
+            return idx;
+        }
+        let idx = self.table.len();
+        self.indices.insert(s.to_string(), idx);
+        self.table.push(s.to_string());
+        idx
+    }
+
+    fn string(&mut self, s: &str) {
+        let idx = self.index(s);
+        self.buf.push(STRING);
+        write_u32(&mut self.buf, idx);
+    }
+
+    fn key(&mut self, s: &str) {
+        let idx = self.index(s);
+        write_u32(&mut self.buf, idx);
+    }
+
+    fn int(&mut self, v: i64) {
+        if let Ok(v) = v.try_into() {
+            let v: i32 = v;
+            self.buf.push(I32);
+            self.buf.extend_from_slice(&v.to_le_bytes());
+        } else if let Ok(v) = v.try_into() {
+            let v: u32 = v;
+            self.buf.push(U32);
+            self.buf.extend_from_slice(&v.to_le_bytes());
+        } else {
+            self.float(v as f64);
+        }
+    }
+
+    fn uint(&mut self, v: u64) {
+        if let Ok(v) = v.try_into() {
+            self.int(v)
+        } else {
+            self.float(v as f64)
+        }
+    }
+
+    fn float(&mut self, v: f64) {
+        // Like json, there's no representation for nan and infinity.
+        if v.is_finite() {
+            self.buf.push(F64);
+            self.buf.extend_from_slice(&v.to_le_bytes());
+        } else {
+            self.buf.push(NULL);
+        }
+    }
+
+    /// Writes placeholders for the count and the length of a container and
+    /// returns their position.
+    fn start(&mut self, tag: u8) -> usize {
+        self.buf.push(tag);
+        let pos = self.buf.len();
+        write_u32(&mut self.buf, 0);
+        write_u32(&mut self.buf, 0);
+        pos
+    }
+
+    fn end(&mut self, pos: usize, count: usize) {
+        let len = (self.buf.len() - pos - 8) as u32;
+        self.buf[pos..pos + 4].copy_from_slice(&(count as u32).to_le_bytes());
+        self.buf[pos + 4..pos + 8].copy_from_slice(&len.to_le_bytes());
+    }
+
+    /// Enum variants with data are encoded as `{ variant: data }`.
+    fn start_variant(&mut self, variant: &str) -> usize {
+        let pos = self.start(OBJECT);
+        self.key(variant);
+        pos
+    }
+
+    fn container(&mut self, tag: u8, variant: Option<&str>) -> Container<'_> {
+        let outer = variant.map(|variant| self.start_variant(variant));
+        let pos = self.start(tag);
+        Container {
+            enc: self,
+            pos,
+            count: 0,
+            outer,
+        }
+    }
+}
+
+struct Container<'a> {
+    enc: &'a mut Encoder,
+    pos: usize,
+    count: usize,
+    /// Position of the object wrapping an enum variant.
+    outer: Option<usize>,
+}
+
+impl Container<'_> {
+    fn value<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), CodecError> {
+        self.count += 1;
+        value.serialize(&mut *self.enc)
+    }
+
+    fn field<T: ?Sized + Serialize>(&mut self, key: &str, value: &T) -> Result<(), CodecError> {
+        self.enc.key(key);
+        self.value(value)
+    }
+
+    fn finish(self) -> Result<(), CodecError> {
+        self.enc.end(self.pos, self.count);
+        if let Some(outer) = self.outer {
+            self.enc.end(outer, 1);
+        }
+        Ok(())
+    }
+}
+
+impl<'a> ser::Serializer for &'a mut Encoder {
+    type Ok = ();
+    type Error = CodecError;
+
+    type SerializeSeq = Container<'a>;
+    type SerializeTuple = Container<'a>;
+    type SerializeTupleStruct = Container<'a>;
+    type SerializeTupleVariant = Container<'a>;
+    type SerializeMap = Container<'a>;
+    type SerializeStruct = Container<'a>;
+    type SerializeStructVariant = Container<'a>;
+
+    fn serialize_bool(self, v: bool) -> Result<(), CodecError> {
+        self.buf.push(if v { TRUE } else { FALSE });
+        Ok(())
+    }
+
+    fn serialize_i8(self, v: i8) -> Result<(), CodecError> {
+        self.serialize_i64(v as i64)
+    }
+
+    fn serialize_i16(self, v: i16) -> Result<(), CodecError> {
+        self.serialize_i64(v as i64)
+    }
+
+    fn serialize_i32(self, v: i32) -> Result<(), CodecError> {
+        self.serialize_i64(v as i64)
+    }
+
+    fn serialize_i64(self, v: i64) -> Result<(), CodecError> {
+        self.int(v);
+        Ok(())
+    }
+
+    fn serialize_u8(self, v: u8) -> Result<(), CodecError> {
+        self.serialize_u64(v as u64)
+    }
+
+    fn serialize_u16(self, v: u16) -> Result<(), CodecError> {
+        self.serialize_u64(v as u64)
+    }
+
+    fn serialize_u32(self, v: u32) -> Result<(), CodecError> {
+        self.serialize_u64(v as u64)
+    }
+
+    fn serialize_u64(self, v: u64) -> Result<(), CodecError> {
+        self.uint(v);
+        Ok(())
+    }
+
+    fn serialize_f32(self, v: f32) -> Result<(), CodecError> {
+        self.serialize_f64(v as f64)
+    }
+
+    fn serialize_f64(self, v: f64) -> Result<(), CodecError> {
+        self.float(v);
+        Ok(())
+    }
+
+    fn serialize_char(self, v: char) -> Result<(), CodecError> {
+        self.string(v.encode_utf8(&mut [0; 4]));
+        Ok(())
+    }
+
+    fn serialize_str(self, v: &str) -> Result<(), CodecError> {
+        self.string(v);
+        Ok(())
+    }
+
+    fn serialize_bytes(self, v: &[u8]) -> Result<(), CodecError> {
+        let mut seq = self.container(ARRAY, None);
+        for b in v {
+            seq.value(b)?;
+        }
+        seq.finish()
+    }
+
+    fn serialize_none(self) -> Result<(), CodecError> {
+        self.serialize_unit()
+    }
+
+    fn serialize_some<T: ?Sized + Serialize>(self, value: &T) -> Result<(), CodecError> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<(), CodecError> {
+        self.buf.push(NULL);
+        Ok(())
+    }
+
+    fn serialize_unit_struct(self, _: &'static str) -> Result<(), CodecError> {
+        self.serialize_unit()
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        variant: &'static str,
+    ) -> Result<(), CodecError> {
+        self.serialize_str(variant)
+    }
+
+    fn serialize_newtype_struct<T: ?Sized + Serialize>(
+        self,
+        _: &'static str,
+        value: &T,
+    ) -> Result<(), CodecError> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: ?Sized + Serialize>(
+        self,
+        _: &'static str,
+        _: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<(), CodecError> {
+        let pos = self.start_variant(variant);
+        value.serialize(&mut *self)?;
+        self.end(pos, 1);
+        Ok(())
+    }
+
+    fn serialize_seq(self, _: Option<usize>) -> Result<Container<'a>, CodecError> {
+        Ok(self.container(ARRAY, None))
+    }
+
+    fn serialize_tuple(self, _: usize) -> Result<Container<'a>, CodecError> {
+        Ok(self.container(ARRAY, None))
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Container<'a>, CodecError> {
+        Ok(self.container(ARRAY, None))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        variant: &'static str,
+        _: usize,
+    ) -> Result<Container<'a>, CodecError> {
+        Ok(self.container(ARRAY, Some(variant)))
+    }
+
+    fn serialize_map(self, _: Option<usize>) -> Result<Container<'a>, CodecError> {
+        Ok(self.container(OBJECT, None))
+    }
+
+    fn serialize_struct(self, _: &'static str, _: usize) -> Result<Container<'a>, CodecError> {
+        Ok(self.container(OBJECT, None))
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        variant: &'static str,
+        _: usize,
+    ) -> Result<Container<'a>, CodecError> {
+        Ok(self.container(OBJECT, Some(variant)))
+    }
+}
+
+macro_rules! impl_seq {
+    ($($T:ident::$method:ident),*) => {
+        $(
+            impl ser::$T for Container<'_> {
+                type Ok = ();
+                type Error = CodecError;
+
+                fn $method<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), CodecError> {
+                    self.value(value)
+                }
+
+                fn end(self) -> Result<(), CodecError> {
+                    self.finish()
+                }
+            }
+        )*
+    };
+}
+
+impl_seq!(
+    SerializeSeq::serialize_element,
+    SerializeTuple::serialize_element,
+    SerializeTupleStruct::serialize_field,
+    SerializeTupleVariant::serialize_field
+);
+
+impl ser::SerializeMap for Container<'_> {
+    type Ok = ();
+    type Error = CodecError;
+
+    fn serialize_key<T: ?Sized + Serialize>(&mut self, key: &T) -> Result<(), CodecError> {
+        let key = key.serialize(KeySerializer)?;
+        self.enc.key(&key);
+        Ok(())
+    }
+
+    fn serialize_value<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), CodecError> {
+        self.value(value)
+    }
+
+    fn end(self) -> Result<(), CodecError> {
+        self.finish()
+    }
+}
+
+impl ser::SerializeStruct for Container<'_> {
+    type Ok = ();
+    type Error = CodecError;
+
+    fn serialize_field<T: ?Sized + Serialize>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), CodecError> {
+        self.field(key, value)
+    }
+
+    fn end(self) -> Result<(), CodecError> {
+        self.finish()
+    }
+}
+
+impl ser::SerializeStructVariant for Container<'_> {
+    type Ok = ();
+    type Error = CodecError;
+
+    fn serialize_field<T: ?Sized + Serialize>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), CodecError> {
+        self.field(key, value)
+    }
+
+    fn end(self) -> Result<(), CodecError> {
+        self.finish()
+    }
+}
+
+/// Converts map keys to strings, like `serde_json` does.
+struct KeySerializer;
+
+fn key_must_be_a_string() -> CodecError {
+    CodecError("key must be a string".into())
+}
+
+macro_rules! key_to_string {
+    ($($method:ident($T:ty)),*) => {
+        $(
+            fn $method(self, v: $T) -> Result<String, CodecError> {
+                Ok(v.to_string())
+            }
+        )*
+    };
+}
+
+macro_rules! key_unsupported {
+    ($($method:ident($($T:ty),*)),*) => {
+        $(
+            fn $method(self, $(_: $T),*) -> Result<String, CodecError> {
+                Err(key_must_be_a_string())
+            }
+        )*
+    };
+}
+
+impl ser::Serializer for KeySerializer {
+    type Ok = String;
+    type Error = CodecError;
+
+    type SerializeSeq = Impossible<String, CodecError>;
+    type SerializeTuple = Impossible<String, CodecError>;
+    type SerializeTupleStruct = Impossible<String, CodecError>;
+    type SerializeTupleVariant = Impossible<String, CodecError>;
+    type SerializeMap = Impossible<String, CodecError>;
+    type SerializeStruct = Impossible<String, CodecError>;
+    type SerializeStructVariant = Impossible<String, CodecError>;
+
+    key_to_string!(
+        serialize_i8(i8),
+        serialize_i16(i16),
+        serialize_i32(i32),
+        serialize_i64(i64),
+        serialize_u8(u8),
+        serialize_u16(u16),
+        serialize_u32(u32),
+        serialize_u64(u64),
+        serialize_char(char),
+        serialize_str(&str)
+    );
+
+    key_unsupported!(
+        serialize_bool(bool),
+        serialize_f32(f32),
+        serialize_f64(f64),
+        serialize_bytes(&[u8]),
+        serialize_none(),
+        serialize_unit(),
+        serialize_unit_struct(&'static str)
+    );
+
+    fn serialize_unit_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        variant: &'static str,
+    ) -> Result<String, CodecError> {
+        Ok(variant.to_string())
+    }
+
+    fn serialize_some<T: ?Sized + Serialize>(self, _: &T) -> Result<String, CodecError> {
+        Err(key_must_be_a_string())
+    }
+
+    fn serialize_newtype_struct<T: ?Sized + Serialize>(
+        self,
+        _: &'static str,
+        value: &T,
+    ) -> Result<String, CodecError> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: ?Sized + Serialize>(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        _: &T,
+    ) -> Result<String, CodecError> {
+        Err(key_must_be_a_string())
+    }
+
+    fn serialize_seq(self, _: Option<usize>) -> Result<Self::SerializeSeq, CodecError> {
+        Err(key_must_be_a_string())
+    }
+
+    fn serialize_tuple(self, _: usize) -> Result<Self::SerializeTuple, CodecError> {
+        Err(key_must_be_a_string())
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Self::SerializeTupleStruct, CodecError> {
+        Err(key_must_be_a_string())
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Self::SerializeTupleVariant, CodecError> {
+        Err(key_must_be_a_string())
+    }
+
+    fn serialize_map(self, _: Option<usize>) -> Result<Self::SerializeMap, CodecError> {
+        Err(key_must_be_a_string())
+    }
+
+    fn serialize_struct(
+        self,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Self::SerializeStruct, CodecError> {
+        Err(key_must_be_a_string())
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Self::SerializeStructVariant, CodecError> {
+        Err(key_must_be_a_string())
+    }
+}
+
+struct Decoder<'de> {
+    bytes: &'de [u8],
+    pos: usize,
+    table: Vec<&'de str>,
+}
+
+impl<'de> Decoder<'de> {
+    fn take(&mut self, len: usize) -> Result<&'de [u8], CodecError> {
+        if self.bytes.len() < self.pos + len {
+            return Err(CodecError("unexpected end of binary program".into()));
+        }
+        let v = &self.bytes[self.pos..self.pos + len];
+        self.pos += len;
+        Ok(v)
+    }
+
+    fn peek(&self) -> Result<u8, CodecError> {
+        match self.bytes.get(self.pos) {
+            Some(&tag) => Ok(tag),
+            None => Err(CodecError("unexpected end of binary program".into())),
+        }
+    }
+
+    fn u8(&mut self) -> Result<u8, CodecError> {
+        Ok(self.take(1)?[0])
+    }
+
+    fn u32(&mut self) -> Result<u32, CodecError> {
+        Ok(u32::from_le_bytes(self.take(4)?.try_into().unwrap()))
+    }
+
+    fn string(&mut self) -> Result<&'de str, CodecError> {
+        let idx = self.u32()? as usize;
+        match self.table.get(idx) {
+            Some(s) => Ok(s),
+            None => Err(CodecError(format!(
+                "invalid string index in binary program: {}",
+                idx
+            ))),
+        }
+    }
+
+    /// Reads the header of a container and returns the number of items.
+    fn container(&mut self) -> Result<u32, CodecError> {
+        let count = self.u32()?;
+        let _len = self.u32()?;
+        Ok(count)
+    }
+
+    fn skip(&mut self) -> Result<(), CodecError> {
+        match self.u8()? {
+            NULL | FALSE | TRUE => {}
+            I32 | U32 | STRING => {
+                self.take(4)?;
+            }
+            F64 => {
+                self.take(8)?;
+            }
+            ARRAY | OBJECT => {
+                let _count = self.u32()?;
+                let len = self.u32()?;
+                self.take(len as usize)?;
+            }
+            tag => return Err(invalid_tag(tag)),
+        }
+        Ok(())
+    }
+}
+
+fn invalid_tag(tag: u8) -> CodecError {
+    CodecError(format!("invalid tag in binary program: {}", tag))
+}
+
+fn check_consumed(left: u32) -> Result<(), CodecError> {
+    if left != 0 {
+        return Err(CodecError(format!(
+            "invalid binary program: {} unused items in a container",
+            left
+        )));
+    }
+    Ok(())
+}
+
+impl<'de, 'a> de::Deserializer<'de> for &'a mut Decoder<'de> {
+    type Error = CodecError;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, CodecError> {
+        match self.u8()? {
+            NULL => visitor.visit_unit(),
+            FALSE => visitor.visit_bool(false),
+            TRUE => visitor.visit_bool(true),
+            I32 => visitor.visit_i32(i32::from_le_bytes(self.take(4)?.try_into().unwrap())),
+            U32 => visitor.visit_u32(self.u32()?),
+            F64 => visitor.visit_f64(f64::from_le_bytes(self.take(8)?.try_into().unwrap())),
+            STRING => visitor.visit_borrowed_str(self.string()?),
+            ARRAY => {
+                let left = self.container()?;
+                let mut seq = Items { de: self, left };
+                let value = visitor.visit_seq(&mut seq)?;
+                check_consumed(seq.left)?;
+                Ok(value)
+            }
+            OBJECT => {
+                let left = self.container()?;
+                let mut map = Items { de: self, left };
+                let value = visitor.visit_map(&mut map)?;
+                check_consumed(map.left)?;
+                Ok(value)
+            }
+            tag => Err(invalid_tag(tag)),
+        }
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, CodecError> {
+        if self.peek()? == NULL {
+            self.pos += 1;
+            visitor.visit_none()
+        } else {
+            visitor.visit_some(self)
+        }
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, CodecError> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _: &'static str,
+        _: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, CodecError> {
+        match self.u8()? {
+            STRING => visitor.visit_enum(BorrowedStrDeserializer::new(self.string()?)),
+            OBJECT => {
+                let count = self.container()?;
+                if count != 1 {
+                    return Err(CodecError(
+                        "invalid binary program: expected an object with a single key".into(),
+                    ));
+                }
+                visitor.visit_enum(Variant { de: self })
+            }
+            tag => Err(invalid_tag(tag)),
+        }
+    }
+
+    fn deserialize_ignored_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, CodecError> {
+        self.skip()?;
+        visitor.visit_unit()
+    }
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf unit unit_struct seq tuple tuple_struct map struct
+        identifier
+    }
+}
+
+/// Items of an array or an object.
+struct Items<'a, 'de> {
+    de: &'a mut Decoder<'de>,
+    left: u32,
+}
+
+impl<'de> SeqAccess<'de> for Items<'_, 'de> {
+    type Error = CodecError;
+
+    fn next_element_seed<T: DeserializeSeed<'de>>(
+        &mut self,
+        seed: T,
+    ) -> Result<Option<T::Value>, CodecError> {
+        if self.left == 0 {
+            return Ok(None);
+        }
+        self.left -= 1;
+        seed.deserialize(&mut *self.de).map(Some)
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.left as usize)
+    }
+}
+
+impl<'de> MapAccess<'de> for Items<'_, 'de> {
+    type Error = CodecError;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(
+        &mut self,
+        seed: K,
+    ) -> Result<Option<K::Value>, CodecError> {
+        if self.left == 0 {
+            return Ok(None);
+        }
+        self.left -= 1;
+        let key = self.de.string()?;
+        seed.deserialize(BorrowedStrDeserializer::new(key))
+            .map(Some)
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'de>>(
+        &mut self,
+        seed: V,
+    ) -> Result<V::Value, CodecError> {
+        seed.deserialize(&mut *self.de)
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.left as usize)
+    }
+}
+
+/// An enum variant with data, encoded as `{ variant: data }`.
+struct Variant<'a, 'de> {
+    de: &'a mut Decoder<'de>,
+}
+
+impl<'a, 'de> EnumAccess<'de> for Variant<'a, 'de> {
+    type Error = CodecError;
+    type Variant = Self;
+
+    fn variant_seed<V: DeserializeSeed<'de>>(
+        self,
+        seed: V,
+    ) -> Result<(V::Value, Self), CodecError> {
+        let key = self.de.string()?;
+        let variant = seed.deserialize(BorrowedStrDeserializer::new(key))?;
+        Ok((variant, self))
+    }
+}
+
+impl<'a, 'de> VariantAccess<'de> for Variant<'a, 'de> {
+    type Error = CodecError;
+
+    fn unit_variant(self) -> Result<(), CodecError> {
+        <()>::deserialize(self.de)
+    }
+
+    fn newtype_variant_seed<T: DeserializeSeed<'de>>(
+        self,
+        seed: T,
+    ) -> Result<T::Value, CodecError> {
+        seed.deserialize(self.de)
+    }
+
+    fn tuple_variant<V: Visitor<'de>>(self, _: usize, visitor: V) -> Result<V::Value, CodecError> {
+        de::Deserializer::deserialize_seq(self.de, visitor)
+    }
+
+    fn struct_variant<V: Visitor<'de>>(
+        self,
+        _: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, CodecError> {
+        de::Deserializer::deserialize_map(self.de, visitor)
+    }
+}
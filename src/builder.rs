@@ -7,9 +7,9 @@ use swc_ecmascript::{
     parser::Syntax,
     preset_env,
     transforms::{
-        compat, const_modules, fixer, helpers, hygiene, modules,
+        compat, const_modules, fixer, helpers, hygiene, jest_hoist, modules,
         pass::{Optional, Pass},
-        typescript,
+        proposals, typescript, Assumptions,
     },
 };
 
@@ -22,6 +22,9 @@ pub struct PassBuilder<'a, 'b, P: Pass> {
     global_mark: Mark,
     target: JscTarget,
     loose: bool,
+    assumptions: Assumptions,
+    jest_hoist: bool,
+    keep_import_attributes: bool,
 }
 
 impl<'a, 'b, P: Pass> PassBuilder<'a, 'b, P> {
@@ -39,7 +42,10 @@ impl<'a, 'b, P: Pass> PassBuilder<'a, 'b, P> {
             target: JscTarget::Es5,
             global_mark,
             loose,
+            assumptions: Default::default(),
             env: None,
+            jest_hoist: false,
+            keep_import_attributes: false,
         }
     }
 
@@ -51,8 +57,11 @@ impl<'a, 'b, P: Pass> PassBuilder<'a, 'b, P> {
             pass,
             target: self.target,
             loose: self.loose,
+            assumptions: self.assumptions,
             env: self.env,
             global_mark: self.global_mark,
+            jest_hoist: self.jest_hoist,
+            keep_import_attributes: self.keep_import_attributes,
         }
     }
 
@@ -77,11 +86,29 @@ impl<'a, 'b, P: Pass> PassBuilder<'a, 'b, P> {
         self
     }
 
+    /// Assumptions about the input code, which are used by compat passes.
+    pub fn assumptions(mut self, assumptions: Assumptions) -> Self {
+        self.assumptions = assumptions;
+        self
+    }
+
     pub fn preset_env(mut self, env: Option<preset_env::Config>) -> Self {
         self.env = env;
         self
     }
 
+    /// Hoists `jest.mock()` calls above imports.
+    pub fn jest_hoist(mut self, enabled: bool) -> Self {
+        self.jest_hoist = enabled;
+        self
+    }
+
+    /// Emit import attributes instead of removing them.
+    pub fn keep_import_attributes(mut self, keep: bool) -> Self {
+        self.keep_import_attributes = keep;
+        self
+    }
+
     /// # Arguments
     /// ## module
     ///  - Use `None` if you want swc to emit import statements.
@@ -91,6 +118,7 @@ impl<'a, 'b, P: Pass> PassBuilder<'a, 'b, P> {
     ///
     ///  - compatibility helper
     ///  - module handler
+    ///  - jest hoisting
     ///  - helper injector
     ///  - identifier hygiene handler
     ///  - fixer
@@ -108,23 +136,41 @@ impl<'a, 'b, P: Pass> PassBuilder<'a, 'b, P> {
         };
 
         // compat
+        let assumptions = self.assumptions;
+        let iterable_is_array = self.loose || assumptions.iterable_is_array;
         let compat_pass = if let Some(env) = self.env {
-            Either::Left(preset_env::preset_env(self.global_mark, env))
+            Either::Left(preset_env::preset_env(self.global_mark, env, assumptions))
         } else {
             Either::Right(chain!(
-                Optional::new(compat::es2018(), self.target <= JscTarget::Es2018),
+                // es2019 is the latest target, so this is always enabled.
+                Optional::new(compat::es2021(), self.target <= JscTarget::Es2019),
+                Optional::new(
+                    compat::es2018(compat::es2018::Config {
+                        object_rest_spread: compat::es2018::object_rest_spread::Config {
+                            set_spread_properties: assumptions.set_spread_properties,
+                            ignore_to_primitive_hint: assumptions.ignore_to_primitive_hint,
+                        },
+                    }),
+                    self.target <= JscTarget::Es2018
+                ),
                 Optional::new(compat::es2017(), self.target <= JscTarget::Es2017),
                 Optional::new(compat::es2016(), self.target <= JscTarget::Es2016),
                 Optional::new(
                     compat::es2015(
                         self.global_mark,
                         compat::es2015::Config {
+                            classes: compat::es2015::classes::Config {
+                                constant_super: assumptions.constant_super,
+                                no_class_calls: assumptions.no_class_calls,
+                            },
                             for_of: compat::es2015::for_of::Config {
-                                assume_array: self.loose
+                                assume_array: iterable_is_array
+                            },
+                            spread: compat::es2015::spread::Config {
+                                loose: iterable_is_array
                             },
-                            spread: compat::es2015::spread::Config { loose: self.loose },
                             destructuring: compat::es2015::destructuring::Config {
-                                loose: self.loose
+                                loose: iterable_is_array
                             },
                         }
                     ),
@@ -148,6 +194,9 @@ impl<'a, 'b, P: Pass> PassBuilder<'a, 'b, P> {
             ),
             helpers::InjectHelpers,
             ModuleConfig::build(self.cm.clone(), root_mark, module),
+            // Module transforms use import attributes.
+            Optional::new(proposals::import_attributes(), !self.keep_import_attributes),
+            Optional::new(jest_hoist(), self.jest_hoist),
             // hygiene
             hygiene(),
             // fixer
@@ -0,0 +1,283 @@
+//! On-disk cache for outputs of [Compiler::process_js_file].
+//!
+//! [Compiler::process_js_file]: crate::Compiler::process_js_file
+use crate::{
+    config::{BuiltConfig, CacheConfig, InputSourceMap},
+    TransformOutput,
+};
+use anyhow::{Context, Error};
+use serde_json::Value;
+use std::{
+    fmt,
+    fs::{self, File},
+    io::{BufReader, ErrorKind},
+    path::PathBuf,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
+    time::SystemTime,
+};
+use swc_common::{FileName, SourceFile};
+use swc_ecmascript::transforms::pass::Pass;
+
+/// Used to invalidate entries created by other versions of swc.
+const VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// Identifies an entry in [TransformCache].
+///
+/// The key is derived from the source text, the resolved config (including
+/// `.swcrc` files, the `envs` and `overrides` selected for the file and
+/// `tsconfig.json`), the input source map, the target and the version of swc.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CacheKey(u64);
+
+impl CacheKey {
+    /// Returns `None` if the output depends on files which are not hashed.
+    pub fn new<P: Pass>(fm: &SourceFile, config: &BuiltConfig<P>) -> Option<Self> {
+        let mut hasher = StableHasher::default();
+
+        hasher.write(VERSION.as_bytes());
+        hasher.write(&fm.name_hash.to_le_bytes());
+        hasher.write(&fm.src_hash.to_le_bytes());
+        hasher.write(&config.fingerprint?.to_le_bytes());
+        hasher.write(&serde_json::to_vec(&config.target).unwrap_or_default());
+        hasher.write(&[config.is_module as u8]);
+        hasher.write(&serde_json::to_vec(&config.source_maps).unwrap_or_default());
+        hasher.write(&serde_json::to_vec(&config.input_source_map).unwrap_or_default());
+
+        // `inputSourceMap: true` loads `<file>.map` next to the input.
+        if let (InputSourceMap::Bool(true), FileName::Real(path)) =
+            (&config.input_source_map, &fm.name)
+        {
+            match fs::read(format!("{}.map", path.display())) {
+                Ok(map) => hasher.write(&map),
+                Err(err) if err.kind() == ErrorKind::NotFound => hasher.write(b""),
+                Err(_) => return None,
+            }
+        }
+
+        Some(CacheKey(hasher.finish()))
+    }
+}
+
+/// FNV-1a.
+///
+/// Unlike [std::collections::hash_map::DefaultHasher], the output does not
+/// depend on the version of rustc, so keys are stable across builds.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct StableHasher(u64);
+
+impl Default for StableHasher {
+    fn default() -> Self {
+        StableHasher(0xcbf2_9ce4_8422_2325)
+    }
+}
+
+impl StableHasher {
+    pub fn write(&mut self, bytes: &[u8]) {
+        // Length prefix, so that `("ab", "c")` and `("a", "bc")` differ.
+        for b in (bytes.len() as u64).to_le_bytes().iter().chain(bytes) {
+            self.0 ^= *b as u64;
+            self.0 = self.0.wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+
+    /// Object keys are hashed in sorted order.
+    pub fn write_json(&mut self, value: &Value) {
+        match value {
+            Value::Null => self.write(b"n"),
+            Value::Bool(v) => self.write(if *v { b"t" } else { b"f" }),
+            Value::Number(v) => {
+                self.write(b"0");
+                self.write(v.to_string().as_bytes());
+            }
+            Value::String(v) => {
+                self.write(b"s");
+                self.write(v.as_bytes());
+            }
+            Value::Array(v) => {
+                self.write(b"a");
+                self.write(&(v.len() as u64).to_le_bytes());
+                for v in v {
+                    self.write_json(v);
+                }
+            }
+            Value::Object(v) => {
+                let mut entries = v.iter().collect::<Vec<_>>();
+                entries.sort_by(|a, b| a.0.cmp(b.0));
+
+                self.write(b"o");
+                self.write(&(entries.len() as u64).to_le_bytes());
+                for (k, v) in entries {
+                    self.write(k.as_bytes());
+                    self.write_json(v);
+                }
+            }
+        }
+    }
+
+    pub fn finish(&self) -> u64 {
+        self.0
+    }
+}
+
+impl fmt::Display for CacheKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:016x}", self.0)
+    }
+}
+
+/// Stores [TransformOutput]s in a directory.
+///
+/// When the total size of entries exceeds `max_size`, the oldest entries are
+/// removed. The directory is scanned for this on the first write and then
+/// once per `max_size / EVICT_RATIO` bytes written, not on every write.
+///
+/// Clones share the count of written bytes.
+#[derive(Debug, Clone)]
+pub struct TransformCache {
+    dir: PathBuf,
+    max_size: u64,
+    /// Bytes written since the last scan.
+    written: Arc<AtomicU64>,
+}
+
+const EVICT_RATIO: u64 = 8;
+
+impl TransformCache {
+    pub fn new(config: &CacheConfig) -> Self {
+        TransformCache {
+            dir: config.dir.clone(),
+            max_size: config.max_size,
+            // Scan on the first write, as the directory may be shared with
+            // other processes.
+            written: Arc::new(AtomicU64::new(u64::MAX)),
+        }
+    }
+
+    pub fn max_size(&self) -> u64 {
+        self.max_size
+    }
+
+    fn path(&self, key: &CacheKey) -> PathBuf {
+        self.dir.join(format!("{}.json", key))
+    }
+
+    /// Returns `None` if there's no entry or the entry is not readable.
+    pub fn get(&self, key: &CacheKey) -> Option<TransformOutput> {
+        let path = self.path(key);
+        let file = File::open(&path).ok()?;
+
+        match serde_json::from_reader(BufReader::new(file)) {
+            Ok(v) => Some(v),
+            Err(err) => {
+                log::debug!("removing broken cache entry {}: {}", path.display(), err);
+                let _ = fs::remove_file(&path);
+                None
+            }
+        }
+    }
+
+    pub fn put(&self, key: &CacheKey, output: &TransformOutput) -> Result<(), Error> {
+        fs::create_dir_all(&self.dir)
+            .with_context(|| format!("failed to create cache directory {}", self.dir.display()))?;
+
+        // Write to a temporary file first so that concurrent readers never see
+        // a partially written entry.
+        let path = self.path(key);
+        let tmp = path.with_extension(format!("{}.tmp", std::process::id()));
+        let content = serde_json::to_vec(output).context("failed to serialize cache entry")?;
+        fs::write(&tmp, &content)
+            .with_context(|| format!("failed to write cache entry {}", tmp.display()))?;
+        fs::rename(&tmp, &path)
+            .with_context(|| format!("failed to write cache entry {}", path.display()))?;
+
+        let len = content.len() as u64;
+        let written = self
+            .written
+            .fetch_add(len, Ordering::Relaxed)
+            .saturating_add(len);
+        if written < self.max_size / EVICT_RATIO {
+            return Ok(());
+        }
+        self.written.store(0, Ordering::Relaxed);
+
+        self.evict()
+    }
+
+    /// Removes an entry.
+    pub fn invalidate(&self, key: &CacheKey) -> Result<(), Error> {
+        match fs::remove_file(self.path(key)) {
+            Ok(()) => Ok(()),
+            Err(ref err) if err.kind() == ErrorKind::NotFound => Ok(()),
+            Err(err) => Err(Error::new(err).context("failed to remove cache entry")),
+        }
+    }
+
+    /// Removes all entries.
+    pub fn clear(&self) -> Result<(), Error> {
+        for (path, _, _) in self.entries()? {
+            fs::remove_file(&path)
+                .with_context(|| format!("failed to remove cache entry {}", path.display()))?;
+        }
+
+        Ok(())
+    }
+
+    fn entries(&self) -> Result<Vec<(PathBuf, u64, SystemTime)>, Error> {
+        let dir = match fs::read_dir(&self.dir) {
+            Ok(dir) => dir,
+            Err(ref err) if err.kind() == ErrorKind::NotFound => return Ok(vec![]),
+            Err(err) => return Err(Error::new(err).context("failed to read cache directory")),
+        };
+
+        let mut entries = vec![];
+        for entry in dir {
+            let entry = entry.context("failed to read cache directory")?;
+            let path = entry.path();
+            if path.extension().map(|ext| ext != "json").unwrap_or(true) {
+                continue;
+            }
+
+            let metadata = match entry.metadata() {
+                Ok(v) => v,
+                // Removed by another process.
+                Err(..) => continue,
+            };
+            let modified = metadata.modified().unwrap_or(SystemTime::UNIX_EPOCH);
+            entries.push((path, metadata.len(), modified));
+        }
+
+        Ok(entries)
+    }
+
+    /// Removes the oldest entries until the total size is less than
+    /// `max_size`.
+    fn evict(&self) -> Result<(), Error> {
+        let mut entries = self.entries()?;
+        let mut total: u64 = entries.iter().map(|(_, size, _)| size).sum();
+        if total <= self.max_size {
+            return Ok(());
+        }
+
+        entries.sort_by_key(|(_, _, modified)| *modified);
+
+        for (path, size, _) in entries {
+            if total <= self.max_size {
+                break;
+            }
+
+            match fs::remove_file(&path) {
+                Ok(()) => {}
+                Err(ref err) if err.kind() == ErrorKind::NotFound => {}
+                Err(err) => {
+                    return Err(Error::new(err).context("failed to evict cache entry"));
+                }
+            }
+            total -= size;
+        }
+
+        Ok(())
+    }
+}
@@ -1,35 +1,51 @@
-use crate::builder::PassBuilder;
+use crate::{builder::PassBuilder, cache::StableHasher};
 use anyhow::{bail, Context, Error};
 use dashmap::DashMap;
 use once_cell::sync::Lazy;
 use regex::Regex;
 use serde::{Deserialize, Serialize};
 use std::{
-    collections::{HashMap, HashSet},
-    env,
+    collections::{BTreeMap, BTreeSet, HashMap, HashSet},
+    convert::TryFrom,
+    env, mem,
     path::{Path, PathBuf},
     sync::Arc,
     usize,
 };
 use swc_atoms::JsWord;
 pub use swc_common::chain;
-use swc_common::{errors::Handler, FileName, Mark, SourceMap};
+use swc_common::{
+    comments::{Comment, Comments},
+    errors::Handler,
+    BytePos, FileName, Mark, SourceMap,
+};
 pub use swc_ecmascript::parser::JscTarget;
 use swc_ecmascript::{
     ast::{Expr, ExprStmt, ModuleItem, Stmt},
+    codegen::{self, QuoteStyle},
+    lints::LintConfig,
     parser::{lexer::Lexer, Parser, Session as ParseSess, SourceFileInput, Syntax, TsConfig},
     preset_env,
     transforms::{
-        const_modules, modules,
+        self,
+        compat::es2021::logical_assignments,
+        const_modules, flow,
+        modules::{self, paths},
         optimization::{simplifier, InlineGlobals, JsonParse},
         pass::{noop, Optional, Pass},
-        proposals::{class_properties, decorators, export, nullish_coalescing, optional_chaining},
-        react, resolver_with_mark, typescript,
+        proposals::{
+            class_properties, decorators, export, function_bind, nullish_coalescing, opt_chaining,
+            optional_chaining,
+        },
+        react, resolver_with_mark, typescript, Assumptions,
     },
 };
 
 #[cfg(test)]
 mod tests;
+mod tsconfig;
+
+pub(crate) use self::tsconfig::{find_tsconfig, load_tsconfig};
 
 #[derive(Default, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -95,13 +111,24 @@ pub struct Options {
 
     #[serde(default = "default_is_module")]
     pub is_module: bool,
+
+    /// `true` to load `compilerOptions` from the nearest `tsconfig.json`,
+    /// or a path to `tsconfig.json`.
+    ///
+    /// Values from `.swcrc` have priority over `tsconfig.json`.
+    #[serde(default)]
+    pub tsconfig: Option<ConfigFile>,
+
+    /// If set, outputs of `Compiler::process_js_file` are cached on disk.
+    #[serde(default)]
+    pub cache: Option<CacheConfig>,
 }
 
 fn default_is_module() -> bool {
     true
 }
 
-#[derive(Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum SourceMapsConfig {
     Bool(bool),
@@ -139,12 +166,28 @@ impl Default for InputSourceMap {
     }
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+pub struct CacheConfig {
+    /// Directory used to store cached outputs.
+    pub dir: PathBuf,
+
+    /// Maximum total size of cached outputs, in bytes.
+    #[serde(default = "default_cache_max_size")]
+    pub max_size: u64,
+}
+
+fn default_cache_max_size() -> u64 {
+    256 * 1024 * 1024
+}
+
 impl Options {
     pub fn build(
         &self,
         cm: &Arc<SourceMap>,
         handler: &Handler,
         is_module: bool,
+        filename: Option<&Path>,
         config: Option<Config>,
     ) -> BuiltConfig<impl Pass> {
         let mut config = config.unwrap_or_else(Default::default);
@@ -152,20 +195,42 @@ impl Options {
             config.merge(c)
         }
 
+        let fingerprint = config.fingerprint();
+        let codegen = config.codegen_config();
+
         let JscConfig {
             transform,
             syntax,
             external_helpers,
             target,
             loose,
+            base_url,
+            paths,
+            early_errors,
+            comments,
+            output: _,
+            assumptions,
+            keep_import_attributes,
         } = config.jsc;
 
         let syntax = syntax.unwrap_or_default();
-        let mut transform = transform.unwrap_or_default();
-
-        if syntax.typescript() {
-            transform.legacy_decorator = true;
-        }
+        let target = target.unwrap_or_default();
+        let external_helpers = external_helpers.unwrap_or(false);
+        let early_errors = early_errors.unwrap_or(false);
+        let keep_import_attributes = keep_import_attributes.unwrap_or(false);
+        let transform = transform.unwrap_or_default();
+        let assumptions = assumptions.unwrap_or_default();
+
+        let decorator_version = transform.decorator_version.unwrap_or_default();
+        // Typescript uses legacy decorators unless the new ones are requested
+        // explicitly.
+        let legacy_decorator = transform.legacy_decorator.unwrap_or(false)
+            || (syntax.typescript() && decorator_version == decorators::DecoratorVersion::V201809);
+        let decorator_metadata = transform.decorator_metadata.unwrap_or(false);
+        let uses_decorator_2022_03 = syntax.decorators()
+            && !legacy_decorator
+            && decorator_version == decorators::DecoratorVersion::V202203;
+        let jest_hoist = transform.jest_hoist.unwrap_or(false);
         let optimizer = transform.optimizer;
         let enable_optimizer = optimizer.is_some();
 
@@ -201,24 +266,80 @@ impl Options {
             pass
         };
 
+        // Resolved paths depend on the file system, which is not a part of the
+        // fingerprint.
+        let resolves_paths = filename.is_some() && (base_url.is_some() || !paths.is_empty());
+        let paths = {
+            let root = self
+                .root
+                .clone()
+                .unwrap_or_else(|| env::current_dir().unwrap_or_default());
+            let config = paths::Config {
+                base_url: base_url.map(|base_url| root.join(base_url)),
+                paths,
+            };
+
+            let file = filename.map(|f| root.join(f)).unwrap_or_default();
+
+            // Paths from tsconfig.json are already resolved against the
+            // directory of the file declaring them.
+            Optional::new(paths::paths(config, root, file), resolves_paths)
+        };
+
         let root_mark = Mark::fresh(Mark::root());
 
         let pass = chain!(
+            // Validation should be done with the input ast.
+            Optional::new(transforms::early_errors(), early_errors),
             // handle jsx
-            Optional::new(react::react(cm.clone(), transform.react), syntax.jsx()),
+            Optional::new(
+                react::react(cm.clone(), transform.react.into()),
+                syntax.jsx()
+            ),
+            Optional::new(
+                typescript::decorator_metadata(),
+                syntax.typescript() && syntax.decorators() && decorator_metadata
+            ),
             Optional::new(typescript::strip(), syntax.typescript()),
-            Optional::new(nullish_coalescing(), syntax.nullish_coalescing()),
-            Optional::new(optional_chaining(), syntax.optional_chaining()),
+            Optional::new(flow::strip(), syntax.flow()),
+            paths,
+            // `a.#x ??= b` and `super.x ??= b` are lowered to the plain
+            // assignments class_properties and classes know how to handle.
+            logical_assignments(),
+            Optional::new(
+                nullish_coalescing(nullish_coalescing::Config {
+                    no_document_all: assumptions.no_document_all,
+                }),
+                syntax.nullish_coalescing()
+            ),
+            Optional::new(
+                optional_chaining(opt_chaining::Config {
+                    no_document_all: assumptions.no_document_all,
+                }),
+                syntax.optional_chaining()
+            ),
+            Optional::new(function_bind(), syntax.fn_bind()),
             resolver_with_mark(root_mark),
             const_modules,
             optimization,
             Optional::new(
                 decorators(decorators::Config {
-                    legacy: transform.legacy_decorator
+                    legacy: legacy_decorator,
+                    version: decorator_version,
                 }),
                 syntax.decorators()
             ),
-            Optional::new(class_properties(), syntax.class_props()),
+            // Output of the 2022-03 decorators pass contains class fields.
+            Optional::new(
+                class_properties(class_properties::Config {
+                    set_public_fields: assumptions.set_public_class_fields,
+                    private_as_properties: assumptions.private_fields_as_properties,
+                }),
+                syntax.class_props()
+                    || syntax.static_blocks()
+                    || syntax.private_in_object()
+                    || uses_decorator_2022_03
+            ),
             Optional::new(
                 export(),
                 syntax.export_default_from() || syntax.export_namespace_from()
@@ -229,21 +350,34 @@ impl Options {
 
         let pass = PassBuilder::new(&cm, &handler, loose, root_mark, pass)
             .target(target)
+            .assumptions(assumptions)
             .preset_env(config.env)
+            .jest_hoist(jest_hoist)
+            .keep_import_attributes(keep_import_attributes)
             .finalize(root_mark, syntax, config.module);
 
         BuiltConfig {
             minify: config.minify.unwrap_or(false),
+            codegen,
             pass,
             external_helpers,
             syntax,
             target,
             is_module,
+            lint: config.lint,
+            instrument: config.instrument,
+            comments,
             source_maps: self
                 .source_maps
                 .clone()
+                .or(config.source_maps)
                 .unwrap_or(SourceMapsConfig::Bool(false)),
             input_source_map: self.input_source_map.clone(),
+            fingerprint: if resolves_paths {
+                None
+            } else {
+                Some(fingerprint)
+            },
         }
     }
 }
@@ -309,12 +443,14 @@ impl Default for Rc {
                 jsc: JscConfig {
                     syntax: Some(Default::default()),
                     transform: None,
-                    external_helpers: false,
-                    target: Default::default(),
+                    external_helpers: None,
+                    target: None,
                     loose: false,
+                    ..Default::default()
                 },
                 module: None,
                 minify: None,
+                ..Default::default()
             },
             Config {
                 env: None,
@@ -326,12 +462,14 @@ impl Default for Rc {
                         ..Default::default()
                     })),
                     transform: None,
-                    external_helpers: false,
-                    target: Default::default(),
+                    external_helpers: None,
+                    target: None,
                     loose: false,
+                    ..Default::default()
                 },
                 module: None,
                 minify: None,
+                ..Default::default()
             },
             Config {
                 env: None,
@@ -343,12 +481,14 @@ impl Default for Rc {
                         ..Default::default()
                     })),
                     transform: None,
-                    external_helpers: false,
-                    target: Default::default(),
+                    external_helpers: None,
+                    target: None,
                     loose: false,
+                    ..Default::default()
                 },
                 module: None,
                 minify: None,
+                ..Default::default()
             },
         ])
     }
@@ -388,7 +528,7 @@ impl Rc {
 }
 
 /// A single object in the `.swcrc` file
-#[derive(Debug, Default, Clone, Deserialize)]
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
 #[serde(deny_unknown_fields, rename_all = "camelCase")]
 pub struct Config {
     #[serde(default)]
@@ -408,6 +548,29 @@ pub struct Config {
 
     #[serde(default)]
     pub minify: Option<bool>,
+
+    #[serde(default)]
+    pub lint: Option<LintConfig>,
+
+    /// Injects istanbul-compatible coverage counters if set.
+    #[serde(default)]
+    pub instrument: Option<instrument::Config>,
+
+    /// Configs merged over this one if the key matches `Options::env_name`.
+    ///
+    /// This is `env` of babel, which is renamed because `env` is used by
+    /// `preset-env`.
+    #[serde(default)]
+    pub envs: BTreeMap<String, Config>,
+
+    /// Configs merged over this one if `test` and `exclude` of the item
+    /// matches the file.
+    #[serde(default)]
+    pub overrides: Vec<Config>,
+
+    /// Set by `tsconfig.json`. `Options::source_maps` has priority over this.
+    #[serde(skip)]
+    pub source_maps: Option<SourceMapsConfig>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -457,6 +620,15 @@ impl FileMatcher {
 }
 
 impl Config {
+    /// Hash of the config which is stable across processes and builds.
+    pub(crate) fn fingerprint(&self) -> u64 {
+        let value = serde_json::to_value(self).expect("failed to serialize config");
+
+        let mut hasher = StableHasher::default();
+        hasher.write_json(&value);
+        hasher.finish()
+    }
+
     pub fn matches(&self, filename: &Path) -> Result<bool, Error> {
         if let Some(ref exclude) = self.exclude {
             if exclude.matches(filename)? {
@@ -473,6 +645,47 @@ impl Config {
 
         Ok(true)
     }
+
+    /// Merges the config for `env_name` and overrides matching `filename` over
+    /// `self`.
+    pub fn resolve(mut self, env_name: &str, filename: Option<&Path>) -> Result<Config, Error> {
+        let mut envs = mem::replace(&mut self.envs, Default::default());
+        let overrides = mem::replace(&mut self.overrides, vec![]);
+
+        if let Some(env) = envs.remove(env_name) {
+            let env = env
+                .resolve(env_name, filename)
+                .with_context(|| format!("failed to resolve env `{}`", env_name))?;
+            self.merge(&env);
+        }
+
+        for c in overrides {
+            let matches = match filename {
+                Some(filename) => c.matches(filename)?,
+                None => c.test.is_none() && c.exclude.is_none(),
+            };
+            if matches {
+                let c = c.resolve(env_name, filename)?;
+                self.merge(&c);
+            }
+        }
+
+        Ok(self)
+    }
+
+    /// Options for the code generator.
+    pub fn codegen_config(&self) -> codegen::Config {
+        let output = self.jsc.output.clone().unwrap_or_default();
+
+        codegen::Config {
+            minify: self.minify.unwrap_or(false),
+            ascii_only: output.ascii_only,
+            quote_style: output.quote_style,
+            keep_raw: output.keep_raw,
+            target: self.jsc.target.unwrap_or_default(),
+            format: None,
+        }
+    }
 }
 
 /// One `BuiltConfig` per a directory with swcrc
@@ -481,10 +694,20 @@ pub struct BuiltConfig<P: Pass> {
     pub syntax: Syntax,
     pub target: JscTarget,
     pub minify: bool,
+    pub codegen: codegen::Config,
     pub external_helpers: bool,
     pub source_maps: SourceMapsConfig,
     pub input_source_map: InputSourceMap,
     pub is_module: bool,
+    pub lint: Option<LintConfig>,
+    pub instrument: Option<instrument::Config>,
+    pub comments: Option<CommentsConfig>,
+    /// Hash of the resolved config, used as a part of
+    /// [CacheKey](crate::cache::CacheKey).
+    ///
+    /// `None` if the output depends on the file system, e.g. because imports
+    /// are resolved using `jsc.paths`.
+    pub fingerprint: Option<u64>,
 }
 
 #[derive(Debug, Default, Clone, Serialize, Deserialize)]
@@ -497,13 +720,193 @@ pub struct JscConfig {
     pub transform: Option<TransformConfig>,
 
     #[serde(default)]
-    pub external_helpers: bool,
+    pub external_helpers: Option<bool>,
 
     #[serde(default)]
-    pub target: JscTarget,
+    pub target: Option<JscTarget>,
 
     #[serde(default)]
     pub loose: bool,
+
+    /// Same as `compilerOptions.baseUrl` of `tsconfig.json`.
+    #[serde(default)]
+    pub base_url: Option<PathBuf>,
+
+    /// Same as `compilerOptions.paths` of `tsconfig.json`.
+    #[serde(default)]
+    pub paths: BTreeMap<String, Vec<String>>,
+
+    /// Report early errors which require scope analysis, like redeclaration
+    /// of lexically declared names.
+    #[serde(default)]
+    pub early_errors: Option<bool>,
+
+    /// Comments to keep in the output. If omitted, all comments are kept
+    /// unless `minify` is enabled.
+    #[serde(default)]
+    pub comments: Option<CommentsConfig>,
+
+    #[serde(default)]
+    pub output: Option<OutputConfig>,
+
+    /// Assumptions about the input code, which allow compat passes to emit
+    /// smaller code. Same as `assumptions` of babel.
+    #[serde(default)]
+    pub assumptions: Option<Assumptions>,
+
+    /// Emit import attributes like `with { type: "json" }` as written. They
+    /// are removed by default, as no target supports them yet.
+    #[serde(default)]
+    pub keep_import_attributes: Option<bool>,
+}
+
+/// `jsc.output`
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+pub struct OutputConfig {
+    /// Escape non-ASCII characters. Code point escapes (`\u{...}`) are used
+    /// only if `jsc.target` is es2015 or newer.
+    #[serde(default)]
+    pub ascii_only: bool,
+
+    /// `"single"` or `"double"`. The other quote is used if it requires fewer
+    /// escapes.
+    #[serde(default)]
+    pub quote_style: QuoteStyle,
+
+    /// Print numbers and strings as written in the input, unless it's not
+    /// allowed by `jsc.target` or `asciiOnly`.
+    #[serde(default)]
+    pub keep_raw: bool,
+}
+
+/// `jsc.comments`
+///
+/// - `"all"`: Keep all comments.
+/// - `"some"` or `"license"`: Keep `/*! ... */` and comments containing
+///   `@license` or `@preserve`.
+/// - `"none"`: Drop all comments.
+/// - `"/pattern/"` or `{ "regex": "pattern" }`: Keep comments whose text
+///   matches the regex.
+///
+/// Other strings are rejected. The regex is compiled while deserializing the
+/// config.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(try_from = "CommentsConfigRepr", into = "CommentsConfigRepr")]
+pub enum CommentsConfig {
+    All,
+    Some,
+    None,
+    Regex(Regex),
+}
+
+impl PartialEq for CommentsConfig {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (CommentsConfig::All, CommentsConfig::All)
+            | (CommentsConfig::Some, CommentsConfig::Some)
+            | (CommentsConfig::None, CommentsConfig::None) => true,
+            (CommentsConfig::Regex(l), CommentsConfig::Regex(r)) => l.as_str() == r.as_str(),
+            _ => false,
+        }
+    }
+}
+
+impl Eq for CommentsConfig {}
+
+#[derive(Serialize, Deserialize)]
+#[serde(untagged)]
+enum CommentsConfigRepr {
+    Str(String),
+    Regex(CommentsRegex),
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct CommentsRegex {
+    regex: String,
+}
+
+impl TryFrom<CommentsConfigRepr> for CommentsConfig {
+    type Error = Error;
+
+    fn try_from(repr: CommentsConfigRepr) -> Result<Self, Error> {
+        let s = match repr {
+            CommentsConfigRepr::Str(s) => s,
+            CommentsConfigRepr::Regex(CommentsRegex { regex }) => {
+                return CommentsConfig::regex(&regex)
+            }
+        };
+
+        match &*s {
+            "all" => Ok(CommentsConfig::All),
+            "some" | "license" => Ok(CommentsConfig::Some),
+            "none" => Ok(CommentsConfig::None),
+            _ if s.len() >= 2 && s.starts_with('/') && s.ends_with('/') => {
+                CommentsConfig::regex(&s[1..s.len() - 1])
+            }
+            _ => bail!(
+                "unknown value `{}` for `jsc.comments`; use `\"/pattern/\"` or `{{ \"regex\": \
+                 \"pattern\" }}` to match comments with a regex",
+                s
+            ),
+        }
+    }
+}
+
+impl From<CommentsConfig> for CommentsConfigRepr {
+    fn from(c: CommentsConfig) -> Self {
+        match c {
+            CommentsConfig::All => CommentsConfigRepr::Str("all".into()),
+            CommentsConfig::Some => CommentsConfigRepr::Str("some".into()),
+            CommentsConfig::None => CommentsConfigRepr::Str("none".into()),
+            CommentsConfig::Regex(re) => CommentsConfigRepr::Regex(CommentsRegex {
+                regex: re.as_str().into(),
+            }),
+        }
+    }
+}
+
+impl CommentsConfig {
+    pub fn regex(s: &str) -> Result<Self, Error> {
+        let re = Regex::new(s).with_context(|| format!("invalid regex: {}", s))?;
+        Ok(CommentsConfig::Regex(re))
+    }
+
+    /// Returns false if comments are not printed at all, so the parser can
+    /// skip collecting them.
+    pub fn needs_comments(&self) -> bool {
+        match *self {
+            CommentsConfig::None => false,
+            _ => true,
+        }
+    }
+
+    /// Removes comments which should not be printed.
+    pub fn retain(&self, comments: &Comments) {
+        if let CommentsConfig::All = *self {
+            return;
+        }
+
+        let keep = |c: &Comment| match *self {
+            CommentsConfig::All => true,
+            CommentsConfig::None => false,
+            CommentsConfig::Some => {
+                c.text.starts_with('!')
+                    || c.text.contains("@license")
+                    || c.text.contains("@preserve")
+            }
+            CommentsConfig::Regex(ref re) => re.is_match(&c.text),
+        };
+
+        let f = |_: &BytePos, v: &mut Vec<Comment>| -> bool {
+            v.retain(|c| keep(c));
+            !v.is_empty()
+        };
+
+        comments.retain_leading(&f);
+        comments.retain_trailing(&f);
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -539,7 +942,7 @@ impl ModuleConfig {
 #[serde(deny_unknown_fields, rename_all = "camelCase")]
 pub struct TransformConfig {
     #[serde(default)]
-    pub react: react::Options,
+    pub react: ReactConfig,
 
     #[serde(default)]
     pub const_modules: Option<ConstModulesConfig>,
@@ -548,7 +951,58 @@ pub struct TransformConfig {
     pub optimizer: Option<OptimizerConfig>,
 
     #[serde(default)]
-    pub legacy_decorator: bool,
+    pub legacy_decorator: Option<bool>,
+
+    /// Version of the decorators proposal. Ignored if `legacy_decorator` is
+    /// true.
+    #[serde(default)]
+    pub decorator_version: Option<decorators::DecoratorVersion>,
+
+    /// Emits `design:*` metadata for decorated classes. Typescript only.
+    #[serde(default)]
+    pub decorator_metadata: Option<bool>,
+
+    /// Hoists `jest.mock()` and similar calls above imports, like
+    /// `babel-plugin-jest-hoist`.
+    #[serde(default)]
+    pub jest_hoist: Option<bool>,
+}
+
+/// `jsc.transform.react`
+///
+/// Fields are optional so that configs merged over this one override only
+/// the fields they set. See [react::Options] for the defaults.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReactConfig {
+    #[serde(default)]
+    pub pragma: Option<String>,
+
+    #[serde(default)]
+    pub pragma_frag: Option<String>,
+
+    #[serde(default)]
+    pub throw_if_namespace: Option<bool>,
+
+    #[serde(default)]
+    pub development: Option<bool>,
+
+    #[serde(default)]
+    pub use_builtins: Option<bool>,
+}
+
+impl From<ReactConfig> for react::Options {
+    fn from(c: ReactConfig) -> Self {
+        let default = react::Options::default();
+
+        react::Options {
+            pragma: c.pragma.unwrap_or(default.pragma),
+            pragma_frag: c.pragma_frag.unwrap_or(default.pragma_frag),
+            throw_if_namespace: c.throw_if_namespace.unwrap_or(default.throw_if_namespace),
+            development: c.development.unwrap_or(default.development),
+            use_builtins: c.use_builtins.unwrap_or(default.use_builtins),
+        }
+    }
 }
 
 #[derive(Debug, Default, Clone, Serialize, Deserialize)]
@@ -584,10 +1038,18 @@ fn default_jsonify_min_cost() -> usize {
 pub struct GlobalPassOption {
     #[serde(default)]
     pub vars: HashMap<String, String>,
-    #[serde(default = "default_envs")]
+    #[serde(default = "default_envs", serialize_with = "serialize_sorted")]
     pub envs: HashSet<String>,
 }
 
+/// Keeps the output (and the fingerprint of [Config]) stable.
+fn serialize_sorted<S>(set: &HashSet<String>, s: S) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+{
+    set.iter().collect::<BTreeSet<_>>().serialize(s)
+}
+
 fn default_envs() -> HashSet<String> {
     let mut v = HashSet::default();
     v.insert(String::from("NODE_ENV"));
@@ -702,6 +1164,27 @@ impl Merge for Config {
         self.module.merge(&from.module);
         self.minify.merge(&from.minify);
         self.env.merge(&from.env);
+        self.lint.merge(&from.lint);
+        self.instrument.merge(&from.instrument);
+        self.source_maps.merge(&from.source_maps);
+    }
+}
+
+impl Merge for LintConfig {
+    fn merge(&mut self, from: &Self) {
+        *self = from.clone();
+    }
+}
+
+impl Merge for instrument::Config {
+    fn merge(&mut self, from: &Self) {
+        *self = from.clone();
+    }
+}
+
+impl Merge for SourceMapsConfig {
+    fn merge(&mut self, from: &Self) {
+        *self = from.clone();
     }
 }
 
@@ -717,14 +1200,29 @@ impl Merge for JscConfig {
         self.transform.merge(&from.transform);
         self.target.merge(&from.target);
         self.external_helpers.merge(&from.external_helpers);
+        self.early_errors.merge(&from.early_errors);
+        self.keep_import_attributes
+            .merge(&from.keep_import_attributes);
+        if from.comments.is_some() {
+            self.comments = from.comments.clone();
+        }
+        if from.output.is_some() {
+            self.output = from.output.clone();
+        }
+        if from.assumptions.is_some() {
+            self.assumptions = from.assumptions;
+        }
+        if from.base_url.is_some() {
+            self.base_url = from.base_url.clone();
+        }
+        self.paths
+            .extend(from.paths.iter().map(|(k, v)| (k.clone(), v.clone())));
     }
 }
 
 impl Merge for JscTarget {
     fn merge(&mut self, from: &Self) {
-        if *self < *from {
-            *self = *from
-        }
+        *self = *from;
     }
 }
 
@@ -739,7 +1237,7 @@ impl Merge for Option<ModuleConfig> {
 
 impl Merge for bool {
     fn merge(&mut self, from: &Self) {
-        *self |= *from
+        *self = *from;
     }
 }
 
@@ -754,6 +1252,12 @@ impl Merge for TransformConfig {
         self.optimizer.merge(&from.optimizer);
         self.const_modules.merge(&from.const_modules);
         self.react.merge(&from.react);
+        self.legacy_decorator.merge(&from.legacy_decorator);
+        if from.decorator_version.is_some() {
+            self.decorator_version = from.decorator_version;
+        }
+        self.decorator_metadata.merge(&from.decorator_metadata);
+        self.jest_hoist.merge(&from.jest_hoist);
     }
 }
 
@@ -769,9 +1273,25 @@ impl Merge for GlobalPassOption {
     }
 }
 
-impl Merge for react::Options {
+impl Merge for ReactConfig {
     fn merge(&mut self, from: &Self) {
-        *self = from.clone();
+        macro_rules! merge {
+            ($($field:ident),*) => {
+                $(
+                    if from.$field.is_some() {
+                        self.$field = from.$field.clone();
+                    }
+                )*
+            };
+        }
+
+        merge!(
+            pragma,
+            pragma_frag,
+            throw_if_namespace,
+            development,
+            use_builtins
+        );
     }
 }
 
@@ -1,5 +1,11 @@
-use super::Rc;
+use super::{
+    tsconfig::{load_tsconfig, strip_json_comments},
+    CommentsConfig, Config, JscTarget, Merge, Rc, ReactConfig,
+};
 use serde_json;
+use std::path::Path;
+use swc_ecmascript::transforms::react;
+use testing::Tester;
 
 #[test]
 fn object() {
@@ -10,3 +16,280 @@ fn object() {
 fn array() {
     let _: Rc = serde_json::from_str(include_str!("array.json")).expect("failed to parse");
 }
+
+/// Merges `.swcrc` over `tests/tsconfig/tsconfig.json`.
+fn with_tsconfig(s: &str) -> Config {
+    let rc: Rc = serde_json::from_str(s).expect("failed to parse");
+
+    Tester::new()
+        .print_errors(|_, handler| {
+            let mut config = load_tsconfig(
+                &handler,
+                Path::new("tests/tsconfig/tsconfig.json"),
+                Path::new("a.tsx"),
+            )
+            .unwrap();
+            config.merge(&rc.into_config(None).unwrap());
+
+            Ok(config)
+        })
+        .unwrap()
+}
+
+#[test]
+fn tsconfig_jsx_factory_with_swcrc_react() {
+    let c = with_tsconfig(r#"{ "jsc": { "transform": { "react": { "development": true } } } }"#);
+    let react = c.jsc.transform.unwrap().react;
+
+    assert_eq!(react.pragma.as_deref(), Some("h"));
+    assert_eq!(react.pragma_frag.as_deref(), Some("Fragment"));
+    assert_eq!(react.development, Some(true));
+}
+
+#[test]
+fn tsconfig_target_lowered_by_swcrc() {
+    assert_eq!(with_tsconfig("{}").jsc.target, Some(JscTarget::Es2017));
+    assert_eq!(
+        with_tsconfig(r#"{ "jsc": { "target": "es5" } }"#)
+            .jsc
+            .target,
+        Some(JscTarget::Es5)
+    );
+}
+
+#[test]
+fn tsconfig_decorators_disabled_by_swcrc() {
+    let c = with_tsconfig("{}");
+    assert!(c.jsc.syntax.unwrap().decorators());
+    let transform = c.jsc.transform.unwrap();
+    assert_eq!(transform.legacy_decorator, Some(true));
+    assert_eq!(transform.decorator_metadata, Some(true));
+
+    let c = with_tsconfig(
+        r#"{
+    "jsc": {
+        "parser": { "syntax": "typescript", "decorators": false },
+        "transform": { "legacyDecorator": false, "decoratorMetadata": false }
+    }
+}"#,
+    );
+    let syntax = c.jsc.syntax.unwrap();
+    assert!(syntax.typescript() && !syntax.decorators());
+    let transform = c.jsc.transform.unwrap();
+    assert_eq!(transform.legacy_decorator, Some(false));
+    assert_eq!(transform.decorator_metadata, Some(false));
+}
+
+#[test]
+fn fingerprint_ignores_map_order() {
+    let a: Config = serde_json::from_str(
+        r#"{
+    "jsc": { "transform": { "optimizer": { "globals": {
+        "vars": { "a": "1", "b": "2", "c": "3", "d": "4" },
+        "envs": ["A", "B", "C", "D"]
+    } } } }
+}"#,
+    )
+    .unwrap();
+    let b: Config = serde_json::from_str(
+        r#"{
+    "jsc": { "transform": { "optimizer": { "globals": {
+        "vars": { "d": "4", "c": "3", "b": "2", "a": "1" },
+        "envs": ["D", "C", "B", "A"]
+    } } } }
+}"#,
+    )
+    .unwrap();
+
+    assert_eq!(a.fingerprint(), b.fingerprint());
+    assert_ne!(a.fingerprint(), Config::default().fingerprint());
+}
+
+#[test]
+fn tsconfig_comments_and_trailing_commas() {
+    let s = strip_json_comments(
+        r#"{
+    // line comment
+    "a": "// not a comment", /* block */
+    "b": [1, 2,],
+}"#,
+    );
+    let v: serde_json::Value = serde_json::from_str(&s).expect("failed to parse");
+
+    assert_eq!(v["a"], "// not a comment");
+    assert_eq!(v["b"].as_array().unwrap().len(), 2);
+}
+
+#[test]
+fn envs_and_overrides() {
+    let rc: Rc = serde_json::from_str(
+        r#"{
+    "jsc": { "parser": { "syntax": "ecmascript" } },
+    "envs": {
+        "development": { "jsc": { "transform": { "react": { "development": true } } } },
+        "production": { "minify": true }
+    },
+    "overrides": [
+        {
+            "test": "\\.ts$",
+            "exclude": "\\.d\\.ts$",
+            "jsc": { "parser": { "syntax": "typescript" } },
+            "envs": { "production": { "module": { "type": "commonjs" } } }
+        }
+    ]
+}"#,
+    )
+    .expect("failed to parse");
+
+    let resolve = |env_name: &str, path: &str| {
+        rc.clone()
+            .into_config(Some(Path::new(path)))
+            .unwrap()
+            .resolve(env_name, Some(Path::new(path)))
+            .unwrap()
+    };
+
+    let c = resolve("development", "a.js");
+    assert_eq!(c.jsc.transform.unwrap().react.development, Some(true));
+    assert_eq!(c.minify, None);
+    assert!(!c.jsc.syntax.unwrap().typescript());
+
+    let c = resolve("production", "a.js");
+    assert_eq!(c.minify, Some(true));
+    assert!(c.module.is_none());
+
+    let c = resolve("production", "a.ts");
+    assert!(c.jsc.syntax.unwrap().typescript());
+    assert!(c.module.is_some());
+    assert!(c.envs.is_empty() && c.overrides.is_empty());
+
+    let c = resolve("production", "a.d.ts");
+    assert!(!c.jsc.syntax.unwrap().typescript());
+}
+
+#[test]
+fn envs_override_only_set_fields() {
+    let rc: Rc = serde_json::from_str(
+        r#"{
+    "jsc": {
+        "externalHelpers": true,
+        "transform": { "react": { "pragma": "h", "pragmaFrag": "Fragment" } }
+    },
+    "envs": {
+        "development": { "jsc": { "transform": { "react": { "development": true } } } },
+        "production": { "jsc": { "externalHelpers": false } }
+    }
+}"#,
+    )
+    .expect("failed to parse");
+    let resolve = |env_name: &str| {
+        rc.clone()
+            .into_config(None)
+            .unwrap()
+            .resolve(env_name, None)
+            .unwrap()
+    };
+
+    let c = resolve("development");
+    assert_eq!(c.jsc.external_helpers, Some(true));
+    let react = c.jsc.transform.unwrap().react;
+    assert_eq!(react.development, Some(true));
+    assert_eq!(react.pragma.as_deref(), Some("h"));
+    assert_eq!(react.pragma_frag.as_deref(), Some("Fragment"));
+
+    let c = resolve("production");
+    assert_eq!(c.jsc.external_helpers, Some(false));
+    assert_eq!(c.jsc.transform.unwrap().react.pragma.as_deref(), Some("h"));
+}
+
+#[test]
+fn envs_override_with_default_values() {
+    let rc: Rc = serde_json::from_str(
+        r#"{
+    "jsc": { "transform": { "react": { "development": true, "useBuiltins": true } } },
+    "envs": {
+        "production": { "jsc": { "transform": { "react": { "development": false } } } }
+    }
+}"#,
+    )
+    .expect("failed to parse");
+
+    let c = rc
+        .into_config(None)
+        .unwrap()
+        .resolve("production", None)
+        .unwrap();
+    let react = c.jsc.transform.unwrap().react;
+    assert_eq!(react.development, Some(false));
+    assert_eq!(react.use_builtins, Some(true));
+}
+
+#[test]
+fn react_options_merged_per_field() {
+    let mut base = ReactConfig {
+        pragma: Some("h".into()),
+        development: Some(true),
+        throw_if_namespace: Some(false),
+        ..Default::default()
+    };
+    base.merge(&ReactConfig {
+        development: Some(false),
+        throw_if_namespace: Some(true),
+        ..Default::default()
+    });
+
+    // Values equal to the defaults still override the base.
+    let options = react::Options::from(base);
+    assert_eq!(options.pragma, "h");
+    assert!(!options.development);
+    assert!(options.throw_if_namespace);
+    assert_eq!(options.pragma_frag, react::Options::default().pragma_frag);
+}
+
+#[test]
+fn comments() {
+    let c = |s: &str| -> CommentsConfig { serde_json::from_str(s).expect("failed to parse") };
+
+    assert_eq!(c(r#""all""#), CommentsConfig::All);
+    assert_eq!(c(r#""some""#), CommentsConfig::Some);
+    assert_eq!(c(r#""license""#), CommentsConfig::Some);
+    assert_eq!(c(r#""none""#), CommentsConfig::None);
+    assert_eq!(
+        c(r#""/^\\*\\s*@api/""#),
+        CommentsConfig::regex("^\\*\\s*@api").unwrap()
+    );
+    assert_eq!(
+        c(r#"{ "regex": "@api" }"#),
+        CommentsConfig::regex("@api").unwrap()
+    );
+
+    let err = |s: &str| serde_json::from_str::<CommentsConfig>(s).is_err();
+    assert!(err(r#""license-only""#));
+    assert!(err(r#""@api""#));
+    assert!(err(r#""/(/""#));
+    assert!(err(r#"{ "regex": "@api", "flags": "i" }"#));
+}
+
+#[test]
+fn assumptions() {
+    let rc: Rc = serde_json::from_str(
+        r#"{
+    "jsc": { "assumptions": { "setPublicClassFields": true, "noDocumentAll": true } }
+}"#,
+    )
+    .expect("failed to parse");
+
+    let c = rc
+        .into_config(None)
+        .unwrap()
+        .resolve("production", None)
+        .unwrap();
+    let assumptions = c.jsc.assumptions.unwrap();
+    assert!(assumptions.set_public_class_fields);
+    assert!(assumptions.no_document_all);
+    assert!(!assumptions.iterable_is_array);
+
+    let rc: Result<Rc, _> =
+        serde_json::from_str(r#"{ "jsc": { "assumptions": { "pureGetters": true } } }"#);
+    assert!(rc.is_err());
+}
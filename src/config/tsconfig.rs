@@ -0,0 +1,377 @@
+//! Support for `compilerOptions` of `tsconfig.json`.
+use super::{Config, SourceMapsConfig};
+use anyhow::{Context, Error};
+use serde::Deserialize;
+use serde_json::{json, Map};
+use std::{
+    collections::BTreeMap,
+    fs::read_to_string,
+    path::{Path, PathBuf},
+};
+use swc_common::errors::Handler;
+
+/// `tsconfig.json`
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct TsConfigFile {
+    #[serde(default)]
+    extends: Option<String>,
+
+    #[serde(default)]
+    compiler_options: CompilerOptions,
+}
+
+/// Options not listed here are ignored silently, as most of them are
+/// related to type checking.
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CompilerOptions {
+    #[serde(default)]
+    target: Option<String>,
+
+    #[serde(default)]
+    jsx: Option<String>,
+
+    #[serde(default)]
+    jsx_factory: Option<String>,
+
+    #[serde(default)]
+    jsx_fragment_factory: Option<String>,
+
+    #[serde(default)]
+    experimental_decorators: Option<bool>,
+
+    #[serde(default)]
+    emit_decorator_metadata: Option<bool>,
+
+    #[serde(default)]
+    module: Option<String>,
+
+    #[serde(default)]
+    es_module_interop: Option<bool>,
+
+    /// Resolved against the directory of the file declaring it.
+    #[serde(default)]
+    base_url: Option<PathBuf>,
+
+    #[serde(default)]
+    paths: Option<BTreeMap<String, Vec<String>>>,
+
+    /// Directory of the file declaring `paths`, which are resolved against it
+    /// if `baseUrl` is not set.
+    #[serde(skip)]
+    paths_dir: Option<PathBuf>,
+
+    #[serde(default)]
+    source_map: Option<bool>,
+
+    #[serde(default)]
+    inline_source_map: Option<bool>,
+}
+
+impl CompilerOptions {
+    /// Options of `self` have priority over options of `parent`.
+    fn inherit(&mut self, parent: CompilerOptions) {
+        macro_rules! inherit {
+            ($($field:ident),*) => {
+                $(
+                    if self.$field.is_none() {
+                        self.$field = parent.$field;
+                    }
+                )*
+            };
+        }
+
+        inherit!(
+            target,
+            jsx,
+            jsx_factory,
+            jsx_fragment_factory,
+            experimental_decorators,
+            emit_decorator_metadata,
+            module,
+            es_module_interop,
+            base_url,
+            paths,
+            paths_dir,
+            source_map,
+            inline_source_map
+        );
+    }
+}
+
+/// Finds `tsconfig.json` for `filename`, searching upward until `root`.
+pub(crate) fn find_tsconfig(filename: &Path, root: Option<&Path>) -> Option<PathBuf> {
+    let mut parent = filename.parent();
+    while let Some(dir) = parent {
+        let tsconfig = dir.join("tsconfig.json");
+        if tsconfig.is_file() {
+            return Some(tsconfig);
+        }
+
+        if Some(dir) == root {
+            break;
+        }
+        parent = dir.parent();
+    }
+
+    None
+}
+
+/// Loads `tsconfig.json` at `path`, following `extends`.
+///
+/// Options which cannot be honored by swc are reported as warnings.
+pub(crate) fn load_tsconfig(
+    handler: &Handler,
+    path: &Path,
+    filename: &Path,
+) -> Result<Config, Error> {
+    let mut opts = CompilerOptions::default();
+    let mut visited = vec![];
+    let mut next = Some(path.to_path_buf());
+
+    while let Some(path) = next.take() {
+        if visited.contains(&path) {
+            anyhow::bail!("tsconfig.json at {} extends itself", path.display());
+        }
+
+        let file = read_tsconfig(&path)?;
+        let dir = path.parent().unwrap_or_else(|| Path::new(""));
+
+        let mut compiler_options = file.compiler_options;
+        compiler_options.base_url = compiler_options.base_url.map(|p| dir.join(p));
+        if compiler_options.paths.is_some() {
+            compiler_options.paths_dir = Some(dir.to_path_buf());
+        }
+        opts.inherit(compiler_options);
+
+        next = match file.extends {
+            Some(extends) => Some(resolve_extends(dir, &extends).with_context(|| {
+                format!(
+                    "failed to resolve `extends` of tsconfig.json at {}",
+                    path.display()
+                )
+            })?),
+            None => None,
+        };
+        visited.push(path);
+    }
+
+    opts.into_config(handler, filename)
+}
+
+fn read_tsconfig(path: &Path) -> Result<TsConfigFile, Error> {
+    let content = read_to_string(path)
+        .with_context(|| format!("failed to read tsconfig.json at {}", path.display()))?;
+
+    serde_json::from_str(&strip_json_comments(&content))
+        .with_context(|| format!("failed to deserialize tsconfig.json at {}", path.display()))
+}
+
+/// Resolves a relative path or a path in `node_modules`.
+fn resolve_extends(dir: &Path, extends: &str) -> Result<PathBuf, Error> {
+    let with_ext = |path: PathBuf| {
+        if path.is_file() {
+            return Some(path);
+        }
+        let path = path.with_file_name(format!("{}.json", path.file_name()?.to_string_lossy()));
+        if path.is_file() {
+            return Some(path);
+        }
+        None
+    };
+
+    if extends.starts_with("./") || extends.starts_with("../") || Path::new(extends).is_absolute() {
+        return with_ext(dir.join(extends))
+            .ok_or_else(|| Error::msg(format!("file not found: {}", extends)));
+    }
+
+    let mut parent = Some(dir);
+    while let Some(dir) = parent {
+        let pkg = dir.join("node_modules").join(extends);
+        if let Some(path) = with_ext(pkg.clone()) {
+            return Ok(path);
+        }
+        let path = pkg.join("tsconfig.json");
+        if path.is_file() {
+            return Ok(path);
+        }
+
+        parent = dir.parent();
+    }
+
+    Err(Error::msg(format!("package not found: {}", extends)))
+}
+
+/// `tsconfig.json` allows comments and trailing commas.
+pub(super) fn strip_json_comments(s: &str) -> String {
+    let mut buf = String::with_capacity(s.len());
+    let mut chars = s.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '"' => {
+                buf.push(c);
+                while let Some(c) = chars.next() {
+                    buf.push(c);
+                    match c {
+                        '\\' => {
+                            if let Some(c) = chars.next() {
+                                buf.push(c);
+                            }
+                        }
+                        '"' => break,
+                        _ => {}
+                    }
+                }
+            }
+            '/' if chars.peek() == Some(&'/') => {
+                while let Some(&c) = chars.peek() {
+                    if c == '\n' {
+                        break;
+                    }
+                    chars.next();
+                }
+            }
+            '/' if chars.peek() == Some(&'*') => {
+                chars.next();
+                let mut prev = '\0';
+                while let Some(c) = chars.next() {
+                    if prev == '*' && c == '/' {
+                        break;
+                    }
+                    prev = c;
+                }
+            }
+            ',' => {
+                // Drop trailing comma
+                let rest = chars.clone().skip_while(|c| c.is_whitespace()).next();
+                match rest {
+                    Some('}') | Some(']') => {}
+                    _ => buf.push(c),
+                }
+            }
+            _ => buf.push(c),
+        }
+    }
+
+    buf
+}
+
+impl CompilerOptions {
+    /// The returned config is the base `.swcrc` is merged over, so options
+    /// not set in tsconfig.json are left unset.
+    fn into_config(self, handler: &Handler, filename: &Path) -> Result<Config, Error> {
+        let warn = |msg: String| handler.warn(&format!("tsconfig.json: {}", msg));
+
+        let mut config = Map::new();
+        let mut jsc = Map::new();
+        let mut transform = Map::new();
+        let mut react = Map::new();
+
+        if let Some(target) = self.target {
+            let t = match &*target.to_ascii_lowercase() {
+                "es3" => "es3",
+                "es5" => "es5",
+                "es6" | "es2015" => "es2015",
+                "es2016" => "es2016",
+                "es2017" => "es2017",
+                "es2018" => "es2018",
+                "es2019" => "es2019",
+                _ => {
+                    warn(format!(
+                        "target `{}` is not supported; using es2019 instead",
+                        target
+                    ));
+                    "es2019"
+                }
+            };
+            jsc.insert("target".into(), t.into());
+        }
+
+        let ext = filename
+            .extension()
+            .map(|ext| ext.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        if ext == "ts" || ext == "tsx" {
+            let mut parser = Map::new();
+            parser.insert("syntax".into(), "typescript".into());
+            parser.insert("tsx".into(), (ext == "tsx").into());
+            if let Some(decorators) = self.experimental_decorators {
+                parser.insert("decorators".into(), decorators.into());
+            }
+            jsc.insert("parser".into(), parser.into());
+        }
+
+        if let Some(jsx) = self.jsx {
+            match &*jsx {
+                "react" => {}
+                _ => warn(format!(
+                    "jsx `{}` is not supported; jsx will be transformed using `react`",
+                    jsx
+                )),
+            }
+        }
+        if let Some(pragma) = self.jsx_factory {
+            react.insert("pragma".into(), pragma.into());
+        }
+        if let Some(pragma_frag) = self.jsx_fragment_factory {
+            react.insert("pragmaFrag".into(), pragma_frag.into());
+        }
+        if !react.is_empty() {
+            transform.insert("react".into(), react.into());
+        }
+
+        if let Some(decorators) = self.experimental_decorators {
+            transform.insert("legacyDecorator".into(), decorators.into());
+        }
+        if let Some(metadata) = self.emit_decorator_metadata {
+            transform.insert("decoratorMetadata".into(), metadata.into());
+        }
+        if !transform.is_empty() {
+            jsc.insert("transform".into(), transform.into());
+        }
+
+        if let Some(module) = self.module {
+            let no_interop = !self.es_module_interop.unwrap_or(false);
+
+            match &*module.to_ascii_lowercase() {
+                ty @ "commonjs" | ty @ "amd" | ty @ "umd" => {
+                    config.insert(
+                        "module".into(),
+                        json!({ "type": ty, "noInterop": no_interop }),
+                    );
+                }
+                "es6" | "es2015" | "es2020" | "esnext" | "none" => {}
+                _ => warn(format!("module `{}` is not supported", module)),
+            }
+        }
+
+        if let Some(ref base_url) = self.base_url {
+            jsc.insert("baseUrl".into(), json!(base_url.to_string_lossy()));
+        }
+        if let Some(mut paths) = self.paths {
+            // `.swcrc` resolves `paths` against the root directory, while tsc
+            // resolves them against the directory of tsconfig.json.
+            if let (None, Some(dir)) = (&self.base_url, &self.paths_dir) {
+                for target in paths.values_mut().flatten() {
+                    *target = dir.join(&*target).to_string_lossy().into_owned();
+                }
+            }
+            jsc.insert("paths".into(), json!(paths));
+        }
+
+        config.insert("jsc".into(), jsc.into());
+
+        let mut config: Config = serde_json::from_value(config.into())
+            .context("failed to convert compilerOptions of tsconfig.json")?;
+
+        config.source_maps = if self.inline_source_map == Some(true) {
+            Some(SourceMapsConfig::Str("inline".into()))
+        } else {
+            self.source_map.map(SourceMapsConfig::Bool)
+        };
+
+        Ok(config)
+    }
+}
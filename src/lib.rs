@@ -5,13 +5,18 @@ pub use swc_atoms as atoms;
 pub use swc_common as common;
 pub use swc_ecmascript as ecmascript;
 
+pub mod binary;
 mod builder;
+pub mod cache;
 pub mod config;
 
 pub use crate::builder::PassBuilder;
-use crate::config::{
-    BuiltConfig, Config, ConfigFile, InputSourceMap, JscTarget, Merge, Options, Rc, RootMode,
-    SourceMapsConfig,
+use crate::{
+    cache::{CacheKey, TransformCache},
+    config::{
+        find_tsconfig, load_tsconfig, BuiltConfig, CacheConfig, Config, ConfigFile, InputSourceMap,
+        JscTarget, Merge, Options, Rc, RootMode, SourceMapsConfig,
+    },
 };
 use anyhow::{Context, Error};
 use common::{
@@ -22,10 +27,11 @@ use common::{
 use ecmascript::{
     ast::Program,
     codegen::{self, Emitter},
+    lints,
     parser::{lexer::Lexer, Parser, Session as ParseSess, Syntax},
     transforms::{
         helpers::{self, Helpers},
-        util,
+        instrument, util,
         util::COMMENTS,
     },
 };
@@ -33,12 +39,13 @@ pub use ecmascript::{
     parser::SourceFileInput,
     transforms::{chain_at, pass::Pass},
 };
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use serde_json::error::Category;
 use std::{
+    collections::HashMap,
     fs::{read_to_string, File},
     path::{Path, PathBuf},
-    sync::Arc,
+    sync::{Arc, Mutex},
 };
 
 pub struct Compiler {
@@ -50,9 +57,11 @@ pub struct Compiler {
     pub cm: Arc<SourceMap>,
     pub handler: Handler,
     comments: Comments,
+    /// Reused so that the number of bytes written is tracked across calls.
+    caches: Mutex<HashMap<PathBuf, TransformCache>>,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct TransformOutput {
     pub code: String,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -189,7 +198,7 @@ impl Compiler {
         comments: &Comments,
         source_map: SourceMapsConfig,
         orig: Option<&sourcemap::SourceMap>,
-        minify: bool,
+        cfg: codegen::Config,
     ) -> Result<TransformOutput, Error> {
         self.run(|| {
             let mut src_map_buf = vec![];
@@ -199,7 +208,7 @@ impl Compiler {
                 {
                     let handlers = box MyHandlers;
                     let mut emitter = Emitter {
-                        cfg: codegen::Config { minify },
+                        cfg,
                         comments: Some(&comments),
                         cm: self.cm.clone(),
                         wr: box codegen::text_writer::JsWriter::new(
@@ -218,6 +227,7 @@ impl Compiler {
                     emitter
                         .emit_program(&program)
                         .context("failed to emit module")?;
+                    emitter.wr.finish().context("failed to write output")?;
                 }
                 // Invalid utf8 is valid in javascript world.
                 unsafe { String::from_utf8_unchecked(buf) }
@@ -271,6 +281,7 @@ impl Compiler {
             handler,
             globals: Globals::new(),
             comments: Default::default(),
+            caches: Default::default(),
         }
     }
 
@@ -287,6 +298,8 @@ impl Compiler {
                 swcrc,
                 config_file,
                 is_module,
+                tsconfig,
+                ref env_name,
                 ..
             } = opts;
             let root = root.clone().unwrap_or_else(|| {
@@ -301,6 +314,36 @@ impl Compiler {
                 Some(ConfigFile::Str(ref s)) => Some(load_swcrc(Path::new(&s))?),
                 _ => None,
             };
+            // Selects a config for the file and applies `envs` and `overrides` of it.
+            let into_config = |rc: Rc, path: Option<&Path>| -> Result<Config, Error> {
+                rc.into_config(path)?.resolve(env_name, path)
+            };
+
+            let tsconfig = match (tsconfig, name) {
+                (Some(ConfigFile::Bool(true)), FileName::Real(ref path)) => {
+                    let root = if *root_mode == RootMode::Root {
+                        Some(root.as_path())
+                    } else {
+                        None
+                    };
+                    find_tsconfig(path, root)
+                        .map(|tsconfig| load_tsconfig(&self.handler, &tsconfig, path))
+                        .transpose()?
+                }
+                (Some(ConfigFile::Str(ref s)), FileName::Real(ref path)) => {
+                    Some(load_tsconfig(&self.handler, Path::new(s), path)?)
+                }
+                _ => None,
+            };
+            // Values from tsconfig.json are used only if .swcrc does not set them.
+            let with_tsconfig = |config: Config| match tsconfig {
+                Some(ref tsconfig) => {
+                    let mut tsconfig = tsconfig.clone();
+                    tsconfig.merge(&config);
+                    tsconfig
+                }
+                None => config,
+            };
 
             match name {
                 FileName::Real(ref path) => {
@@ -312,15 +355,20 @@ impl Compiler {
                             if swcrc.exists() {
                                 let config = load_swcrc(&swcrc)?;
 
-                                let mut config = config
-                                    .into_config(Some(path))
+                                let config = into_config(config, Some(path))
                                     .context("failed to process config file")?;
+                                let mut config = with_tsconfig(config);
 
                                 if let Some(config_file) = config_file {
-                                    config.merge(&config_file.into_config(Some(path))?)
+                                    config.merge(&into_config(config_file, Some(path))?);
                                 }
-                                let built =
-                                    opts.build(&self.cm, &self.handler, *is_module, Some(config));
+                                let built = opts.build(
+                                    &self.cm,
+                                    &self.handler,
+                                    *is_module,
+                                    Some(path.as_path()),
+                                    Some(config),
+                                );
                                 return Ok(built);
                             }
 
@@ -331,12 +379,29 @@ impl Compiler {
                         }
                     }
 
-                    let config_file = config_file.unwrap_or_else(|| Rc::default());
+                    let config = match config_file {
+                        Some(config_file) => with_tsconfig(into_config(config_file, Some(path))?),
+                        None => {
+                            let config = Rc::default().into_config(Some(path))?;
+                            match tsconfig {
+                                // The default config only selects the parser.
+                                Some(ref tsconfig) => {
+                                    let mut tsconfig = tsconfig.clone();
+                                    if tsconfig.jsc.syntax.is_none() {
+                                        tsconfig.jsc.syntax = config.jsc.syntax;
+                                    }
+                                    tsconfig
+                                }
+                                None => config,
+                            }
+                        }
+                    };
                     let built = opts.build(
                         &self.cm,
                         &self.handler,
                         *is_module,
-                        Some(config_file.into_config(Some(path))?),
+                        Some(path.as_path()),
+                        Some(config),
                     );
                     return Ok(built);
                 }
@@ -347,8 +412,9 @@ impl Compiler {
                 &self.cm,
                 &self.handler,
                 *is_module,
+                None,
                 match config_file {
-                    Some(config_file) => Some(config_file.into_config(None)?),
+                    Some(config_file) => Some(into_config(config_file, None)?),
                     None => Some(Rc::default().into_config(None)?),
                 },
             );
@@ -365,20 +431,67 @@ impl Compiler {
     ) -> Result<TransformOutput, Error> {
         self.run(|| -> Result<_, Error> {
             let config = self.run(|| self.config_for_file(opts, &fm.name))?;
+
+            // Diagnostics of lint rules are not cached.
+            let cache = opts
+                .cache
+                .as_ref()
+                .filter(|_| config.lint.is_none())
+                .and_then(|c| Some((self.transform_cache(c), CacheKey::new(&fm, &config)?)));
+            if let Some((ref cache, key)) = cache {
+                if let Some(output) = cache.get(&key) {
+                    return Ok(output);
+                }
+            }
+
+            // The handler is shared by all files.
+            let err_count = self.handler.err_count();
+
+            // Instrumentation reads `istanbul ignore` comments even if they are
+            // not printed.
+            let parse_comments = config.instrument.is_some()
+                || config
+                    .comments
+                    .as_ref()
+                    .map(|c| c.needs_comments())
+                    .unwrap_or(true);
             let (program, src_map) = self.parse_js(
                 fm.clone(),
                 config.target,
                 config.syntax,
                 config.is_module,
-                true,
+                parse_comments,
                 &config.input_source_map,
             )?;
 
-            self.process_js_inner(program, src_map, config)
+            let output = self.process_js_inner(program, src_map, config)?;
+
+            if let Some((ref cache, key)) = cache {
+                if self.handler.err_count() == err_count {
+                    if let Err(err) = cache.put(&key, &output) {
+                        log::warn!("failed to store output in cache: {:?}", err);
+                    }
+                }
+            }
+
+            Ok(output)
         })
         .context("failed to process js file")
     }
 
+    fn transform_cache(&self, config: &CacheConfig) -> TransformCache {
+        let mut caches = self.caches.lock().unwrap();
+
+        match caches.get(&config.dir) {
+            Some(cache) if cache.max_size() == config.max_size => cache.clone(),
+            _ => {
+                let cache = TransformCache::new(config);
+                caches.insert(config.dir.clone(), cache.clone());
+                cache
+            }
+        }
+    }
+
     /// You can use custom pass with this method.
     ///
     /// There exists a [PassBuilder] to help building custom passes.
@@ -406,7 +519,25 @@ impl Compiler {
         config: BuiltConfig<impl Pass>,
     ) -> Result<TransformOutput, Error> {
         self.run(|| {
-            if config.minify {
+            if let Some(ref lint) = config.lint {
+                lints::lint(&program, lint, &self.handler);
+            }
+
+            // Instrumentation depends on `istanbul ignore` comments and the
+            // original locations.
+            let program = match config.instrument {
+                Some(ref c) => program.fold_with(&mut instrument(
+                    self.cm.clone(),
+                    &self.comments,
+                    src_map.as_ref(),
+                    c.clone(),
+                )),
+                None => program,
+            };
+
+            if let Some(ref c) = config.comments {
+                c.retain(&self.comments);
+            } else if config.minify {
                 let preserve_excl = |_: &BytePos, vc: &mut Vec<Comment>| -> bool {
                     vc.retain(|c: &Comment| c.text.starts_with("!"));
                     !vc.is_empty()
@@ -414,6 +545,7 @@ impl Compiler {
                 self.comments.retain_leading(preserve_excl);
                 self.comments.retain_trailing(preserve_excl);
             }
+
             let mut pass = config.pass;
             let program = helpers::HELPERS.set(&Helpers::new(config.external_helpers), || {
                 util::HANDLER.set(&self.handler, || {
@@ -427,7 +559,7 @@ impl Compiler {
                 &self.comments,
                 config.source_maps,
                 src_map.as_ref(),
-                config.minify,
+                config.codegen,
             )
         })
     }
@@ -0,0 +1,117 @@
+use swc::{
+    binary::{decode_program, encode_program},
+    common::FileName,
+    config::{InputSourceMap, JscTarget},
+    ecmascript::{
+        ast::Program,
+        parser::{EsConfig, Syntax, TsConfig},
+    },
+    Compiler,
+};
+use testing::Tester;
+
+fn parse(src: &str, syntax: Syntax) -> Program {
+    Tester::new()
+        .print_errors(|cm, handler| {
+            let c = Compiler::new(cm.clone(), handler);
+            let fm = cm.new_source_file(FileName::Anon, src.into());
+            let (program, _) = c
+                .parse_js(
+                    fm,
+                    JscTarget::Es2019,
+                    syntax,
+                    true,
+                    false,
+                    &InputSourceMap::Bool(false),
+                )
+                .unwrap();
+            Ok(program)
+        })
+        .unwrap()
+}
+
+fn round_trip(src: &str, syntax: Syntax) {
+    let program = parse(src, syntax);
+
+    let bytes = encode_program(&program).unwrap();
+    let decoded = decode_program(&bytes).unwrap();
+    assert_eq!(program, decoded);
+
+    // The encoding must not depend on how the program was built.
+    assert_eq!(bytes, encode_program(&decoded).unwrap());
+}
+
+#[test]
+fn round_trip_es() {
+    round_trip(
+        r#"
+        import React, { Component as C } from 'react';
+        export * from './a';
+
+        const big = 1n, num = 0.5, neg = -1, large = 4294967296, re = /a+/gi;
+        let { a, b: [c, ...d] = [] } = obj;
+
+        export default class A extends C {
+            static foo = 1;
+            #bar = 'bar';
+
+            async *gen(x = 1, ...rest) {
+                yield* rest;
+                await x?.y ?? null;
+                return `tpl ${x} end`;
+            }
+        }
+
+        label: for (const x of y) {
+            if (x) continue label; else break;
+        }
+        "#,
+        Syntax::Es(EsConfig {
+            class_private_props: true,
+            class_props: true,
+            nullish_coalescing: true,
+            optional_chaining: true,
+            ..Default::default()
+        }),
+    );
+}
+
+#[test]
+fn round_trip_jsx() {
+    round_trip(
+        "const el = <div a='b' {...props}><A.B />{child}text</div>;",
+        Syntax::Es(EsConfig {
+            jsx: true,
+            ..Default::default()
+        }),
+    );
+}
+
+#[test]
+fn round_trip_ts() {
+    round_trip(
+        r#"
+        enum E { A = 1, B }
+        interface I<T> extends J { a?: T; readonly [k: string]: unknown }
+        type U = 'a' | { b: number }[] | ((x: I<string>) => void);
+        declare module 'm' { export const x: number }
+        function f<T extends object>(this: Window, x: T): x is T { return !!(x as any)!; }
+        "#,
+        Syntax::Typescript(TsConfig {
+            ..Default::default()
+        }),
+    );
+}
+
+#[test]
+fn invalid_input() {
+    let program = parse("foo(1, 'a')", Default::default());
+    let bytes = encode_program(&program).unwrap();
+
+    assert!(decode_program(b"JSON").is_err());
+    assert!(decode_program(&bytes[..bytes.len() - 1]).is_err());
+
+    let mut trailing = bytes.clone();
+    trailing.push(0);
+    assert!(decode_program(&trailing).is_err());
+}
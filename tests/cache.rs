@@ -0,0 +1,187 @@
+use std::{fs, path::Path};
+use swc::{
+    cache::{CacheKey, TransformCache},
+    config::{CacheConfig, Config, InputSourceMap, JscConfig, Options},
+    Compiler,
+};
+use testing::Tester;
+
+#[test]
+fn reuse_output() {
+    let dir = tempfile::tempdir().unwrap();
+    let cache = CacheConfig {
+        dir: dir.path().to_path_buf(),
+        max_size: 1024 * 1024,
+    };
+
+    Tester::new()
+        .print_errors(|cm, handler| {
+            let c = Compiler::new(cm.clone(), handler);
+            let opts = Options {
+                swcrc: true,
+                is_module: true,
+                cache: Some(cache.clone()),
+                ..Default::default()
+            };
+
+            let fm = cm
+                .load_file(Path::new("tests/cache/input.js"))
+                .expect("failed to load file");
+            let first = c.process_js_file(fm.clone(), &opts).unwrap();
+
+            let config = c.config_for_file(&opts, &fm.name).unwrap();
+            let key = CacheKey::new(&fm, &config).unwrap();
+            let cached = TransformCache::new(&cache)
+                .get(&key)
+                .expect("output should be cached");
+            assert_eq!(first.code, cached.code);
+
+            let second = c.process_js_file(fm.clone(), &opts).unwrap();
+            assert_eq!(first.code, second.code);
+
+            TransformCache::new(&cache).invalidate(&key).unwrap();
+            assert!(TransformCache::new(&cache).get(&key).is_none());
+
+            Ok(())
+        })
+        .unwrap();
+}
+
+#[test]
+fn evict_old_entries() {
+    let dir = tempfile::tempdir().unwrap();
+    let cache = CacheConfig {
+        dir: dir.path().to_path_buf(),
+        max_size: 0,
+    };
+
+    Tester::new()
+        .print_errors(|cm, handler| {
+            let c = Compiler::new(cm.clone(), handler);
+            let opts = Options {
+                swcrc: true,
+                is_module: true,
+                cache: Some(cache.clone()),
+                ..Default::default()
+            };
+
+            let fm = cm
+                .load_file(Path::new("tests/cache/input.js"))
+                .expect("failed to load file");
+            c.process_js_file(fm.clone(), &opts).unwrap();
+
+            let config = c.config_for_file(&opts, &fm.name).unwrap();
+            let key = CacheKey::new(&fm, &config).unwrap();
+            assert!(TransformCache::new(&cache).get(&key).is_none());
+
+            Ok(())
+        })
+        .unwrap();
+}
+
+#[test]
+fn errors_of_other_files_are_ignored() {
+    let dir = tempfile::tempdir().unwrap();
+    let cache = CacheConfig {
+        dir: dir.path().to_path_buf(),
+        max_size: 1024 * 1024,
+    };
+
+    Tester::new()
+        .print_errors(|cm, handler| {
+            let c = Compiler::new(cm.clone(), handler);
+            let opts = Options {
+                swcrc: true,
+                is_module: true,
+                cache: Some(cache.clone()),
+                ..Default::default()
+            };
+
+            // Reported while processing another file.
+            c.handler.err("error from another file");
+
+            let fm = cm
+                .load_file(Path::new("tests/cache/input.js"))
+                .expect("failed to load file");
+            c.process_js_file(fm.clone(), &opts).unwrap();
+
+            let config = c.config_for_file(&opts, &fm.name).unwrap();
+            let key = CacheKey::new(&fm, &config).unwrap();
+            assert!(TransformCache::new(&cache).get(&key).is_some());
+
+            Ok(())
+        })
+        .unwrap();
+}
+
+#[test]
+fn skip_cache_when_resolving_paths() {
+    let dir = tempfile::tempdir().unwrap();
+    let cache = CacheConfig {
+        dir: dir.path().to_path_buf(),
+        max_size: 1024 * 1024,
+    };
+
+    Tester::new()
+        .print_errors(|cm, handler| {
+            let c = Compiler::new(cm.clone(), handler);
+            let opts = Options {
+                swcrc: true,
+                is_module: true,
+                cache: Some(cache.clone()),
+                config: Some(Config {
+                    jsc: JscConfig {
+                        base_url: Some(".".into()),
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                }),
+                ..Default::default()
+            };
+
+            let fm = cm
+                .load_file(Path::new("tests/cache/input.js"))
+                .expect("failed to load file");
+            c.process_js_file(fm.clone(), &opts).unwrap();
+
+            let config = c.config_for_file(&opts, &fm.name).unwrap();
+            assert_eq!(CacheKey::new(&fm, &config), None);
+            assert_eq!(fs::read_dir(dir.path()).unwrap().count(), 0);
+
+            Ok(())
+        })
+        .unwrap();
+}
+
+#[test]
+fn input_source_map_file_is_hashed() {
+    let dir = tempfile::tempdir().unwrap();
+    let input = dir.path().join("input.js");
+    fs::copy("tests/cache/input.js", &input).unwrap();
+
+    Tester::new()
+        .print_errors(|cm, handler| {
+            let c = Compiler::new(cm.clone(), handler);
+            let opts = Options {
+                swcrc: false,
+                is_module: true,
+                input_source_map: InputSourceMap::Bool(true),
+                ..Default::default()
+            };
+
+            let fm = cm.load_file(&input).expect("failed to load file");
+            let config = c.config_for_file(&opts, &fm.name).unwrap();
+            let key = |map: &str| {
+                fs::write(dir.path().join("input.js.map"), map).unwrap();
+                CacheKey::new(&fm, &config).unwrap()
+            };
+
+            assert_ne!(
+                key(r#"{"version":3,"sources":["a.js"],"names":[],"mappings":""}"#),
+                key(r#"{"version":3,"sources":["b.js"],"names":[],"mappings":""}"#)
+            );
+
+            Ok(())
+        })
+        .unwrap();
+}
@@ -1,10 +1,13 @@
 use rayon::prelude::*;
 use std::path::Path;
 use swc::{
-    config::{Config, Options, SourceMapsConfig},
+    config::{CommentsConfig, Config, ConfigFile, JscConfig, JscTarget, Options, SourceMapsConfig},
     Compiler,
 };
-use swc_ecmascript::preset_env;
+use swc_ecmascript::{
+    parser::{EsConfig, Syntax},
+    preset_env,
+};
 use testing::{NormalizedOutput, StdErr, Tester};
 use walkdir::WalkDir;
 
@@ -495,6 +498,31 @@ fn issue_783_core_js_3() {
     );
 }
 
+#[test]
+fn pure_ignores_shadowed_globals() {
+    let f = file_with_opt(
+        "tests/projects/pure-shadowed/input.js",
+        Options {
+            swcrc: false,
+            config: Some(Config {
+                env: Some(preset_env::Config {
+                    core_js: Some("3".parse().unwrap()),
+                    mode: Some(preset_env::Mode::Pure),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            }),
+            ..Default::default()
+        },
+    )
+    .unwrap();
+    println!("{}", f);
+
+    assert!(f.contains("wrap(_Promise)"));
+    assert!(f.contains(".resolve(1)"));
+    assert!(!f.contains("_Promise$resolve"));
+}
+
 #[test]
 fn issue_801() {
     let f = file("tests/projects/issue-801/input.ts").unwrap();
@@ -502,3 +530,140 @@ fn issue_801() {
 
     assert!(!f.contains("function delete"));
 }
+
+#[test]
+fn tsconfig() {
+    let f = file_with_opt(
+        "tests/projects/tsconfig/input.ts",
+        Options {
+            swcrc: false,
+            tsconfig: Some(ConfigFile::Bool(true)),
+            ..Default::default()
+        },
+    )
+    .unwrap();
+    println!("{}", f);
+
+    assert!(f.contains("_interopRequireDefault(require('foo'))"));
+    assert!(f.contains("async"));
+    assert!(f.contains("_decorate") || f.contains("dec("));
+}
+
+#[test]
+fn tsconfig_paths_without_base_url() {
+    let opts = || Options {
+        swcrc: false,
+        tsconfig: Some(ConfigFile::Bool(true)),
+        ..Default::default()
+    };
+
+    // `paths` are resolved against the directory of tsconfig.json, not
+    // against the importing file.
+    let a = file_with_opt("tests/projects/tsconfig-paths/src/a.ts", opts()).unwrap();
+    println!("{}", a);
+    assert!(a.contains("'../lib/foo'"));
+
+    let b = file_with_opt("tests/projects/tsconfig-paths/src/nested/b.ts", opts()).unwrap();
+    println!("{}", b);
+    assert!(b.contains("'../../lib/foo'"));
+}
+
+fn comments_with(comments: Option<CommentsConfig>) -> NormalizedOutput {
+    file_with_opt(
+        "tests/projects/comments/input.js",
+        Options {
+            swcrc: false,
+            config: Some(Config {
+                jsc: JscConfig {
+                    target: Some(JscTarget::Es5),
+                    comments,
+                    ..Default::default()
+                },
+                ..Default::default()
+            }),
+            ..Default::default()
+        },
+    )
+    .unwrap()
+}
+
+#[test]
+fn comments_all() {
+    let f = comments_with(None);
+    println!("{}", f);
+
+    assert!(f.contains("// Not a license."));
+    // compat::es2015 moves JSDoc to the generated code.
+    let doc = "/** Creates a Foo. */";
+    let pos = f
+        .find(doc)
+        .expect("comment of constructor should be preserved");
+    assert!(f[pos + doc.len()..]
+        .trim_start()
+        .starts_with("function Foo("));
+    assert!(f.contains("/** Returns bar. */"));
+    assert!(f.contains("/** The baz. */"));
+    assert!(f.contains("/** Sets baz. */"));
+}
+
+#[test]
+fn comments_some() {
+    let f = comments_with(Some(CommentsConfig::Some));
+    println!("{}", f);
+
+    assert!(f.contains("/*! Copyright (c) foo */"));
+    assert!(f.contains("/** @license MIT */"));
+    assert!(!f.contains("Not a license"));
+    assert!(!f.contains("Returns bar"));
+}
+
+#[test]
+fn comments_none() {
+    let f = comments_with(Some(CommentsConfig::None));
+    println!("{}", f);
+
+    assert!(!f.contains("/*"));
+    assert!(!f.contains("//"));
+}
+
+#[test]
+fn comments_regex() {
+    let f = comments_with(Some(CommentsConfig::regex("baz").unwrap()));
+    println!("{}", f);
+
+    assert!(f.contains("/** The baz. */"));
+    assert!(f.contains("/** Sets baz. */"));
+    assert!(!f.contains("@license"));
+}
+
+#[test]
+fn logical_assignments_es5() {
+    let f = file_with_opt(
+        "tests/projects/logical-assignments/input.js",
+        Options {
+            swcrc: false,
+            config: Some(Config {
+                jsc: JscConfig {
+                    syntax: Some(Syntax::Es(EsConfig {
+                        class_private_props: true,
+                        class_props: true,
+                        ..Default::default()
+                    })),
+                    target: Some(JscTarget::Es5),
+                    ..Default::default()
+                },
+                ..Default::default()
+            }),
+            ..Default::default()
+        },
+    )
+    .unwrap();
+    println!("{}", f);
+
+    assert!(!f.contains("??"));
+    assert!(!f.contains("||="));
+    assert!(!f.contains("&&="));
+    assert!(!f.contains("#"));
+    assert!(f.contains("_classPrivateFieldSet"));
+    assert!(f.contains("_classStaticPrivateFieldSpecSet"));
+}
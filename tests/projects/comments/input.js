@@ -0,0 +1,21 @@
+/*! Copyright (c) foo */
+/** @license MIT */
+
+// Not a license.
+export default class Foo {
+    /** Creates a Foo. */
+    constructor() {}
+
+    /** Returns bar. */
+    static bar() {
+        return 1;
+    }
+
+    /** The baz. */
+    get baz() {
+        return 2;
+    }
+
+    /** Sets baz. */
+    set baz(v) {}
+}
@@ -0,0 +1,18 @@
+class Base {
+  get x() {
+    return 1;
+  }
+}
+
+export class Foo extends Base {
+  #a = null;
+  static #b;
+
+  run(obj) {
+    this.#a ??= obj.a;
+    obj.foo.#a ||= 1;
+    Foo.#b &&= 2;
+    super.x ??= 3;
+    return this.#a;
+  }
+}
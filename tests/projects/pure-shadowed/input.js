@@ -0,0 +1,5 @@
+function wrap(Promise) {
+    return Promise.resolve(1);
+}
+
+wrap(Promise);
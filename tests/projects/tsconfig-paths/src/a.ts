@@ -0,0 +1,3 @@
+import { foo } from '@lib/foo';
+
+export const a = foo;
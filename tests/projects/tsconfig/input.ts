@@ -0,0 +1,8 @@
+import foo from 'foo';
+
+function dec(target: any) {}
+
+@dec
+class Foo {}
+
+export const bar = async (): Promise<number> => foo;